@@ -0,0 +1,51 @@
+//! 빌드 시점에 잠금 파일(Cargo.lock)에서 pqcrypto 백엔드 크레이트 버전을 읽어
+//! `KEYPAIRER_BACKEND_VERSIONS` 환경 변수(`이름=버전;...`)로 전달합니다.
+//! `custom-entropy` 기능을 켰는데 getrandom 사용자 백엔드 설정이 없으면 경고합니다.
+use std::env;
+use std::fs;
+use std::path::PathBuf;
+
+fn main() {
+    let manifest_dir = PathBuf::from(env::var("CARGO_MANIFEST_DIR").unwrap_or_default());
+
+    // 워크스페이스에 포함된 경우를 위해 상위 디렉토리까지 잠금 파일을 탐색
+    let lock = manifest_dir
+        .ancestors()
+        .map(|dir| dir.join("Cargo.lock"))
+        .find(|path| path.is_file());
+
+    let mut versions = Vec::new();
+    if let Some(lock) = lock {
+        println!("cargo:rerun-if-changed={}", lock.display());
+        let text = fs::read_to_string(&lock).unwrap_or_default();
+        let mut name: Option<String> = None;
+        for line in text.lines() {
+            let line = line.trim();
+            if line == "[[package]]" {
+                name = None;
+            } else if let Some(v) = line.strip_prefix("name = ") {
+                name = Some(v.trim_matches('"').to_string());
+            } else if let Some(v) = line.strip_prefix("version = ")
+                && let Some(n) = name.take()
+                && n.starts_with("pqcrypto")
+            {
+                versions.push(format!("{}={}", n, v.trim_matches('"')));
+            }
+        }
+    }
+
+    // custom-entropy 기능은 getrandom 사용자 백엔드 설정 없이는 효과가 없음
+    if env::var_os("CARGO_FEATURE_CUSTOM_ENTROPY").is_some()
+        && env::var("CARGO_CFG_GETRANDOM_BACKEND").as_deref() != Ok("custom")
+    {
+        println!(
+            "cargo:warning=custom-entropy 기능을 쓰려면 RUSTFLAGS='--cfg getrandom_backend=\"custom\"'이 필요합니다. 이 빌드는 운영체제 난수 생성기만 사용합니다."
+        );
+    }
+
+    println!("cargo:rerun-if-changed=build.rs");
+    println!(
+        "cargo:rustc-env=KEYPAIRER_BACKEND_VERSIONS={}",
+        versions.join(";")
+    );
+}
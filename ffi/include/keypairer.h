@@ -0,0 +1,212 @@
+/* Keypairer C ABI. Generated by cbindgen from ffi/src/lib.rs; do not edit. */
+
+#ifndef KEYPAIRER_H
+#define KEYPAIRER_H
+
+#include <stdbool.h>
+#include <stddef.h>
+#include <stdint.h>
+
+// 함수 호출 결과
+typedef enum KeypairerStatus {
+  // 성공
+  KEYPAIRER_STATUS_OK = 0,
+  // 필수 포인터 인자가 NULL임
+  KEYPAIRER_STATUS_NULL_POINTER = 1,
+  // 문자열 인자가 UTF-8이 아님
+  KEYPAIRER_STATUS_INVALID_UTF8 = 2,
+  // 지원하지 않는 알고리즘
+  KEYPAIRER_STATUS_UNKNOWN_ALGORITHM = 3,
+  // 알고리즘에 없는 배리언트
+  KEYPAIRER_STATUS_UNKNOWN_VARIANT = 4,
+  // 키의 길이나 형식이 배리언트와 맞지 않음
+  KEYPAIRER_STATUS_INVALID_KEY = 5,
+  // 암호문 또는 서명의 길이가 배리언트와 맞지 않음
+  KEYPAIRER_STATUS_INVALID_INPUT = 6,
+  // KEM이 아닌 배리언트로 캡슐화/디캡슐화를 요청함
+  KEYPAIRER_STATUS_NOT_KEM = 7,
+  // 서명 알고리즘이 아닌 배리언트로 서명/검증을 요청함
+  KEYPAIRER_STATUS_NOT_SIGNATURE = 8,
+  // 파일 입출력 실패
+  KEYPAIRER_STATUS_IO = 9,
+  // 덮어쓰기가 허용되지 않았는데 대상 파일이 이미 존재함
+  KEYPAIRER_STATUS_EXISTS = 10,
+  // 키 생성 실패
+  KEYPAIRER_STATUS_GENERATE = 11,
+  // 작업 스레드를 만들지 못함
+  KEYPAIRER_STATUS_THREAD = 12,
+  // 내부 패닉
+  KEYPAIRER_STATUS_PANIC = 13,
+} KeypairerStatus;
+
+// 비밀키 핸들 (배리언트와 잠긴 메모리의 비밀키)
+typedef struct KeypairerSecretKey KeypairerSecretKey;
+
+// 라이브러리가 할당한 바이트열
+//
+// `keypairer_buffer_free`로 해제해야 합니다. 비어 있으면 `data`는 NULL입니다.
+typedef struct KeypairerBuffer {
+  uint8_t *data;
+  size_t len;
+} KeypairerBuffer;
+
+#ifdef __cplusplus
+extern "C" {
+#endif // __cplusplus
+
+// 키 페어를 생성합니다.
+//
+// `variant`가 NULL이면 알고리즘의 최소 배리언트를 씁니다. 알고리즘과 배리언트 이름은
+// CLI의 `-alg`, `-var`와 같습니다 (예: `"mlkem"`, `"768"`).
+//
+// # Safety
+//
+// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+enum KeypairerStatus keypairer_generate(const char *algorithm,
+                                        const char *variant,
+                                        struct KeypairerBuffer *public_key_out,
+                                        struct KeypairerSecretKey **secret_key_out);
+
+// 비밀키 파일(바이너리 또는 PEM)을 읽어 핸들을 만듭니다.
+//
+// # Safety
+//
+// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+enum KeypairerStatus keypairer_secret_key_load(const char *path,
+                                               const char *algorithm,
+                                               const char *variant,
+                                               struct KeypairerSecretKey **secret_key_out);
+
+// 비밀키 바이트열을 복사해 핸들을 만듭니다. 원본은 호출자가 지워야 합니다.
+//
+// # Safety
+//
+// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, `data`는 `len`바이트를 읽을 수 있어야 하며,
+// 출력 포인터는 쓸 수 있는 주소여야 합니다.
+enum KeypairerStatus keypairer_secret_key_import(const char *algorithm,
+                                                 const char *variant,
+                                                 const uint8_t *data,
+                                                 size_t len,
+                                                 struct KeypairerSecretKey **secret_key_out);
+
+// 비밀키 바이트열을 버퍼로 복사합니다 (보안 저장소에 옮길 때 등).
+//
+// # Safety
+//
+// `secret_key`는 이 라이브러리가 만든 유효한 핸들이어야 하고, 출력 포인터는 쓸 수 있는
+// 주소여야 합니다.
+enum KeypairerStatus keypairer_secret_key_export(const struct KeypairerSecretKey *secret_key,
+                                                 struct KeypairerBuffer *out);
+
+// 공개키 파일(바이너리 또는 PEM)을 읽습니다.
+//
+// # Safety
+//
+// `path`는 NUL로 끝나야 하고, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+enum KeypairerStatus keypairer_public_key_load(const char *path,
+                                               struct KeypairerBuffer *public_key_out);
+
+// 키 페어를 파일로 저장합니다.
+//
+// CLI와 같이 임시 파일에 쓴 뒤 옮기며, 비밀키 파일은 소유자만 읽을 수 있습니다.
+// `pk_pem`/`sk_pem`이면 PEM 형식, 아니면 바이너리로 저장합니다.
+//
+// # Safety
+//
+// `public_key`는 `public_key_len`바이트를 읽을 수 있어야 하고, `secret_key`는 유효한
+// 핸들이어야 하며, 경로는 NUL로 끝나야 합니다.
+enum KeypairerStatus keypairer_save_key_pair(const uint8_t *public_key,
+                                             size_t public_key_len,
+                                             const struct KeypairerSecretKey *secret_key,
+                                             const char *pk_path,
+                                             const char *sk_path,
+                                             bool pk_pem,
+                                             bool sk_pem,
+                                             bool force);
+
+// 공개키로 공유 비밀을 캡슐화합니다.
+//
+// # Safety
+//
+// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, `public_key`는 `public_key_len`바이트를
+// 읽을 수 있어야 하며, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+enum KeypairerStatus keypairer_encapsulate(const char *algorithm,
+                                           const char *variant,
+                                           const uint8_t *public_key,
+                                           size_t public_key_len,
+                                           struct KeypairerBuffer *ciphertext_out,
+                                           struct KeypairerBuffer *shared_secret_out);
+
+// 비밀키로 암호문을 디캡슐화해 공유 비밀을 복원합니다.
+//
+// # Safety
+//
+// `secret_key`는 유효한 핸들이어야 하고, `ciphertext`는 `ciphertext_len`바이트를 읽을 수
+// 있어야 하며, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+enum KeypairerStatus keypairer_decapsulate(const struct KeypairerSecretKey *secret_key,
+                                           const uint8_t *ciphertext,
+                                           size_t ciphertext_len,
+                                           struct KeypairerBuffer *shared_secret_out);
+
+// 비밀키로 메시지에 서명합니다 (분리 서명).
+//
+// # Safety
+//
+// `secret_key`는 유효한 핸들이어야 하고, `message`는 `message_len`바이트를 읽을 수
+// 있어야 하며, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+enum KeypairerStatus keypairer_sign(const struct KeypairerSecretKey *secret_key,
+                                    const uint8_t *message,
+                                    size_t message_len,
+                                    struct KeypairerBuffer *signature_out);
+
+// 공개키로 분리 서명을 검증합니다.
+//
+// 서명이 맞지 않으면 `KEYPAIRER_STATUS_OK`를 반환하고 `valid_out`에 `false`를 씁니다.
+//
+// # Safety
+//
+// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, 각 바이트열 포인터는 해당 길이만큼 읽을 수
+// 있어야 하며, `valid_out`은 쓸 수 있는 주소여야 합니다.
+enum KeypairerStatus keypairer_verify(const char *algorithm,
+                                      const char *variant,
+                                      const uint8_t *public_key,
+                                      size_t public_key_len,
+                                      const uint8_t *message,
+                                      size_t message_len,
+                                      const uint8_t *signature,
+                                      size_t signature_len,
+                                      bool *valid_out);
+
+// 버퍼 내용을 지우고 해제합니다. 해제 후 버퍼는 비어 있는 상태가 됩니다.
+//
+// # Safety
+//
+// `buffer`는 NULL이거나 이 라이브러리가 채운 버퍼를 가리켜야 합니다.
+void keypairer_buffer_free(struct KeypairerBuffer *buffer);
+
+// 비밀키 핸들을 지우고 해제합니다. NULL이면 아무것도 하지 않습니다.
+//
+// # Safety
+//
+// `secret_key`는 NULL이거나 이 라이브러리가 만든, 아직 해제하지 않은 핸들이어야 합니다.
+void keypairer_secret_key_free(struct KeypairerSecretKey *secret_key);
+
+// 상태 코드의 영어 설명 (정적 문자열)
+//
+// C 코드가 임의의 정수를 넘길 수 있으므로 열거형 대신 정수로 받으며, 정의되지 않은 값이면
+// "unknown status"입니다.
+const char *keypairer_status_message(int32_t status);
+
+// 호출 스레드에서 마지막으로 실패한 호출의 상세 메시지
+//
+// 실패가 없었으면 NULL입니다. 같은 스레드에서 다음 함수를 호출하기 전까지 유효합니다.
+const char *keypairer_last_error(void);
+
+// 라이브러리 버전 (정적 문자열)
+const char *keypairer_version(void);
+
+#ifdef __cplusplus
+}  // extern "C"
+#endif  // __cplusplus
+
+#endif  /* KEYPAIRER_H */
@@ -0,0 +1,863 @@
+//! Keypairer C ABI
+//!
+//! C/C++ 코드에서 Keypairer의 키 생성, 키 파일 입출력, KEM 캡슐화와 서명을 쓰기 위한
+//! `extern "C"` 함수입니다. 헤더(`include/keypairer.h`)는 cbindgen으로 생성해 커밋하며,
+//! 이 파일의 `header` 테스트가 소스와 맞는지 확인합니다
+//! (다시 만들기: `KEYPAIRER_UPDATE_HEADER=1 cargo test -p keypairer-ffi header`).
+//!
+//! - 모든 함수는 [`KeypairerStatus`]를 반환하고 결과는 출력 포인터로 돌려줍니다.
+//! - 비밀키는 불투명 핸들([`KeypairerSecretKey`])로만 다루며 [`keypairer_secret_key_free`]가
+//!   메모리를 지운 뒤 해제합니다.
+//! - 라이브러리가 할당한 바이트열([`KeypairerBuffer`])은 [`keypairer_buffer_free`]로 지우고
+//!   해제합니다. 실패한 호출의 출력 버퍼도 비어 있는 상태로 초기화되므로 그대로 해제해도 됩니다.
+//! - 연산은 배리언트에 맞춘 크기의 스택을 가진 스레드에서 실행되므로 호출 스레드의 스택이
+//!   작아도 됩니다. 패닉은 경계를 넘지 않고 `KEYPAIRER_STATUS_PANIC`으로 바뀝니다.
+
+use std::cell::RefCell;
+use std::ffi::{CStr, CString, c_char};
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::ptr;
+use std::slice;
+use std::thread;
+
+use zeroize::Zeroize;
+
+use keypairer::internals::key_io::{read_key, write_key_pair};
+use keypairer::internals::keygen::{
+    Algorithm, Variant, generate_keys, minimal_variant_for_algorithm, parse_algorithm,
+    parse_variant,
+};
+use keypairer::internals::secret::SecretBuffer;
+use keypairer::internals::stack::stack_size_for;
+use keypairer::internals::{kem, sign};
+
+/// 함수 호출 결과
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KeypairerStatus {
+    /// 성공
+    Ok = 0,
+    /// 필수 포인터 인자가 NULL임
+    NullPointer = 1,
+    /// 문자열 인자가 UTF-8이 아님
+    InvalidUtf8 = 2,
+    /// 지원하지 않는 알고리즘
+    UnknownAlgorithm = 3,
+    /// 알고리즘에 없는 배리언트
+    UnknownVariant = 4,
+    /// 키의 길이나 형식이 배리언트와 맞지 않음
+    InvalidKey = 5,
+    /// 암호문 또는 서명의 길이가 배리언트와 맞지 않음
+    InvalidInput = 6,
+    /// KEM이 아닌 배리언트로 캡슐화/디캡슐화를 요청함
+    NotKem = 7,
+    /// 서명 알고리즘이 아닌 배리언트로 서명/검증을 요청함
+    NotSignature = 8,
+    /// 파일 입출력 실패
+    Io = 9,
+    /// 덮어쓰기가 허용되지 않았는데 대상 파일이 이미 존재함
+    Exists = 10,
+    /// 키 생성 실패
+    Generate = 11,
+    /// 작업 스레드를 만들지 못함
+    Thread = 12,
+    /// 내부 패닉
+    Panic = 13,
+}
+
+/// 라이브러리가 할당한 바이트열
+///
+/// `keypairer_buffer_free`로 해제해야 합니다. 비어 있으면 `data`는 NULL입니다.
+#[repr(C)]
+pub struct KeypairerBuffer {
+    pub data: *mut u8,
+    pub len: usize,
+}
+
+/// 비밀키 핸들 (배리언트와 잠긴 메모리의 비밀키)
+pub struct KeypairerSecretKey {
+    variant: Variant,
+    bytes: SecretBuffer,
+}
+
+thread_local! {
+    /// 호출 스레드에서 마지막으로 실패한 호출의 상세 메시지
+    static LAST_ERROR: RefCell<Option<CString>> = const { RefCell::new(None) };
+}
+
+type FfiResult<T> = Result<T, KeypairerStatus>;
+
+/// 상세 메시지를 남기고 상태를 반환합니다.
+fn failure(status: KeypairerStatus, message: impl Into<String>) -> KeypairerStatus {
+    let message = message.into().replace('\0', " ");
+    LAST_ERROR.with(|slot| *slot.borrow_mut() = CString::new(message).ok());
+    status
+}
+
+/// 패닉을 상태로 바꾸고, 성공하면 이전 오류 메시지를 지웁니다.
+fn guard(f: impl FnOnce() -> FfiResult<()>) -> KeypairerStatus {
+    match panic::catch_unwind(AssertUnwindSafe(f)) {
+        Ok(Ok(())) => {
+            LAST_ERROR.with(|slot| *slot.borrow_mut() = None);
+            KeypairerStatus::Ok
+        }
+        Ok(Err(status)) => status,
+        Err(_) => failure(KeypairerStatus::Panic, "internal panic"),
+    }
+}
+
+/// 배리언트 크기의 스택을 가진 스레드에서 `f`를 실행합니다.
+fn on_variant_stack<R: Send>(variant: &Variant, f: impl FnOnce() -> R + Send) -> FfiResult<R> {
+    thread::scope(|scope| {
+        let handle = thread::Builder::new()
+            .name("keypairer-ffi".to_string())
+            .stack_size(stack_size_for(variant))
+            .spawn_scoped(scope, f)
+            .map_err(|e| failure(KeypairerStatus::Thread, e.to_string()))?;
+        handle
+            .join()
+            .map_err(|_| failure(KeypairerStatus::Panic, "internal panic"))
+    })
+}
+
+/// NULL이 아닌 C 문자열을 읽습니다.
+///
+/// # Safety
+///
+/// `ptr`은 NULL이거나 NUL로 끝나는 유효한 문자열이어야 합니다.
+unsafe fn str_arg<'a>(ptr: *const c_char, name: &str) -> FfiResult<&'a str> {
+    if ptr.is_null() {
+        return Err(failure(
+            KeypairerStatus::NullPointer,
+            format!("{name} is NULL"),
+        ));
+    }
+    // SAFETY: 호출자가 NUL로 끝나는 문자열임을 보장
+    unsafe { CStr::from_ptr(ptr) }
+        .to_str()
+        .map_err(|_| failure(KeypairerStatus::InvalidUtf8, format!("{name} is not UTF-8")))
+}
+
+/// 포인터와 길이를 슬라이스로 바꿉니다. 길이가 0이면 포인터가 NULL이어도 됩니다.
+///
+/// # Safety
+///
+/// `len`이 0이 아니면 `ptr`은 `len`바이트를 읽을 수 있어야 합니다.
+unsafe fn bytes_arg<'a>(ptr: *const u8, len: usize, name: &str) -> FfiResult<&'a [u8]> {
+    if len == 0 {
+        return Ok(&[]);
+    }
+    if ptr.is_null() {
+        return Err(failure(
+            KeypairerStatus::NullPointer,
+            format!("{name} is NULL"),
+        ));
+    }
+    // SAFETY: 호출자가 `len`바이트를 읽을 수 있음을 보장
+    Ok(unsafe { slice::from_raw_parts(ptr, len) })
+}
+
+/// 출력 포인터를 빌리고 가리키는 값을 `empty`로 초기화합니다.
+///
+/// # Safety
+///
+/// `ptr`은 NULL이거나 `T`를 쓸 수 있는 주소여야 합니다.
+unsafe fn out_arg<'a, T>(ptr: *mut T, empty: T, name: &str) -> FfiResult<&'a mut T> {
+    if ptr.is_null() {
+        return Err(failure(
+            KeypairerStatus::NullPointer,
+            format!("{name} is NULL"),
+        ));
+    }
+    // SAFETY: 호출자가 쓸 수 있는 주소임을 보장하며, 이전 값은 읽지 않고 덮어씀
+    unsafe {
+        ptr.write(empty);
+        Ok(&mut *ptr)
+    }
+}
+
+/// 알고리즘과 배리언트 문자열을 해석합니다. 배리언트가 NULL이면 최소 배리언트를 씁니다.
+///
+/// # Safety
+///
+/// [`str_arg`]와 같습니다.
+unsafe fn variant_arg(algorithm: *const c_char, variant: *const c_char) -> FfiResult<Variant> {
+    // SAFETY: 호출자의 보장을 그대로 전달
+    let name = unsafe { str_arg(algorithm, "algorithm") }?;
+    let alg = parse_algorithm(name)
+        .filter(|alg| *alg != Algorithm::Unknown)
+        .ok_or_else(|| {
+            failure(
+                KeypairerStatus::UnknownAlgorithm,
+                format!("unknown algorithm: {name}"),
+            )
+        })?;
+    if variant.is_null() {
+        return Ok(minimal_variant_for_algorithm(alg));
+    }
+    // SAFETY: 호출자의 보장을 그대로 전달
+    let value = unsafe { str_arg(variant, "variant") }?;
+    parse_variant(alg, value).ok_or_else(|| {
+        failure(
+            KeypairerStatus::UnknownVariant,
+            format!("unknown variant for {}: {value}", alg.as_str()),
+        )
+    })
+}
+
+/// 배리언트의 비밀키 길이
+fn secret_key_len(variant: &Variant) -> Option<usize> {
+    kem::secret_key_len(variant).or_else(|| sign::secret_key_len(variant))
+}
+
+/// 비밀키 바이트열의 길이를 확인하고 핸들을 만듭니다.
+fn secret_key_handle(variant: Variant, bytes: &[u8]) -> FfiResult<*mut KeypairerSecretKey> {
+    if secret_key_len(&variant) != Some(bytes.len()) {
+        return Err(failure(
+            KeypairerStatus::InvalidKey,
+            format!(
+                "secret key length {} does not match {}",
+                bytes.len(),
+                variant.standard_name()
+            ),
+        ));
+    }
+    Ok(Box::into_raw(Box::new(KeypairerSecretKey {
+        variant,
+        bytes: SecretBuffer::from_slice(bytes),
+    })))
+}
+
+fn empty_buffer() -> KeypairerBuffer {
+    KeypairerBuffer {
+        data: ptr::null_mut(),
+        len: 0,
+    }
+}
+
+/// 바이트열을 C에 넘길 버퍼로 복사합니다.
+fn to_buffer(bytes: &[u8]) -> KeypairerBuffer {
+    if bytes.is_empty() {
+        return empty_buffer();
+    }
+    let boxed: Box<[u8]> = bytes.into();
+    let len = boxed.len();
+    KeypairerBuffer {
+        data: Box::into_raw(boxed).cast::<u8>(),
+        len,
+    }
+}
+
+fn io_failure(path: &str, err: io::Error) -> KeypairerStatus {
+    let status = if err.kind() == io::ErrorKind::InvalidData {
+        KeypairerStatus::InvalidKey
+    } else {
+        KeypairerStatus::Io
+    };
+    failure(status, format!("{path}: {err}"))
+}
+
+fn kem_failure(err: kem::KemError) -> KeypairerStatus {
+    let status = match err {
+        kem::KemError::NotKem(_) => KeypairerStatus::NotKem,
+        kem::KemError::InvalidInput(_) => KeypairerStatus::InvalidInput,
+    };
+    failure(status, err.to_string())
+}
+
+fn sign_failure(err: sign::SignError) -> KeypairerStatus {
+    let status = match err {
+        sign::SignError::NotSignature(_) => KeypairerStatus::NotSignature,
+        sign::SignError::InvalidInput(_) => KeypairerStatus::InvalidInput,
+    };
+    failure(status, err.to_string())
+}
+
+/// 키 페어를 생성합니다.
+///
+/// `variant`가 NULL이면 알고리즘의 최소 배리언트를 씁니다. 알고리즘과 배리언트 이름은
+/// CLI의 `-alg`, `-var`와 같습니다 (예: `"mlkem"`, `"768"`).
+///
+/// # Safety
+///
+/// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_generate(
+    algorithm: *const c_char,
+    variant: *const c_char,
+    public_key_out: *mut KeypairerBuffer,
+    secret_key_out: *mut *mut KeypairerSecretKey,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let pk_out = unsafe { out_arg(public_key_out, empty_buffer(), "public_key_out") }?;
+        let sk_out = unsafe { out_arg(secret_key_out, ptr::null_mut(), "secret_key_out") }?;
+        let variant = unsafe { variant_arg(algorithm, variant) }?;
+
+        let target = variant.clone();
+        // 오류 메시지는 호출 스레드에 남겨야 하므로 작업 스레드 밖에서 변환
+        let (pk, sk) = on_variant_stack(&variant, move || {
+            generate_keys(target.algorithm(), target).map_err(|e| e.to_string())
+        })?
+        .map_err(|e| failure(KeypairerStatus::Generate, e))?;
+        *sk_out = Box::into_raw(Box::new(KeypairerSecretKey { variant, bytes: sk }));
+        *pk_out = to_buffer(&pk);
+        Ok(())
+    })
+}
+
+/// 비밀키 파일(바이너리 또는 PEM)을 읽어 핸들을 만듭니다.
+///
+/// # Safety
+///
+/// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_secret_key_load(
+    path: *const c_char,
+    algorithm: *const c_char,
+    variant: *const c_char,
+    secret_key_out: *mut *mut KeypairerSecretKey,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let out = unsafe { out_arg(secret_key_out, ptr::null_mut(), "secret_key_out") }?;
+        let path = unsafe { str_arg(path, "path") }?;
+        let variant = unsafe { variant_arg(algorithm, variant) }?;
+
+        let bytes = read_key(path, "SECRET KEY").map_err(|e| io_failure(path, e))?;
+        *out = secret_key_handle(variant, &bytes)?;
+        Ok(())
+    })
+}
+
+/// 비밀키 바이트열을 복사해 핸들을 만듭니다. 원본은 호출자가 지워야 합니다.
+///
+/// # Safety
+///
+/// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, `data`는 `len`바이트를 읽을 수 있어야 하며,
+/// 출력 포인터는 쓸 수 있는 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_secret_key_import(
+    algorithm: *const c_char,
+    variant: *const c_char,
+    data: *const u8,
+    len: usize,
+    secret_key_out: *mut *mut KeypairerSecretKey,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let out = unsafe { out_arg(secret_key_out, ptr::null_mut(), "secret_key_out") }?;
+        let variant = unsafe { variant_arg(algorithm, variant) }?;
+        let bytes = unsafe { bytes_arg(data, len, "data") }?;
+
+        *out = secret_key_handle(variant, bytes)?;
+        Ok(())
+    })
+}
+
+/// 비밀키 바이트열을 버퍼로 복사합니다 (보안 저장소에 옮길 때 등).
+///
+/// # Safety
+///
+/// `secret_key`는 이 라이브러리가 만든 유효한 핸들이어야 하고, 출력 포인터는 쓸 수 있는
+/// 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_secret_key_export(
+    secret_key: *const KeypairerSecretKey,
+    out: *mut KeypairerBuffer,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let out = unsafe { out_arg(out, empty_buffer(), "out") }?;
+        let sk = unsafe { secret_key.as_ref() }
+            .ok_or_else(|| failure(KeypairerStatus::NullPointer, "secret_key is NULL"))?;
+        *out = to_buffer(&sk.bytes);
+        Ok(())
+    })
+}
+
+/// 공개키 파일(바이너리 또는 PEM)을 읽습니다.
+///
+/// # Safety
+///
+/// `path`는 NUL로 끝나야 하고, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_public_key_load(
+    path: *const c_char,
+    public_key_out: *mut KeypairerBuffer,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let out = unsafe { out_arg(public_key_out, empty_buffer(), "public_key_out") }?;
+        let path = unsafe { str_arg(path, "path") }?;
+
+        let bytes = read_key(path, "PUBLIC KEY").map_err(|e| io_failure(path, e))?;
+        *out = to_buffer(&bytes);
+        Ok(())
+    })
+}
+
+/// 키 페어를 파일로 저장합니다.
+///
+/// CLI와 같이 임시 파일에 쓴 뒤 옮기며, 비밀키 파일은 소유자만 읽을 수 있습니다.
+/// `pk_pem`/`sk_pem`이면 PEM 형식, 아니면 바이너리로 저장합니다.
+///
+/// # Safety
+///
+/// `public_key`는 `public_key_len`바이트를 읽을 수 있어야 하고, `secret_key`는 유효한
+/// 핸들이어야 하며, 경로는 NUL로 끝나야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_save_key_pair(
+    public_key: *const u8,
+    public_key_len: usize,
+    secret_key: *const KeypairerSecretKey,
+    pk_path: *const c_char,
+    sk_path: *const c_char,
+    pk_pem: bool,
+    sk_pem: bool,
+    force: bool,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let pk = unsafe { bytes_arg(public_key, public_key_len, "public_key") }?;
+        let sk = unsafe { secret_key.as_ref() }
+            .ok_or_else(|| failure(KeypairerStatus::NullPointer, "secret_key is NULL"))?;
+        let pk_path = unsafe { str_arg(pk_path, "pk_path") }?;
+        let sk_path = unsafe { str_arg(sk_path, "sk_path") }?;
+
+        let expected =
+            kem::public_key_len(&sk.variant).or_else(|| sign::public_key_len(&sk.variant));
+        if expected != Some(pk.len()) {
+            return Err(failure(
+                KeypairerStatus::InvalidKey,
+                format!(
+                    "public key length {} does not match {}",
+                    pk.len(),
+                    sk.variant.standard_name()
+                ),
+            ));
+        }
+
+        write_key_pair(pk, &sk.bytes, pk_path, sk_path, pk_pem, sk_pem, force).map_err(|e| {
+            let status = match e {
+                keypairer::internals::key_io::SaveError::Exists(_) => KeypairerStatus::Exists,
+                _ => KeypairerStatus::Io,
+            };
+            failure(status, e.message("en"))
+        })
+    })
+}
+
+/// 공개키로 공유 비밀을 캡슐화합니다.
+///
+/// # Safety
+///
+/// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, `public_key`는 `public_key_len`바이트를
+/// 읽을 수 있어야 하며, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_encapsulate(
+    algorithm: *const c_char,
+    variant: *const c_char,
+    public_key: *const u8,
+    public_key_len: usize,
+    ciphertext_out: *mut KeypairerBuffer,
+    shared_secret_out: *mut KeypairerBuffer,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let ct_out = unsafe { out_arg(ciphertext_out, empty_buffer(), "ciphertext_out") }?;
+        let ss_out = unsafe { out_arg(shared_secret_out, empty_buffer(), "shared_secret_out") }?;
+        let variant = unsafe { variant_arg(algorithm, variant) }?;
+        let pk = unsafe { bytes_arg(public_key, public_key_len, "public_key") }?;
+
+        let (ss, ct) =
+            on_variant_stack(&variant, || kem::encapsulate(&variant, pk))?.map_err(kem_failure)?;
+        *ct_out = to_buffer(&ct);
+        *ss_out = to_buffer(&ss);
+        Ok(())
+    })
+}
+
+/// 비밀키로 암호문을 디캡슐화해 공유 비밀을 복원합니다.
+///
+/// # Safety
+///
+/// `secret_key`는 유효한 핸들이어야 하고, `ciphertext`는 `ciphertext_len`바이트를 읽을 수
+/// 있어야 하며, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_decapsulate(
+    secret_key: *const KeypairerSecretKey,
+    ciphertext: *const u8,
+    ciphertext_len: usize,
+    shared_secret_out: *mut KeypairerBuffer,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let out = unsafe { out_arg(shared_secret_out, empty_buffer(), "shared_secret_out") }?;
+        let sk = unsafe { secret_key.as_ref() }
+            .ok_or_else(|| failure(KeypairerStatus::NullPointer, "secret_key is NULL"))?;
+        let ct = unsafe { bytes_arg(ciphertext, ciphertext_len, "ciphertext") }?;
+
+        let ss = on_variant_stack(&sk.variant, || kem::decapsulate(&sk.variant, &sk.bytes, ct))?
+            .map_err(kem_failure)?;
+        *out = to_buffer(&ss);
+        Ok(())
+    })
+}
+
+/// 비밀키로 메시지에 서명합니다 (분리 서명).
+///
+/// # Safety
+///
+/// `secret_key`는 유효한 핸들이어야 하고, `message`는 `message_len`바이트를 읽을 수
+/// 있어야 하며, 출력 포인터는 쓸 수 있는 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_sign(
+    secret_key: *const KeypairerSecretKey,
+    message: *const u8,
+    message_len: usize,
+    signature_out: *mut KeypairerBuffer,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let out = unsafe { out_arg(signature_out, empty_buffer(), "signature_out") }?;
+        let sk = unsafe { secret_key.as_ref() }
+            .ok_or_else(|| failure(KeypairerStatus::NullPointer, "secret_key is NULL"))?;
+        let msg = unsafe { bytes_arg(message, message_len, "message") }?;
+
+        let sig = on_variant_stack(&sk.variant, || sign::sign(&sk.variant, &sk.bytes, msg))?
+            .map_err(sign_failure)?;
+        *out = to_buffer(&sig);
+        Ok(())
+    })
+}
+
+/// 공개키로 분리 서명을 검증합니다.
+///
+/// 서명이 맞지 않으면 `KEYPAIRER_STATUS_OK`를 반환하고 `valid_out`에 `false`를 씁니다.
+///
+/// # Safety
+///
+/// 문자열 인자는 NULL이거나 NUL로 끝나야 하고, 각 바이트열 포인터는 해당 길이만큼 읽을 수
+/// 있어야 하며, `valid_out`은 쓸 수 있는 주소여야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_verify(
+    algorithm: *const c_char,
+    variant: *const c_char,
+    public_key: *const u8,
+    public_key_len: usize,
+    message: *const u8,
+    message_len: usize,
+    signature: *const u8,
+    signature_len: usize,
+    valid_out: *mut bool,
+) -> KeypairerStatus {
+    guard(|| {
+        // SAFETY: 함수의 안전 조건에 따름
+        let out = unsafe { out_arg(valid_out, false, "valid_out") }?;
+        let variant = unsafe { variant_arg(algorithm, variant) }?;
+        let pk = unsafe { bytes_arg(public_key, public_key_len, "public_key") }?;
+        let msg = unsafe { bytes_arg(message, message_len, "message") }?;
+        let sig = unsafe { bytes_arg(signature, signature_len, "signature") }?;
+
+        *out = on_variant_stack(&variant, || sign::verify(&variant, pk, msg, sig))?
+            .map_err(sign_failure)?;
+        Ok(())
+    })
+}
+
+/// 버퍼 내용을 지우고 해제합니다. 해제 후 버퍼는 비어 있는 상태가 됩니다.
+///
+/// # Safety
+///
+/// `buffer`는 NULL이거나 이 라이브러리가 채운 버퍼를 가리켜야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_buffer_free(buffer: *mut KeypairerBuffer) {
+    // SAFETY: 함수의 안전 조건에 따름
+    let Some(buffer) = (unsafe { buffer.as_mut() }) else {
+        return;
+    };
+    if !buffer.data.is_null() {
+        // SAFETY: `to_buffer`가 `Box<[u8]>`에서 만든 포인터와 길이
+        let mut boxed =
+            unsafe { Box::from_raw(ptr::slice_from_raw_parts_mut(buffer.data, buffer.len)) };
+        boxed.zeroize();
+    }
+    *buffer = empty_buffer();
+}
+
+/// 비밀키 핸들을 지우고 해제합니다. NULL이면 아무것도 하지 않습니다.
+///
+/// # Safety
+///
+/// `secret_key`는 NULL이거나 이 라이브러리가 만든, 아직 해제하지 않은 핸들이어야 합니다.
+#[unsafe(no_mangle)]
+pub unsafe extern "C" fn keypairer_secret_key_free(secret_key: *mut KeypairerSecretKey) {
+    if !secret_key.is_null() {
+        // SAFETY: `Box::into_raw`로 만든 핸들이며 `SecretBuffer`는 드롭할 때 메모리를 지움
+        drop(unsafe { Box::from_raw(secret_key) });
+    }
+}
+
+impl KeypairerStatus {
+    /// C에서 넘어온 정수를 상태로 바꿉니다. 정의되지 않은 값이면 `None`입니다.
+    pub fn from_code(code: i32) -> Option<KeypairerStatus> {
+        Some(match code {
+            0 => KeypairerStatus::Ok,
+            1 => KeypairerStatus::NullPointer,
+            2 => KeypairerStatus::InvalidUtf8,
+            3 => KeypairerStatus::UnknownAlgorithm,
+            4 => KeypairerStatus::UnknownVariant,
+            5 => KeypairerStatus::InvalidKey,
+            6 => KeypairerStatus::InvalidInput,
+            7 => KeypairerStatus::NotKem,
+            8 => KeypairerStatus::NotSignature,
+            9 => KeypairerStatus::Io,
+            10 => KeypairerStatus::Exists,
+            11 => KeypairerStatus::Generate,
+            12 => KeypairerStatus::Thread,
+            13 => KeypairerStatus::Panic,
+            _ => return None,
+        })
+    }
+}
+
+/// 상태 코드의 영어 설명 (정적 문자열)
+///
+/// C 코드가 임의의 정수를 넘길 수 있으므로 열거형 대신 정수로 받으며, 정의되지 않은 값이면
+/// "unknown status"입니다.
+#[unsafe(no_mangle)]
+pub extern "C" fn keypairer_status_message(status: i32) -> *const c_char {
+    let Some(status) = KeypairerStatus::from_code(status) else {
+        return c"unknown status".as_ptr();
+    };
+    let message: &'static CStr = match status {
+        KeypairerStatus::Ok => c"ok",
+        KeypairerStatus::NullPointer => c"required pointer argument is NULL",
+        KeypairerStatus::InvalidUtf8 => c"string argument is not valid UTF-8",
+        KeypairerStatus::UnknownAlgorithm => c"unknown algorithm",
+        KeypairerStatus::UnknownVariant => c"unknown variant",
+        KeypairerStatus::InvalidKey => c"key length or format does not match the variant",
+        KeypairerStatus::InvalidInput => c"ciphertext or signature length does not match",
+        KeypairerStatus::NotKem => c"variant is not a KEM",
+        KeypairerStatus::NotSignature => c"variant is not a signature scheme",
+        KeypairerStatus::Io => c"file I/O failed",
+        KeypairerStatus::Exists => c"file already exists",
+        KeypairerStatus::Generate => c"key generation failed",
+        KeypairerStatus::Thread => c"could not spawn worker thread",
+        KeypairerStatus::Panic => c"internal panic",
+    };
+    message.as_ptr()
+}
+
+/// 호출 스레드에서 마지막으로 실패한 호출의 상세 메시지
+///
+/// 실패가 없었으면 NULL입니다. 같은 스레드에서 다음 함수를 호출하기 전까지 유효합니다.
+#[unsafe(no_mangle)]
+pub extern "C" fn keypairer_last_error() -> *const c_char {
+    LAST_ERROR.with(|slot| slot.borrow().as_ref().map_or(ptr::null(), |m| m.as_ptr()))
+}
+
+/// 라이브러리 버전 (정적 문자열)
+#[unsafe(no_mangle)]
+pub extern "C" fn keypairer_version() -> *const c_char {
+    concat!(env!("CARGO_PKG_VERSION"), "\0").as_ptr().cast()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::env;
+    use std::fs;
+    use std::path::PathBuf;
+
+    use super::*;
+
+    fn empty() -> KeypairerBuffer {
+        KeypairerBuffer {
+            data: ptr::null_mut(),
+            len: 0,
+        }
+    }
+
+    fn bytes(buffer: &KeypairerBuffer) -> &[u8] {
+        // SAFETY: 라이브러리가 채운 버퍼는 `len`바이트를 가리킴
+        unsafe { slice::from_raw_parts(buffer.data, buffer.len) }
+    }
+
+    fn text(ptr: *const c_char) -> String {
+        // SAFETY: 라이브러리가 돌려준 NUL로 끝나는 정적 또는 스레드별 문자열
+        unsafe { CStr::from_ptr(ptr) }
+            .to_string_lossy()
+            .into_owned()
+    }
+
+    #[test]
+    fn generate_export_import_sign_verify_and_free() {
+        let message = b"firmware image";
+        let (mut pk, mut exported, mut sig) = (empty(), empty(), empty());
+        let (mut sk, mut imported) = (ptr::null_mut(), ptr::null_mut());
+        let mut valid = false;
+
+        // SAFETY: 모든 포인터는 이 함수의 지역 변수나 라이브러리가 돌려준 핸들
+        unsafe {
+            assert_eq!(
+                keypairer_generate(c"mldsa".as_ptr(), c"44".as_ptr(), &mut pk, &mut sk),
+                KeypairerStatus::Ok
+            );
+            assert!(!sk.is_null());
+            assert_eq!(pk.len, 1312);
+
+            assert_eq!(
+                keypairer_secret_key_export(sk, &mut exported),
+                KeypairerStatus::Ok
+            );
+            assert_eq!(exported.len, 2560);
+            assert_eq!(
+                keypairer_secret_key_import(
+                    c"mldsa".as_ptr(),
+                    c"44".as_ptr(),
+                    exported.data,
+                    exported.len,
+                    &mut imported,
+                ),
+                KeypairerStatus::Ok
+            );
+
+            assert_eq!(
+                keypairer_sign(imported, message.as_ptr(), message.len(), &mut sig),
+                KeypairerStatus::Ok
+            );
+            let verify = |msg: &[u8], valid: &mut bool| {
+                keypairer_verify(
+                    c"mldsa".as_ptr(),
+                    c"44".as_ptr(),
+                    pk.data,
+                    pk.len,
+                    msg.as_ptr(),
+                    msg.len(),
+                    sig.data,
+                    sig.len,
+                    valid,
+                )
+            };
+            assert_eq!(verify(message, &mut valid), KeypairerStatus::Ok);
+            assert!(valid);
+            assert_eq!(verify(b"tampered image", &mut valid), KeypairerStatus::Ok);
+            assert!(!valid);
+
+            for buffer in [&mut pk, &mut exported, &mut sig] {
+                keypairer_buffer_free(buffer);
+                assert!(buffer.data.is_null() && buffer.len == 0);
+            }
+            keypairer_secret_key_free(sk);
+            keypairer_secret_key_free(imported);
+            // NULL 해제는 아무것도 하지 않음
+            keypairer_buffer_free(ptr::null_mut());
+            keypairer_secret_key_free(ptr::null_mut());
+        }
+    }
+
+    #[test]
+    fn encapsulate_and_decapsulate_agree() {
+        let (mut pk, mut ct, mut ss, mut recovered) = (empty(), empty(), empty(), empty());
+        let mut sk = ptr::null_mut();
+
+        // SAFETY: 모든 포인터는 이 함수의 지역 변수나 라이브러리가 돌려준 핸들
+        unsafe {
+            // 배리언트를 생략하면 알고리즘의 최소 배리언트 (ML-KEM-512)
+            assert_eq!(
+                keypairer_generate(c"mlkem".as_ptr(), ptr::null(), &mut pk, &mut sk),
+                KeypairerStatus::Ok
+            );
+            assert_eq!(
+                keypairer_encapsulate(
+                    c"mlkem".as_ptr(),
+                    c"512".as_ptr(),
+                    pk.data,
+                    pk.len,
+                    &mut ct,
+                    &mut ss,
+                ),
+                KeypairerStatus::Ok
+            );
+            assert_eq!(
+                keypairer_decapsulate(sk, ct.data, ct.len, &mut recovered),
+                KeypairerStatus::Ok
+            );
+            assert_eq!(bytes(&ss), bytes(&recovered));
+
+            // 서명 핸들이 아니므로 서명은 실패하고 출력은 비어 있음
+            let mut sig = empty();
+            assert_eq!(
+                keypairer_sign(sk, b"x".as_ptr(), 1, &mut sig),
+                KeypairerStatus::NotSignature
+            );
+            assert!(sig.data.is_null());
+            assert!(!keypairer_last_error().is_null());
+
+            for buffer in [&mut pk, &mut ct, &mut ss, &mut recovered] {
+                keypairer_buffer_free(buffer);
+            }
+            keypairer_secret_key_free(sk);
+        }
+    }
+
+    #[test]
+    fn invalid_arguments_are_reported_not_trusted() {
+        let mut pk = empty();
+        let mut sk = ptr::null_mut();
+        // SAFETY: 출력 포인터는 지역 변수이며 나머지는 NULL 또는 정적 문자열
+        unsafe {
+            assert_eq!(
+                keypairer_generate(c"rsa".as_ptr(), ptr::null(), &mut pk, &mut sk),
+                KeypairerStatus::UnknownAlgorithm
+            );
+            assert!(pk.data.is_null() && sk.is_null());
+            assert_eq!(
+                keypairer_generate(ptr::null(), ptr::null(), &mut pk, &mut sk),
+                KeypairerStatus::NullPointer
+            );
+        }
+        assert_eq!(text(keypairer_status_message(0)), "ok");
+        assert_eq!(
+            text(keypairer_status_message(KeypairerStatus::Panic as i32)),
+            "internal panic"
+        );
+        // C 코드가 넘긴 정의되지 않은 값
+        for code in [-1, 14, i32::MAX] {
+            assert_eq!(text(keypairer_status_message(code)), "unknown status");
+        }
+        assert_eq!(text(keypairer_version()), env!("CARGO_PKG_VERSION"));
+    }
+
+    /// 커밋된 `include/keypairer.h`가 이 파일에서 cbindgen으로 생성한 헤더와 같은지 확인합니다.
+    /// 함수 시그니처를 바꾼 뒤에는 `KEYPAIRER_UPDATE_HEADER=1 cargo test -p keypairer-ffi header`로
+    /// 헤더를 다시 만들어 함께 커밋하세요.
+    #[test]
+    fn committed_header_matches_the_sources() {
+        let crate_dir = PathBuf::from(env!("CARGO_MANIFEST_DIR"));
+        let config = cbindgen::Config::from_file(crate_dir.join("cbindgen.toml"))
+            .expect("cbindgen.toml을 읽을 수 없습니다");
+
+        // cargo metadata 없이 소스만 파싱하므로 오프라인에서도 동작
+        let mut generated = Vec::new();
+        cbindgen::Builder::new()
+            .with_config(config)
+            .with_src(crate_dir.join("src/lib.rs"))
+            .generate()
+            .expect("헤더를 생성할 수 없습니다")
+            .write(&mut generated);
+        let generated = String::from_utf8(generated).unwrap();
+
+        let path = crate_dir.join("include/keypairer.h");
+        if env::var_os("KEYPAIRER_UPDATE_HEADER").is_some_and(|v| v == "1") {
+            fs::write(&path, &generated).unwrap();
+        }
+        let committed = fs::read_to_string(&path).unwrap_or_default();
+        assert!(
+            committed == generated,
+            "include/keypairer.h is out of date; rerun with KEYPAIRER_UPDATE_HEADER=1"
+        );
+    }
+}
@@ -0,0 +1,440 @@
+use std::collections::HashSet;
+use std::io::{self, Read};
+use std::path::Path;
+use std::process;
+use std::sync::Arc;
+use std::thread;
+
+use keypairer::internals::batch::{
+    BatchError, BatchJob, BatchOptions, BatchOutcome, run_batch, write_index,
+};
+use keypairer::internals::datetime::now_unix;
+use keypairer::internals::key_io::STDIO_PATH;
+use keypairer::internals::policy::Policy;
+use keypairer::internals::secret::disable_core_dumps;
+use keypairer::internals::suite::{SUITES, Suite, find_suite};
+
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{
+    TemplateVars, enforce_policy, has_unique_placeholder, place_in_outdir, render_template,
+    resolve_algorithm, template_with_ext,
+};
+
+/// `gen -count`/`gen -manifest`/`gen -suite` 인자
+pub struct BatchArgs {
+    pub algorithm: Option<String>,
+    pub variant: Option<String>,
+    pub pk_template: Option<String>,
+    pub sk_template: Option<String>,
+    pub count: Option<String>,
+    pub manifest: Option<String>,
+    /// 내장 보안 묶음 이름 (KEM·서명 키 페어를 함께 생성)
+    pub suite: Option<String>,
+    pub jobs: Option<String>,
+    pub index: Option<String>,
+    pub outdir: Option<String>,
+    pub pk_text: bool,
+    pub sk_text: bool,
+    pub force: bool,
+    pub metadata: bool,
+    /// 모든 작업에 적용할 키 생성 정책
+    pub policy: Policy,
+    pub lang: String,
+}
+
+/// 여러 키 페어를 일괄 생성합니다.
+pub fn run(prog: &str, args: BatchArgs) {
+    let lang = args.lang.as_str();
+
+    if args.count.is_some() && args.manifest.is_some() {
+        eprintln!(
+            "{}",
+            text(
+                lang,
+                "error.batch.exclusive",
+                "-count와 -manifest는 함께 사용할 수 없습니다."
+            )
+        );
+        usage_and_exit(prog, lang);
+    }
+    // 묶음이 알고리즘과 배리언트를 정하므로 다른 선택과 함께 쓸 수 없음
+    if args.suite.is_some()
+        && (args.count.is_some()
+            || args.manifest.is_some()
+            || args.algorithm.is_some()
+            || args.variant.is_some())
+    {
+        eprintln!(
+            "{}",
+            text(
+                lang,
+                "error.suite.exclusive",
+                "-suite는 -alg, -var, -count, -manifest와 함께 사용할 수 없습니다."
+            )
+        );
+        usage_and_exit(prog, lang);
+    }
+
+    let suite = args.suite.as_deref().map(|name| resolve_suite(name, lang));
+    let jobs = match (&args.manifest, suite) {
+        (_, Some(suite)) => jobs_from_suite(suite, lang),
+        (Some(path), None) => load_manifest(path, &args),
+        (None, None) => jobs_from_count(prog, &args),
+    };
+
+    // 하나라도 정책을 위반하면 아무 키도 만들지 않음
+    enforce_policy(
+        &args.policy,
+        jobs.iter().map(|job| &job.variant),
+        args.sk_text,
+        lang,
+    );
+
+    // 경로 템플릿 (각 작업마다 고유한 이름이 나오도록 {id} 또는 {n} 필수)
+    let prefix = suite.map_or("{alg}", |suite| suite.name);
+    let pk_template = template_with_ext(
+        args.pk_template.as_deref(),
+        &format!("{}-{{id}}.pub", prefix),
+        "pub",
+    );
+    let sk_template = template_with_ext(
+        args.sk_template.as_deref(),
+        &format!("{}-{{id}}.sk", prefix),
+        "sk",
+    );
+    for template in [&pk_template, &sk_template] {
+        if template == STDIO_PATH {
+            fail(text(
+                lang,
+                "error.batch.stdout",
+                "일괄 생성에서는 표준 출력('-')을 사용할 수 없습니다.",
+            ));
+        }
+        if !has_unique_placeholder(template) {
+            fail(
+                text(
+                    lang,
+                    "error.batch.template",
+                    "일괄 생성 경로 템플릿에는 {id}, {n}, {fp} 중 하나가 포함되어야 합니다: {template}",
+                )
+                .replace("{template}", template),
+            );
+        }
+    }
+
+    // 생성 후에 인덱스 기록이 막히지 않도록 미리 확인
+    let index_path = place_in_outdir(
+        args.index.clone().unwrap_or_else(|| match suite {
+            Some(suite) => format!("{}-index.json", suite.name),
+            None => "index.json".to_string(),
+        }),
+        args.outdir.as_deref(),
+    );
+    let index_path = index_path.as_str();
+    if !args.force && Path::new(index_path).exists() {
+        fail(
+            text(
+                lang,
+                "error.file.exists",
+                "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)",
+            )
+            .replace("{path}", index_path),
+        );
+    }
+
+    let workers = match &args.jobs {
+        Some(v) => match v.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => fail(
+                text(lang, "error.batch.jobs", "잘못된 워커 수: {value}").replace("{value}", v),
+            ),
+        },
+        None => thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+    };
+
+    let options = BatchOptions {
+        workers,
+        pk_text: args.pk_text,
+        sk_text: args.sk_text,
+        force: args.force,
+        metadata: args.metadata,
+    };
+    let outdir = args.outdir.clone();
+    let namer = Arc::new(move |job: &BatchJob, fp: &str| {
+        let vars = TemplateVars {
+            id: &job.id,
+            n: job.n,
+            alg: job.algorithm,
+            var: &job.variant,
+            fp,
+            created: now_unix(),
+        };
+        (
+            place_in_outdir(render_template(&pk_template, &vars), outdir.as_deref()),
+            place_in_outdir(render_template(&sk_template, &vars), outdir.as_deref()),
+        )
+    });
+
+    let ok_msg = text(lang, "info.batch.progress", "[{done}/{total}] {id}: {fp}");
+    let fail_msg = text(
+        lang,
+        "error.batch.failed",
+        "[{done}/{total}] {id} 실패: {err}",
+    );
+    let lost_msg = text(
+        lang,
+        "error.batch.lost",
+        "워커가 결과를 보고하지 못하고 종료되었습니다.",
+    );
+    let meta_msg = text(
+        lang,
+        "error.meta.write",
+        "메타데이터 파일을 기록하는 도중 오류가 발생했습니다({path}): {err}",
+    );
+    let keygen_msg = text(
+        lang,
+        "error.keygen",
+        "키 생성 도중 오류가 발생했습니다: {err}",
+    );
+    let progress = |done: usize, total: usize, outcome: &BatchOutcome| {
+        let line = match &outcome.result {
+            Ok(record) => ok_msg.replace("{fp}", &record.fingerprint),
+            Err(e) => {
+                let err = match e {
+                    BatchError::KeyGen(msg) => keygen_msg.replace("{err}", msg),
+                    BatchError::Save(save) => save.message(lang),
+                    BatchError::Metadata(path, err) => meta_msg
+                        .replace("{path}", path)
+                        .replace("{err}", &err.to_string()),
+                    BatchError::Lost => lost_msg.clone(),
+                };
+                fail_msg.replace("{err}", &err)
+            }
+        };
+        eprintln!(
+            "{}",
+            line.replace("{done}", &done.to_string())
+                .replace("{total}", &total.to_string())
+                .replace("{id}", &outcome.job.id)
+        );
+    };
+
+    let _no_core = disable_core_dumps();
+    let outcomes = match run_batch(jobs, &options, namer, progress) {
+        Ok(outcomes) => outcomes,
+        Err(e) => fail(
+            text(
+                lang,
+                "error.large_stack_create",
+                "대용량 스택 스레드를 생성하는 도중 오류가 발생했습니다: {err}",
+            )
+            .replace("{err}", &e.to_string()),
+        ),
+    };
+
+    if let Err(e) = write_index(index_path, &outcomes, args.force) {
+        let msg = if e.kind() == io::ErrorKind::AlreadyExists {
+            text(
+                lang,
+                "error.file.exists",
+                "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)",
+            )
+            .replace("{path}", index_path)
+        } else {
+            text(
+                lang,
+                "error.batch.index",
+                "인덱스 파일을 기록하는 도중 오류가 발생했습니다: {err}",
+            )
+            .replace("{err}", &e.to_string())
+        };
+        fail(msg);
+    }
+
+    let failed = outcomes.iter().filter(|o| o.result.is_err()).count();
+    println!(
+        "{}",
+        text(
+            lang,
+            "info.batch.done",
+            "일괄 생성 완료: 성공 {ok}건, 실패 {failed}건. 인덱스: {path}",
+        )
+        .replace("{ok}", &(outcomes.len() - failed).to_string())
+        .replace("{failed}", &failed.to_string())
+        .replace("{path}", index_path)
+    );
+    if failed > 0 {
+        process::exit(1);
+    }
+}
+
+/// 보안 묶음 이름을 해석합니다. 없는 이름이면 사용 가능한 묶음을 안내하고 종료합니다.
+pub fn resolve_suite(name: &str, lang: &str) -> &'static Suite {
+    find_suite(name).unwrap_or_else(|| {
+        fail(
+            text(
+                lang,
+                "error.suite.unknown",
+                "알 수 없는 보안 묶음: {suite} (사용 가능: {suites})",
+            )
+            .replace("{suite}", name)
+            .replace(
+                "{suites}",
+                &SUITES.iter().map(|s| s.name).collect::<Vec<_>>().join(", "),
+            ),
+        )
+    })
+}
+
+/// 보안 묶음의 KEM·서명 키 페어 작업 두 건을 만듭니다.
+fn jobs_from_suite(suite: &Suite, lang: &str) -> Vec<BatchJob> {
+    eprintln!(
+        "{}",
+        text(lang, "info.suite.selected", "보안 묶음 {suite}: {pairs}")
+            .replace("{suite}", suite.name)
+            .replace("{pairs}", &suite.describe())
+    );
+    suite
+        .pairs()
+        .into_iter()
+        .enumerate()
+        .map(|(i, (role, variant))| BatchJob {
+            id: role.to_string(),
+            n: i + 1,
+            algorithm: variant.algorithm(),
+            variant: variant.clone(),
+        })
+        .collect()
+}
+
+/// `-count N`으로부터 0으로 채운 순번 ID의 작업 목록을 만듭니다.
+fn jobs_from_count(prog: &str, args: &BatchArgs) -> Vec<BatchJob> {
+    let lang = args.lang.as_str();
+    let raw = args.count.as_deref().unwrap_or_default();
+    let count = match raw.parse::<usize>() {
+        Ok(n) if n > 0 => n,
+        _ => fail(text(lang, "error.batch.count", "잘못된 개수: {value}").replace("{value}", raw)),
+    };
+
+    let algorithm = match &args.algorithm {
+        Some(a) => a,
+        None => {
+            eprintln!(
+                "{}",
+                text(lang, "error.missing_alg", "필수 인자 누락: -alg <알고리즘>")
+            );
+            usage_and_exit(prog, lang);
+        }
+    };
+    let (alg, variant) =
+        resolve_algorithm(algorithm, args.variant.as_deref(), lang).unwrap_or_else(|msg| fail(msg));
+
+    let width = count.to_string().len();
+    (1..=count)
+        .map(|n| BatchJob {
+            id: format!("{:0width$}", n, width = width),
+            n,
+            algorithm: alg,
+            variant: variant.clone(),
+        })
+        .collect()
+}
+
+/// 매니페스트 파일(또는 `-`일 때 표준 입력)을 읽어 작업 목록을 만듭니다.
+///
+/// 각 행은 `<id> [<알고리즘> [<배리언트>]]` 형식이며 `#` 이후는 주석입니다.
+/// 알고리즘을 생략한 행은 `-alg`/`-var` 값을 사용합니다.
+fn load_manifest(path: &str, args: &BatchArgs) -> Vec<BatchJob> {
+    let lang = args.lang.as_str();
+    let mut content = String::new();
+    let read = if path == STDIO_PATH {
+        io::stdin().lock().read_to_string(&mut content).map(|_| ())
+    } else {
+        std::fs::read_to_string(path).map(|c| content = c)
+    };
+    if let Err(e) = read {
+        fail(
+            text(
+                lang,
+                "error.batch.manifest_read",
+                "매니페스트를 읽는 도중 오류가 발생했습니다({path}): {err}",
+            )
+            .replace("{path}", path)
+            .replace("{err}", &e.to_string()),
+        );
+    }
+
+    let line_error = |line_no: usize, reason: String| -> ! {
+        fail(
+            text(
+                lang,
+                "error.batch.manifest_line",
+                "매니페스트 {line}행을 해석할 수 없습니다: {reason}",
+            )
+            .replace("{line}", &line_no.to_string())
+            .replace("{reason}", &reason),
+        )
+    };
+
+    let mut seen = HashSet::new();
+    let mut jobs = Vec::new();
+    for (idx, raw_line) in content.lines().enumerate() {
+        let line = raw_line.split('#').next().unwrap_or_default().trim();
+        if line.is_empty() {
+            continue;
+        }
+        let fields: Vec<&str> = line.split_whitespace().collect();
+        if fields.len() > 3 {
+            line_error(idx + 1, line.to_string());
+        }
+
+        let id = fields[0];
+        if id == "." || id == ".." || id.contains(['/', '\\']) {
+            line_error(
+                idx + 1,
+                text(lang, "error.batch.invalid_id", "사용할 수 없는 ID: {id}").replace("{id}", id),
+            );
+        }
+        if !seen.insert(id.to_string()) {
+            line_error(
+                idx + 1,
+                text(lang, "error.batch.duplicate_id", "중복된 ID: {id}").replace("{id}", id),
+            );
+        }
+
+        let resolved = match fields.get(1) {
+            Some(alg) => resolve_algorithm(alg, fields.get(2).copied(), lang),
+            None => match &args.algorithm {
+                Some(alg) => resolve_algorithm(alg, args.variant.as_deref(), lang),
+                None => Err(text(
+                    lang,
+                    "error.missing_alg",
+                    "필수 인자 누락: -alg <알고리즘>",
+                )),
+            },
+        };
+        let (algorithm, variant) = resolved.unwrap_or_else(|msg| line_error(idx + 1, msg));
+
+        jobs.push(BatchJob {
+            id: id.to_string(),
+            n: jobs.len() + 1,
+            algorithm,
+            variant,
+        });
+    }
+
+    if jobs.is_empty() {
+        fail(
+            text(
+                lang,
+                "error.batch.manifest_empty",
+                "매니페스트에 생성할 항목이 없습니다: {path}",
+            )
+            .replace("{path}", path),
+        );
+    }
+    jobs
+}
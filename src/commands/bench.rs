@@ -0,0 +1,489 @@
+use std::io::{self, Write};
+use std::path::Path;
+use std::time::Duration;
+
+use serde::Serialize;
+
+use keypairer::internals::bench::{BenchOptions, BenchResult, Timing, all_variants, bench_variant};
+use keypairer::internals::key_io::{STDIO_PATH, write_atomic};
+use keypairer::internals::keygen::Variant;
+use keypairer::run_with_variant_stack;
+
+use super::share::write_error;
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{default_lang, resolve_algorithm};
+
+/// `bench` 출력 형식
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum BenchFormat {
+    Table,
+    Json,
+}
+
+/// `bench` 인자
+struct BenchArgs {
+    algorithm: Option<String>,
+    variant: Option<String>,
+    iterations: Option<String>,
+    budget: Option<String>,
+    format: BenchFormat,
+    output: Option<String>,
+    force: bool,
+    lang: String,
+}
+
+/// JSON 보고서
+#[derive(Serialize)]
+struct BenchReport<'a> {
+    tool_version: &'static str,
+    /// `<아키텍처>-<운영체제>`
+    target: String,
+    /// 디버그 빌드에서 측정했는지 여부 (디버그 빌드 수치는 비교에 쓰지 마세요)
+    debug_build: bool,
+    iterations: usize,
+    budget_seconds: f64,
+    results: &'a [BenchResult],
+}
+
+/// 인자 해석 실패
+#[derive(Debug)]
+enum ArgError {
+    /// 사용법을 안내할 오류 (먼저 출력할 메시지)
+    Usage(Option<String>),
+    /// 메시지를 출력하고 종료할 오류
+    Invalid(String),
+}
+
+impl BenchArgs {
+    fn new(lang: String) -> BenchArgs {
+        BenchArgs {
+            algorithm: None,
+            variant: None,
+            iterations: None,
+            budget: None,
+            format: BenchFormat::Table,
+            output: None,
+            force: false,
+            lang,
+        }
+    }
+}
+
+/// `bench` 하위 명령을 실행합니다.
+pub fn run(prog: &str, args: &[String]) {
+    let mut parsed = BenchArgs::new(default_lang());
+    let selected = parse_args(args, &mut parsed)
+        .and_then(|()| select_variants(&parsed))
+        .and_then(|variants| Ok((variants, bench_options(&parsed)?)));
+    match selected {
+        Ok((variants, options)) => bench(&parsed, variants, options),
+        Err(ArgError::Usage(message)) => {
+            if let Some(message) = message {
+                eprintln!("{}", message);
+            }
+            usage_and_exit(prog, &parsed.lang)
+        }
+        Err(ArgError::Invalid(message)) => fail(message),
+    }
+}
+
+/// 플래그를 `parsed`에 채웁니다. 실패해도 그때까지 읽은 언어는 남아 있습니다.
+fn parse_args(args: &[String], parsed: &mut BenchArgs) -> Result<(), ArgError> {
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-alg" | "-algorithm" | "-var" | "-variant" | "-n" | "-iterations" | "-time"
+            | "-format" | "-out" | "-l" | "-lang" => {
+                let flag = args[i].as_str();
+                i += 1;
+                let value = args.get(i).ok_or(ArgError::Usage(None))?.clone();
+                match flag {
+                    "-alg" | "-algorithm" => parsed.algorithm = Some(value.to_lowercase()),
+                    "-var" | "-variant" => parsed.variant = Some(value.to_lowercase()),
+                    "-n" | "-iterations" => parsed.iterations = Some(value),
+                    "-time" => parsed.budget = Some(value),
+                    "-format" => {
+                        parsed.format = match value.to_lowercase().as_str() {
+                            "table" => BenchFormat::Table,
+                            "json" => BenchFormat::Json,
+                            _ => {
+                                return Err(ArgError::Invalid(
+                                    text(
+                                        &parsed.lang,
+                                        "error.bench.format",
+                                        "지원하지 않는 벤치마크 출력 형식: {format} (table, json)",
+                                    )
+                                    .replace("{format}", &value),
+                                ));
+                            }
+                        }
+                    }
+                    "-out" => parsed.output = Some(value),
+                    _ => parsed.lang = value.to_lowercase(),
+                }
+            }
+            "-f" | "-force" => parsed.force = true,
+            "-h" | "--help" => return Err(ArgError::Usage(None)),
+            other => {
+                return Err(ArgError::Usage(Some(
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other),
+                )));
+            }
+        }
+        i += 1;
+    }
+    Ok(())
+}
+
+/// 측정 대상: -alg/-var로 좁히지 않으면 모든 배리언트
+fn select_variants(args: &BenchArgs) -> Result<Vec<Variant>, ArgError> {
+    let lang = args.lang.as_str();
+    match (&args.algorithm, &args.variant) {
+        (Some(alg), Some(var)) => resolve_algorithm(alg, Some(var), lang)
+            .map(|(_, variant)| vec![variant])
+            .map_err(ArgError::Invalid),
+        (Some(alg), None) => {
+            let (algorithm, _) = resolve_algorithm(alg, None, lang).map_err(ArgError::Invalid)?;
+            Ok(all_variants()
+                .into_iter()
+                .filter(|v| v.algorithm() == algorithm)
+                .collect())
+        }
+        (None, Some(_)) => Err(ArgError::Usage(None)),
+        (None, None) => Ok(all_variants()),
+    }
+}
+
+/// `-n`, `-time`을 측정 옵션으로 해석합니다.
+fn bench_options(args: &BenchArgs) -> Result<BenchOptions, ArgError> {
+    let lang = args.lang.as_str();
+    let mut options = BenchOptions::default();
+    if let Some(value) = &args.iterations {
+        options.iterations = match value.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => {
+                return Err(ArgError::Invalid(
+                    text(lang, "error.bench.iterations", "잘못된 반복 횟수: {value}")
+                        .replace("{value}", value),
+                ));
+            }
+        };
+    }
+    if let Some(value) = &args.budget {
+        options.budget = match value.parse::<f64>() {
+            Ok(secs) if secs.is_finite() && secs > 0.0 => Duration::from_secs_f64(secs),
+            _ => {
+                return Err(ArgError::Invalid(
+                    text(lang, "error.bench.time", "잘못된 시간 예산(초): {value}")
+                        .replace("{value}", value),
+                ));
+            }
+        };
+    }
+    Ok(options)
+}
+
+fn bench(args: &BenchArgs, variants: Vec<Variant>, options: BenchOptions) {
+    let lang = args.lang.as_str();
+
+    if cfg!(debug_assertions) {
+        eprintln!(
+            "{}",
+            text(
+                lang,
+                "warn.bench.debug",
+                "경고: 디버그 빌드입니다. 비교할 수치는 --release로 빌드해서 측정하세요.",
+            )
+        );
+    }
+
+    let total = variants.len();
+    let mut results = Vec::with_capacity(total);
+    let mut failed = 0usize;
+    for (n, variant) in variants.into_iter().enumerate() {
+        eprintln!(
+            "{}",
+            text(
+                lang,
+                "info.bench.progress",
+                "[{done}/{total}] {standard} 측정 중"
+            )
+            .replace("{done}", &(n + 1).to_string())
+            .replace("{total}", &total.to_string())
+            .replace("{standard}", &variant.standard_name())
+        );
+        let target = variant.clone();
+        match run_with_variant_stack(&variant, move || bench_variant(&target, &options), lang) {
+            Ok(result) => results.push(result),
+            Err(e) => {
+                failed += 1;
+                eprintln!(
+                    "{}",
+                    text(lang, "error.bench.failed", "{standard} 측정 실패: {err}")
+                        .replace("{standard}", &variant.standard_name())
+                        .replace("{err}", &e.to_string())
+                );
+            }
+        }
+    }
+
+    let report = match args.format {
+        BenchFormat::Table => render_table(&results, lang),
+        BenchFormat::Json => {
+            let report = BenchReport {
+                tool_version: env!("CARGO_PKG_VERSION"),
+                target: format!("{}-{}", std::env::consts::ARCH, std::env::consts::OS),
+                debug_build: cfg!(debug_assertions),
+                iterations: options.iterations,
+                budget_seconds: options.budget.as_secs_f64(),
+                results: &results,
+            };
+            let mut json =
+                serde_json::to_string_pretty(&report).unwrap_or_else(|e| fail(e.to_string()));
+            json.push('\n');
+            json
+        }
+    };
+
+    match args.output.as_deref() {
+        None | Some(STDIO_PATH) => {
+            let mut stdout = io::stdout().lock();
+            if let Err(e) = stdout
+                .write_all(report.as_bytes())
+                .and_then(|_| stdout.flush())
+            {
+                fail(write_error(STDIO_PATH, &e, lang));
+            }
+        }
+        Some(path) => {
+            if let Err(e) = write_atomic(Path::new(path), report.as_bytes(), 0o644, args.force) {
+                fail(write_error(path, &e, lang));
+            }
+        }
+    }
+
+    if failed > 0 {
+        std::process::exit(1);
+    }
+}
+
+/// 측정 결과를 열 맞춤한 표로 만듭니다.
+fn render_table(results: &[BenchResult], lang: &str) -> String {
+    let header = [
+        text(lang, "bench.column.variant", "배리언트"),
+        text(lang, "bench.column.category", "범주"),
+        text(lang, "bench.column.keygen", "키 생성 (중앙값/p95)"),
+        text(lang, "bench.column.encaps_sign", "캡슐화·서명"),
+        text(lang, "bench.column.decaps_verify", "역캡슐화·검증"),
+        text(lang, "bench.column.pk", "공개키"),
+        text(lang, "bench.column.sk", "비밀키"),
+        text(lang, "bench.column.ct_sig", "암호문·서명"),
+    ];
+    let mut rows = vec![header.to_vec()];
+    for r in results {
+        rows.push(vec![
+            r.standard_name.clone(),
+            r.nist_category.to_string(),
+            timing_cell(Some(&r.keygen)),
+            timing_cell(r.encapsulate.as_ref().or(r.sign.as_ref())),
+            timing_cell(r.decapsulate.as_ref().or(r.verify.as_ref())),
+            r.public_key_bytes.to_string(),
+            r.secret_key_bytes.to_string(),
+            r.ciphertext_bytes
+                .or(r.signature_bytes)
+                .map_or_else(|| "-".to_string(), |n| n.to_string()),
+        ]);
+    }
+
+    let columns = rows[0].len();
+    let widths: Vec<usize> = (0..columns)
+        .map(|c| {
+            rows.iter()
+                .map(|row| display_width(&row[c]))
+                .max()
+                .unwrap_or(0)
+        })
+        .collect();
+    let mut out = String::new();
+    for (idx, row) in rows.iter().enumerate() {
+        let line: Vec<String> = row
+            .iter()
+            .enumerate()
+            .map(|(c, cell)| {
+                let pad = " ".repeat(widths[c] - display_width(cell));
+                // 이름 열은 왼쪽, 수치 열은 오른쪽 정렬
+                if c == 0 {
+                    format!("{}{}", cell, pad)
+                } else {
+                    format!("{}{}", pad, cell)
+                }
+            })
+            .collect();
+        out.push_str(line.join("  ").trim_end());
+        out.push('\n');
+        if idx == 0 {
+            let rule: Vec<String> = widths.iter().map(|w| "-".repeat(*w)).collect();
+            out.push_str(&rule.join("  "));
+            out.push('\n');
+        }
+    }
+    out
+}
+
+/// `중앙값 / p95` 표기 (없으면 `-`)
+fn timing_cell(timing: Option<&Timing>) -> String {
+    match timing {
+        Some(t) => format!(
+            "{} / {}",
+            format_micros(t.median_us),
+            format_micros(t.p95_us)
+        ),
+        None => "-".to_string(),
+    }
+}
+
+/// 마이크로초를 µs, ms, s 중 알맞은 단위로 표시합니다.
+fn format_micros(us: f64) -> String {
+    if us < 1_000.0 {
+        format!("{:.1} µs", us)
+    } else if us < 1_000_000.0 {
+        format!("{:.2} ms", us / 1_000.0)
+    } else {
+        format!("{:.2} s", us / 1_000_000.0)
+    }
+}
+
+/// 터미널 표시 폭 (한글 등 전각 문자는 2칸)
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60 => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<BenchArgs, ArgError> {
+        let args: Vec<String> = args.iter().map(|s| s.to_string()).collect();
+        let mut parsed = BenchArgs::new("ko".to_string());
+        parse_args(&args, &mut parsed).map(|()| parsed)
+    }
+
+    #[test]
+    fn flags_and_their_aliases_are_parsed() {
+        let parsed = parse(&[
+            "-algorithm",
+            "MLKEM",
+            "-var",
+            "768",
+            "-n",
+            "5",
+            "-time",
+            "0.5",
+            "-format",
+            "JSON",
+            "-out",
+            "bench.json",
+            "-force",
+            "-lang",
+            "EN",
+        ])
+        .unwrap();
+        assert_eq!(parsed.algorithm.as_deref(), Some("mlkem"));
+        assert_eq!(parsed.variant.as_deref(), Some("768"));
+        assert_eq!(parsed.iterations.as_deref(), Some("5"));
+        assert_eq!(parsed.budget.as_deref(), Some("0.5"));
+        assert!(parsed.format == BenchFormat::Json);
+        assert_eq!(parsed.output.as_deref(), Some("bench.json"));
+        assert!(parsed.force);
+        assert_eq!(parsed.lang, "en");
+
+        let defaults = parse(&[]).unwrap();
+        assert!(defaults.format == BenchFormat::Table && !defaults.force);
+        assert_eq!(defaults.output, None);
+    }
+
+    #[test]
+    fn missing_values_unknown_flags_and_formats_are_errors() {
+        assert!(matches!(parse(&["-n"]), Err(ArgError::Usage(None))));
+        assert!(matches!(parse(&["-h"]), Err(ArgError::Usage(None))));
+        assert!(matches!(
+            parse(&["-iters", "5"]),
+            Err(ArgError::Usage(Some(message))) if message.contains("-iters")
+        ));
+        assert!(matches!(
+            parse(&["-format", "csv"]),
+            Err(ArgError::Invalid(message)) if message.contains("csv")
+        ));
+
+        // 실패해도 그 앞에서 읽은 언어로 사용법을 안내
+        let args: Vec<String> = ["-lang", "en", "-bogus"].map(String::from).to_vec();
+        let mut parsed = BenchArgs::new("ko".to_string());
+        assert!(parse_args(&args, &mut parsed).is_err());
+        assert_eq!(parsed.lang, "en");
+    }
+
+    #[test]
+    fn variants_are_narrowed_by_algorithm_and_variant() {
+        let all = select_variants(&parse(&[]).unwrap()).unwrap();
+        assert_eq!(all, all_variants());
+
+        let mlkem = select_variants(&parse(&["-alg", "mlkem"]).unwrap()).unwrap();
+        assert_eq!(
+            mlkem,
+            [Variant::MLKEM512, Variant::MLKEM768, Variant::MLKEM1024]
+        );
+        let one = select_variants(&parse(&["-alg", "mldsa", "-var", "65"]).unwrap()).unwrap();
+        assert_eq!(one, [Variant::MLDSA65]);
+
+        // 배리언트만 주면 알고리즘을 알 수 없음
+        assert!(matches!(
+            select_variants(&parse(&["-var", "768"]).unwrap()),
+            Err(ArgError::Usage(None))
+        ));
+        for args in [&["-alg", "rsa"][..], &["-alg", "mlkem", "-var", "2048"]] {
+            assert!(matches!(
+                select_variants(&parse(args).unwrap()),
+                Err(ArgError::Invalid(_))
+            ));
+        }
+    }
+
+    #[test]
+    fn iterations_and_time_budget_must_be_positive() {
+        let options = bench_options(&parse(&["-n", "3", "-time", "0.25"]).unwrap()).unwrap();
+        assert_eq!(options.iterations, 3);
+        assert_eq!(options.budget, Duration::from_millis(250));
+
+        let defaults = bench_options(&parse(&[]).unwrap()).unwrap();
+        assert_eq!(defaults.iterations, BenchOptions::default().iterations);
+
+        for args in [
+            &["-n", "0"][..],
+            &["-n", "-1"],
+            &["-n", "many"],
+            &["-time", "0"],
+            &["-time", "-2"],
+            &["-time", "inf"],
+            &["-time", "NaN"],
+        ] {
+            assert!(
+                matches!(
+                    bench_options(&parse(args).unwrap()),
+                    Err(ArgError::Invalid(_))
+                ),
+                "{args:?}"
+            );
+        }
+    }
+}
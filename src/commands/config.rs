@@ -0,0 +1,194 @@
+use keypairer::internals::config::{DEFAULT_LANG, Encoding, Setting, Source, default_path};
+use keypairer::internals::entropy;
+
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{default_lang, load_settings};
+
+/// `config show` 인자
+struct ConfigArgs {
+    config: Option<String>,
+    profile: Option<String>,
+    /// 명령행에서 지정한 언어 (`config show`에서는 출처를 `-lang`으로 표시)
+    cli_lang: Option<String>,
+    lang: String,
+}
+
+/// `config` 하위 명령을 실행합니다.
+pub fn run(prog: &str, args: &[String]) {
+    let mut parsed = ConfigArgs {
+        config: None,
+        profile: None,
+        cli_lang: None,
+        lang: default_lang(),
+    };
+
+    let action = args.first().map(String::as_str);
+    let mut i = 1usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-config" | "-profile" | "-l" | "-lang" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(prog, &parsed.lang);
+                }
+                let value = args[i].clone();
+                match flag.as_str() {
+                    "-config" => parsed.config = Some(value),
+                    "-profile" => parsed.profile = Some(value),
+                    _ => {
+                        parsed.lang = value.to_lowercase();
+                        parsed.cli_lang = Some(parsed.lang.clone());
+                    }
+                }
+            }
+            "-h" | "--help" => usage_and_exit(prog, &parsed.lang),
+            other => {
+                eprintln!(
+                    "{}",
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other)
+                );
+                usage_and_exit(prog, &parsed.lang);
+            }
+        }
+        i += 1;
+    }
+
+    match action {
+        Some("show") => show(&parsed),
+        Some(other) => fail(
+            text(
+                &parsed.lang,
+                "error.config.action",
+                "알 수 없는 config 동작: {action} (show)",
+            )
+            .replace("{action}", other),
+        ),
+        None => usage_and_exit(prog, &parsed.lang),
+    }
+}
+
+/// 적용되는 설정과 각 값의 출처를 TOML 형식으로 출력합니다.
+fn show(args: &ConfigArgs) {
+    let settings = load_settings(args.config.as_deref(), args.profile.as_deref(), &args.lang);
+    let lang = args.cli_lang.clone().unwrap_or_else(|| settings.lang());
+    let lang = lang.as_str();
+
+    match &settings.path {
+        Some(path) => println!(
+            "# {}",
+            text(lang, "info.config.file", "설정 파일: {path}")
+                .replace("{path}", &path.display().to_string())
+        ),
+        None => println!(
+            "# {}",
+            text(
+                lang,
+                "info.config.no_file",
+                "설정 파일 없음 (기본 경로: {path})",
+            )
+            .replace(
+                "{path}",
+                &default_path()
+                    .map(|p| p.display().to_string())
+                    .unwrap_or_else(|| "-".to_string()),
+            )
+        ),
+    }
+    if let Some(profile) = &settings.profile {
+        println!(
+            "# {}",
+            text(lang, "info.config.profile", "프로필: {profile}").replace("{profile}", profile)
+        );
+    }
+
+    let entropy = if entropy::is_pluggable() {
+        text(
+            lang,
+            "info.config.entropy_pluggable",
+            "난수 소스 선택(-entropy): 사용 가능",
+        )
+    } else {
+        text(
+            lang,
+            "info.config.entropy_os_only",
+            "난수 소스 선택(-entropy): 사용 불가, 이 빌드는 custom-entropy 기능과 getrandom_backend=\"custom\" 없이 컴파일되어 os만 지원",
+        )
+    };
+    println!("# {}", entropy);
+
+    let lang_setting = match &args.cli_lang {
+        Some(value) => Some((value.clone(), None)),
+        None => {
+            Some(string_row(&settings.lang).unwrap_or_else(|| (DEFAULT_LANG.to_string(), None)))
+        }
+    };
+    let rows = [
+        ("algorithm", string_row(&settings.algorithm)),
+        ("variant", string_row(&settings.variant)),
+        ("pk_encoding", encoding_row(&settings.pk_encoding)),
+        ("sk_encoding", encoding_row(&settings.sk_encoding)),
+        ("outdir", string_row(&settings.outdir)),
+        ("lang", lang_setting),
+        (
+            "policy",
+            Some((
+                settings.policy().to_string(),
+                settings.policy.as_ref().map(|s| &s.source),
+            )),
+        ),
+    ];
+    for (key, row) in rows {
+        match row {
+            Some((value, source)) => {
+                let origin = match (source, key) {
+                    (Some(source), _) => source_label(source, lang),
+                    (None, "lang") if args.cli_lang.is_some() => {
+                        text(lang, "config.source.cli", "명령행 {flag}").replace("{flag}", "-lang")
+                    }
+                    (None, _) => text(lang, "config.source.default", "기본값"),
+                };
+                println!("{:<11} = {:<20} # {}", key, quote(&value), origin);
+            }
+            None => println!(
+                "# {:<9} = {:<20} # {}",
+                key,
+                "",
+                text(lang, "config.source.unset", "설정 없음")
+            ),
+        }
+    }
+}
+
+/// 문자열 설정의 값과 출처
+fn string_row(setting: &Option<Setting<String>>) -> Option<(String, Option<&Source>)> {
+    setting.as_ref().map(|s| (s.value.clone(), Some(&s.source)))
+}
+
+/// 저장 형식 설정의 값과 출처 (없으면 기본값 `binary`)
+fn encoding_row(setting: &Option<Setting<Encoding>>) -> Option<(String, Option<&Source>)> {
+    Some(match setting {
+        Some(s) => (s.value.as_str().to_string(), Some(&s.source)),
+        None => (Encoding::Binary.as_str().to_string(), None),
+    })
+}
+
+/// 출처 설명
+fn source_label(source: &Source, lang: &str) -> String {
+    match source {
+        Source::File => text(lang, "config.source.file", "설정 파일 [defaults]"),
+        Source::Policy => text(lang, "config.source.policy", "설정 파일 [policy]"),
+        Source::Profile(name) => text(lang, "config.source.profile", "프로필 [profiles.{profile}]")
+            .replace("{profile}", name),
+        Source::Env(var) => {
+            text(lang, "config.source.env", "환경 변수 {var}").replace("{var}", var)
+        }
+    }
+}
+
+/// TOML 기본 문자열로 인용합니다.
+fn quote(value: &str) -> String {
+    format!("\"{}\"", value.replace('\\', "\\\\").replace('"', "\\\""))
+}
@@ -0,0 +1,488 @@
+use std::fs::{self, File};
+use std::io::{self, BufReader, BufWriter, IsTerminal, Read, Write};
+use std::path::Path;
+
+use keypairer::internals::datetime::now_unix;
+use keypairer::internals::encrypt::{
+    DEFAULT_CHUNK_SIZE, Header, MAX_RECIPIENTS, decrypt_payload, encrypt_payload, open, seal,
+};
+use keypairer::internals::kem::{is_kem, variants_for_public_key};
+use keypairer::internals::key_io::{AtomicFile, STDIO_PATH, fingerprint};
+use keypairer::internals::keygen::{Variant, parse_algorithm, parse_variant};
+use keypairer::internals::lifecycle::KeyState;
+use keypairer::internals::metadata::{KeyMetadata, sidecar_path};
+use keypairer::{load_key, run_with_variant_stack};
+
+use super::store::open_store;
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{default_lang, resolve_algorithm};
+
+/// 암호화 파일 기본 확장자
+const ENCRYPTED_EXT: &str = "kpe";
+
+/// `encrypt`/`decrypt` 공통 인자
+struct CryptArgs {
+    /// 수신자 키 저장소 키 (여러 번 지정 가능)
+    to: Vec<String>,
+    /// 수신자 공개키 파일 (여러 번 지정 가능)
+    pk_paths: Vec<String>,
+    key: Option<String>,
+    sk_path: Option<String>,
+    algorithm: Option<String>,
+    variant: Option<String>,
+    input: String,
+    output: Option<String>,
+    force: bool,
+    store_dir: Option<String>,
+    lang: String,
+}
+
+/// 암호화 대상 공개키
+pub(crate) struct Recipient {
+    pub variant: Variant,
+    pub pk: Vec<u8>,
+    /// 메시지에 표시할 이름 (키 저장소 ID 또는 파일 경로)
+    pub name: String,
+}
+
+/// `encrypt`, `decrypt` 하위 명령을 실행합니다.
+pub fn run(prog: &str, command: &str, args: &[String]) {
+    let mut parsed = CryptArgs {
+        to: Vec::new(),
+        pk_paths: Vec::new(),
+        key: None,
+        sk_path: None,
+        algorithm: None,
+        variant: None,
+        input: STDIO_PATH.to_string(),
+        output: None,
+        force: false,
+        store_dir: None,
+        lang: default_lang(),
+    };
+
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-to" | "-pk" | "-pkpath" | "-key" | "-sk" | "-skpath" | "-alg" | "-algorithm"
+            | "-var" | "-variant" | "-in" | "-out" | "-store-dir" | "-l" | "-lang" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(prog, &parsed.lang);
+                }
+                let value = args[i].clone();
+                match flag.as_str() {
+                    "-to" => parsed.to.push(value),
+                    "-pk" | "-pkpath" => parsed.pk_paths.push(value),
+                    "-key" => parsed.key = Some(value),
+                    "-sk" | "-skpath" => parsed.sk_path = Some(value),
+                    "-alg" | "-algorithm" => parsed.algorithm = Some(value.to_lowercase()),
+                    "-var" | "-variant" => parsed.variant = Some(value.to_lowercase()),
+                    "-in" => parsed.input = value,
+                    "-out" => parsed.output = Some(value),
+                    "-store-dir" => parsed.store_dir = Some(value),
+                    _ => parsed.lang = value.to_lowercase(),
+                }
+            }
+            "-f" | "-force" => parsed.force = true,
+            "-h" | "--help" => usage_and_exit(prog, &parsed.lang),
+            other => {
+                eprintln!(
+                    "{}",
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other)
+                );
+                usage_and_exit(prog, &parsed.lang);
+            }
+        }
+        i += 1;
+    }
+
+    if command == "encrypt" {
+        encrypt(prog, &parsed);
+    } else {
+        decrypt(prog, &parsed);
+    }
+}
+
+fn encrypt(prog: &str, args: &CryptArgs) {
+    let lang = args.lang.as_str();
+    if args.to.is_empty() && args.pk_paths.is_empty() {
+        eprintln!(
+            "{}",
+            text(
+                lang,
+                "error.crypt.recipient",
+                "수신자를 -to <키 저장소 키> 또는 -pk <공개키 파일>로 지정하세요.",
+            )
+        );
+        usage_and_exit(prog, lang);
+    }
+
+    // 수신자 수집 (같은 공개키는 한 번만)
+    let mut recipients: Vec<Recipient> = Vec::new();
+    let from_store = args
+        .to
+        .iter()
+        .map(|query| recipient_from_store(query, args.store_dir.as_deref(), lang));
+    let from_files = args.pk_paths.iter().map(|path| {
+        recipient_from_file(
+            path,
+            args.algorithm.as_deref(),
+            args.variant.as_deref(),
+            lang,
+        )
+    });
+    for recipient in from_store.chain(from_files) {
+        if !recipients.iter().any(|r| r.pk == recipient.pk) {
+            recipients.push(recipient);
+        }
+    }
+    if recipients.len() > MAX_RECIPIENTS {
+        fail(
+            text(
+                lang,
+                "error.crypt.too_many",
+                "수신자는 최대 {max}명까지 지정할 수 있습니다.",
+            )
+            .replace("{max}", &MAX_RECIPIENTS.to_string()),
+        );
+    }
+
+    let output = args
+        .output
+        .clone()
+        .unwrap_or_else(|| default_encrypt_output(&args.input));
+    if output == STDIO_PATH && io::stdout().is_terminal() {
+        fail(text(
+            lang,
+            "error.crypt.tty",
+            "암호문을 터미널에 출력할 수 없습니다. -out으로 파일을 지정하거나 파이프로 연결하세요.",
+        ));
+    }
+
+    // 수신자별 KEM 캡슐화 (수신자 배리언트 중 가장 큰 스택 예산만큼)
+    let names = recipients
+        .iter()
+        .map(|r| format!("{} ({})", r.name, r.variant.standard_name()))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let keys = recipients
+        .into_iter()
+        .map(|r| (r.variant, r.pk))
+        .collect::<Vec<_>>();
+    let largest = largest_stack_variant(keys.iter().map(|(variant, _)| variant));
+    let sealed = run_with_variant_stack(&largest, move || seal(&keys, DEFAULT_CHUNK_SIZE), lang);
+    let (header, key) = sealed.unwrap_or_else(|e| fail(e.message(lang)));
+
+    let mut input = open_input(&args.input, lang);
+    let mut sink = Sink::create(&output, 0o644, args.force, lang);
+    let written = sink
+        .write_all(&header.to_bytes())
+        .map_err(Into::into)
+        .and_then(|_| encrypt_payload(&key, header.chunk_size, &mut input, &mut sink));
+    let total = match written {
+        Ok(total) => total,
+        Err(e) => {
+            // 종료 전에 임시 파일 정리
+            drop(sink);
+            fail(e.message(lang));
+        }
+    };
+    sink.commit(args.force, lang);
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.crypt.encrypted",
+            "암호화 완료: {bytes}바이트 → {path} (수신자: {recipients})",
+        )
+        .replace("{bytes}", &total.to_string())
+        .replace("{path}", &display_path(&output))
+        .replace("{recipients}", &names)
+    );
+}
+
+fn decrypt(prog: &str, args: &CryptArgs) {
+    let lang = args.lang.as_str();
+    let sk = match (&args.key, &args.sk_path) {
+        (Some(query), None) => {
+            let store = open_store(args.store_dir.as_deref(), lang);
+            let entry = store.find(query).unwrap_or_else(|e| fail(e.message(lang)));
+            store
+                .read_secret(&entry.id)
+                .unwrap_or_else(|e| fail(e.message(lang)))
+        }
+        (None, Some(path)) => load_key(path, "SECRET KEY", lang),
+        _ => {
+            eprintln!(
+                "{}",
+                text(
+                    lang,
+                    "error.crypt.secret",
+                    "비밀키를 -key <키 저장소 키> 또는 -sk <비밀키 파일> 중 하나로 지정하세요.",
+                )
+            );
+            usage_and_exit(prog, lang);
+        }
+    };
+
+    let mut input = open_input(&args.input, lang);
+    let (header, raw) = Header::read_from(&mut input).unwrap_or_else(|e| fail(e.message(lang)));
+
+    // 수신자 항목마다 역캡슐화 시도 (항목 배리언트 중 가장 큰 스택 예산만큼)
+    let ciphertext_header = header.clone();
+    let largest = largest_stack_variant(header.stanzas.iter().map(|stanza| &stanza.variant));
+    let (key, index) =
+        run_with_variant_stack(&largest, move || open(&ciphertext_header, &raw, &sk), lang)
+            .unwrap_or_else(|e| fail(e.message(lang)));
+    let variant = header.stanzas[index].variant.clone();
+
+    let output = args
+        .output
+        .clone()
+        .unwrap_or_else(|| default_decrypt_output(&args.input));
+    let mut sink = Sink::create(&output, 0o600, args.force, lang);
+    let total = match decrypt_payload(&key, header.chunk_size, &mut input, &mut sink) {
+        Ok(total) => total,
+        Err(e) => {
+            // 인증에 실패한 평문이 남지 않도록 임시 파일 정리
+            drop(sink);
+            fail(e.message(lang));
+        }
+    };
+    sink.commit(args.force, lang);
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.crypt.decrypted",
+            "복호화 완료: {bytes}바이트 → {path} ({standard})",
+        )
+        .replace("{bytes}", &total.to_string())
+        .replace("{path}", &display_path(&output))
+        .replace("{standard}", &variant.standard_name())
+    );
+}
+
+/// 키 저장소의 KEM 공개키를 수신자로 사용합니다. 사용 중이 아니거나 만료된 키는 거부합니다.
+pub(crate) fn recipient_from_store(query: &str, store_dir: Option<&str>, lang: &str) -> Recipient {
+    let store = open_store(store_dir, lang);
+    let entry = store.find(query).unwrap_or_else(|e| fail(e.message(lang)));
+    if entry.lifecycle.state != KeyState::Active || entry.lifecycle.is_expired(now_unix()) {
+        fail(
+            text(
+                lang,
+                "error.crypt.inactive",
+                "사용 중이 아니거나 만료된 키로는 암호화할 수 없습니다: {id} ({state})",
+            )
+            .replace("{id}", &entry.id)
+            .replace("{state}", entry.lifecycle.state.as_str()),
+        );
+    }
+    let variant = entry
+        .algorithm_variant()
+        .map(|(_, v)| v)
+        .filter(is_kem)
+        .unwrap_or_else(|| fail(not_kem_message(&entry.metadata.standard_name, lang)));
+    let pk = store
+        .read_public(&entry.id)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    Recipient {
+        variant,
+        pk,
+        name: entry.label.clone().unwrap_or(entry.id),
+    }
+}
+
+/// 공개키 파일을 수신자로 사용합니다.
+///
+/// 배리언트는 `-alg`/`-var`, 메타데이터 사이드카(`<이름>.json`), 공개키 길이 순으로 정합니다.
+pub(crate) fn recipient_from_file(
+    path: &str,
+    algorithm: Option<&str>,
+    variant: Option<&str>,
+    lang: &str,
+) -> Recipient {
+    let pk = load_key(path, "PUBLIC KEY", lang).to_vec();
+
+    let variant = if let Some(alg) = algorithm {
+        resolve_algorithm(alg, variant, lang)
+            .map(|(_, v)| v)
+            .unwrap_or_else(|msg| fail(msg))
+    } else if let Some(v) = variant_from_sidecar(path, &pk) {
+        v
+    } else {
+        match variants_for_public_key(pk.len()).as_slice() {
+            [one] => one.clone(),
+            [] => fail(not_kem_message(path, lang)),
+            many => fail(
+                text(
+                    lang,
+                    "error.crypt.ambiguous_variant",
+                    "공개키 길이로 배리언트를 정할 수 없습니다({variants}). -alg와 -var를 지정하세요.",
+                )
+                .replace(
+                    "{variants}",
+                    &many
+                        .iter()
+                        .map(|v| v.standard_name())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+            ),
+        }
+    };
+    if !is_kem(&variant) {
+        fail(not_kem_message(&variant.standard_name(), lang));
+    }
+
+    Recipient {
+        variant,
+        pk,
+        name: path.to_string(),
+    }
+}
+
+/// 공개키 옆의 메타데이터 사이드카에서 배리언트를 읽습니다. 지문이 다르면 무시합니다.
+fn variant_from_sidecar(pk_path: &str, pk: &[u8]) -> Option<Variant> {
+    let path = sidecar_path(pk_path, STDIO_PATH)?;
+    let meta: KeyMetadata = serde_json::from_str(&fs::read_to_string(path).ok()?).ok()?;
+    if meta.fingerprint != fingerprint(pk) {
+        return None;
+    }
+    let alg = parse_algorithm(&meta.algorithm)?;
+    parse_variant(alg, &meta.variant)
+}
+
+fn not_kem_message(name: &str, lang: &str) -> String {
+    text(
+        lang,
+        "error.crypt.not_kem",
+        "KEM(ML-KEM, HQC, Classic McEliece) 공개키가 아닙니다: {name}",
+    )
+    .replace("{name}", name)
+}
+
+/// 입력 파일(또는 표준 입력)을 엽니다.
+pub(crate) fn open_input(path: &str, lang: &str) -> Box<dyn Read> {
+    if path == STDIO_PATH {
+        return Box::new(BufReader::new(io::stdin()));
+    }
+    match File::open(path) {
+        Ok(file) => Box::new(BufReader::new(file)),
+        Err(e) => fail(
+            text(
+                lang,
+                "error.file.read",
+                "파일을 읽는 도중 오류가 발생했습니다({path}): {err}",
+            )
+            .replace("{path}", path)
+            .replace("{err}", &e.to_string()),
+        ),
+    }
+}
+
+fn default_encrypt_output(input: &str) -> String {
+    if input == STDIO_PATH {
+        STDIO_PATH.to_string()
+    } else {
+        format!("{}.{}", input, ENCRYPTED_EXT)
+    }
+}
+
+fn default_decrypt_output(input: &str) -> String {
+    match Path::new(input).extension() {
+        Some(ext) if input != STDIO_PATH && ext == ENCRYPTED_EXT => {
+            Path::new(input).with_extension("").display().to_string()
+        }
+        _ => STDIO_PATH.to_string(),
+    }
+}
+
+fn display_path(path: &str) -> String {
+    if path == STDIO_PATH {
+        "<stdout>".to_string()
+    } else {
+        path.to_string()
+    }
+}
+
+/// 출력 대상: 표준 출력 또는 성공 시에만 커밋되는 원자적 파일
+pub(crate) enum Sink {
+    Stdout(BufWriter<io::Stdout>),
+    File(BufWriter<AtomicFile>, String),
+}
+
+impl Sink {
+    pub(crate) fn create(path: &str, mode: u32, force: bool, lang: &str) -> Sink {
+        if path == STDIO_PATH {
+            return Sink::Stdout(BufWriter::new(io::stdout()));
+        }
+        match AtomicFile::create(Path::new(path), mode, force) {
+            Ok(file) => Sink::File(BufWriter::new(file), path.to_string()),
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => fail(
+                text(
+                    lang,
+                    "error.file.exists",
+                    "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)",
+                )
+                .replace("{path}", path),
+            ),
+            Err(e) => fail(write_error(path, &e, lang)),
+        }
+    }
+
+    pub(crate) fn commit(self, force: bool, lang: &str) {
+        let (committed, path) = match self {
+            Sink::Stdout(mut out) => (out.flush(), STDIO_PATH.to_string()),
+            Sink::File(out, path) => (
+                out.into_inner()
+                    .map_err(|e| e.into_error())
+                    .and_then(|file| file.commit(force)),
+                path,
+            ),
+        };
+        if let Err(e) = committed {
+            fail(write_error(&path, &e, lang));
+        }
+    }
+}
+
+impl Write for Sink {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        match self {
+            Sink::Stdout(out) => out.write(buf),
+            Sink::File(out, _) => out.write(buf),
+        }
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        match self {
+            Sink::Stdout(out) => out.flush(),
+            Sink::File(out, _) => out.flush(),
+        }
+    }
+}
+
+/// 스택 예산이 가장 큰 배리언트 (없으면 [`Variant::Unknown`], 즉 기본 크기)
+fn largest_stack_variant<'a>(variants: impl Iterator<Item = &'a Variant>) -> Variant {
+    variants
+        .max_by_key(|variant| variant.stack_size())
+        .cloned()
+        .unwrap_or(Variant::Unknown)
+}
+
+fn write_error(path: &str, e: &io::Error, lang: &str) -> String {
+    text(
+        lang,
+        "error.file.write",
+        "파일에 쓰는 도중 오류가 발생했습니다({path}): {err}",
+    )
+    .replace("{path}", &display_path(path))
+    .replace("{err}", &e.to_string())
+}
@@ -0,0 +1,567 @@
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use base64::Engine as _;
+use base64::engine::general_purpose;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use keypairer::internals::datetime::{format_rfc3339, now_unix};
+use keypairer::internals::key_io::{STDIO_PATH, fingerprint, write_atomic};
+use keypairer::internals::keygen::{Variant, parse_algorithm, parse_variant};
+use keypairer::internals::lifecycle::KeyState;
+use keypairer::internals::manifest::{
+    FileStatus, MANIFEST_NAME, Manifest, ManifestSignature, SIGNATURE_EXT, SIGNATURE_FORMAT, check,
+    relative_to, unlisted,
+};
+use keypairer::internals::metadata::{KeyMetadata, sidecar_path};
+use keypairer::internals::sign::{
+    SignError, is_signature, public_key_len, sign, variants_for_secret_key, verify,
+};
+use keypairer::{load_key, run_with_variant_stack};
+
+use super::store::open_store;
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{default_lang, resolve_algorithm};
+
+/// 메시지에 표시할 지문 접두사 길이
+const SIGNER_SHORT_LEN: usize = 16;
+
+/// `sign-manifest`/`verify-manifest` 공통 인자
+struct ManifestArgs {
+    dir: Option<String>,
+    manifest: Option<String>,
+    sig: Option<String>,
+    key: Option<String>,
+    sk_path: Option<String>,
+    pk_path: Option<String>,
+    algorithm: Option<String>,
+    variant: Option<String>,
+    force: bool,
+    strict: bool,
+    store_dir: Option<String>,
+    lang: String,
+}
+
+/// 서명에 사용할 비밀키
+struct Signer {
+    variant: Variant,
+    sk: Zeroizing<Vec<u8>>,
+    /// 서명 직후 자체 검증에 쓰는 공개키 (알 수 있을 때만)
+    pk: Option<Vec<u8>>,
+    /// 공개키 지문 (알 수 있을 때만)
+    fingerprint: Option<String>,
+}
+
+/// `sign-manifest`, `verify-manifest` 하위 명령을 실행합니다.
+pub fn run(prog: &str, command: &str, args: &[String]) {
+    let mut parsed = ManifestArgs {
+        dir: None,
+        manifest: None,
+        sig: None,
+        key: None,
+        sk_path: None,
+        pk_path: None,
+        algorithm: None,
+        variant: None,
+        force: false,
+        strict: false,
+        store_dir: None,
+        lang: default_lang(),
+    };
+
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-dir" | "-manifest" | "-sig" | "-key" | "-sk" | "-skpath" | "-pk" | "-pkpath"
+            | "-alg" | "-algorithm" | "-var" | "-variant" | "-store-dir" | "-l" | "-lang" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(prog, &parsed.lang);
+                }
+                let value = args[i].clone();
+                match flag.as_str() {
+                    "-dir" => parsed.dir = Some(value),
+                    "-manifest" => parsed.manifest = Some(value),
+                    "-sig" => parsed.sig = Some(value),
+                    "-key" => parsed.key = Some(value),
+                    "-sk" | "-skpath" => parsed.sk_path = Some(value),
+                    "-pk" | "-pkpath" => parsed.pk_path = Some(value),
+                    "-alg" | "-algorithm" => parsed.algorithm = Some(value.to_lowercase()),
+                    "-var" | "-variant" => parsed.variant = Some(value.to_lowercase()),
+                    "-store-dir" => parsed.store_dir = Some(value),
+                    _ => parsed.lang = value.to_lowercase(),
+                }
+            }
+            "-f" | "-force" => parsed.force = true,
+            "-strict" => parsed.strict = true,
+            "-h" | "--help" => usage_and_exit(prog, &parsed.lang),
+            other => {
+                eprintln!(
+                    "{}",
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other)
+                );
+                usage_and_exit(prog, &parsed.lang);
+            }
+        }
+        i += 1;
+    }
+
+    if command == "sign-manifest" {
+        sign_manifest(prog, &parsed);
+    } else {
+        verify_manifest(prog, &parsed);
+    }
+}
+
+/// 기준 디렉토리, 매니페스트, 서명 파일 경로를 정합니다.
+///
+/// 디렉토리를 생략하면 매니페스트가 있는 디렉토리(매니페스트도 생략하면 현재 디렉토리)를 사용합니다.
+fn resolve_paths(args: &ManifestArgs) -> (PathBuf, PathBuf, PathBuf) {
+    let dir = match (&args.dir, &args.manifest) {
+        (Some(dir), _) => PathBuf::from(dir),
+        (None, Some(manifest)) => match Path::new(manifest).parent() {
+            Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+            _ => PathBuf::from("."),
+        },
+        (None, None) => PathBuf::from("."),
+    };
+    let manifest = args
+        .manifest
+        .as_ref()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| dir.join(MANIFEST_NAME));
+    let sig = args.sig.as_ref().map(PathBuf::from).unwrap_or_else(|| {
+        let mut name = manifest.clone().into_os_string();
+        name.push(format!(".{}", SIGNATURE_EXT));
+        PathBuf::from(name)
+    });
+    (dir, manifest, sig)
+}
+
+/// 기준 디렉토리 안에 있는 매니페스트와 서명 파일은 해시 대상에서 뺍니다.
+fn excluded(dir: &Path, manifest: &Path, sig: &Path) -> Vec<String> {
+    [manifest, sig]
+        .into_iter()
+        .filter_map(|p| relative_to(dir, p))
+        .collect()
+}
+
+fn sign_manifest(prog: &str, args: &ManifestArgs) {
+    let lang = args.lang.as_str();
+    let signer = match (&args.key, &args.sk_path) {
+        (Some(query), None) => signer_from_store(query, args.store_dir.as_deref(), lang),
+        (None, Some(path)) => signer_from_file(path, args, lang),
+        _ => {
+            eprintln!(
+                "{}",
+                text(
+                    lang,
+                    "error.sign.secret",
+                    "서명 키를 -key <키 저장소 키> 또는 -sk <비밀키 파일> 중 하나로 지정하세요.",
+                )
+            );
+            usage_and_exit(prog, lang);
+        }
+    };
+
+    let (dir, manifest_path, sig_path) = resolve_paths(args);
+    // 매니페스트만 기록되고 서명이 실패하는 일이 없도록 미리 확인
+    if !args.force {
+        for path in [&manifest_path, &sig_path] {
+            if path.exists() {
+                fail(write_error(
+                    path,
+                    &io::Error::from(io::ErrorKind::AlreadyExists),
+                    lang,
+                ));
+            }
+        }
+    }
+    let exclude = excluded(&dir, &manifest_path, &sig_path);
+    let (manifest, skipped) =
+        Manifest::build(&dir, &exclude).unwrap_or_else(|e| fail(e.message(lang)));
+    for path in &skipped {
+        eprintln!(
+            "{}",
+            text(
+                lang,
+                "warn.manifest.skipped",
+                "일반 파일이 아니므로 건너뜁니다: {path}",
+            )
+            .replace("{path}", path)
+        );
+    }
+    let body = manifest.to_text().into_bytes();
+
+    // 서명 (배리언트의 스택 예산만큼) 후 공개키를 알면 자체 검증
+    let Signer {
+        variant,
+        sk,
+        pk,
+        fingerprint: signer_fp,
+    } = signer;
+    let message = body.clone();
+    let sign_variant = variant.clone();
+    let signature = run_with_variant_stack(
+        &variant,
+        move || {
+            let signature = sign(&sign_variant, &sk, &message)?;
+            match pk {
+                Some(pk) if !verify(&sign_variant, &pk, &message, &signature)? => Ok(None),
+                _ => Ok::<_, SignError>(Some(signature)),
+            }
+        },
+        lang,
+    )
+    .unwrap_or_else(|e| fail(sign_error(&e, lang)))
+    .unwrap_or_else(|| {
+        fail(text(
+            lang,
+            "error.sign.pair_mismatch",
+            "비밀키와 공개키가 한 쌍이 아닙니다.",
+        ))
+    });
+
+    let record = ManifestSignature {
+        format: SIGNATURE_FORMAT.to_string(),
+        algorithm: variant.algorithm().as_str().to_string(),
+        variant: variant.as_str().to_string(),
+        standard_name: variant.standard_name(),
+        signer: signer_fp.clone(),
+        created: format_rfc3339(now_unix()),
+        manifest_sha256: hex(&Sha256::digest(&body)),
+        signature: general_purpose::STANDARD.encode(&signature),
+    };
+    let mut sig_json = serde_json::to_string_pretty(&record).unwrap_or_default();
+    sig_json.push('\n');
+
+    for (path, data) in [(&manifest_path, &body), (&sig_path, &sig_json.into_bytes())] {
+        if let Err(e) = write_atomic(path, data, 0o644, args.force) {
+            fail(write_error(path, &e, lang));
+        }
+    }
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.manifest.signed",
+            "매니페스트 서명 완료: 파일 {count}개 → {manifest} (서명: {sig}, {standard}, 서명자: {signer})",
+        )
+        .replace("{count}", &manifest.entries.len().to_string())
+        .replace("{manifest}", &manifest_path.display().to_string())
+        .replace("{sig}", &sig_path.display().to_string())
+        .replace("{standard}", &variant.standard_name())
+        .replace("{signer}", &short_signer(signer_fp.as_deref()))
+    );
+}
+
+fn verify_manifest(prog: &str, args: &ManifestArgs) {
+    let lang = args.lang.as_str();
+    let (dir, manifest_path, sig_path) = resolve_paths(args);
+    let body =
+        fs::read(&manifest_path).unwrap_or_else(|e| fail(read_error(&manifest_path, &e, lang)));
+    let sig_text =
+        fs::read_to_string(&sig_path).unwrap_or_else(|e| fail(read_error(&sig_path, &e, lang)));
+    let format_error = || {
+        text(
+            lang,
+            "error.sign.format",
+            "서명 파일 형식이 올바르지 않습니다: {path}",
+        )
+        .replace("{path}", &sig_path.display().to_string())
+    };
+    let record: ManifestSignature =
+        serde_json::from_str(&sig_text).unwrap_or_else(|_| fail(format_error()));
+    if record.format != SIGNATURE_FORMAT {
+        fail(format_error());
+    }
+    let variant = parse_algorithm(&record.algorithm)
+        .and_then(|alg| parse_variant(alg, &record.variant))
+        .filter(is_signature)
+        .unwrap_or_else(|| fail(format_error()));
+    let signature = general_purpose::STANDARD
+        .decode(record.signature.as_bytes())
+        .unwrap_or_else(|_| fail(format_error()));
+
+    // 신뢰할 공개키
+    let pk = match (&args.key, &args.pk_path) {
+        (Some(query), None) => {
+            let store = open_store(args.store_dir.as_deref(), lang);
+            let entry = store.find(query).unwrap_or_else(|e| fail(e.message(lang)));
+            if entry.lifecycle.state == KeyState::Compromised {
+                fail(
+                    text(
+                        lang,
+                        "error.sign.compromised",
+                        "유출된 키의 서명은 신뢰할 수 없습니다: {id}",
+                    )
+                    .replace("{id}", &entry.id),
+                );
+            }
+            if entry.algorithm_variant().map(|(_, v)| v) != Some(variant.clone()) {
+                fail(key_mismatch(&variant, lang));
+            }
+            store
+                .read_public(&entry.id)
+                .unwrap_or_else(|e| fail(e.message(lang)))
+        }
+        (None, Some(path)) => load_key(path, "PUBLIC KEY", lang).to_vec(),
+        _ => {
+            eprintln!(
+                "{}",
+                text(
+                    lang,
+                    "error.sign.public",
+                    "검증 키를 -key <키 저장소 키> 또는 -pk <공개키 파일> 중 하나로 지정하세요.",
+                )
+            );
+            usage_and_exit(prog, lang);
+        }
+    };
+    let signer = fingerprint(&pk);
+    if public_key_len(&variant) != Some(pk.len())
+        || record.signer.as_ref().is_some_and(|s| *s != signer)
+    {
+        fail(key_mismatch(&variant, lang));
+    }
+
+    let valid = {
+        let (check_variant, message) = (variant.clone(), body.clone());
+        run_with_variant_stack(
+            &variant,
+            move || verify(&check_variant, &pk, &message, &signature),
+            lang,
+        )
+        .unwrap_or_else(|e| fail(sign_error(&e, lang)))
+    };
+    if !valid || record.manifest_sha256 != hex(&Sha256::digest(&body)) {
+        fail(
+            text(
+                lang,
+                "error.sign.invalid",
+                "매니페스트 서명이 올바르지 않습니다: {path}",
+            )
+            .replace("{path}", &manifest_path.display().to_string()),
+        );
+    }
+
+    // 파일별 검사
+    let text_body = String::from_utf8(body).unwrap_or_else(|_| fail(format_error()));
+    let manifest = Manifest::parse(&text_body).unwrap_or_else(|e| fail(e.message(lang)));
+    let mut failures = 0usize;
+    for (path, status) in check(&dir, &manifest) {
+        let line = match status {
+            FileStatus::Ok => continue,
+            FileStatus::Modified => text(lang, "error.manifest.modified", "변경됨: {path}"),
+            FileStatus::Missing => text(lang, "error.manifest.missing", "없음: {path}"),
+            FileStatus::Unreadable(err) => text(
+                lang,
+                "error.manifest.unreadable",
+                "읽을 수 없음: {path} ({err})",
+            )
+            .replace("{err}", &err),
+        };
+        eprintln!("{}", line.replace("{path}", &path));
+        failures += 1;
+    }
+    let exclude = excluded(&dir, &manifest_path, &sig_path);
+    let extra = unlisted(&dir, &manifest, &exclude).unwrap_or_else(|e| fail(e.message(lang)));
+    for path in &extra {
+        eprintln!(
+            "{}",
+            text(
+                lang,
+                "warn.manifest.unlisted",
+                "매니페스트에 없는 파일: {path}"
+            )
+            .replace("{path}", path)
+        );
+    }
+    if args.strict {
+        failures += extra.len();
+    }
+
+    if failures > 0 {
+        fail(
+            text(
+                lang,
+                "error.manifest.failed",
+                "매니페스트 검증 실패: 파일 {count}개에 문제가 있습니다.",
+            )
+            .replace("{count}", &failures.to_string()),
+        );
+    }
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.manifest.verified",
+            "매니페스트 검증 완료: 파일 {count}개 일치 ({standard}, 서명자: {signer}, 서명 시각: {created})",
+        )
+        .replace("{count}", &manifest.entries.len().to_string())
+        .replace("{standard}", &variant.standard_name())
+        .replace("{signer}", &short_signer(Some(&signer)))
+        .replace("{created}", &record.created)
+    );
+}
+
+/// 키 저장소의 서명 키를 사용합니다. 사용 중이 아니거나 만료된 키는 거부합니다.
+fn signer_from_store(query: &str, store_dir: Option<&str>, lang: &str) -> Signer {
+    let store = open_store(store_dir, lang);
+    let entry = store.find(query).unwrap_or_else(|e| fail(e.message(lang)));
+    if entry.lifecycle.state != KeyState::Active || entry.lifecycle.is_expired(now_unix()) {
+        fail(
+            text(
+                lang,
+                "error.sign.inactive",
+                "사용 중이 아니거나 만료된 키로는 서명할 수 없습니다: {id} ({state})",
+            )
+            .replace("{id}", &entry.id)
+            .replace("{state}", entry.lifecycle.state.as_str()),
+        );
+    }
+    let variant = entry
+        .algorithm_variant()
+        .map(|(_, v)| v)
+        .filter(is_signature)
+        .unwrap_or_else(|| fail(not_signature_message(&entry.metadata.standard_name, lang)));
+    let pk = store
+        .read_public(&entry.id)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    let sk = store
+        .read_secret(&entry.id)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    Signer {
+        variant,
+        sk,
+        fingerprint: Some(fingerprint(&pk)),
+        pk: Some(pk),
+    }
+}
+
+/// 비밀키 파일을 사용합니다.
+///
+/// 배리언트는 `-alg`/`-var`, 메타데이터 사이드카(`<이름>.json`), 비밀키 길이 순으로 정하며,
+/// 공개키(`-pk`)를 함께 주면 서명 직후 자체 검증하고 지문을 기록합니다.
+fn signer_from_file(path: &str, args: &ManifestArgs, lang: &str) -> Signer {
+    let sk = load_key(path, "SECRET KEY", lang);
+    let pk = args
+        .pk_path
+        .as_deref()
+        .map(|p| load_key(p, "PUBLIC KEY", lang).to_vec());
+    let sidecar = sidecar_path(STDIO_PATH, path)
+        .and_then(|p| fs::read_to_string(p).ok())
+        .and_then(|json| serde_json::from_str::<KeyMetadata>(&json).ok());
+
+    let variant = if let Some(alg) = &args.algorithm {
+        resolve_algorithm(alg, args.variant.as_deref(), lang)
+            .map(|(_, v)| v)
+            .unwrap_or_else(|msg| fail(msg))
+    } else if let Some(v) = sidecar.as_ref().and_then(|meta| {
+        parse_algorithm(&meta.algorithm).and_then(|alg| parse_variant(alg, &meta.variant))
+    }) {
+        v
+    } else {
+        match variants_for_secret_key(sk.len()).as_slice() {
+            [one] => one.clone(),
+            [] => fail(not_signature_message(path, lang)),
+            many => fail(
+                text(
+                    lang,
+                    "error.sign.ambiguous_variant",
+                    "비밀키 길이로 배리언트를 정할 수 없습니다({variants}). -alg와 -var를 지정하세요.",
+                )
+                .replace(
+                    "{variants}",
+                    &many
+                        .iter()
+                        .map(|v| v.standard_name())
+                        .collect::<Vec<_>>()
+                        .join(", "),
+                ),
+            ),
+        }
+    };
+    if !is_signature(&variant) {
+        fail(not_signature_message(&variant.standard_name(), lang));
+    }
+
+    let fingerprint = match &pk {
+        Some(pk) => Some(fingerprint(pk)),
+        None => sidecar.map(|meta| meta.fingerprint),
+    };
+    Signer {
+        variant,
+        sk,
+        pk,
+        fingerprint,
+    }
+}
+
+fn not_signature_message(name: &str, lang: &str) -> String {
+    text(
+        lang,
+        "error.sign.not_signature",
+        "서명(ML-DSA, FALCON, SPHINCS+) 키가 아닙니다: {name}",
+    )
+    .replace("{name}", name)
+}
+
+fn key_mismatch(variant: &Variant, lang: &str) -> String {
+    text(
+        lang,
+        "error.sign.key_mismatch",
+        "검증 키가 서명자({standard})와 일치하지 않습니다.",
+    )
+    .replace("{standard}", &variant.standard_name())
+}
+
+fn sign_error(e: &dyn std::error::Error, lang: &str) -> String {
+    text(lang, "error.sign.failed", "서명 연산에 실패했습니다: {err}")
+        .replace("{err}", &e.to_string())
+}
+
+fn short_signer(fp: Option<&str>) -> String {
+    match fp {
+        Some(fp) => fp[..SIGNER_SHORT_LEN.min(fp.len())].to_string(),
+        None => "-".to_string(),
+    }
+}
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn read_error(path: &Path, e: &io::Error, lang: &str) -> String {
+    text(
+        lang,
+        "error.file.read",
+        "파일을 읽는 도중 오류가 발생했습니다({path}): {err}",
+    )
+    .replace("{path}", &path.display().to_string())
+    .replace("{err}", &e.to_string())
+}
+
+fn write_error(path: &Path, e: &io::Error, lang: &str) -> String {
+    if e.kind() == io::ErrorKind::AlreadyExists {
+        return text(
+            lang,
+            "error.file.exists",
+            "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)",
+        )
+        .replace("{path}", &path.display().to_string());
+    }
+    text(
+        lang,
+        "error.file.write",
+        "파일에 쓰는 도중 오류가 발생했습니다({path}): {err}",
+    )
+    .replace("{path}", &path.display().to_string())
+    .replace("{err}", &e.to_string())
+}
@@ -0,0 +1,30 @@
+//! 키 생성 외의 하위 명령 및 생성 모드 구현
+use std::process;
+
+use keypairer::load_translations;
+
+pub mod batch;
+pub mod bench;
+pub mod config;
+pub mod encrypt;
+pub mod manifest;
+pub mod paper;
+#[cfg(unix)]
+pub mod pool;
+pub mod serve;
+pub mod share;
+pub mod store;
+
+/// 번역된 메시지를 가져옵니다.
+pub(crate) fn text(lang: &str, key: &str, fallback: &str) -> String {
+    load_translations(lang)
+        .get(key)
+        .cloned()
+        .unwrap_or_else(|| fallback.to_string())
+}
+
+/// 번역된 오류 메시지를 출력하고 종료합니다.
+pub(crate) fn fail(msg: String) -> ! {
+    eprintln!("{}", msg);
+    process::exit(1);
+}
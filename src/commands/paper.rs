@@ -0,0 +1,294 @@
+use std::fs;
+use std::io::{self, Read, Write};
+use std::path::Path;
+
+use zeroize::Zeroizing;
+
+use keypairer::internals::datetime::{format_rfc3339, now_unix};
+use keypairer::internals::key_io::{STDIO_PATH, fingerprint, to_pem, write_atomic};
+use keypairer::internals::keygen::verify_key_pair;
+use keypairer::internals::paper::PaperBackup;
+use keypairer::{load_key, run_with_variant_stack};
+
+use super::share::{
+    KeyPair, exists_message, public_key_by_fingerprint, read_key_pair, write_error,
+};
+use super::store::open_store;
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{configured_policy, default_lang, enforce_policy};
+
+/// 메시지와 기본 파일 이름에 쓰는 지문 접두사 길이
+const FP_SHORT_LEN: usize = 16;
+
+/// 종이 백업 출력 형식
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum PaperFormat {
+    Text,
+    Svg,
+}
+
+/// `paper-export`/`paper-import` 공통 인자
+struct PaperArgs {
+    key: Option<String>,
+    sk_path: Option<String>,
+    pk_path: Option<String>,
+    algorithm: Option<String>,
+    variant: Option<String>,
+    format: PaperFormat,
+    input: Option<String>,
+    output: Option<String>,
+    sk_text: bool,
+    force: bool,
+    store_dir: Option<String>,
+    lang: String,
+}
+
+/// `paper-export`, `paper-import` 하위 명령을 실행합니다.
+pub fn run(prog: &str, command: &str, args: &[String]) {
+    let mut parsed = PaperArgs {
+        key: None,
+        sk_path: None,
+        pk_path: None,
+        algorithm: None,
+        variant: None,
+        format: PaperFormat::Text,
+        input: None,
+        output: None,
+        sk_text: false,
+        force: false,
+        store_dir: None,
+        lang: default_lang(),
+    };
+
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-key" | "-sk" | "-skpath" | "-pk" | "-pkpath" | "-alg" | "-algorithm" | "-var"
+            | "-variant" | "-format" | "-in" | "-out" | "-store-dir" | "-l" | "-lang" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(prog, &parsed.lang);
+                }
+                let value = args[i].clone();
+                match flag.as_str() {
+                    "-key" => parsed.key = Some(value),
+                    "-sk" | "-skpath" => parsed.sk_path = Some(value),
+                    "-pk" | "-pkpath" => parsed.pk_path = Some(value),
+                    "-alg" | "-algorithm" => parsed.algorithm = Some(value.to_lowercase()),
+                    "-var" | "-variant" => parsed.variant = Some(value.to_lowercase()),
+                    "-format" => {
+                        parsed.format = match value.to_lowercase().as_str() {
+                            "text" | "txt" => PaperFormat::Text,
+                            "svg" => PaperFormat::Svg,
+                            _ => fail(
+                                text(
+                                    &parsed.lang,
+                                    "error.paper.format",
+                                    "지원하지 않는 종이 백업 형식: {format} (text, svg)",
+                                )
+                                .replace("{format}", &value),
+                            ),
+                        }
+                    }
+                    "-in" => parsed.input = Some(value),
+                    "-out" => parsed.output = Some(value),
+                    "-store-dir" => parsed.store_dir = Some(value),
+                    _ => parsed.lang = value.to_lowercase(),
+                }
+            }
+            "-skt" | "-sktext" => parsed.sk_text = true,
+            "-f" | "-force" => parsed.force = true,
+            "-h" | "--help" => usage_and_exit(prog, &parsed.lang),
+            other => {
+                eprintln!(
+                    "{}",
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other)
+                );
+                usage_and_exit(prog, &parsed.lang);
+            }
+        }
+        i += 1;
+    }
+
+    if command == "paper-export" {
+        export(prog, &parsed);
+    } else {
+        import(prog, &parsed);
+    }
+}
+
+fn export(prog: &str, args: &PaperArgs) {
+    let lang = args.lang.as_str();
+    let Some(pair) = read_key_pair(
+        args.key.as_deref(),
+        args.sk_path.as_deref(),
+        args.pk_path.as_deref(),
+        (args.algorithm.as_deref(), args.variant.as_deref()),
+        args.store_dir.as_deref(),
+        lang,
+    ) else {
+        usage_and_exit(prog, lang);
+    };
+    let KeyPair {
+        variant,
+        pk,
+        sk,
+        name,
+    } = pair;
+    let fp = fingerprint(&pk);
+
+    let backup = PaperBackup::new(variant.clone(), &fp, &format_rfc3339(now_unix()), sk)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    let sheet = match args.format {
+        PaperFormat::Text => backup.to_text(),
+        PaperFormat::Svg => backup.to_svg(),
+    };
+
+    let output = args.output.clone().unwrap_or_else(|| {
+        let ext = match args.format {
+            PaperFormat::Text => "txt",
+            PaperFormat::Svg => "svg",
+        };
+        format!("{}.paper.{}", name, ext)
+    });
+    if output == STDIO_PATH {
+        let mut stdout = io::stdout().lock();
+        if let Err(e) = stdout
+            .write_all(sheet.as_bytes())
+            .and_then(|_| stdout.flush())
+        {
+            fail(write_error(&output, &e, lang));
+        }
+    } else if let Err(e) = write_atomic(Path::new(&output), sheet.as_bytes(), 0o600, args.force) {
+        fail(write_error(&output, &e, lang));
+    }
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.paper.exported",
+            "비밀키 종이 백업을 만들었습니다: {path} ({standard}, {len}바이트, 지문 {fp}). 인쇄한 뒤 파일은 안전하게 삭제하세요.",
+        )
+        .replace("{path}", &output)
+        .replace("{standard}", &variant.standard_name())
+        .replace("{len}", &backup.secret.len().to_string())
+        .replace("{fp}", &fp[..FP_SHORT_LEN])
+    );
+}
+
+fn import(prog: &str, args: &PaperArgs) {
+    let lang = args.lang.as_str();
+    enforce_policy(&configured_policy(lang), [], args.sk_text, lang);
+    let Some(input) = &args.input else {
+        usage_and_exit(prog, lang);
+    };
+    let sheet = Zeroizing::new(
+        if input == STDIO_PATH {
+            let mut buf = String::new();
+            io::stdin()
+                .read_to_string(&mut buf)
+                .map(|_| buf)
+                .map_err(|e| (input.as_str(), e))
+        } else {
+            fs::read_to_string(input).map_err(|e| (input.as_str(), e))
+        }
+        .unwrap_or_else(|(path, e)| {
+            fail(
+                text(
+                    lang,
+                    "error.file.read",
+                    "파일을 읽는 도중 오류가 발생했습니다({path}): {err}",
+                )
+                .replace("{path}", path)
+                .replace("{err}", &e.to_string()),
+            )
+        }),
+    );
+    let backup = PaperBackup::parse(&sheet).unwrap_or_else(|e| fail(e.message(lang)));
+    let variant = backup.variant.clone();
+    let short_fp = &backup.fingerprint[..FP_SHORT_LEN.min(backup.fingerprint.len())];
+
+    let output = args
+        .output
+        .clone()
+        .unwrap_or_else(|| format!("{}.sk", short_fp));
+    if !args.force && Path::new(&output).exists() {
+        fail(exists_message(&output, lang));
+    }
+
+    // 원래 공개키가 있으면 한 쌍인지 확인 (-pk, -key, 또는 키 저장소에서 지문으로 검색)
+    let pk = match (&args.pk_path, &args.key) {
+        (Some(path), _) => Some(load_key(path, "PUBLIC KEY", lang).to_vec()),
+        (None, Some(query)) => {
+            let store = open_store(args.store_dir.as_deref(), lang);
+            let entry = store.find(query).unwrap_or_else(|e| fail(e.message(lang)));
+            Some(
+                store
+                    .read_public(&entry.id)
+                    .unwrap_or_else(|e| fail(e.message(lang))),
+            )
+        }
+        (None, None) => public_key_by_fingerprint(&backup.fingerprint, args.store_dir.as_deref()),
+    };
+    match pk {
+        Some(pk) => {
+            if fingerprint(&pk) != backup.fingerprint {
+                fail(
+                    text(
+                        lang,
+                        "error.paper.fingerprint",
+                        "공개키 지문이 종이 백업에 적힌 지문과 다릅니다: {fp}",
+                    )
+                    .replace("{fp}", short_fp),
+                );
+            }
+            let check_variant = variant.clone();
+            let check_sk = backup.secret.clone();
+            let matches = run_with_variant_stack(
+                &variant,
+                move || verify_key_pair(&check_variant, &pk, &check_sk).unwrap_or(false),
+                lang,
+            );
+            if !matches {
+                fail(text(
+                    lang,
+                    "error.paper.verify",
+                    "복원한 비밀키가 공개키와 한 쌍이 아닙니다. 옮겨 적은 내용을 확인하세요.",
+                ));
+            }
+        }
+        None => eprintln!(
+            "{}",
+            text(
+                lang,
+                "warn.paper.unverified",
+                "경고: 공개키가 없어 체크섬만 확인했습니다. -pk 또는 -key로 공개키를 지정하면 한 쌍인지도 확인합니다.",
+            )
+        ),
+    }
+
+    let data = if args.sk_text {
+        Zeroizing::new(to_pem("SECRET KEY", &backup.secret).into_bytes())
+    } else {
+        backup.secret.clone()
+    };
+    if let Err(e) = write_atomic(Path::new(&output), &data, 0o600, args.force) {
+        fail(write_error(&output, &e, lang));
+    }
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.paper.imported",
+            "종이 백업에서 비밀키를 복원했습니다: {path} ({standard}, 지문 {fp})",
+        )
+        .replace("{path}", &output)
+        .replace("{standard}", &variant.standard_name())
+        .replace("{fp}", short_fp)
+    );
+}
@@ -0,0 +1,308 @@
+use std::path::{Path, PathBuf};
+use std::thread;
+
+use keypairer::internals::key_io::{STDIO_PATH, effective_text, write_key_pair};
+use keypairer::internals::pool::{
+    self, PoolEvent, PoolStatus, PoolStore, default_dir, default_socket, parse_targets,
+};
+use keypairer::internals::secret::disable_core_dumps;
+
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{
+    TemplateVars, configured_policy, default_lang, enforce_policy, finalize_paths,
+    resolve_algorithm,
+};
+
+/// `pool` 인자
+struct PoolArgs {
+    dir: Option<String>,
+    socket: Option<String>,
+    key_file: Option<String>,
+    size: Option<String>,
+    jobs: Option<String>,
+    algorithm: Option<String>,
+    variant: Option<String>,
+    pk_path: Option<String>,
+    sk_path: Option<String>,
+    pk_text: bool,
+    sk_text: bool,
+    force: bool,
+    json: bool,
+    lang: String,
+}
+
+/// `pool serve`, `pool take`, `pool status` 하위 명령을 실행합니다.
+pub fn run(prog: &str, args: &[String]) {
+    let mut parsed = PoolArgs {
+        dir: None,
+        socket: None,
+        key_file: None,
+        size: None,
+        jobs: None,
+        algorithm: None,
+        variant: None,
+        pk_path: None,
+        sk_path: None,
+        pk_text: false,
+        sk_text: false,
+        force: false,
+        json: false,
+        lang: default_lang(),
+    };
+
+    let command = args.first().map(String::as_str);
+    let mut i = 1usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-dir" | "-socket" | "-key-file" | "-size" | "-jobs" | "-alg" | "-algorithm"
+            | "-var" | "-variant" | "-pkpath" | "-skpath" | "-l" | "-lang" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(prog, &parsed.lang);
+                }
+                let value = args[i].clone();
+                match flag.as_str() {
+                    "-dir" => parsed.dir = Some(value),
+                    "-socket" => parsed.socket = Some(value),
+                    "-key-file" => parsed.key_file = Some(value),
+                    "-size" => parsed.size = Some(value),
+                    "-jobs" => parsed.jobs = Some(value),
+                    "-alg" | "-algorithm" => parsed.algorithm = Some(value.to_lowercase()),
+                    "-var" | "-variant" => parsed.variant = Some(value.to_lowercase()),
+                    "-pkpath" => parsed.pk_path = Some(value),
+                    "-skpath" => parsed.sk_path = Some(value),
+                    _ => parsed.lang = value.to_lowercase(),
+                }
+            }
+            "-pkt" | "-pktext" => parsed.pk_text = true,
+            "-skt" | "-sktext" => parsed.sk_text = true,
+            "-f" | "-force" => parsed.force = true,
+            "-json" => parsed.json = true,
+            "-h" | "--help" => usage_and_exit(prog, &parsed.lang),
+            other => {
+                eprintln!(
+                    "{}",
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other)
+                );
+                usage_and_exit(prog, &parsed.lang);
+            }
+        }
+        i += 1;
+    }
+
+    match command {
+        Some("serve") => serve(prog, &parsed),
+        Some("take") => take(prog, &parsed),
+        Some("status") => status(&parsed),
+        _ => usage_and_exit(prog, &parsed.lang),
+    }
+}
+
+/// `-socket` 또는 기본 소켓 경로
+fn socket_path(args: &PoolArgs, dir: Option<&PathBuf>) -> PathBuf {
+    args.socket
+        .as_ref()
+        .map(PathBuf::from)
+        .or_else(|| default_socket(dir.map(PathBuf::as_path)))
+        .unwrap_or_else(|| fail(no_dir_message(&args.lang)))
+}
+
+fn no_dir_message(lang: &str) -> String {
+    text(
+        lang,
+        "error.pool.no_dir",
+        "키 풀 디렉토리를 정할 수 없습니다. -dir 또는 KEYPAIRER_HOME을 지정하세요.",
+    )
+}
+
+fn serve(prog: &str, args: &PoolArgs) {
+    let lang = args.lang.as_str();
+    let Some(spec) = &args.size else {
+        usage_and_exit(prog, lang);
+    };
+    let targets = parse_targets(spec).unwrap_or_else(|e| fail(e.message(lang)));
+    enforce_policy(
+        &configured_policy(lang),
+        targets.iter().map(|t| &t.variant),
+        false,
+        lang,
+    );
+
+    let workers = match &args.jobs {
+        Some(v) => match v.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => fail(
+                text(lang, "error.batch.jobs", "잘못된 워커 수: {value}").replace("{value}", v),
+            ),
+        },
+        None => thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+    };
+
+    let dir = args
+        .dir
+        .as_ref()
+        .map(PathBuf::from)
+        .or_else(default_dir)
+        .unwrap_or_else(|| fail(no_dir_message(lang)));
+    let socket = socket_path(args, Some(&dir));
+    // 풀 키가 항목과 함께 복사되지 않도록 풀 디렉토리 밖의 위치를 직접 지정받음
+    let Some(key_file) = &args.key_file else {
+        fail(text(
+            lang,
+            "error.pool.key_file",
+            "-key-file로 풀 디렉토리 밖의 풀 키 파일을 지정하세요 (없으면 만들어 0o600으로 저장합니다).",
+        ))
+    };
+    let store =
+        PoolStore::open(&dir, Path::new(key_file)).unwrap_or_else(|e| fail(e.message(lang)));
+
+    // 키 생성 워커의 메모리에 비밀키가 머무르므로 코어 덤프를 끔
+    let _no_core = disable_core_dumps();
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.pool.serving",
+            "키 풀을 시작했습니다: {socket} (디렉토리: {dir}, 워커 {jobs}개)",
+        )
+        .replace("{socket}", &socket.display().to_string())
+        .replace("{dir}", &dir.display().to_string())
+        .replace("{jobs}", &workers.to_string())
+    );
+
+    let event_lang = args.lang.clone();
+    let result = pool::serve(store, &socket, targets, workers, move |event| {
+        let lang = event_lang.as_str();
+        match event {
+            PoolEvent::Refilled { variant, available } => eprintln!(
+                "{}",
+                text(lang, "info.pool.refilled", "{standard} 보충: {available}개")
+                    .replace("{standard}", &variant.standard_name())
+                    .replace("{available}", &available.to_string())
+            ),
+            PoolEvent::Served {
+                variant,
+                pooled,
+                remaining,
+            } => {
+                let (key, fallback) = if pooled {
+                    ("info.pool.served", "{standard} 전달: 남은 키 {remaining}개")
+                } else {
+                    (
+                        "info.pool.served_fresh",
+                        "{standard} 전달: 풀이 비어 요청 시 생성",
+                    )
+                };
+                eprintln!(
+                    "{}",
+                    text(lang, key, fallback)
+                        .replace("{standard}", &variant.standard_name())
+                        .replace("{remaining}", &remaining.to_string())
+                );
+            }
+            PoolEvent::Failed(e) => eprintln!("{}", e.message(lang)),
+        }
+    });
+    if let Err(e) = result {
+        fail(e.message(lang));
+    }
+}
+
+fn take(prog: &str, args: &PoolArgs) {
+    let lang = args.lang.as_str();
+    let Some(algorithm) = &args.algorithm else {
+        usage_and_exit(prog, lang);
+    };
+    let (alg, variant) =
+        resolve_algorithm(algorithm, args.variant.as_deref(), lang).unwrap_or_else(|msg| fail(msg));
+    let socket = socket_path(args, args.dir.as_ref().map(PathBuf::from).as_ref());
+
+    let _no_core = disable_core_dumps();
+    let (key, pooled, remaining) =
+        pool::take(&socket, &variant).unwrap_or_else(|e| fail(e.message(lang)));
+
+    let vars = TemplateVars {
+        id: "1",
+        n: 1,
+        alg,
+        var: &variant,
+        fp: &key.fingerprint,
+        created: key.created,
+    };
+    let (pk_path, sk_path) = finalize_paths(&args.pk_path, &args.sk_path, algorithm, None, &vars);
+    let (pk_text, sk_text) = effective_text(&pk_path, &sk_path, args.pk_text, args.sk_text);
+    enforce_policy(&configured_policy(lang), [&variant], sk_text, lang);
+    if let Err(e) = write_key_pair(
+        &key.public_key,
+        &key.secret_key,
+        &pk_path,
+        &sk_path,
+        pk_text,
+        sk_text,
+        args.force,
+    ) {
+        fail(e.message(lang));
+    }
+
+    let to_stdout = pk_path == STDIO_PATH || sk_path == STDIO_PATH;
+    let shown = |p: &str| {
+        if p == STDIO_PATH {
+            "<stdout>".to_string()
+        } else {
+            p.to_string()
+        }
+    };
+    let summary = if pooled {
+        text(
+            lang,
+            "info.pool.taken",
+            "풀에서 {standard} 키를 꺼냈습니다 (남은 키 {remaining}개)",
+        )
+    } else {
+        text(
+            lang,
+            "info.pool.taken_fresh",
+            "풀이 비어 있어 {standard} 키를 새로 생성했습니다",
+        )
+    };
+    let lines = [
+        summary
+            .replace("{standard}", &variant.standard_name())
+            .replace("{remaining}", &remaining.to_string()),
+        text(lang, "info.pk_saved", "공개키 저장: {path}").replace("{path}", &shown(&pk_path)),
+        text(lang, "info.sk_saved", "비밀키 저장: {path}").replace("{path}", &shown(&sk_path)),
+    ];
+    for line in lines {
+        if to_stdout {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+}
+
+fn status(args: &PoolArgs) {
+    let lang = args.lang.as_str();
+    let socket = socket_path(args, args.dir.as_ref().map(PathBuf::from).as_ref());
+    let status = pool::status(&socket).unwrap_or_else(|e| fail(e.message(lang)));
+    if args.json {
+        let json = serde_json::to_string_pretty(&status).unwrap_or_else(|e| fail(e.to_string()));
+        println!("{}", json);
+        return;
+    }
+    for PoolStatus {
+        standard_name,
+        available,
+        target,
+        ..
+    } in &status
+    {
+        println!("{:<28} {:>4} / {}", standard_name, available, target);
+    }
+}
@@ -0,0 +1,230 @@
+use std::fs;
+use std::net::TcpListener;
+use std::path::PathBuf;
+use std::thread;
+
+use zeroize::Zeroizing;
+
+use keypairer::internals::key_io::write_atomic;
+use keypairer::internals::keystore::{Keystore, default_root};
+use keypairer::internals::secret::disable_core_dumps;
+use keypairer::internals::service::{self, ServiceEvent, ServiceOptions, is_loopback};
+
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{configured_policy, default_lang};
+
+/// `serve` 기본 주소
+const DEFAULT_BIND: &str = "127.0.0.1:7878";
+
+/// `serve` 인자
+struct ServeArgs {
+    bind: String,
+    store_dir: Option<String>,
+    token_file: Option<String>,
+    jobs: Option<String>,
+    lang: String,
+}
+
+/// `serve` 하위 명령을 실행합니다.
+pub fn run(prog: &str, args: &[String]) {
+    let mut parsed = ServeArgs {
+        bind: DEFAULT_BIND.to_string(),
+        store_dir: None,
+        token_file: None,
+        jobs: None,
+        lang: default_lang(),
+    };
+
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-bind" | "--bind" | "-store-dir" | "-token-file" | "-jobs" | "-l" | "-lang" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(prog, &parsed.lang);
+                }
+                let value = args[i].clone();
+                match flag.as_str() {
+                    "-bind" | "--bind" => parsed.bind = value,
+                    "-store-dir" => parsed.store_dir = Some(value),
+                    "-token-file" => parsed.token_file = Some(value),
+                    "-jobs" => parsed.jobs = Some(value),
+                    _ => parsed.lang = value.to_lowercase(),
+                }
+            }
+            "-h" | "--help" => usage_and_exit(prog, &parsed.lang),
+            other => {
+                eprintln!(
+                    "{}",
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other)
+                );
+                usage_and_exit(prog, &parsed.lang);
+            }
+        }
+        i += 1;
+    }
+
+    serve(&parsed);
+}
+
+fn serve(args: &ServeArgs) {
+    let lang = args.lang.as_str();
+
+    let workers = match &args.jobs {
+        Some(v) => match v.parse::<usize>() {
+            Ok(n) if n > 0 => n,
+            _ => fail(
+                text(lang, "error.batch.jobs", "잘못된 워커 수: {value}").replace("{value}", v),
+            ),
+        },
+        None => thread::available_parallelism()
+            .map(|n| n.get())
+            .unwrap_or(1),
+    };
+
+    // 저장소 위치를 정할 수 없으면 저장소가 필요한 요청만 거절
+    let store = args
+        .store_dir
+        .as_ref()
+        .map(PathBuf::from)
+        .or_else(default_root)
+        .map(|root| Keystore::open(&root).unwrap_or_else(|e| fail(e.message(lang))));
+
+    let listener = TcpListener::bind(&args.bind).unwrap_or_else(|e| {
+        fail(
+            text(
+                lang,
+                "error.serve.bind",
+                "주소에 바인딩할 수 없습니다({addr}): {err}",
+            )
+            .replace("{addr}", &args.bind)
+            .replace("{err}", &e.to_string()),
+        )
+    });
+    if !is_loopback(&listener).unwrap_or(false) {
+        fail(
+            text(
+                lang,
+                "error.serve.remote",
+                "키 서비스는 루프백 주소(127.0.0.1, ::1)에만 바인딩할 수 있습니다: {addr}",
+            )
+            .replace("{addr}", &args.bind),
+        );
+    }
+    let addr = listener
+        .local_addr()
+        .map_or_else(|_| args.bind.clone(), |a| a.to_string());
+    let port = listener.local_addr().map_or(0, |a| a.port());
+
+    let token = match &args.token_file {
+        Some(path) => read_token(path, lang),
+        None => create_token(port, lang),
+    };
+
+    // 생성한 비밀키와 공유 비밀이 워커 메모리를 거쳐 가므로 코어 덤프를 끔
+    let _no_core = disable_core_dumps();
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.serve.listening",
+            "키 서비스를 시작했습니다: http://{addr} (워커 {jobs}개)",
+        )
+        .replace("{addr}", &addr)
+        .replace("{jobs}", &workers.to_string())
+    );
+
+    let options = ServiceOptions {
+        store,
+        token,
+        policy: configured_policy(lang),
+        workers,
+    };
+    let event_lang = args.lang.clone();
+    let result = service::serve(listener, options, move |event| match event {
+        ServiceEvent::Handled {
+            method,
+            path,
+            status,
+        } => eprintln!("{} {} {}", method, path, status),
+        ServiceEvent::Failed(e) => eprintln!(
+            "{}",
+            text(&event_lang, "error.serve.io", "키 서비스 연결 오류: {err}")
+                .replace("{err}", &e.to_string())
+        ),
+    });
+    if let Err(e) = result {
+        fail(
+            text(lang, "error.serve.io", "키 서비스 연결 오류: {err}")
+                .replace("{err}", &e.to_string()),
+        );
+    }
+}
+
+/// `-token-file`의 토큰을 읽습니다. 비어 있으면 종료합니다.
+fn read_token(path: &str, lang: &str) -> Zeroizing<String> {
+    let data = fs::read_to_string(path)
+        .map(Zeroizing::new)
+        .unwrap_or_else(|e| {
+            fail(
+                text(
+                    lang,
+                    "error.serve.token",
+                    "토큰 파일을 읽을 수 없습니다({path}): {err}",
+                )
+                .replace("{path}", path)
+                .replace("{err}", &e.to_string()),
+            )
+        });
+    let token = Zeroizing::new(data.trim().to_string());
+    if token.is_empty() {
+        fail(
+            text(
+                lang,
+                "error.serve.token",
+                "토큰 파일을 읽을 수 없습니다({path}): {err}",
+            )
+            .replace("{path}", path)
+            .replace("{err}", "empty"),
+        );
+    }
+    token
+}
+
+/// `-token-file`이 없으면 임의의 토큰을 만들어 소유자 전용(0o600) 파일에 저장하고 경로를 알립니다.
+fn create_token(port: u16, lang: &str) -> Zeroizing<String> {
+    let path = service::default_token_path(port).unwrap_or_else(|| {
+        fail(text(
+            lang,
+            "error.serve.token_path",
+            "인증 토큰을 둘 위치를 정할 수 없습니다. -token-file을 지정하세요.",
+        ))
+    });
+    let token = service::generate_token()
+        .and_then(|token| write_atomic(&path, token.as_bytes(), 0o600, true).map(|_| token))
+        .unwrap_or_else(|e| {
+            fail(
+                text(
+                    lang,
+                    "error.serve.token_write",
+                    "인증 토큰을 저장할 수 없습니다({path}): {err}",
+                )
+                .replace("{path}", &path.display().to_string())
+                .replace("{err}", &e.to_string()),
+            )
+        });
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.serve.token",
+            "인증 토큰을 만들었습니다: {path} ('Authorization: Bearer <파일 내용>'으로 요청하세요)",
+        )
+        .replace("{path}", &path.display().to_string())
+    );
+    token
+}
@@ -0,0 +1,472 @@
+use std::fs;
+use std::io;
+use std::path::Path;
+
+use zeroize::Zeroizing;
+
+use keypairer::internals::kem;
+use keypairer::internals::key_io::{STDIO_PATH, fingerprint, to_pem, write_atomic};
+use keypairer::internals::keygen::{Variant, parse_algorithm, parse_variant, verify_key_pair};
+use keypairer::internals::metadata::{KeyMetadata, sidecar_path};
+use keypairer::internals::shamir::{Share, combine, new_set_id, split};
+use keypairer::internals::sign;
+use keypairer::{load_key, run_with_variant_stack};
+
+use super::store::open_store;
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{configured_policy, default_lang, enforce_policy, resolve_algorithm};
+
+/// 메시지와 기본 파일 이름에 쓰는 지문 접두사 길이
+const FP_SHORT_LEN: usize = 16;
+
+/// `split`/`recombine` 공통 인자
+struct ShareArgs {
+    key: Option<String>,
+    sk_path: Option<String>,
+    pk_path: Option<String>,
+    algorithm: Option<String>,
+    variant: Option<String>,
+    shares: Option<String>,
+    threshold: Option<String>,
+    inputs: Vec<String>,
+    output: Option<String>,
+    sk_text: bool,
+    force: bool,
+    store_dir: Option<String>,
+    lang: String,
+}
+
+/// 키 저장소 또는 파일에서 읽은 키 페어
+pub(crate) struct KeyPair {
+    pub variant: Variant,
+    pub pk: Vec<u8>,
+    pub sk: Zeroizing<Vec<u8>>,
+    /// 기본 출력 파일 이름에 쓰는 이름 (라벨, 키 ID 또는 비밀키 파일 이름)
+    pub name: String,
+}
+
+/// `split`, `recombine` 하위 명령을 실행합니다.
+pub fn run(prog: &str, command: &str, args: &[String]) {
+    let mut parsed = ShareArgs {
+        key: None,
+        sk_path: None,
+        pk_path: None,
+        algorithm: None,
+        variant: None,
+        shares: None,
+        threshold: None,
+        inputs: Vec::new(),
+        output: None,
+        sk_text: false,
+        force: false,
+        store_dir: None,
+        lang: default_lang(),
+    };
+
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-key" | "-sk" | "-skpath" | "-pk" | "-pkpath" | "-alg" | "-algorithm" | "-var"
+            | "-variant" | "-n" | "-shares" | "-k" | "-threshold" | "-in" | "-out"
+            | "-store-dir" | "-l" | "-lang" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(prog, &parsed.lang);
+                }
+                let value = args[i].clone();
+                match flag.as_str() {
+                    "-key" => parsed.key = Some(value),
+                    "-sk" | "-skpath" => parsed.sk_path = Some(value),
+                    "-pk" | "-pkpath" => parsed.pk_path = Some(value),
+                    "-alg" | "-algorithm" => parsed.algorithm = Some(value.to_lowercase()),
+                    "-var" | "-variant" => parsed.variant = Some(value.to_lowercase()),
+                    "-n" | "-shares" => parsed.shares = Some(value),
+                    "-k" | "-threshold" => parsed.threshold = Some(value),
+                    "-in" => parsed.inputs.push(value),
+                    "-out" => parsed.output = Some(value),
+                    "-store-dir" => parsed.store_dir = Some(value),
+                    _ => parsed.lang = value.to_lowercase(),
+                }
+            }
+            "-skt" | "-sktext" => parsed.sk_text = true,
+            "-f" | "-force" => parsed.force = true,
+            "-h" | "--help" => usage_and_exit(prog, &parsed.lang),
+            // recombine: 조각 파일은 -in 없이 나열해도 됨 (셸 글롭용)
+            other if command == "recombine" && !other.starts_with('-') => {
+                parsed.inputs.push(other.to_string())
+            }
+            other => {
+                eprintln!(
+                    "{}",
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other)
+                );
+                usage_and_exit(prog, &parsed.lang);
+            }
+        }
+        i += 1;
+    }
+
+    if command == "split" {
+        split_key(prog, &parsed);
+    } else {
+        recombine(prog, &parsed);
+    }
+}
+
+fn split_key(prog: &str, args: &ShareArgs) {
+    let lang = args.lang.as_str();
+    let (Some(shares), Some(threshold)) = (&args.shares, &args.threshold) else {
+        usage_and_exit(prog, lang);
+    };
+    let parse_count = |value: &str| {
+        value.parse::<usize>().unwrap_or_else(|_| {
+            fail(text(lang, "error.share.count", "잘못된 개수: {value}").replace("{value}", value))
+        })
+    };
+    let (total, threshold) = (parse_count(shares), parse_count(threshold));
+
+    let Some(pair) = read_key_pair(
+        args.key.as_deref(),
+        args.sk_path.as_deref(),
+        args.pk_path.as_deref(),
+        (args.algorithm.as_deref(), args.variant.as_deref()),
+        args.store_dir.as_deref(),
+        lang,
+    ) else {
+        usage_and_exit(prog, lang);
+    };
+
+    let template = args
+        .output
+        .clone()
+        .unwrap_or_else(|| format!("{}.share{{n}}.pem", pair.name));
+    if !template.contains("{n}") {
+        fail(text(
+            lang,
+            "error.share.template",
+            "조각 파일 경로에는 {n}이 포함되어야 합니다.",
+        ));
+    }
+    let paths: Vec<String> = (1..=total)
+        .map(|n| template.replace("{n}", &n.to_string()))
+        .collect();
+    if !args.force
+        && let Some(existing) = paths.iter().find(|p| Path::new(p).exists())
+    {
+        fail(exists_message(existing, lang));
+    }
+
+    let KeyPair {
+        variant, pk, sk, ..
+    } = pair;
+    let fp = fingerprint(&pk);
+    let pieces = split(&sk, threshold, total).unwrap_or_else(|e| fail(e.message(lang)));
+    let set_id = new_set_id().unwrap_or_else(|e| fail(e.message(lang)));
+
+    for ((index, data), path) in pieces.into_iter().zip(&paths) {
+        let share = Share {
+            set_id,
+            threshold: threshold as u8,
+            total: total as u8,
+            index,
+            algorithm: variant.algorithm(),
+            variant: variant.clone(),
+            fingerprint: fp.clone(),
+            data,
+        };
+        let armored = share.to_armored().unwrap_or_else(|e| fail(e.message(lang)));
+        if let Err(e) = write_atomic(Path::new(path), armored.as_bytes(), 0o600, args.force) {
+            fail(write_error(path, &e, lang));
+        }
+    }
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.share.split",
+            "비밀키를 조각 {n}개로 나눴습니다 (복원에 {k}개 필요, {standard}, 지문 {fp}):",
+        )
+        .replace("{n}", &total.to_string())
+        .replace("{k}", &threshold.to_string())
+        .replace("{standard}", &variant.standard_name())
+        .replace("{fp}", &fp[..FP_SHORT_LEN])
+    );
+    for path in &paths {
+        eprintln!("  {}", path);
+    }
+}
+
+fn recombine(prog: &str, args: &ShareArgs) {
+    let lang = args.lang.as_str();
+    enforce_policy(&configured_policy(lang), [], args.sk_text, lang);
+    if args.inputs.is_empty() {
+        usage_and_exit(prog, lang);
+    }
+    let shares: Vec<Share> = args
+        .inputs
+        .iter()
+        .map(|path| {
+            let armored = Zeroizing::new(fs::read_to_string(path).unwrap_or_else(|e| {
+                fail(
+                    text(
+                        lang,
+                        "error.file.read",
+                        "파일을 읽는 도중 오류가 발생했습니다({path}): {err}",
+                    )
+                    .replace("{path}", path)
+                    .replace("{err}", &e.to_string()),
+                )
+            }));
+            Share::from_armored(&armored).unwrap_or_else(|e| {
+                fail(format!("{} ({})", e.message(lang), path));
+            })
+        })
+        .collect();
+    let sk = combine(&shares).unwrap_or_else(|e| fail(e.message(lang)));
+    let first = &shares[0];
+    let variant = first.variant.clone();
+
+    // 원래 공개키: -pk, -key, 또는 키 저장소에서 지문으로 검색
+    let pk = match (&args.pk_path, &args.key) {
+        (Some(path), _) => load_key(path, "PUBLIC KEY", lang).to_vec(),
+        (None, Some(query)) => {
+            let store = open_store(args.store_dir.as_deref(), lang);
+            let entry = store.find(query).unwrap_or_else(|e| fail(e.message(lang)));
+            store
+                .read_public(&entry.id)
+                .unwrap_or_else(|e| fail(e.message(lang)))
+        }
+        (None, None) => public_key_by_fingerprint(&first.fingerprint, args.store_dir.as_deref())
+            .unwrap_or_else(|| {
+                fail(text(
+                    lang,
+                    "error.share.public",
+                    "복원한 키를 확인할 공개키를 -pk 또는 -key로 지정하세요.",
+                ))
+            }),
+    };
+    if fingerprint(&pk) != first.fingerprint {
+        fail(
+            text(
+                lang,
+                "error.share.fingerprint",
+                "공개키 지문이 조각에 기록된 지문과 다릅니다: {fp}",
+            )
+            .replace("{fp}", &first.fingerprint[..FP_SHORT_LEN]),
+        );
+    }
+
+    // 공개키로 복원 결과 확인 (배리언트의 스택 예산만큼)
+    let check_variant = variant.clone();
+    let check_sk = sk.clone();
+    let matches = run_with_variant_stack(
+        &variant,
+        move || verify_key_pair(&check_variant, &pk, &check_sk).unwrap_or(false),
+        lang,
+    );
+    if !matches {
+        fail(text(
+            lang,
+            "error.share.verify",
+            "복원한 비밀키가 공개키와 한 쌍이 아닙니다. 조각이 잘못되었습니다.",
+        ));
+    }
+
+    let output = args
+        .output
+        .clone()
+        .unwrap_or_else(|| format!("{}.sk", &first.fingerprint[..FP_SHORT_LEN]));
+    let data = if args.sk_text {
+        Zeroizing::new(to_pem("SECRET KEY", &sk).into_bytes())
+    } else {
+        sk
+    };
+    if let Err(e) = write_atomic(Path::new(&output), &data, 0o600, args.force) {
+        fail(write_error(&output, &e, lang));
+    }
+
+    eprintln!(
+        "{}",
+        text(
+            lang,
+            "info.share.recombined",
+            "조각 {count}개로 비밀키를 복원하고 공개키로 확인했습니다: {path} ({standard}, 지문 {fp})",
+        )
+        .replace("{count}", &shares.len().to_string())
+        .replace("{path}", &output)
+        .replace("{standard}", &variant.standard_name())
+        .replace("{fp}", &first.fingerprint[..FP_SHORT_LEN])
+    );
+}
+
+/// 키 저장소(`-key`) 또는 비밀키 파일(`-sk`)에서 키 페어를 읽고 한 쌍인지 확인합니다.
+///
+/// 파일이면 공개키는 `-pk` 또는 메타데이터 사이드카에 기록된 경로에서 읽으며, 배리언트는
+/// `-alg`/`-var`, 사이드카, 공개키 길이 순으로 정합니다. 키를 지정하지 않았으면 `None`입니다.
+pub(crate) fn read_key_pair(
+    key: Option<&str>,
+    sk_path: Option<&str>,
+    pk_path: Option<&str>,
+    (algorithm, variant): (Option<&str>, Option<&str>),
+    store_dir: Option<&str>,
+    lang: &str,
+) -> Option<KeyPair> {
+    let pair = match (key, sk_path) {
+        (Some(query), None) => {
+            let store = open_store(store_dir, lang);
+            let entry = store.find(query).unwrap_or_else(|e| fail(e.message(lang)));
+            let variant = entry
+                .algorithm_variant()
+                .map(|(_, v)| v)
+                .unwrap_or_else(|| fail(unknown_variant(&entry.metadata.variant, lang)));
+            KeyPair {
+                variant,
+                pk: store
+                    .read_public(&entry.id)
+                    .unwrap_or_else(|e| fail(e.message(lang))),
+                sk: store
+                    .read_secret(&entry.id)
+                    .unwrap_or_else(|e| fail(e.message(lang))),
+                name: entry.label.clone().unwrap_or(entry.id),
+            }
+        }
+        (None, Some(path)) => {
+            let sk = load_key(path, "SECRET KEY", lang);
+            let sidecar = sidecar_path(STDIO_PATH, path)
+                .and_then(|p| fs::read_to_string(p).ok())
+                .and_then(|json| serde_json::from_str::<KeyMetadata>(&json).ok());
+            let pk = match (pk_path, &sidecar) {
+                (Some(p), _) => load_key(p, "PUBLIC KEY", lang).to_vec(),
+                (None, Some(meta)) if meta.files.public_key != STDIO_PATH => {
+                    load_key(&meta.files.public_key, "PUBLIC KEY", lang).to_vec()
+                }
+                _ => fail(text(
+                    lang,
+                    "error.share.need_public",
+                    "비밀키를 확인할 공개키를 -pk로 지정하세요.",
+                )),
+            };
+            let variant = if let Some(alg) = algorithm {
+                resolve_algorithm(alg, variant, lang)
+                    .map(|(_, v)| v)
+                    .unwrap_or_else(|msg| fail(msg))
+            } else if let Some(v) = sidecar
+                .as_ref()
+                .filter(|meta| meta.fingerprint == fingerprint(&pk))
+                .and_then(|meta| {
+                    parse_algorithm(&meta.algorithm).and_then(|a| parse_variant(a, &meta.variant))
+                })
+            {
+                v
+            } else {
+                let mut candidates = kem::variants_for_public_key(pk.len());
+                candidates.extend(sign::variants_for_public_key(pk.len()));
+                match candidates.as_slice() {
+                    [one] => one.clone(),
+                    [] => fail(unknown_variant(path, lang)),
+                    many => fail(
+                        text(
+                            lang,
+                            "error.crypt.ambiguous_variant",
+                            "공개키 길이로 배리언트를 정할 수 없습니다({variants}). -alg와 -var를 지정하세요.",
+                        )
+                        .replace(
+                            "{variants}",
+                            &many
+                                .iter()
+                                .map(|v| v.standard_name())
+                                .collect::<Vec<_>>()
+                                .join(", "),
+                        ),
+                    ),
+                }
+            };
+            let name = Path::new(path)
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+                .unwrap_or_else(|| "key".to_string());
+            KeyPair {
+                variant,
+                pk,
+                sk,
+                name,
+            }
+        }
+        _ => {
+            eprintln!(
+                "{}",
+                text(
+                    lang,
+                    "error.share.secret",
+                    "비밀키를 -key <키 저장소 키> 또는 -sk <비밀키 파일> 중 하나로 지정하세요.",
+                )
+            );
+            return None;
+        }
+    };
+
+    // 잘못된 키를 백업하지 않도록 한 쌍인지 먼저 확인 (배리언트의 스택 예산만큼)
+    let (variant, pk, sk) = (pair.variant.clone(), pair.pk.clone(), pair.sk.clone());
+    let matches = run_with_variant_stack(
+        &pair.variant,
+        move || verify_key_pair(&variant, &pk, &sk).unwrap_or(false),
+        lang,
+    );
+    if !matches {
+        fail(text(
+            lang,
+            "error.sign.pair_mismatch",
+            "비밀키와 공개키가 한 쌍이 아닙니다.",
+        ));
+    }
+    Some(pair)
+}
+
+/// 키 저장소에서 지문이 같은 키의 공개키를 찾습니다.
+pub(crate) fn public_key_by_fingerprint(fp: &str, store_dir: Option<&str>) -> Option<Vec<u8>> {
+    let root = store_dir
+        .map(std::path::PathBuf::from)
+        .or_else(keypairer::internals::keystore::default_root)?;
+    let store = keypairer::internals::keystore::Keystore::open(&root).ok()?;
+    let entry = store
+        .entries()
+        .ok()?
+        .into_iter()
+        .find(|e| e.metadata.fingerprint == fp)?;
+    store.read_public(&entry.id).ok()
+}
+
+fn unknown_variant(name: &str, lang: &str) -> String {
+    text(
+        lang,
+        "error.share.unknown_key",
+        "키의 알고리즘/배리언트를 알 수 없습니다: {name}",
+    )
+    .replace("{name}", name)
+}
+
+pub(crate) fn exists_message(path: &str, lang: &str) -> String {
+    text(
+        lang,
+        "error.file.exists",
+        "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)",
+    )
+    .replace("{path}", path)
+}
+
+pub(crate) fn write_error(path: &str, e: &io::Error, lang: &str) -> String {
+    if e.kind() == io::ErrorKind::AlreadyExists {
+        return exists_message(path, lang);
+    }
+    text(
+        lang,
+        "error.file.write",
+        "파일에 쓰는 도중 오류가 발생했습니다({path}): {err}",
+    )
+    .replace("{path}", path)
+    .replace("{err}", &e.to_string())
+}
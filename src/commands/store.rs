@@ -0,0 +1,662 @@
+use std::io::{self, BufRead, Write};
+use std::path::{Path, PathBuf};
+
+use zeroize::Zeroizing;
+
+use keypairer::internals::datetime::{now_unix, parse_time};
+use keypairer::internals::key_io::{
+    STDIO_PATH, effective_text, to_pem, write_atomic, write_key_pair,
+};
+use keypairer::internals::keygen::generate_keys;
+use keypairer::internals::keystore::{Keystore, StoreEntry, default_root};
+use keypairer::internals::lifecycle::KeyState;
+use keypairer::internals::secret::disable_core_dumps;
+use keypairer::run_with_variant_stack;
+
+use super::{fail, text};
+use crate::usage_and_exit;
+use crate::util::{
+    configured_policy, default_lang, enforce_policy, load_settings, resolve_policy, select_entropy,
+};
+
+/// 키 저장소 하위 명령 공통 인자
+struct StoreArgs {
+    store_dir: Option<String>,
+    lang: String,
+    json: bool,
+    yes: bool,
+    tag: Option<String>,
+    pk_path: Option<String>,
+    sk_path: Option<String>,
+    pk_text: bool,
+    sk_text: bool,
+    force: bool,
+    query: Option<String>,
+    /// `state`/`expire`의 두 번째 위치 인자
+    value: Option<String>,
+    state: Option<String>,
+    at: Option<String>,
+    expires: Option<String>,
+    pending: bool,
+    reason: Option<String>,
+    /// `rotate`의 난수 소스
+    entropy: Option<String>,
+    /// `rotate`에 더할 정책
+    policy: Option<String>,
+}
+
+/// `list`, `show`, `export`, `delete`, `rotate`, `state`, `expire`, `history` 하위 명령을 실행합니다.
+pub fn run(prog: &str, command: &str, args: &[String]) {
+    let mut parsed = StoreArgs {
+        store_dir: None,
+        lang: default_lang(),
+        json: false,
+        yes: false,
+        tag: None,
+        pk_path: None,
+        sk_path: None,
+        pk_text: false,
+        sk_text: false,
+        force: false,
+        query: None,
+        value: None,
+        state: None,
+        at: None,
+        expires: None,
+        pending: false,
+        reason: None,
+        entropy: None,
+        policy: None,
+    };
+
+    let mut i = 0usize;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-store-dir" | "-tag" | "-pkpath" | "-skpath" | "-state" | "-at" | "-expires"
+            | "-reason" | "-entropy" | "-policy" | "-l" | "-lang" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(prog, &parsed.lang);
+                }
+                let value = args[i].clone();
+                match flag.as_str() {
+                    "-store-dir" => parsed.store_dir = Some(value),
+                    "-tag" => parsed.tag = Some(value),
+                    "-pkpath" => parsed.pk_path = Some(value),
+                    "-skpath" => parsed.sk_path = Some(value),
+                    "-state" => parsed.state = Some(value),
+                    "-at" => parsed.at = Some(value),
+                    "-expires" => parsed.expires = Some(value),
+                    "-reason" => parsed.reason = Some(value),
+                    "-entropy" => parsed.entropy = Some(value),
+                    "-policy" => parsed.policy = Some(value),
+                    _ => parsed.lang = value.to_lowercase(),
+                }
+            }
+            "-json" => parsed.json = true,
+            "-y" | "-yes" => parsed.yes = true,
+            "-pkt" | "-pktext" => parsed.pk_text = true,
+            "-skt" | "-sktext" => parsed.sk_text = true,
+            "-f" | "-force" => parsed.force = true,
+            "-pending" => parsed.pending = true,
+            "-h" | "--help" => usage_and_exit(prog, &parsed.lang),
+            other if !other.starts_with('-') && parsed.query.is_none() => {
+                parsed.query = Some(other.to_string());
+            }
+            other if !other.starts_with('-') && parsed.value.is_none() => {
+                parsed.value = Some(other.to_string());
+            }
+            other => {
+                eprintln!(
+                    "{}",
+                    text(&parsed.lang, "error.unknown_arg", "알 수 없는 인자: {arg}")
+                        .replace("{arg}", other)
+                );
+                usage_and_exit(prog, &parsed.lang);
+            }
+        }
+        i += 1;
+    }
+
+    let store = open_store(parsed.store_dir.as_deref(), &parsed.lang);
+    if command == "list" {
+        return list(&store, &parsed);
+    }
+
+    let query = match &parsed.query {
+        Some(q) => q.clone(),
+        None => {
+            eprintln!(
+                "{}",
+                text(
+                    &parsed.lang,
+                    "error.store.missing_query",
+                    "대상 키(ID, ID 접두사, 지문 또는 라벨)를 지정하세요."
+                )
+            );
+            usage_and_exit(prog, &parsed.lang);
+        }
+    };
+    let lang = parsed.lang.as_str();
+    let entry = store.find(&query).unwrap_or_else(|e| fail(e.message(lang)));
+
+    match command {
+        "show" => show(&store, &entry, &parsed),
+        "export" => export(&store, &entry, &parsed),
+        "rotate" => rotate(&store, &entry, &parsed),
+        "state" | "expire" => {
+            let Some(value) = parsed.value.clone() else {
+                usage_and_exit(prog, lang);
+            };
+            if command == "state" {
+                set_state(&store, &entry, &value, &parsed)
+            } else {
+                set_expiry(&store, &entry, &value, &parsed)
+            }
+        }
+        "history" => history(&store, &entry, &parsed),
+        _ => delete(&store, &entry, &parsed),
+    }
+}
+
+/// 키 저장소 경로를 결정하여 엽니다.
+pub(crate) fn open_store(store_dir: Option<&str>, lang: &str) -> Keystore {
+    let root = match store_dir.map(PathBuf::from).or_else(default_root) {
+        Some(root) => root,
+        None => fail(text(
+            lang,
+            "error.store.no_home",
+            "키 저장소 위치를 정할 수 없습니다. -store-dir 또는 KEYPAIRER_HOME을 지정하세요.",
+        )),
+    };
+    Keystore::open(&root).unwrap_or_else(|e| fail(e.message(lang)))
+}
+
+fn list(store: &Keystore, args: &StoreArgs) {
+    let lang = args.lang.as_str();
+    let now = now_unix();
+    let state = args.state.as_deref().map(|s| parse_state(s, lang));
+    let at = args.at.as_deref().map(|t| parse_time_arg(t, now, lang));
+    let entries: Vec<StoreEntry> = store
+        .entries()
+        .unwrap_or_else(|e| fail(e.message(lang)))
+        .into_iter()
+        .filter(|e| match &args.tag {
+            Some(tag) => e.tags.iter().any(|t| t == tag),
+            None => true,
+        })
+        .filter(|e| state.is_none_or(|s| e.lifecycle.state == s))
+        .filter(|e| at.is_none_or(|t| e.lifecycle.was_active_at(t)))
+        .collect();
+
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&entries).unwrap_or_default()
+        );
+        return;
+    }
+    if entries.is_empty() {
+        println!(
+            "{}",
+            text(lang, "info.store.empty", "저장된 키가 없습니다: {path}")
+                .replace("{path}", &store.root().display().to_string())
+        );
+        return;
+    }
+
+    let header = [
+        text(lang, "store.col.id", "ID"),
+        text(lang, "store.col.algorithm", "알고리즘"),
+        text(lang, "store.col.variant", "배리언트"),
+        text(lang, "store.col.label", "라벨"),
+        text(lang, "store.col.tags", "태그"),
+        text(lang, "store.col.state", "상태"),
+        text(lang, "store.col.created", "생성 시각"),
+        text(lang, "store.col.expires", "만료 시각"),
+    ];
+    let rows: Vec<[String; 8]> = entries
+        .iter()
+        .map(|e| {
+            [
+                e.id.clone(),
+                e.metadata.algorithm.clone(),
+                e.metadata.variant.clone(),
+                e.label.clone().unwrap_or_else(|| "-".to_string()),
+                if e.tags.is_empty() {
+                    "-".to_string()
+                } else {
+                    e.tags.join(",")
+                },
+                state_label(e, now, lang),
+                e.metadata.created.clone(),
+                e.lifecycle
+                    .expires
+                    .clone()
+                    .unwrap_or_else(|| "-".to_string()),
+            ]
+        })
+        .collect();
+    print_table(&header, &rows);
+}
+
+/// 열 너비를 맞춰 표를 출력합니다.
+pub(crate) fn print_table<const N: usize>(header: &[String; N], rows: &[[String; N]]) {
+    let mut widths = [0usize; N];
+    for row in std::iter::once(header).chain(rows.iter()) {
+        for (w, cell) in widths.iter_mut().zip(row.iter()) {
+            *w = (*w).max(display_width(cell));
+        }
+    }
+    for row in std::iter::once(header).chain(rows.iter()) {
+        let line: Vec<String> = row
+            .iter()
+            .zip(widths.iter())
+            .map(|(cell, w)| format!("{}{}", cell, " ".repeat(w - display_width(cell))))
+            .collect();
+        println!("{}", line.join("  ").trim_end());
+    }
+}
+
+/// 터미널 표시 폭을 계산합니다. 한글·CJK 문자는 두 칸으로 셉니다.
+fn display_width(s: &str) -> usize {
+    s.chars()
+        .map(|c| match c as u32 {
+            0x1100..=0x115F
+            | 0x2E80..=0xA4CF
+            | 0xAC00..=0xD7A3
+            | 0xF900..=0xFAFF
+            | 0xFF00..=0xFF60 => 2,
+            _ => 1,
+        })
+        .sum()
+}
+
+fn show(store: &Keystore, entry: &StoreEntry, args: &StoreArgs) {
+    let lang = args.lang.as_str();
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(entry).unwrap_or_default()
+        );
+        return;
+    }
+
+    let meta = &entry.metadata;
+    let root = store.root();
+    let fields = [
+        ("store.field.id", "ID", entry.id.clone()),
+        (
+            "store.field.label",
+            "라벨",
+            entry.label.clone().unwrap_or_else(|| "-".to_string()),
+        ),
+        ("store.field.tags", "태그", entry.tags.join(", ")),
+        ("store.field.algorithm", "알고리즘", meta.algorithm.clone()),
+        ("store.field.variant", "배리언트", meta.variant.clone()),
+        (
+            "store.field.standard",
+            "표준 이름",
+            meta.standard_name.clone(),
+        ),
+        ("store.field.fingerprint", "지문", meta.fingerprint.clone()),
+        ("store.field.created", "생성 시각", meta.created.clone()),
+        (
+            "store.field.state",
+            "상태",
+            state_label(entry, now_unix(), lang),
+        ),
+        (
+            "store.field.expires",
+            "만료 시각",
+            or_dash(entry.lifecycle.expires.clone()),
+        ),
+        (
+            "store.field.predecessor",
+            "이전 키",
+            or_dash(entry.lifecycle.predecessor.clone()),
+        ),
+        (
+            "store.field.successor",
+            "다음 키",
+            or_dash(entry.lifecycle.successor.clone()),
+        ),
+        (
+            "store.field.public_key",
+            "공개키 파일",
+            root.join(&meta.files.public_key).display().to_string(),
+        ),
+        (
+            "store.field.secret_key",
+            "비밀키 파일",
+            root.join(&meta.files.secret_key).display().to_string(),
+        ),
+        (
+            "store.field.backend",
+            "백엔드",
+            format!(
+                "{} {} (pqcrypto {})",
+                meta.provenance.backend_crate,
+                meta.provenance.backend_crate_version,
+                meta.provenance.backend
+            ),
+        ),
+    ];
+    for (key, fallback, value) in fields {
+        println!("{}: {}", text(lang, key, fallback), value);
+    }
+}
+
+fn export(store: &Keystore, entry: &StoreEntry, args: &StoreArgs) {
+    let lang = args.lang.as_str();
+    let name = entry.label.clone().unwrap_or_else(|| entry.id.clone());
+    let pk_path = match &args.pk_path {
+        Some(p) if p != STDIO_PATH && Path::new(p).extension().is_none() => format!("{}.pub", p),
+        Some(p) => p.clone(),
+        None => format!("{}.pub", name),
+    };
+    let pk_bytes = store
+        .read_public(&entry.id)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+
+    // 비밀키는 -skpath를 명시한 경우에만 내보냄
+    let Some(sk_path) = &args.sk_path else {
+        let (pk_text, _) = effective_text(&pk_path, "", args.pk_text, false);
+        let data = if pk_text {
+            to_pem("PUBLIC KEY", &pk_bytes).into_bytes()
+        } else {
+            pk_bytes.clone()
+        };
+        let written = if pk_path == STDIO_PATH {
+            let mut out = io::stdout().lock();
+            out.write_all(&data).and_then(|_| out.flush())
+        } else {
+            write_atomic(Path::new(&pk_path), &data, 0o644, args.force)
+        };
+        if let Err(e) = written {
+            fail(if e.kind() == io::ErrorKind::AlreadyExists {
+                text(
+                    lang,
+                    "error.file.exists",
+                    "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)",
+                )
+                .replace("{path}", &pk_path)
+            } else {
+                text(
+                    lang,
+                    "error.file.write_pk",
+                    "공개키 파일에 쓰는 도중 오류가 발생했습니다: {err}",
+                )
+                .replace("{err}", &e.to_string())
+            });
+        }
+        report_exported(lang, &entry.id, &pk_path, None);
+        return;
+    };
+
+    let sk_path = if sk_path != STDIO_PATH && Path::new(sk_path).extension().is_none() {
+        format!("{}.sk", sk_path)
+    } else {
+        sk_path.clone()
+    };
+    let sk_bytes = store
+        .read_secret(&entry.id)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    let (pk_text, sk_text) = effective_text(&pk_path, &sk_path, args.pk_text, args.sk_text);
+    enforce_policy(&configured_policy(lang), [], sk_text, lang);
+    if let Err(e) = write_key_pair(
+        &pk_bytes, &sk_bytes, &pk_path, &sk_path, pk_text, sk_text, args.force,
+    ) {
+        fail(e.message(lang));
+    }
+    report_exported(lang, &entry.id, &pk_path, Some(&sk_path));
+}
+
+fn report_exported(lang: &str, id: &str, pk_path: &str, sk_path: Option<&str>) {
+    let to_stdout = pk_path == STDIO_PATH || sk_path == Some(STDIO_PATH);
+    let shown = |p: &str| {
+        if p == STDIO_PATH {
+            "<stdout>".to_string()
+        } else {
+            p.to_string()
+        }
+    };
+    let mut lines = vec![
+        text(lang, "info.store.exported", "키를 내보냈습니다: {id}").replace("{id}", id),
+        text(lang, "info.pk_saved", "공개키 저장: {path}").replace("{path}", &shown(pk_path)),
+    ];
+    if let Some(sk) = sk_path {
+        lines
+            .push(text(lang, "info.sk_saved", "비밀키 저장: {path}").replace("{path}", &shown(sk)));
+    }
+    for line in lines {
+        if to_stdout {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+}
+
+fn delete(store: &Keystore, entry: &StoreEntry, args: &StoreArgs) {
+    let lang = args.lang.as_str();
+    if !args.yes {
+        eprint!(
+            "{}",
+            text(
+                lang,
+                "prompt.store.delete",
+                "키 {id}({label})를 삭제합니다. 비밀키는 복구할 수 없습니다. 계속할까요? [y/N] ",
+            )
+            .replace("{id}", &entry.id)
+            .replace("{label}", entry.label.as_deref().unwrap_or("-"))
+        );
+        let _ = io::stderr().flush();
+        let mut answer = String::new();
+        let _ = io::stdin().lock().read_line(&mut answer);
+        if !matches!(answer.trim().to_lowercase().as_str(), "y" | "yes") {
+            fail(text(
+                lang,
+                "info.store.delete_cancelled",
+                "삭제를 취소했습니다.",
+            ));
+        }
+    }
+
+    store
+        .delete(&entry.id)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    println!(
+        "{}",
+        text(lang, "info.store.deleted", "키를 삭제했습니다: {id}").replace("{id}", &entry.id)
+    );
+}
+
+fn rotate(store: &Keystore, entry: &StoreEntry, args: &StoreArgs) {
+    let lang = args.lang.as_str();
+    let now = now_unix();
+    let expires = args
+        .expires
+        .as_deref()
+        .map(|e| parse_time_arg(e, now, lang));
+    if let Some(next) = &entry.lifecycle.successor {
+        fail(
+            text(
+                lang,
+                "error.store.already_rotated",
+                "키 {id}는 이미 {next}(으)로 교체되었습니다.",
+            )
+            .replace("{id}", &entry.id)
+            .replace("{next}", next),
+        );
+    }
+    let Some((alg, variant)) = entry.algorithm_variant() else {
+        fail(
+            text(
+                lang,
+                "error.unknown_variant",
+                "알 수 없는 배리언트: {var} (알고리즘: {alg})",
+            )
+            .replace("{var}", &entry.metadata.variant)
+            .replace("{alg}", &entry.metadata.algorithm),
+        );
+    };
+
+    // 이전 키와 같은 배리언트로 새 키 생성 (저장할 때까지 코어 덤프 차단)
+    let settings = load_settings(None, None, lang);
+    let policy = resolve_policy(&settings, args.policy.as_deref(), lang);
+    enforce_policy(&policy, [&variant], false, lang);
+    select_entropy(args.entropy.as_deref(), lang);
+    let _no_core = disable_core_dumps();
+    let keygen_variant = variant.clone();
+    let generated =
+        run_with_variant_stack(&variant, move || generate_keys(alg, keygen_variant), lang);
+    let (pk_bytes, sk_bytes) = match generated {
+        Ok((pk, sk)) => (Zeroizing::new(pk), sk),
+        Err(e) => fail(
+            text(
+                lang,
+                "error.keygen",
+                "키 생성 도중 오류가 발생했습니다: {err}",
+            )
+            .replace("{err}", &e.to_string()),
+        ),
+    };
+
+    let (old, new) = store
+        .rotate(
+            &entry.id,
+            &pk_bytes,
+            &sk_bytes,
+            now_unix(),
+            expires,
+            args.pending,
+        )
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    println!(
+        "{}",
+        text(
+            lang,
+            "info.store.rotated",
+            "키를 교체했습니다: {old}({old_state}) → {new}({new_state})",
+        )
+        .replace("{old}", &old.id)
+        .replace("{old_state}", old.lifecycle.state.as_str())
+        .replace("{new}", &new.id)
+        .replace("{new_state}", new.lifecycle.state.as_str())
+    );
+}
+
+fn set_state(store: &Keystore, entry: &StoreEntry, value: &str, args: &StoreArgs) {
+    let lang = args.lang.as_str();
+    let state = parse_state(value, lang);
+    let updated = store
+        .set_state(&entry.id, state, now_unix(), args.reason.as_deref())
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    println!(
+        "{}",
+        text(lang, "info.store.state", "키 {id}의 상태: {state}")
+            .replace("{id}", &updated.id)
+            .replace("{state}", updated.lifecycle.state.as_str())
+    );
+}
+
+fn set_expiry(store: &Keystore, entry: &StoreEntry, value: &str, args: &StoreArgs) {
+    let lang = args.lang.as_str();
+    let expires = if value.eq_ignore_ascii_case("none") {
+        None
+    } else {
+        Some(parse_time_arg(value, now_unix(), lang))
+    };
+    let updated = store
+        .set_expiry(&entry.id, expires)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    println!(
+        "{}",
+        text(lang, "info.store.expiry", "키 {id}의 만료 시각: {expires}")
+            .replace("{id}", &updated.id)
+            .replace("{expires}", &or_dash(updated.lifecycle.expires))
+    );
+}
+
+/// 키가 속한 교체 이력과 각 키의 사용 기간, 상태 전환을 출력합니다.
+fn history(store: &Keystore, entry: &StoreEntry, args: &StoreArgs) {
+    let lang = args.lang.as_str();
+    let chain = store
+        .chain(&entry.id)
+        .unwrap_or_else(|e| fail(e.message(lang)));
+    if args.json {
+        println!(
+            "{}",
+            serde_json::to_string_pretty(&chain).unwrap_or_default()
+        );
+        return;
+    }
+
+    let now = now_unix();
+    for key in &chain {
+        println!(
+            "{}  {} {}  [{}]",
+            key.id,
+            key.metadata.algorithm,
+            key.metadata.variant,
+            state_label(key, now, lang)
+        );
+        for (start, end) in key.lifecycle.active_periods() {
+            println!(
+                "    {}",
+                text(lang, "store.history.active", "사용 기간: {start} ~ {end}")
+                    .replace("{start}", &start)
+                    .replace("{end}", &end.unwrap_or_default())
+            );
+        }
+        for change in &key.lifecycle.history {
+            match &change.reason {
+                Some(reason) => println!("    {}  {} ({})", change.at, change.state, reason),
+                None => println!("    {}  {}", change.at, change.state),
+            }
+        }
+    }
+}
+
+/// 상태 표시 문자열. 만료된 키에는 표시를 덧붙입니다.
+fn state_label(entry: &StoreEntry, now: u64, lang: &str) -> String {
+    let state = entry.lifecycle.state.as_str().to_string();
+    if entry.lifecycle.is_expired(now) {
+        format!("{} ({})", state, text(lang, "store.expired", "만료됨"))
+    } else {
+        state
+    }
+}
+
+fn or_dash(value: Option<String>) -> String {
+    value.unwrap_or_else(|| "-".to_string())
+}
+
+fn parse_state(input: &str, lang: &str) -> KeyState {
+    KeyState::parse(input).unwrap_or_else(|| {
+        fail(
+            text(
+                lang,
+                "error.store.state",
+                "알 수 없는 상태: {state} (active, pending, retired, compromised)",
+            )
+            .replace("{state}", input),
+        )
+    })
+}
+
+/// 시각 인자를 해석합니다. 해석할 수 없으면 오류를 출력하고 종료합니다.
+pub(crate) fn parse_time_arg(input: &str, now: u64, lang: &str) -> u64 {
+    parse_time(input, now).unwrap_or_else(|| {
+        fail(
+            text(
+                lang,
+                "error.time",
+                "시각을 해석할 수 없습니다: {time} (예: 2025-12-31, 2025-12-31T09:00:00Z, +90d)",
+            )
+            .replace("{time}", input),
+        )
+    })
+}
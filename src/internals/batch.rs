@@ -0,0 +1,367 @@
+use std::collections::VecDeque;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+
+use serde::Serialize;
+use zeroize::Zeroize;
+
+use super::datetime::now_unix;
+use super::key_io::{SaveError, fingerprint, write_atomic, write_key_pair};
+use super::keygen::{Algorithm, Variant, generate_keys};
+use super::metadata::{KeyMetadata, sidecar_path, write_sidecar};
+use super::stack::stack_size_for_all;
+
+/// 일괄 생성할 키 페어 한 건
+#[derive(Debug, Clone)]
+pub struct BatchJob {
+    /// 인덱스에 기록될 식별자 (예: 장치 ID)
+    pub id: String,
+    /// 1부터 시작하는 순번
+    pub n: usize,
+    pub algorithm: Algorithm,
+    pub variant: Variant,
+}
+
+/// 일괄 생성 공통 옵션
+#[derive(Debug, Clone)]
+pub struct BatchOptions {
+    /// 동시에 키를 생성할 워커 스레드 수
+    pub workers: usize,
+    pub pk_text: bool,
+    pub sk_text: bool,
+    pub force: bool,
+    /// 키 페어마다 메타데이터 사이드카(JSON)를 기록할지 여부
+    pub metadata: bool,
+}
+
+/// 저장에 성공한 키 페어 정보
+#[derive(Debug, Clone)]
+pub struct BatchRecord {
+    pub fingerprint: String,
+    pub pk_path: String,
+    pub sk_path: String,
+    pub metadata_path: Option<String>,
+}
+
+/// 한 건의 실패 사유
+#[derive(Debug)]
+pub enum BatchError {
+    KeyGen(String),
+    Save(SaveError),
+    /// 메타데이터 사이드카 기록 실패 (키 파일은 저장됨)
+    Metadata(String, io::Error),
+    /// 워커가 결과를 보고하지 못하고 종료됨 (패닉 등)
+    Lost,
+}
+
+/// 한 건의 처리 결과
+#[derive(Debug)]
+pub struct BatchOutcome {
+    pub job: BatchJob,
+    pub result: Result<BatchRecord, BatchError>,
+}
+
+/// 작업과 공개키 지문으로부터 (공개키 경로, 비밀키 경로)를 정하는 함수
+pub type PathNamer = dyn Fn(&BatchJob, &str) -> (String, String) + Send + Sync;
+
+/// 여러 키 페어를 대용량 스택 워커 풀에서 생성하고 저장합니다.
+///
+/// 각 워커는 작업 배리언트 중 가장 큰 스택 예산([`Variant::stack_size`])으로 실행되며
+/// 공유 큐에서 작업을 가져옵니다.
+/// 결과가 도착할 때마다 `progress(완료 수, 전체 수, 결과)`가 호출 스레드에서 호출되고,
+/// 반환값은 순번(`n`) 순으로 정렬됩니다.
+pub fn run_batch<P>(
+    jobs: Vec<BatchJob>,
+    options: &BatchOptions,
+    namer: Arc<PathNamer>,
+    mut progress: P,
+) -> io::Result<Vec<BatchOutcome>>
+where
+    P: FnMut(usize, usize, &BatchOutcome),
+{
+    let total = jobs.len();
+    let stack_size = stack_size_for_all(jobs.iter().map(|job| &job.variant));
+    let queue = Arc::new(Mutex::new(jobs.into_iter().collect::<VecDeque<_>>()));
+    let (tx, rx) = mpsc::channel::<BatchOutcome>();
+
+    let mut handles = Vec::new();
+    for _ in 0..options.workers.clamp(1, total.max(1)) {
+        let worker_queue = Arc::clone(&queue);
+        let worker_tx = tx.clone();
+        let namer = Arc::clone(&namer);
+        let options = options.clone();
+        let spawned = thread::Builder::new()
+            .stack_size(stack_size)
+            .spawn(move || {
+                loop {
+                    let job = match worker_queue.lock() {
+                        Ok(mut q) => q.pop_front(),
+                        Err(_) => None,
+                    };
+                    let Some(job) = job else { break };
+                    // 이미 큐에서 꺼낸 작업이므로 패닉해도 결과를 남김
+                    let result = panic::catch_unwind(AssertUnwindSafe(|| {
+                        process_job(&job, &options, namer.as_ref())
+                    }))
+                    .unwrap_or(Err(BatchError::Lost));
+                    if worker_tx.send(BatchOutcome { job, result }).is_err() {
+                        break;
+                    }
+                }
+            });
+        match spawned {
+            Ok(handle) => handles.push(handle),
+            Err(e) => {
+                // 남은 작업을 비워 먼저 띄운 워커가 진행 중인 작업만 마치고 끝나게 함
+                if let Ok(mut q) = queue.lock() {
+                    q.clear();
+                }
+                drop(tx);
+                for handle in handles {
+                    let _ = handle.join();
+                }
+                return Err(e);
+            }
+        }
+    }
+    drop(tx);
+
+    let mut outcomes = Vec::with_capacity(total);
+    for outcome in rx {
+        progress(outcomes.len() + 1, total, &outcome);
+        outcomes.push(outcome);
+    }
+    for handle in handles {
+        let _ = handle.join();
+    }
+
+    // 워커가 끝까지 돌지 못해 큐에 남은 작업은 실패로 기록
+    let remaining: Vec<BatchJob> = match queue.lock() {
+        Ok(mut q) => q.drain(..).collect(),
+        Err(_) => Vec::new(),
+    };
+    for job in remaining {
+        let outcome = BatchOutcome {
+            job,
+            result: Err(BatchError::Lost),
+        };
+        progress(outcomes.len() + 1, total, &outcome);
+        outcomes.push(outcome);
+    }
+
+    outcomes.sort_by_key(|o| o.job.n);
+    Ok(outcomes)
+}
+
+/// 워커 스레드에서 한 건을 생성·저장합니다.
+fn process_job(
+    job: &BatchJob,
+    options: &BatchOptions,
+    namer: &PathNamer,
+) -> Result<BatchRecord, BatchError> {
+    let (mut pk_bytes, mut sk_bytes) = generate_keys(job.algorithm, job.variant.clone())
+        .map_err(|e| BatchError::KeyGen(e.to_string()))?;
+
+    let created = now_unix();
+    let fp = fingerprint(&pk_bytes);
+    let (pk_path, sk_path) = namer(job, &fp);
+
+    // 사이드카가 이미 있으면 키를 쓰기 전에 중단
+    let sidecar = if options.metadata {
+        sidecar_path(&pk_path, &sk_path)
+    } else {
+        None
+    };
+    if let Some(path) = &sidecar
+        && !options.force
+        && path.exists()
+    {
+        return Err(BatchError::Save(SaveError::Exists(
+            path.display().to_string(),
+        )));
+    }
+
+    let saved = write_key_pair(
+        &pk_bytes,
+        &sk_bytes,
+        &pk_path,
+        &sk_path,
+        options.pk_text,
+        options.sk_text,
+        options.force,
+    );
+
+    // 비밀키 메모리 안전 삭제
+    sk_bytes.zeroize();
+    pk_bytes.zeroize();
+
+    saved.map_err(BatchError::Save)?;
+
+    if let Some(path) = &sidecar {
+        let meta = KeyMetadata::new(
+            job.algorithm,
+            &job.variant,
+            created,
+            &fp,
+            &pk_path,
+            &sk_path,
+            options.pk_text,
+            options.sk_text,
+        );
+        write_sidecar(path, &meta, options.force)
+            .map_err(|e| BatchError::Metadata(path.display().to_string(), e))?;
+    }
+
+    Ok(BatchRecord {
+        fingerprint: fp,
+        pk_path,
+        sk_path,
+        metadata_path: sidecar.map(|p| p.display().to_string()),
+    })
+}
+
+/// 인덱스 파일의 한 항목
+#[derive(Debug, Serialize)]
+pub struct IndexEntry<'a> {
+    pub id: &'a str,
+    pub algorithm: &'a str,
+    pub variant: &'a str,
+    pub fingerprint: &'a str,
+    pub public_key: &'a str,
+    pub secret_key: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub metadata: Option<&'a str>,
+}
+
+/// 성공한 결과로 ID → 지문 인덱스(JSON)를 원자적으로 기록합니다.
+pub fn write_index(path: &str, outcomes: &[BatchOutcome], force: bool) -> io::Result<()> {
+    let entries: Vec<IndexEntry> = outcomes
+        .iter()
+        .filter_map(|o| {
+            o.result.as_ref().ok().map(|r| IndexEntry {
+                id: &o.job.id,
+                algorithm: o.job.algorithm.as_str(),
+                variant: o.job.variant.as_str(),
+                fingerprint: &r.fingerprint,
+                public_key: &r.pk_path,
+                secret_key: &r.sk_path,
+                metadata: r.metadata_path.as_deref(),
+            })
+        })
+        .collect();
+
+    let mut json = serde_json::to_string_pretty(&entries).map_err(io::Error::other)?;
+    json.push('\n');
+
+    write_atomic(Path::new(path), json.as_bytes(), 0o644, force)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internals::test_util::ScratchDir;
+
+    fn jobs(count: usize) -> Vec<BatchJob> {
+        (1..=count)
+            .map(|n| BatchJob {
+                id: format!("dev-{}", n),
+                n,
+                algorithm: Algorithm::MLKEM,
+                variant: Variant::MLKEM512,
+            })
+            .collect()
+    }
+
+    fn options(workers: usize) -> BatchOptions {
+        BatchOptions {
+            workers,
+            pk_text: false,
+            sk_text: false,
+            force: false,
+            metadata: false,
+        }
+    }
+
+    fn namer_in(dir: &Path) -> Arc<PathNamer> {
+        let dir = dir.to_path_buf();
+        Arc::new(move |job: &BatchJob, _fp: &str| {
+            let path = |ext: &str| {
+                dir.join(format!("{}.{}", job.id, ext))
+                    .display()
+                    .to_string()
+            };
+            (path("pub"), path("sk"))
+        })
+    }
+
+    #[test]
+    fn outcomes_are_sorted_by_sequence_number() {
+        let dir = ScratchDir::new("batch", "order");
+        let mut reported = Vec::new();
+        let outcomes = run_batch(jobs(6), &options(3), namer_in(&dir), |done, total, _| {
+            reported.push((done, total))
+        })
+        .unwrap();
+
+        assert_eq!(reported, (1..=6).map(|done| (done, 6)).collect::<Vec<_>>());
+        let order: Vec<usize> = outcomes.iter().map(|o| o.job.n).collect();
+        assert_eq!(order, [1, 2, 3, 4, 5, 6]);
+        for outcome in &outcomes {
+            let record = outcome.result.as_ref().unwrap();
+            assert_eq!(record.fingerprint.len(), 64);
+            assert!(Path::new(&record.sk_path).is_file());
+        }
+        assert_eq!(dir.names().len(), 12);
+    }
+
+    #[test]
+    fn a_panicking_job_is_reported_as_lost() {
+        let dir = ScratchDir::new("batch", "lost");
+        let inner = namer_in(&dir);
+        let namer: Arc<PathNamer> = Arc::new(move |job: &BatchJob, fp: &str| {
+            assert_ne!(job.n, 2, "namer failure");
+            inner(job, fp)
+        });
+
+        let outcomes = run_batch(jobs(4), &options(2), namer, |_, _, _| {}).unwrap();
+        assert_eq!(outcomes.len(), 4);
+        for outcome in &outcomes {
+            if outcome.job.n == 2 {
+                assert!(matches!(outcome.result, Err(BatchError::Lost)));
+            } else {
+                assert!(outcome.result.is_ok(), "{:?}", outcome.result);
+            }
+        }
+        assert!(!dir.join("dev-2.sk").exists());
+    }
+
+    #[test]
+    fn existing_files_fail_only_their_job_and_are_left_out_of_the_index() {
+        let dir = ScratchDir::new("batch", "index");
+        std::fs::write(dir.join("dev-1.sk"), b"keep").unwrap();
+
+        let outcomes = run_batch(jobs(2), &options(1), namer_in(&dir), |_, _, _| {}).unwrap();
+        assert!(matches!(
+            outcomes[0].result,
+            Err(BatchError::Save(SaveError::Exists(_)))
+        ));
+        assert_eq!(std::fs::read(dir.join("dev-1.sk")).unwrap(), b"keep");
+
+        let index = dir.join("index.json");
+        write_index(index.to_str().unwrap(), &outcomes, false).unwrap();
+        let entries: serde_json::Value =
+            serde_json::from_slice(&std::fs::read(&index).unwrap()).unwrap();
+        let entries = entries.as_array().unwrap();
+        assert_eq!(entries.len(), 1);
+        assert_eq!(entries[0]["id"], "dev-2");
+        assert_eq!(entries[0]["variant"], Variant::MLKEM512.as_str());
+        assert_eq!(
+            entries[0]["fingerprint"],
+            outcomes[1].result.as_ref().unwrap().fingerprint.as_str()
+        );
+        assert!(write_index(index.to_str().unwrap(), &outcomes, false).is_err());
+    }
+}
@@ -0,0 +1,160 @@
+use std::error::Error;
+use std::time::{Duration, Instant};
+
+use serde::Serialize;
+
+use super::kem::{self, KEM_VARIANTS};
+use super::keygen::{Variant, generate_keys};
+use super::sign::{self, SIGNATURE_VARIANTS};
+
+/// 서명·검증 측정에 쓰는 메시지
+const BENCH_MESSAGE: &[u8] = b"keypairer/bench";
+
+/// 측정 반복 설정
+#[derive(Debug, Clone, Copy)]
+pub struct BenchOptions {
+    /// 연산마다 측정할 최대 횟수
+    pub iterations: usize,
+    /// 연산마다 쓸 시간 예산. 넘으면 (한 번 이상 측정한 뒤) 남은 반복을 건너뜁니다.
+    pub budget: Duration,
+}
+
+impl Default for BenchOptions {
+    fn default() -> Self {
+        BenchOptions {
+            iterations: 20,
+            budget: Duration::from_secs(2),
+        }
+    }
+}
+
+/// 한 연산의 측정 결과 (마이크로초)
+#[derive(Debug, Clone, Serialize)]
+pub struct Timing {
+    pub samples: usize,
+    pub median_us: f64,
+    pub p95_us: f64,
+}
+
+impl Timing {
+    /// 측정값에서 중앙값과 95번째 백분위수(최근접 순위)를 구합니다.
+    fn from_samples(mut samples: Vec<Duration>) -> Timing {
+        samples.sort();
+        let n = samples.len().max(1);
+        // 나노초 단위까지만 남깁니다.
+        let micros = |d: Option<&Duration>| d.map_or(0.0, |d| d.as_nanos() as f64 / 1e3);
+        let median = if n % 2 == 1 {
+            micros(samples.get(n / 2))
+        } else {
+            ((micros(samples.get(n / 2 - 1)) + micros(samples.get(n / 2))) * 500.0).round() / 1e3
+        };
+        Timing {
+            samples: samples.len(),
+            median_us: median,
+            p95_us: micros(samples.get((n * 95).div_ceil(100) - 1)),
+        }
+    }
+}
+
+/// 배리언트 하나의 측정 결과
+#[derive(Debug, Clone, Serialize)]
+pub struct BenchResult {
+    pub algorithm: &'static str,
+    pub variant: &'static str,
+    pub standard_name: String,
+    pub nist_category: u8,
+    pub public_key_bytes: usize,
+    pub secret_key_bytes: usize,
+    /// KEM 암호문 길이
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub ciphertext_bytes: Option<usize>,
+    /// 서명 최대 길이
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub signature_bytes: Option<usize>,
+    pub keygen: Timing,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub encapsulate: Option<Timing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub decapsulate: Option<Timing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub sign: Option<Timing>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub verify: Option<Timing>,
+}
+
+/// [`generate_keys`]가 지원하는 모든 배리언트 (KEM, 서명 순)
+pub fn all_variants() -> Vec<Variant> {
+    KEM_VARIANTS
+        .iter()
+        .chain(SIGNATURE_VARIANTS.iter())
+        .cloned()
+        .collect()
+}
+
+/// `op`를 최대 `iterations`번, 시간 예산 안에서 반복 측정합니다.
+fn measure<T, E>(
+    options: &BenchOptions,
+    mut op: impl FnMut() -> Result<T, E>,
+) -> Result<(Timing, T), E> {
+    let iterations = options.iterations.max(1);
+    let mut samples = Vec::with_capacity(iterations);
+    let started = Instant::now();
+    let mut last = None;
+    while samples.len() < iterations && (last.is_none() || started.elapsed() < options.budget) {
+        let t0 = Instant::now();
+        let value = op()?;
+        samples.push(t0.elapsed());
+        last = Some(value);
+    }
+    let last = last.expect("at least one iteration");
+    Ok((Timing::from_samples(samples), last))
+}
+
+/// 배리언트의 키 생성과 (가능하면) 캡슐화·역캡슐화 또는 서명·검증을 측정합니다.
+///
+/// Classic McEliece와 SPHINCS+는 스택 사용량이 크므로 [`crate::run_with_variant_stack`]
+/// 안에서 호출하세요.
+pub fn bench_variant(
+    variant: &Variant,
+    options: &BenchOptions,
+) -> Result<BenchResult, Box<dyn Error + Send + Sync>> {
+    let (keygen, (pk, sk)) = measure(options, || {
+        generate_keys(variant.algorithm(), variant.clone())
+    })?;
+
+    let mut result = BenchResult {
+        algorithm: variant.algorithm().as_str(),
+        variant: variant.as_str(),
+        standard_name: variant.standard_name(),
+        nist_category: variant.nist_category(),
+        public_key_bytes: pk.len(),
+        secret_key_bytes: sk.len(),
+        ciphertext_bytes: None,
+        signature_bytes: None,
+        keygen,
+        encapsulate: None,
+        decapsulate: None,
+        sign: None,
+        verify: None,
+    };
+
+    if kem::is_kem(variant) {
+        let (encapsulate, (_, ct)) = measure(options, || kem::encapsulate(variant, &pk))?;
+        let (decapsulate, _) = measure(options, || kem::decapsulate(variant, &sk, &ct))?;
+        result.ciphertext_bytes = Some(ct.len());
+        result.encapsulate = Some(encapsulate);
+        result.decapsulate = Some(decapsulate);
+    } else if sign::is_signature(variant) {
+        let (signing, signature) = measure(options, || sign::sign(variant, &sk, BENCH_MESSAGE))?;
+        let (verify, valid) = measure(options, || {
+            sign::verify(variant, &pk, BENCH_MESSAGE, &signature)
+        })?;
+        if !valid {
+            return Err(format!("{}: signature did not verify", variant.standard_name()).into());
+        }
+        result.signature_bytes = sign::signature_len(variant);
+        result.sign = Some(signing);
+        result.verify = Some(verify);
+    }
+    Ok(result)
+}
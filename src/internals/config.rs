@@ -0,0 +1,570 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fmt;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::Deserialize;
+
+use super::policy::{Policy, PolicySpecError};
+
+/// 설정 파일 이름 (`$XDG_CONFIG_HOME/keypairer/` 또는 `~/.config/keypairer/` 아래)
+pub const CONFIG_FILE: &str = "config.toml";
+
+/// 설정이 없을 때의 출력 언어
+pub const DEFAULT_LANG: &str = "ko";
+
+/// 키 파일 저장 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Encoding {
+    Binary,
+    #[serde(alias = "text")]
+    Pem,
+}
+
+impl Encoding {
+    /// `binary`, `pem`(`text`)을 해석합니다.
+    pub fn parse(value: &str) -> Option<Encoding> {
+        match value.trim().to_lowercase().as_str() {
+            "binary" | "bin" => Some(Encoding::Binary),
+            "pem" | "text" => Some(Encoding::Pem),
+            _ => None,
+        }
+    }
+
+    pub fn as_str(self) -> &'static str {
+        match self {
+            Encoding::Binary => "binary",
+            Encoding::Pem => "pem",
+        }
+    }
+}
+
+/// `[defaults]` 또는 `[profiles.<이름>]` 테이블 (모두 선택 사항)
+#[derive(Debug, Clone, Default, Deserialize, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct Defaults {
+    pub algorithm: Option<String>,
+    pub variant: Option<String>,
+    /// 두 키에 공통으로 적용하는 저장 형식
+    pub encoding: Option<Encoding>,
+    pub pk_encoding: Option<Encoding>,
+    pub sk_encoding: Option<Encoding>,
+    pub outdir: Option<String>,
+    pub lang: Option<String>,
+}
+
+/// 설정 파일 전체
+#[derive(Debug, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+struct ConfigFile {
+    /// `-profile`, `KEYPAIRER_PROFILE`이 없을 때 쓰는 프로필
+    profile: Option<String>,
+    #[serde(default)]
+    defaults: Defaults,
+    #[serde(default)]
+    profiles: BTreeMap<String, Defaults>,
+    /// 모든 프로필에 적용되는 키 생성 정책
+    policy: Option<Policy>,
+}
+
+/// 설정 값의 출처
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Source {
+    /// 설정 파일의 `[defaults]`
+    File,
+    /// 설정 파일의 `[profiles.<이름>]`
+    Profile(String),
+    /// 설정 파일의 `[policy]`
+    Policy,
+    /// 환경 변수 (변수 이름)
+    Env(&'static str),
+}
+
+/// 출처가 붙은 설정 값
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Setting<T> {
+    pub value: T,
+    pub source: Source,
+}
+
+/// 설정 파일, 프로필, 환경 변수를 차례로 적용한 기본값
+///
+/// 명령행 플래그는 이 값보다 우선하며 호출하는 쪽에서 적용합니다.
+#[derive(Debug, Clone, Default)]
+pub struct Settings {
+    /// 읽은 설정 파일 (없으면 `None`)
+    pub path: Option<PathBuf>,
+    /// 적용한 프로필
+    pub profile: Option<String>,
+    pub algorithm: Option<Setting<String>>,
+    pub variant: Option<Setting<String>>,
+    pub pk_encoding: Option<Setting<Encoding>>,
+    pub sk_encoding: Option<Setting<Encoding>>,
+    pub outdir: Option<Setting<String>>,
+    pub lang: Option<Setting<String>>,
+    /// 키 생성 정책 (`[policy]`와 `KEYPAIRER_POLICY`를 합친 결과)
+    pub policy: Option<Setting<Policy>>,
+}
+
+/// 설정 오류
+#[derive(Debug)]
+pub enum ConfigError {
+    Io(PathBuf, io::Error),
+    /// TOML 구문 또는 알 수 없는 키 (경로, 파서 메시지)
+    Parse(PathBuf, String),
+    /// 설정 파일에 없는 프로필
+    UnknownProfile(String),
+    /// 해석할 수 없는 환경 변수 값 (변수 이름, 값)
+    InvalidEnv(&'static str, String),
+    /// 해석할 수 없는 정책 항목
+    Policy(PolicySpecError),
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message("en"))
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl ConfigError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            ConfigError::Io(path, e) => text(
+                "error.config.io",
+                "설정 파일을 읽을 수 없습니다({path}): {err}",
+            )
+            .replace("{path}", &path.display().to_string())
+            .replace("{err}", &e.to_string()),
+            ConfigError::Parse(path, err) => text(
+                "error.config.parse",
+                "설정 파일을 해석할 수 없습니다({path}): {err}",
+            )
+            .replace("{path}", &path.display().to_string())
+            .replace("{err}", err.trim_end()),
+            ConfigError::UnknownProfile(name) => text(
+                "error.config.profile",
+                "설정 파일에 없는 프로필입니다: {profile}",
+            )
+            .replace("{profile}", name),
+            ConfigError::InvalidEnv(var, value) => text(
+                "error.config.env",
+                "환경 변수 {var}의 값을 해석할 수 없습니다: {value}",
+            )
+            .replace("{var}", var)
+            .replace("{value}", value),
+            ConfigError::Policy(e) => e.message(lang),
+        }
+    }
+}
+
+/// 기본 설정 파일 경로
+///
+/// `$XDG_CONFIG_HOME/keypairer/config.toml` → `~/.config/keypairer/config.toml` 순으로 결정합니다.
+pub fn default_path() -> Option<PathBuf> {
+    if let Some(config) = env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+        return Some(PathBuf::from(config).join("keypairer").join(CONFIG_FILE));
+    }
+    env::var_os("HOME").filter(|v| !v.is_empty()).map(|home| {
+        PathBuf::from(home)
+            .join(".config/keypairer")
+            .join(CONFIG_FILE)
+    })
+}
+
+/// 환경 변수를 읽는 함수 (테스트에서는 고정된 값을 돌려줌)
+type EnvLookup<'a> = &'a dyn Fn(&str) -> Option<String>;
+
+/// 비어 있지 않은 환경 변수 값
+fn env_value(env: EnvLookup, var: &str) -> Option<String> {
+    env(var).filter(|v| !v.trim().is_empty())
+}
+
+impl Settings {
+    /// 설정을 읽어 적용합니다.
+    ///
+    /// 설정 파일은 `path` → `KEYPAIRER_CONFIG` → [`default_path`] 순으로 찾으며, 직접 지정한
+    /// 파일은 반드시 있어야 하고 기본 경로의 파일은 없어도 됩니다. 프로필은 `profile` →
+    /// `KEYPAIRER_PROFILE` → 설정 파일의 `profile` 순으로 정합니다. 적용 순서는
+    /// `[defaults]` → 프로필 → `KEYPAIRER_*` 환경 변수입니다.
+    pub fn load(path: Option<&Path>, profile: Option<&str>) -> Result<Settings, ConfigError> {
+        Self::load_with(path, profile, &|var| env::var(var).ok())
+    }
+
+    /// [`Settings::load`]와 같지만 환경 변수를 `env`로 읽습니다.
+    fn load_with(
+        path: Option<&Path>,
+        profile: Option<&str>,
+        env: EnvLookup,
+    ) -> Result<Settings, ConfigError> {
+        let explicit = path
+            .map(Path::to_path_buf)
+            .or_else(|| env_value(env, "KEYPAIRER_CONFIG").map(PathBuf::from));
+        let (path, required) = match explicit {
+            Some(path) => (Some(path), true),
+            None => (default_path(), false),
+        };
+
+        let mut settings = Settings::default();
+        let mut file = ConfigFile::default();
+        if let Some(path) = path {
+            match fs::read_to_string(&path) {
+                Ok(content) => {
+                    file = toml::from_str(&content)
+                        .map_err(|e| ConfigError::Parse(path.clone(), e.to_string()))?;
+                    settings.path = Some(path);
+                }
+                Err(e) if !required && e.kind() == io::ErrorKind::NotFound => {}
+                Err(e) => return Err(ConfigError::Io(path, e)),
+            }
+        }
+
+        if let Some(policy) = file.policy.take() {
+            if let Some(category) = policy.min_category.filter(|c| !(1..=5).contains(c)) {
+                return Err(ConfigError::Policy(PolicySpecError(format!(
+                    "min_category = {}",
+                    category
+                ))));
+            }
+            settings.policy = Some(Setting {
+                value: policy,
+                source: Source::Policy,
+            });
+        }
+        settings.apply(&file.defaults, Source::File);
+        let profile = profile
+            .map(str::to_string)
+            .or_else(|| env_value(env, "KEYPAIRER_PROFILE"))
+            .or(file.profile);
+        if let Some(name) = profile {
+            let table = file
+                .profiles
+                .get(&name)
+                .ok_or_else(|| ConfigError::UnknownProfile(name.clone()))?;
+            settings.apply(table, Source::Profile(name.clone()));
+            settings.profile = Some(name);
+        }
+        settings.apply_env(env)?;
+        Ok(settings)
+    }
+
+    /// 테이블에 있는 값으로 덮어씁니다.
+    fn apply(&mut self, table: &Defaults, source: Source) {
+        let set = |value: &Option<String>, lower: bool| {
+            value.as_ref().map(|v| Setting {
+                value: if lower { v.to_lowercase() } else { v.clone() },
+                source: source.clone(),
+            })
+        };
+        let encoding = |value: Option<Encoding>| {
+            value.map(|v| Setting {
+                value: v,
+                source: source.clone(),
+            })
+        };
+        if let Some(v) = set(&table.algorithm, true) {
+            self.algorithm = Some(v);
+        }
+        if let Some(v) = set(&table.variant, true) {
+            self.variant = Some(v);
+        }
+        if let Some(v) = encoding(table.encoding) {
+            self.pk_encoding = Some(v.clone());
+            self.sk_encoding = Some(v);
+        }
+        if let Some(v) = encoding(table.pk_encoding) {
+            self.pk_encoding = Some(v);
+        }
+        if let Some(v) = encoding(table.sk_encoding) {
+            self.sk_encoding = Some(v);
+        }
+        if let Some(v) = set(&table.outdir, false) {
+            self.outdir = Some(v);
+        }
+        if let Some(v) = set(&table.lang, true) {
+            self.lang = Some(v);
+        }
+    }
+
+    /// `KEYPAIRER_*` 환경 변수로 덮어씁니다.
+    fn apply_env(&mut self, env: EnvLookup) -> Result<(), ConfigError> {
+        let string = |var: &'static str, lower: bool| {
+            env_value(env, var).map(|v| Setting {
+                value: if lower { v.trim().to_lowercase() } else { v },
+                source: Source::Env(var),
+            })
+        };
+        let encoding = |var: &'static str| -> Result<Option<Setting<Encoding>>, ConfigError> {
+            env_value(env, var)
+                .map(|v| match Encoding::parse(&v) {
+                    Some(value) => Ok(Setting {
+                        value,
+                        source: Source::Env(var),
+                    }),
+                    None => Err(ConfigError::InvalidEnv(var, v)),
+                })
+                .transpose()
+        };
+
+        if let Some(v) = string("KEYPAIRER_ALG", true) {
+            self.algorithm = Some(v);
+        }
+        if let Some(v) = string("KEYPAIRER_VARIANT", true) {
+            self.variant = Some(v);
+        }
+        if let Some(v) = encoding("KEYPAIRER_ENCODING")? {
+            self.pk_encoding = Some(v.clone());
+            self.sk_encoding = Some(v);
+        }
+        if let Some(v) = encoding("KEYPAIRER_PK_ENCODING")? {
+            self.pk_encoding = Some(v);
+        }
+        if let Some(v) = encoding("KEYPAIRER_SK_ENCODING")? {
+            self.sk_encoding = Some(v);
+        }
+        if let Some(v) = string("KEYPAIRER_OUTDIR", false) {
+            self.outdir = Some(v);
+        }
+        if let Some(v) = string("KEYPAIRER_LANG", true) {
+            self.lang = Some(v);
+        }
+        if let Some(spec) = env_value(env, "KEYPAIRER_POLICY") {
+            let mut policy = Policy::parse(&spec).map_err(ConfigError::Policy)?;
+            if let Some(configured) = &self.policy {
+                policy.merge(&configured.value);
+            }
+            self.policy = Some(Setting {
+                value: policy,
+                source: Source::Env("KEYPAIRER_POLICY"),
+            });
+        }
+        Ok(())
+    }
+
+    /// 출력 언어 (설정이 없으면 [`DEFAULT_LANG`])
+    pub fn lang(&self) -> String {
+        self.lang
+            .as_ref()
+            .map(|s| s.value.clone())
+            .unwrap_or_else(|| DEFAULT_LANG.to_string())
+    }
+
+    /// 적용할 키 생성 정책 (설정이 없으면 제한 없음)
+    pub fn policy(&self) -> Policy {
+        self.policy
+            .as_ref()
+            .map(|s| s.value.clone())
+            .unwrap_or_default()
+    }
+
+    /// 공개키를 PEM으로 저장하는지 여부
+    pub fn pk_text(&self) -> bool {
+        self.pk_encoding
+            .as_ref()
+            .is_some_and(|s| s.value == Encoding::Pem)
+    }
+
+    /// 비밀키를 PEM으로 저장하는지 여부
+    pub fn sk_text(&self) -> bool {
+        self.sk_encoding
+            .as_ref()
+            .is_some_and(|s| s.value == Encoding::Pem)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::collections::HashMap;
+
+    use super::*;
+    use crate::internals::test_util::ScratchDir;
+
+    const CONFIG: &str = r#"
+profile = "team"
+
+[defaults]
+algorithm = "MLKEM"
+variant = "768"
+encoding = "pem"
+sk_encoding = "binary"
+outdir = "Keys"
+
+[profiles.team]
+variant = "1024"
+pk_encoding = "binary"
+
+[profiles.ci]
+algorithm = "mldsa"
+encoding = "pem"
+lang = "EN"
+"#;
+
+    /// 설정 파일을 쓰고 주어진 환경 변수만 있는 것처럼 읽습니다.
+    fn load(
+        dir: &ScratchDir,
+        content: &str,
+        profile: Option<&str>,
+        vars: &[(&str, &str)],
+    ) -> Result<Settings, ConfigError> {
+        let path = dir.join(CONFIG_FILE);
+        fs::write(&path, content).unwrap();
+        let vars: HashMap<String, String> = vars
+            .iter()
+            .map(|(k, v)| (k.to_string(), v.to_string()))
+            .collect();
+        Settings::load_with(Some(&path), profile, &|var| vars.get(var).cloned())
+    }
+
+    fn value<T: Clone>(setting: &Option<Setting<T>>) -> (T, Source) {
+        let setting = setting.as_ref().unwrap();
+        (setting.value.clone(), setting.source.clone())
+    }
+
+    #[test]
+    fn profile_overrides_defaults_and_env_overrides_profile() {
+        let dir = ScratchDir::new("config", "precedence");
+        let settings = load(&dir, CONFIG, None, &[]).unwrap();
+        let team = Source::Profile("team".to_string());
+        assert_eq!(settings.profile.as_deref(), Some("team"));
+        assert_eq!(value(&settings.algorithm), ("mlkem".into(), Source::File));
+        assert_eq!(value(&settings.variant), ("1024".into(), team.clone()));
+        assert_eq!(value(&settings.pk_encoding), (Encoding::Binary, team));
+        assert_eq!(
+            value(&settings.sk_encoding),
+            (Encoding::Binary, Source::File)
+        );
+        // 경로는 대소문자를 유지
+        assert_eq!(value(&settings.outdir), ("Keys".into(), Source::File));
+        assert_eq!(settings.lang(), DEFAULT_LANG);
+
+        let settings = load(
+            &dir,
+            CONFIG,
+            None,
+            &[
+                ("KEYPAIRER_VARIANT", "512"),
+                ("KEYPAIRER_ENCODING", "text"),
+                ("KEYPAIRER_LANG", " En "),
+                ("KEYPAIRER_OUTDIR", ""),
+            ],
+        )
+        .unwrap();
+        assert_eq!(
+            value(&settings.variant),
+            ("512".into(), Source::Env("KEYPAIRER_VARIANT"))
+        );
+        assert!(settings.pk_text() && settings.sk_text());
+        assert_eq!(settings.lang(), "en");
+        // 빈 환경 변수는 없는 것으로 봄
+        assert_eq!(value(&settings.outdir).1, Source::File);
+    }
+
+    #[test]
+    fn specific_encodings_override_the_common_one_at_the_same_level() {
+        let dir = ScratchDir::new("config", "encoding");
+        let settings = load(
+            &dir,
+            CONFIG,
+            Some("ci"),
+            &[("KEYPAIRER_SK_ENCODING", "binary")],
+        )
+        .unwrap();
+        let ci = Source::Profile("ci".to_string());
+        assert_eq!(value(&settings.algorithm), ("mldsa".into(), ci.clone()));
+        assert_eq!(value(&settings.pk_encoding), (Encoding::Pem, ci));
+        assert_eq!(
+            value(&settings.sk_encoding),
+            (Encoding::Binary, Source::Env("KEYPAIRER_SK_ENCODING"))
+        );
+        assert_eq!(settings.lang(), "en");
+
+        let invalid = load(&dir, CONFIG, None, &[("KEYPAIRER_PK_ENCODING", "der")]);
+        assert!(matches!(
+            invalid,
+            Err(ConfigError::InvalidEnv("KEYPAIRER_PK_ENCODING", v)) if v == "der"
+        ));
+    }
+
+    #[test]
+    fn profile_is_chosen_by_argument_then_env_then_file() {
+        let dir = ScratchDir::new("config", "profile");
+        let env = [("KEYPAIRER_PROFILE", "ci")];
+        let chosen = |profile, vars: &[(&str, &str)]| {
+            load(&dir, CONFIG, profile, vars).unwrap().profile.unwrap()
+        };
+        assert_eq!(chosen(None, &[]), "team");
+        assert_eq!(chosen(None, &env), "ci");
+        assert_eq!(chosen(Some("team"), &env), "team");
+
+        assert!(matches!(
+            load(&dir, CONFIG, Some("missing"), &[]),
+            Err(ConfigError::UnknownProfile(name)) if name == "missing"
+        ));
+        let no_profile = load(&dir, "[defaults]\nvariant = \"512\"\n", None, &[]).unwrap();
+        assert_eq!(no_profile.profile, None);
+        assert_eq!(value(&no_profile.variant).1, Source::File);
+    }
+
+    #[test]
+    fn config_path_comes_from_argument_or_env_and_must_exist() {
+        let dir = ScratchDir::new("config", "path");
+        let path = dir.join("custom.toml");
+        fs::write(&path, "[defaults]\nalgorithm = \"hqc\"\n").unwrap();
+        let env_path = path.to_string_lossy().into_owned();
+        let settings = Settings::load_with(None, None, &|var| {
+            (var == "KEYPAIRER_CONFIG").then(|| env_path.clone())
+        })
+        .unwrap();
+        assert_eq!(settings.path.as_deref(), Some(path.as_path()));
+        assert_eq!(value(&settings.algorithm).0, "hqc");
+
+        let missing = dir.join("missing.toml");
+        assert!(matches!(
+            Settings::load_with(Some(&missing), None, &|_| None),
+            Err(ConfigError::Io(p, _)) if p == missing
+        ));
+        assert!(matches!(
+            load(&dir, "[defaults]\ncolour = \"red\"\n", None, &[]),
+            Err(ConfigError::Parse(..))
+        ));
+    }
+
+    #[test]
+    fn env_policy_is_merged_with_the_file_policy() {
+        let dir = ScratchDir::new("config", "policy");
+        let file = "[policy]\nmin_category = 3\n";
+        let settings = load(&dir, file, None, &[]).unwrap();
+        assert_eq!(value(&settings.policy).1, Source::Policy);
+
+        // 환경 변수로 완화할 수 없고 더 엄격한 항목만 더해짐
+        let settings = load(
+            &dir,
+            file,
+            None,
+            &[("KEYPAIRER_POLICY", "min-category=1,no-sktext")],
+        )
+        .unwrap();
+        let (policy, source) = value(&settings.policy);
+        assert_eq!(source, Source::Env("KEYPAIRER_POLICY"));
+        assert_eq!(policy.min_category, Some(3));
+        assert!(policy.forbid_sk_text && !policy.standard_only);
+
+        assert!(matches!(
+            load(&dir, "[policy]\nmin_category = 6\n", None, &[]),
+            Err(ConfigError::Policy(_))
+        ));
+        assert!(matches!(
+            load(&dir, file, None, &[("KEYPAIRER_POLICY", "lenient")]),
+            Err(ConfigError::Policy(_))
+        ));
+    }
+}
@@ -0,0 +1,109 @@
+use std::time::{SystemTime, UNIX_EPOCH};
+
+/// 현재 시각을 유닉스 초로 반환합니다.
+pub fn now_unix() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_secs())
+        .unwrap_or(0)
+}
+
+/// 유닉스 초를 UTC 기준 (연, 월, 일, 시, 분, 초)로 변환합니다.
+///
+/// Howard Hinnant의 `civil_from_days` 알고리즘을 사용합니다.
+pub fn civil_from_unix(secs: u64) -> (i64, u32, u32, u32, u32, u32) {
+    let days = (secs / 86_400) as i64;
+    let rem = secs % 86_400;
+
+    let z = days + 719_468;
+    let era = z.div_euclid(146_097);
+    let doe = z.rem_euclid(146_097);
+    let yoe = (doe - doe / 1_460 + doe / 36_524 - doe / 146_096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = (doy - (153 * mp + 2) / 5 + 1) as u32;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 } as u32;
+    let year = yoe + era * 400 + i64::from(month <= 2);
+
+    (
+        year,
+        month,
+        day,
+        (rem / 3_600) as u32,
+        ((rem % 3_600) / 60) as u32,
+        (rem % 60) as u32,
+    )
+}
+
+/// 유닉스 초를 `YYYYMMDD` (UTC) 형식으로 변환합니다.
+pub fn format_date(secs: u64) -> String {
+    let (y, m, d, _, _, _) = civil_from_unix(secs);
+    format!("{:04}{:02}{:02}", y, m, d)
+}
+
+/// 유닉스 초를 RFC 3339 (UTC, 예: `2025-01-31T09:30:00Z`) 형식으로 변환합니다.
+pub fn format_rfc3339(secs: u64) -> String {
+    let (y, mo, d, h, mi, s) = civil_from_unix(secs);
+    format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}Z", y, mo, d, h, mi, s)
+}
+
+/// UTC 날짜를 유닉스 기준 일수로 변환합니다 (`civil_from_unix`의 역).
+pub fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let y = year - i64::from(month <= 2);
+    let era = y.div_euclid(400);
+    let yoe = y.rem_euclid(400);
+    let mp = i64::from((month + 9) % 12);
+    let doy = (153 * mp + 2) / 5 + i64::from(day) - 1;
+    let doe = yoe * 365 + yoe / 4 - yoe / 100 + doy;
+    era * 146_097 + doe - 719_468
+}
+
+/// 시각 문자열을 유닉스 초로 해석합니다.
+///
+/// `2025-01-31T09:30:00Z`, `2025-01-31` (자정 UTC), `now`, 그리고 `now` 기준 상대 시각
+/// `+90d`, `+12h`, `+30m`, `+2w`를 지원합니다.
+pub fn parse_time(input: &str, now: u64) -> Option<u64> {
+    let s = input.trim();
+    if s.eq_ignore_ascii_case("now") {
+        return Some(now);
+    }
+    if let Some(rel) = s.strip_prefix('+') {
+        let unit = rel.chars().last()?;
+        let n: u64 = rel[..rel.len() - unit.len_utf8()].parse().ok()?;
+        let secs = match unit {
+            'm' => 60,
+            'h' => 3_600,
+            'd' => 86_400,
+            'w' => 7 * 86_400,
+            _ => return None,
+        };
+        return now.checked_add(n.checked_mul(secs)?);
+    }
+
+    let (date, time) = match s.split_once(['T', 't', ' ']) {
+        Some((d, t)) => (d, Some(t.strip_suffix(['Z', 'z'])?)),
+        None => (s, None),
+    };
+    let mut parts = date.splitn(3, '-');
+    let year: i64 = parts.next()?.parse().ok()?;
+    let month: u32 = parts.next()?.parse().ok()?;
+    let day: u32 = parts.next()?.parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let (mut h, mut mi, mut sec) = (0u32, 0u32, 0u32);
+    if let Some(time) = time {
+        let mut parts = time.splitn(3, ':');
+        h = parts.next()?.parse().ok()?;
+        mi = parts.next()?.parse().ok()?;
+        sec = parts.next().map_or(Some(0), |p| p.parse().ok())?;
+        if h > 23 || mi > 59 || sec > 60 {
+            return None;
+        }
+    }
+
+    let days = days_from_civil(year, month, day);
+    let secs = days * 86_400 + i64::from(h * 3_600 + mi * 60 + sec);
+    u64::try_from(secs).ok()
+}
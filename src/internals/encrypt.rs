@@ -0,0 +1,683 @@
+use std::io::{self, Read, Write};
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use super::kem::{self, KemError};
+use super::keygen::{Algorithm, Variant, parse_algorithm, parse_variant};
+
+/// 암호화 파일 시작 표식
+pub const MAGIC: &[u8; 5] = b"KPENC";
+
+/// 형식 버전
+pub const VERSION: u8 = 2;
+
+/// 한 파일에 넣을 수 있는 최대 수신자 수
+pub const MAX_RECIPIENTS: usize = 64;
+
+/// 기본 평문 청크 크기 (64 KiB)
+pub const DEFAULT_CHUNK_SIZE: u32 = 64 * 1024;
+
+/// 허용하는 청크 크기 범위
+const MIN_CHUNK_SIZE: u32 = 1024;
+const MAX_CHUNK_SIZE: u32 = 16 * 1024 * 1024;
+
+/// 헤더의 KEM 암호문 최대 길이 (가장 큰 HQC-256 암호문보다 충분히 큼)
+const MAX_KEM_CIPHERTEXT: usize = 64 * 1024;
+
+pub const SALT_LEN: usize = 32;
+const KEY_LEN: usize = 32;
+const TAG_LEN: usize = 16;
+/// 수신자별로 감싼 파일 키 길이 (키 + 태그)
+const WRAPPED_KEY_LEN: usize = KEY_LEN + TAG_LEN;
+const PAYLOAD_INFO: &[u8] = b"keypairer/encrypt/v2/payload";
+const STANZA_INFO: &[u8] = b"keypairer/encrypt/v2/stanza";
+
+/// 페이로드 암호화 키 (드롭 시 0으로 덮어씀)
+pub type PayloadKey = Zeroizing<[u8; KEY_LEN]>;
+
+/// 암호화/복호화 실패 사유
+#[derive(Debug)]
+pub enum CryptError {
+    Io(io::Error),
+    /// keypairer 암호화 파일이 아님
+    BadMagic,
+    UnsupportedVersion(u8),
+    /// 헤더 구조가 올바르지 않음
+    Malformed(&'static str),
+    /// 헤더의 알고리즘/배리언트를 알 수 없음
+    UnknownVariant(String, String),
+    Kem(KemError),
+    /// 파일 키나 청크를 봉인하지 못함
+    Encrypt,
+    /// 인증 실패: 잘못된 비밀키이거나 파일이 변조됨
+    Decrypt,
+    /// 비밀키로 열 수 있는 수신자 항목이 없음
+    NoRecipient,
+    /// 마지막 청크 이전에 파일이 끝남
+    Truncated,
+}
+
+impl From<io::Error> for CryptError {
+    fn from(e: io::Error) -> Self {
+        CryptError::Io(e)
+    }
+}
+
+impl From<KemError> for CryptError {
+    fn from(e: KemError) -> Self {
+        CryptError::Kem(e)
+    }
+}
+
+impl CryptError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            CryptError::Io(e) => text("error.crypt.io", "입출력 도중 오류가 발생했습니다: {err}")
+                .replace("{err}", &e.to_string()),
+            CryptError::BadMagic => {
+                text("error.crypt.magic", "keypairer로 암호화된 파일이 아닙니다.")
+            }
+            CryptError::UnsupportedVersion(v) => text(
+                "error.crypt.version",
+                "지원하지 않는 암호화 형식 버전입니다: {version}",
+            )
+            .replace("{version}", &v.to_string()),
+            CryptError::Malformed(what) => text(
+                "error.crypt.malformed",
+                "암호화 헤더가 올바르지 않습니다: {what}",
+            )
+            .replace("{what}", what),
+            CryptError::UnknownVariant(alg, var) => text(
+                "error.crypt.variant",
+                "헤더의 알고리즘/배리언트를 알 수 없습니다: {alg} {variant}",
+            )
+            .replace("{alg}", alg)
+            .replace("{variant}", var),
+            CryptError::Kem(e) => text("error.crypt.kem", "KEM 연산에 실패했습니다: {err}")
+                .replace("{err}", &e.to_string()),
+            CryptError::Encrypt => text("error.crypt.encrypt", "암호화에 실패했습니다."),
+            CryptError::Decrypt => text(
+                "error.crypt.auth",
+                "복호화에 실패했습니다. 비밀키가 맞지 않거나 파일이 변조되었습니다.",
+            ),
+            CryptError::NoRecipient => text(
+                "error.crypt.no_recipient",
+                "이 비밀키로 열 수 있는 수신자가 파일에 없습니다.",
+            ),
+            CryptError::Truncated => {
+                text("error.crypt.truncated", "암호화 파일이 중간에 잘렸습니다.")
+            }
+        }
+    }
+}
+
+/// 수신자 한 명의 항목
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stanza {
+    pub algorithm: Algorithm,
+    pub variant: Variant,
+    /// 수신자 공개키로 캡슐화한 KEM 암호문
+    pub ciphertext: Vec<u8>,
+    /// 공유 비밀에서 유도한 키로 감싼 파일 키
+    pub wrapped_key: Vec<u8>,
+}
+
+/// 암호화 파일 헤더
+///
+/// ```text
+/// "KPENC" | 2 | 수신자 수(u8) | 수신자 항목... | 솔트(32) | 청크 크기(u32 BE)
+///   항목: 알고리즘 길이(u8) | 알고리즘 | 배리언트 길이(u8) | 배리언트
+///         | KEM 암호문 길이(u32 BE) | KEM 암호문 | 감싼 파일 키(48)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Header {
+    pub version: u8,
+    pub stanzas: Vec<Stanza>,
+    pub salt: [u8; SALT_LEN],
+    /// 평문 청크 크기
+    pub chunk_size: u32,
+}
+
+impl Header {
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(
+            64 + self
+                .stanzas
+                .iter()
+                .map(|s| 64 + s.ciphertext.len())
+                .sum::<usize>(),
+        );
+        out.extend_from_slice(MAGIC);
+        out.push(self.version);
+        out.push(self.stanzas.len() as u8);
+        for stanza in &self.stanzas {
+            for s in [stanza.algorithm.as_str(), stanza.variant.as_str()] {
+                out.push(s.len() as u8);
+                out.extend_from_slice(s.as_bytes());
+            }
+            out.extend_from_slice(&(stanza.ciphertext.len() as u32).to_be_bytes());
+            out.extend_from_slice(&stanza.ciphertext);
+            out.extend_from_slice(&stanza.wrapped_key);
+        }
+        out.extend_from_slice(&self.salt);
+        out.extend_from_slice(&self.chunk_size.to_be_bytes());
+        out
+    }
+
+    /// 입력에서 헤더를 읽습니다. 키 유도에 쓰이는 원본 바이트도 함께 반환합니다.
+    pub fn read_from<R: Read>(input: &mut R) -> Result<(Header, Vec<u8>), CryptError> {
+        let mut raw = Vec::new();
+        let mut take = |n: usize, raw: &mut Vec<u8>| -> Result<Vec<u8>, CryptError> {
+            let mut buf = vec![0u8; n];
+            input.read_exact(&mut buf).map_err(|e| match e.kind() {
+                io::ErrorKind::UnexpectedEof => CryptError::Malformed("truncated header"),
+                _ => CryptError::Io(e),
+            })?;
+            raw.extend_from_slice(&buf);
+            Ok(buf)
+        };
+
+        if take(MAGIC.len(), &mut raw)? != MAGIC {
+            return Err(CryptError::BadMagic);
+        }
+        let version = take(1, &mut raw)?[0];
+        if version != VERSION {
+            return Err(CryptError::UnsupportedVersion(version));
+        }
+        let count = take(1, &mut raw)?[0] as usize;
+        if count == 0 || count > MAX_RECIPIENTS {
+            return Err(CryptError::Malformed("recipient count"));
+        }
+
+        let mut stanzas = Vec::with_capacity(count);
+        for _ in 0..count {
+            let mut names = Vec::new();
+            for _ in 0..2 {
+                let len = take(1, &mut raw)?[0] as usize;
+                let bytes = take(len, &mut raw)?;
+                names.push(String::from_utf8(bytes).map_err(|_| CryptError::Malformed("name"))?);
+            }
+            let (alg_name, var_name) = (names[0].clone(), names[1].clone());
+            let unknown = || CryptError::UnknownVariant(alg_name.clone(), var_name.clone());
+            let algorithm = parse_algorithm(&alg_name)
+                .filter(|a| *a != Algorithm::Unknown)
+                .ok_or_else(unknown)?;
+            let variant = parse_variant(algorithm, &var_name).ok_or_else(unknown)?;
+
+            let ct_len = u32::from_be_bytes(take(4, &mut raw)?.try_into().unwrap_or_default());
+            if ct_len as usize > MAX_KEM_CIPHERTEXT {
+                return Err(CryptError::Malformed("ciphertext length"));
+            }
+            let ciphertext = take(ct_len as usize, &mut raw)?;
+            let wrapped_key = take(WRAPPED_KEY_LEN, &mut raw)?;
+            stanzas.push(Stanza {
+                algorithm,
+                variant,
+                ciphertext,
+                wrapped_key,
+            });
+        }
+
+        let mut salt = [0u8; SALT_LEN];
+        salt.copy_from_slice(&take(SALT_LEN, &mut raw)?);
+        let chunk_size = u32::from_be_bytes(take(4, &mut raw)?.try_into().unwrap_or_default());
+        if !(MIN_CHUNK_SIZE..=MAX_CHUNK_SIZE).contains(&chunk_size) {
+            return Err(CryptError::Malformed("chunk size"));
+        }
+
+        Ok((
+            Header {
+                version,
+                stanzas,
+                salt,
+                chunk_size,
+            },
+            raw,
+        ))
+    }
+}
+
+/// 수신자들의 공개키로 새 헤더를 만들고 페이로드 키를 유도합니다.
+///
+/// 무작위 파일 키를 수신자마다 KEM 공유 비밀에서 유도한 키로 감싸 두므로,
+/// 배리언트가 서로 다른 수신자 누구나 자신의 비밀키로 파일을 열 수 있습니다.
+/// Classic McEliece 캡슐화를 포함하므로 대용량 스택에서 호출하세요.
+pub fn seal(
+    recipients: &[(Variant, Vec<u8>)],
+    chunk_size: u32,
+) -> Result<(Header, PayloadKey), CryptError> {
+    if recipients.is_empty() || recipients.len() > MAX_RECIPIENTS {
+        return Err(CryptError::Malformed("recipient count"));
+    }
+    let mut salt = [0u8; SALT_LEN];
+    let mut file_key = Zeroizing::new([0u8; KEY_LEN]);
+    for buf in [&mut salt[..], &mut file_key[..]] {
+        getrandom::fill(buf).map_err(|e| CryptError::Io(io::Error::other(e.to_string())))?;
+    }
+
+    let mut stanzas = Vec::with_capacity(recipients.len());
+    for (variant, pk_bytes) in recipients {
+        let (ss, ciphertext) = kem::encapsulate(variant, pk_bytes)?;
+        let kek = stanza_key(&ss, &salt, variant, &ciphertext);
+        let wrapped_key = ChaCha20Poly1305::new(Key::from_slice(kek.as_ref()))
+            .encrypt(&Nonce::default(), file_key.as_ref())
+            .map_err(|_| CryptError::Encrypt)?;
+        stanzas.push(Stanza {
+            algorithm: variant.algorithm(),
+            variant: variant.clone(),
+            ciphertext,
+            wrapped_key,
+        });
+    }
+
+    let header = Header {
+        version: VERSION,
+        stanzas,
+        salt,
+        chunk_size: chunk_size.clamp(MIN_CHUNK_SIZE, MAX_CHUNK_SIZE),
+    };
+    let key = derive_key(
+        PAYLOAD_INFO,
+        file_key.as_ref(),
+        &header.salt,
+        &header.to_bytes(),
+    );
+    Ok((header, key))
+}
+
+/// 비밀키로 열 수 있는 수신자 항목을 찾아 페이로드 키를 유도합니다.
+/// 사용한 항목의 위치도 함께 반환합니다.
+///
+/// 항목마다 역캡슐화를 시도하고 감싼 파일 키의 인증으로 맞는 항목을 가려냅니다.
+pub fn open(
+    header: &Header,
+    raw: &[u8],
+    sk_bytes: &[u8],
+) -> Result<(PayloadKey, usize), CryptError> {
+    for (index, stanza) in header.stanzas.iter().enumerate() {
+        // 비밀키 길이가 맞지 않는 배리언트는 건너뜀
+        let Ok(ss) = kem::decapsulate(&stanza.variant, sk_bytes, &stanza.ciphertext) else {
+            continue;
+        };
+        let kek = stanza_key(&ss, &header.salt, &stanza.variant, &stanza.ciphertext);
+        let Ok(file_key) = ChaCha20Poly1305::new(Key::from_slice(kek.as_ref()))
+            .decrypt(&Nonce::default(), stanza.wrapped_key.as_slice())
+            .map(Zeroizing::new)
+        else {
+            continue;
+        };
+        return Ok((
+            derive_key(PAYLOAD_INFO, &file_key, &header.salt, raw),
+            index,
+        ));
+    }
+    Err(CryptError::NoRecipient)
+}
+
+/// 수신자 항목의 파일 키를 감싸는 키를 유도합니다. 키마다 한 번만 쓰이므로 논스는 0으로 둡니다.
+fn stanza_key(ss: &[u8], salt: &[u8], variant: &Variant, ciphertext: &[u8]) -> PayloadKey {
+    let mut info = STANZA_INFO.to_vec();
+    info.extend_from_slice(variant.as_str().as_bytes());
+    info.extend_from_slice(&Sha256::digest(ciphertext));
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Hkdf::<Sha256>::new(Some(salt), ss)
+        .expand(&info, key.as_mut())
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+/// HKDF-SHA256으로 파일 키에서 페이로드 키를 유도합니다.
+/// 헤더 전체의 해시를 info에 넣어 헤더가 변조되면 다른 키가 나오도록 합니다.
+fn derive_key(label: &[u8], ikm: &[u8], salt: &[u8], header: &[u8]) -> PayloadKey {
+    let mut info = label.to_vec();
+    info.extend_from_slice(&Sha256::digest(header));
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Hkdf::<Sha256>::new(Some(salt), ikm)
+        .expand(&info, key.as_mut())
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+/// 청크 순번과 마지막 여부로 96비트 논스를 만듭니다 (STREAM 구성).
+fn chunk_nonce(counter: u64, last: bool) -> Nonce {
+    let mut nonce = [0u8; 12];
+    nonce[3..11].copy_from_slice(&counter.to_be_bytes());
+    nonce[11] = u8::from(last);
+    Nonce::from(nonce)
+}
+
+/// 버퍼가 가득 차거나 입력이 끝날 때까지 읽습니다.
+fn read_full<R: Read>(input: &mut R, buf: &mut [u8]) -> io::Result<usize> {
+    let mut filled = 0;
+    while filled < buf.len() {
+        match input.read(&mut buf[filled..]) {
+            Ok(0) => break,
+            Ok(n) => filled += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(filled)
+}
+
+/// 입력을 청크 단위로 암호화하여 출력에 기록합니다. 평문 바이트 수를 반환합니다.
+///
+/// 각 청크는 ChaCha20-Poly1305로 봉인되며, 마지막 청크는 논스로 구분되어
+/// 청크를 잘라내거나 순서를 바꾸면 복호화에 실패합니다.
+pub fn encrypt_payload<R: Read, W: Write>(
+    key: &PayloadKey,
+    chunk_size: u32,
+    input: &mut R,
+    output: &mut W,
+) -> Result<u64, CryptError> {
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()));
+    let size = chunk_size as usize;
+    let mut cur = Zeroizing::new(vec![0u8; size]);
+    let mut next = Zeroizing::new(vec![0u8; size]);
+    let mut len = read_full(input, &mut cur)?;
+    let mut total = 0u64;
+    let mut counter = 0u64;
+
+    loop {
+        // 다음 청크를 미리 읽어 현재 청크가 마지막인지 판정
+        let next_len = if len == size {
+            read_full(input, &mut next)?
+        } else {
+            0
+        };
+        let last = next_len == 0;
+        let sealed = cipher
+            .encrypt(&chunk_nonce(counter, last), &cur[..len])
+            .map_err(|_| CryptError::Encrypt)?;
+        output.write_all(&sealed)?;
+        total += len as u64;
+        if last {
+            break;
+        }
+        std::mem::swap(&mut cur, &mut next);
+        len = next_len;
+        counter += 1;
+    }
+    output.flush()?;
+    Ok(total)
+}
+
+/// 암호화된 청크를 복호화하여 출력에 기록합니다. 평문 바이트 수를 반환합니다.
+///
+/// 인증에 실패하면 그 청크는 기록하지 않으므로, 출력은 원자적 파일로 받아
+/// 성공했을 때만 커밋해야 합니다.
+pub fn decrypt_payload<R: Read, W: Write>(
+    key: &PayloadKey,
+    chunk_size: u32,
+    input: &mut R,
+    output: &mut W,
+) -> Result<u64, CryptError> {
+    let cipher = ChaCha20Poly1305::new(Key::from_slice(key.as_ref()));
+    let size = chunk_size as usize + TAG_LEN;
+    let mut cur = vec![0u8; size];
+    let mut next = vec![0u8; size];
+    let mut len = read_full(input, &mut cur)?;
+    let mut total = 0u64;
+    let mut counter = 0u64;
+
+    loop {
+        if len < TAG_LEN {
+            return Err(CryptError::Truncated);
+        }
+        let next_len = if len == size {
+            read_full(input, &mut next)?
+        } else {
+            0
+        };
+        let last = next_len == 0;
+        let plain = Zeroizing::new(
+            cipher
+                .decrypt(
+                    &chunk_nonce(counter, last),
+                    Payload {
+                        msg: &cur[..len],
+                        aad: &[],
+                    },
+                )
+                .map_err(|_| CryptError::Decrypt)?,
+        );
+        output.write_all(&plain)?;
+        total += plain.len() as u64;
+        if last {
+            break;
+        }
+        std::mem::swap(&mut cur, &mut next);
+        len = next_len;
+        counter += 1;
+    }
+    output.flush()?;
+    Ok(total)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internals::keygen::generate_keys;
+    use crate::internals::secret::SecretBuffer;
+
+    const CHUNK: u32 = MIN_CHUNK_SIZE;
+    /// 청크 하나의 암호문 길이
+    const SEALED_CHUNK: usize = CHUNK as usize + TAG_LEN;
+
+    fn key_pair(variant: Variant) -> (Vec<u8>, SecretBuffer) {
+        generate_keys(variant.algorithm(), variant).unwrap()
+    }
+
+    fn encrypt(recipients: &[(Variant, Vec<u8>)], plain: &[u8]) -> Vec<u8> {
+        let (header, key) = seal(recipients, CHUNK).unwrap();
+        let mut out = header.to_bytes();
+        let written = encrypt_payload(&key, header.chunk_size, &mut &plain[..], &mut out).unwrap();
+        assert_eq!(written, plain.len() as u64);
+        out
+    }
+
+    fn decrypt(file: &[u8], sk: &[u8]) -> Result<Vec<u8>, CryptError> {
+        let mut input = file;
+        let (header, raw) = Header::read_from(&mut input)?;
+        let (key, _) = open(&header, &raw, sk)?;
+        let mut out = Vec::new();
+        decrypt_payload(&key, header.chunk_size, &mut input, &mut out)?;
+        Ok(out)
+    }
+
+    fn single() -> (Vec<(Variant, Vec<u8>)>, SecretBuffer) {
+        let (pk, sk) = key_pair(Variant::MLKEM512);
+        (vec![(Variant::MLKEM512, pk)], sk)
+    }
+
+    /// 헤더 길이 (페이로드가 시작하는 위치)
+    fn header_len(file: &[u8]) -> usize {
+        let mut input = file;
+        Header::read_from(&mut input).unwrap().1.len()
+    }
+
+    #[test]
+    fn payloads_round_trip_across_chunk_boundaries() {
+        let (recipients, sk) = single();
+        for len in [1, 1000, 3 * CHUNK as usize + 7] {
+            let plain: Vec<u8> = (0..len).map(|i| (i % 251) as u8).collect();
+            let file = encrypt(&recipients, &plain);
+            assert_eq!(
+                file.len(),
+                header_len(&file) + len + len.div_ceil(CHUNK as usize) * TAG_LEN
+            );
+            assert_eq!(decrypt(&file, &sk).unwrap(), plain);
+        }
+    }
+
+    #[test]
+    fn empty_input_is_a_single_authenticated_chunk() {
+        let (recipients, sk) = single();
+        let file = encrypt(&recipients, b"");
+        assert_eq!(file.len(), header_len(&file) + TAG_LEN);
+        assert_eq!(decrypt(&file, &sk).unwrap(), b"");
+    }
+
+    #[test]
+    fn exact_multiple_of_the_chunk_size_has_no_trailing_empty_chunk() {
+        let (recipients, sk) = single();
+        let plain = vec![0x5a; 2 * CHUNK as usize];
+        let file = encrypt(&recipients, &plain);
+        assert_eq!(file.len(), header_len(&file) + 2 * SEALED_CHUNK);
+        assert_eq!(decrypt(&file, &sk).unwrap(), plain);
+
+        // 마지막 청크를 떼어 내면 앞 청크가 마지막 표시 없이 끝나므로 거부
+        let cut = &file[..file.len() - SEALED_CHUNK];
+        assert!(matches!(decrypt(cut, &sk), Err(CryptError::Decrypt)));
+    }
+
+    #[test]
+    fn any_flipped_byte_is_rejected() {
+        let (recipients, sk) = single();
+        let plain = vec![7u8; 2 * CHUNK as usize + 100];
+        let file = encrypt(&recipients, &plain);
+        let payload = header_len(&file);
+
+        for pos in [payload, payload + SEALED_CHUNK + 3, file.len() - 1] {
+            let mut tampered = file.clone();
+            tampered[pos] ^= 0x01;
+            assert!(
+                matches!(decrypt(&tampered, &sk), Err(CryptError::Decrypt)),
+                "byte {pos}"
+            );
+        }
+        // 헤더(솔트)를 바꾸면 페이로드 키가 달라짐
+        let mut tampered = file.clone();
+        tampered[payload - 5] ^= 0x01;
+        assert!(decrypt(&tampered, &sk).is_err());
+    }
+
+    #[test]
+    fn truncated_final_chunk_is_rejected() {
+        let (recipients, sk) = single();
+        let file = encrypt(&recipients, &vec![1u8; CHUNK as usize + 200]);
+
+        let cut = &file[..file.len() - 50];
+        assert!(matches!(decrypt(cut, &sk), Err(CryptError::Decrypt)));
+        // 태그보다 짧게 남은 청크
+        let cut = &file[..file.len() - 205];
+        assert!(matches!(decrypt(cut, &sk), Err(CryptError::Truncated)));
+        // 마지막 청크가 통째로 없음
+        let cut = &file[..file.len() - 216];
+        assert!(matches!(decrypt(cut, &sk), Err(CryptError::Decrypt)));
+    }
+
+    #[test]
+    fn reordered_chunks_are_rejected() {
+        let (recipients, sk) = single();
+        let plain: Vec<u8> = (0..3 * CHUNK as usize).map(|i| (i / 1024) as u8).collect();
+        let file = encrypt(&recipients, &plain);
+        let payload = header_len(&file);
+
+        let mut swapped = file[..payload].to_vec();
+        let chunks: Vec<&[u8]> = file[payload..].chunks(SEALED_CHUNK).collect();
+        for i in [1, 0, 2] {
+            swapped.extend_from_slice(chunks[i]);
+        }
+        assert!(matches!(decrypt(&swapped, &sk), Err(CryptError::Decrypt)));
+    }
+
+    #[test]
+    fn every_recipient_opens_the_file_with_its_own_variant() {
+        let variants = [Variant::MLKEM768, Variant::HQC128, Variant::MLKEM512];
+        let pairs: Vec<_> = variants.iter().cloned().map(key_pair).collect();
+        let recipients: Vec<_> = variants
+            .iter()
+            .cloned()
+            .zip(pairs.iter().map(|(pk, _)| pk.clone()))
+            .collect();
+        let file = encrypt(&recipients, b"release notes");
+
+        let mut input = &file[..];
+        let (header, raw) = Header::read_from(&mut input).unwrap();
+        assert_eq!(header.stanzas.len(), 3);
+        for (index, (variant, (_, sk))) in variants.iter().zip(&pairs).enumerate() {
+            assert_eq!(&header.stanzas[index].variant, variant);
+            assert_eq!(open(&header, &raw, sk).unwrap().1, index);
+            assert_eq!(decrypt(&file, sk).unwrap(), b"release notes");
+        }
+    }
+
+    #[test]
+    fn keys_that_are_not_recipients_are_rejected() {
+        let (pk, _) = key_pair(Variant::MLKEM512);
+        let file = encrypt(&[(Variant::MLKEM512, pk)], b"secret");
+
+        // 같은 배리언트의 다른 키와 헤더에 없는 배리언트의 키
+        for variant in [Variant::MLKEM512, Variant::MLKEM1024] {
+            let (_, other) = key_pair(variant);
+            assert!(matches!(
+                decrypt(&file, &other),
+                Err(CryptError::NoRecipient)
+            ));
+        }
+    }
+
+    #[test]
+    fn removing_a_recipient_breaks_the_payload_key() {
+        let (pk_a, sk_a) = key_pair(Variant::MLKEM512);
+        let (pk_b, _) = key_pair(Variant::MLKEM768);
+        let file = encrypt(
+            &[(Variant::MLKEM512, pk_a), (Variant::MLKEM768, pk_b)],
+            b"secret",
+        );
+
+        let mut input = &file[..];
+        let (mut header, _) = Header::read_from(&mut input).unwrap();
+        header.stanzas.pop();
+        let mut stripped = header.to_bytes();
+        stripped.extend_from_slice(input);
+        assert!(matches!(
+            decrypt(&stripped, &sk_a),
+            Err(CryptError::Decrypt)
+        ));
+    }
+
+    #[test]
+    fn recipient_count_is_bounded() {
+        assert!(matches!(seal(&[], CHUNK), Err(CryptError::Malformed(_))));
+        let (pk, _) = key_pair(Variant::MLKEM512);
+        let many = vec![(Variant::MLKEM512, pk); MAX_RECIPIENTS + 1];
+        assert!(matches!(seal(&many, CHUNK), Err(CryptError::Malformed(_))));
+    }
+
+    #[test]
+    fn headers_are_validated() {
+        let (recipients, sk) = single();
+        let file = encrypt(&recipients, b"hello");
+
+        let mut other = file.clone();
+        other[..MAGIC.len()].copy_from_slice(b"NOPE!");
+        assert!(matches!(decrypt(&other, &sk), Err(CryptError::BadMagic)));
+        let mut other = file.clone();
+        other[MAGIC.len()] = 1;
+        assert!(matches!(
+            decrypt(&other, &sk),
+            Err(CryptError::UnsupportedVersion(1))
+        ));
+        let mut other = file.clone();
+        other[MAGIC.len() + 1] = 0;
+        assert!(matches!(
+            decrypt(&other, &sk),
+            Err(CryptError::Malformed(_))
+        ));
+        assert!(matches!(
+            decrypt(&file[..40], &sk),
+            Err(CryptError::Malformed(_))
+        ));
+    }
+}
@@ -0,0 +1,604 @@
+use std::fmt;
+use std::fs::File;
+use std::io::{self, Read};
+use std::path::PathBuf;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, MutexGuard};
+
+use hkdf::hmac::{Hmac, Mac};
+use sha2::Sha256;
+use zeroize::{Zeroize, Zeroizing};
+
+/// 시작 상태 검사에 쓰는 표본 수 (SP 800-90B 4.3: 최소 1024개)
+pub const STARTUP_SAMPLES: usize = 4096;
+
+/// 건강 검사에서 가정하는 표본(바이트)당 최소 엔트로피(비트)
+///
+/// 낮게 잡을수록 판정 기준이 느슨해져, 정상 소스의 오경보 없이 고장 난 소스만 잡습니다.
+pub const ASSUMED_MIN_ENTROPY: f64 = 1.0;
+
+/// 적응 비율 검사 창 크기 (비이진 표본, SP 800-90B 4.4.2)
+pub const APT_WINDOW: usize = 512;
+
+/// 건강 검사 오경보 확률 α = 2^-20
+const FALSE_POSITIVE_LOG2: f64 = -20.0;
+
+/// 결정적 DRBG 시드의 최소 길이(바이트)
+const MIN_DRBG_SEED: usize = 16;
+
+/// 키 생성에 쓰는 난수 소스
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum EntropySpec {
+    /// 운영체제 난수 생성기 (기본값)
+    Os,
+    /// 파일 또는 장치 (예: `/dev/hwrng`)
+    File(PathBuf),
+    /// 시드로 초기화한 HMAC-DRBG (SHA-256). 결과가 시드로 정해지므로 테스트 전용
+    Drbg(Zeroizing<Vec<u8>>),
+}
+
+impl EntropySpec {
+    /// `os`, `file:<경로>`, `drbg:<16진수 시드>`를 해석합니다.
+    pub fn parse(input: &str) -> Result<EntropySpec, EntropyError> {
+        let (kind, value) = input.split_once(':').unwrap_or((input, ""));
+        match kind.to_lowercase().as_str() {
+            "os" | "system" if value.is_empty() => Ok(EntropySpec::Os),
+            "file" | "device" if !value.is_empty() => Ok(EntropySpec::File(value.into())),
+            "drbg" => {
+                let seed = decode_hex(value)
+                    .filter(|seed| seed.len() >= MIN_DRBG_SEED)
+                    .ok_or(EntropyError::InvalidSeed)?;
+                Ok(EntropySpec::Drbg(seed))
+            }
+            _ => Err(EntropyError::InvalidSpec(input.to_string())),
+        }
+    }
+
+    /// 메타데이터와 메시지에 기록하는 설명 (시드는 드러내지 않음)
+    pub fn describe(&self) -> String {
+        match self {
+            EntropySpec::Os => "os".to_string(),
+            EntropySpec::File(path) => format!("file:{}", path.display()),
+            EntropySpec::Drbg(_) => "drbg (deterministic, test only)".to_string(),
+        }
+    }
+
+    pub fn is_deterministic(&self) -> bool {
+        matches!(self, EntropySpec::Drbg(_))
+    }
+}
+
+/// 건강 검사 실패 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum HealthFailure {
+    /// 반복 횟수 검사: 같은 값이 `run`번 연속 (기준 `cutoff`)
+    RepetitionCount { run: usize, cutoff: usize },
+    /// 적응 비율 검사: 창 안에서 같은 값이 `count`번 (기준 `cutoff`)
+    AdaptiveProportion { count: usize, cutoff: usize },
+}
+
+/// 난수 소스 선택·사용 실패 사유
+#[derive(Debug)]
+pub enum EntropyError {
+    /// 알 수 없는 소스 표기
+    InvalidSpec(String),
+    /// DRBG 시드가 16진수가 아니거나 너무 짧음
+    InvalidSeed,
+    /// 이 빌드에서는 pqcrypto의 난수를 바꿀 수 없음 (`custom-entropy` 기능 또는
+    /// `getrandom_backend="custom"` 설정이 없거나, getrandom 호출이 이 모듈을 거치지 않음)
+    Unsupported,
+    /// 파일/장치를 열거나 읽지 못함
+    Io(PathBuf, io::Error),
+    /// 운영체제 난수 생성기 실패
+    Os(io::Error),
+    /// 시작 상태 검사 실패
+    Health(HealthFailure),
+}
+
+impl fmt::Display for EntropyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message("en"))
+    }
+}
+
+impl std::error::Error for EntropyError {}
+
+impl EntropyError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            EntropyError::InvalidSpec(spec) => text(
+                "error.entropy.spec",
+                "알 수 없는 난수 소스: {spec} (os, file:<경로>, drbg:<16진수 시드>)",
+            )
+            .replace("{spec}", spec),
+            EntropyError::InvalidSeed => text(
+                "error.entropy.seed",
+                "DRBG 시드는 16바이트 이상의 16진수여야 합니다.",
+            ),
+            EntropyError::Unsupported => text(
+                "error.entropy.unsupported",
+                "이 빌드는 운영체제 난수 생성기만 사용할 수 있습니다. 다른 소스를 쓰려면 custom-entropy 기능과 RUSTFLAGS='--cfg getrandom_backend=\"custom\"'으로 빌드하세요.",
+            ),
+            EntropyError::Io(path, e) => text(
+                "error.entropy.io",
+                "난수 소스를 읽을 수 없습니다({path}): {err}",
+            )
+            .replace("{path}", &path.display().to_string())
+            .replace("{err}", &e.to_string()),
+            EntropyError::Os(e) => text(
+                "error.entropy.os",
+                "운영체제 난수 생성기가 실패했습니다: {err}",
+            )
+            .replace("{err}", &e.to_string()),
+            EntropyError::Health(HealthFailure::RepetitionCount { run, cutoff }) => text(
+                "error.entropy.rct",
+                "난수 소스 건강 검사 실패(반복 횟수 검사): 같은 값이 {run}번 연속 나왔습니다(기준 {cutoff}). 키 생성을 중단합니다.",
+            )
+            .replace("{run}", &run.to_string())
+            .replace("{cutoff}", &cutoff.to_string()),
+            EntropyError::Health(HealthFailure::AdaptiveProportion { count, cutoff }) => text(
+                "error.entropy.apt",
+                "난수 소스 건강 검사 실패(적응 비율 검사): {window}개 중 같은 값이 {count}번 나왔습니다(기준 {cutoff}). 키 생성을 중단합니다.",
+            )
+            .replace("{window}", &APT_WINDOW.to_string())
+            .replace("{count}", &count.to_string())
+            .replace("{cutoff}", &cutoff.to_string()),
+        }
+    }
+}
+
+/// SP 800-90A HMAC-DRBG (SHA-256, 재시드·추가 입력 없음)
+struct HmacDrbg {
+    k: [u8; 32],
+    v: [u8; 32],
+}
+
+impl HmacDrbg {
+    const PERSONALIZATION: &'static [u8] = b"keypairer/entropy/drbg";
+
+    fn new(seed: &[u8]) -> HmacDrbg {
+        let material = Zeroizing::new([seed, Self::PERSONALIZATION].concat());
+        Self::instantiate(&material)
+    }
+
+    /// 시드 재료(엔트로피 입력 ‖ 논스 ‖ 개인화 문자열)로 초기화합니다.
+    fn instantiate(seed_material: &[u8]) -> HmacDrbg {
+        let mut drbg = HmacDrbg {
+            k: [0x00; 32],
+            v: [0x01; 32],
+        };
+        drbg.update(seed_material);
+        drbg
+    }
+
+    fn hmac(key: &[u8; 32], parts: &[&[u8]]) -> [u8; 32] {
+        let mut mac = <Hmac<Sha256> as Mac>::new_from_slice(key).expect("HMAC은 모든 키 길이 허용");
+        for part in parts {
+            mac.update(part);
+        }
+        mac.finalize().into_bytes().into()
+    }
+
+    fn update(&mut self, data: &[u8]) {
+        self.k = Self::hmac(&self.k, &[&self.v, &[0x00], data]);
+        self.v = Self::hmac(&self.k, &[&self.v]);
+        if !data.is_empty() {
+            self.k = Self::hmac(&self.k, &[&self.v, &[0x01], data]);
+            self.v = Self::hmac(&self.k, &[&self.v]);
+        }
+    }
+
+    fn generate(&mut self, out: &mut [u8]) {
+        for chunk in out.chunks_mut(32) {
+            self.v = Self::hmac(&self.k, &[&self.v]);
+            chunk.copy_from_slice(&self.v[..chunk.len()]);
+        }
+        self.update(&[]);
+    }
+}
+
+impl Drop for HmacDrbg {
+    fn drop(&mut self) {
+        self.k.zeroize();
+        self.v.zeroize();
+    }
+}
+
+/// 선택된 소스의 열린 상태
+enum Source {
+    Os,
+    File(PathBuf, File),
+    Drbg(HmacDrbg),
+}
+
+impl Source {
+    fn open(spec: &EntropySpec) -> Result<Source, EntropyError> {
+        match spec {
+            EntropySpec::Os => Ok(Source::Os),
+            EntropySpec::File(path) => File::open(path)
+                .map(|file| Source::File(path.clone(), file))
+                .map_err(|e| EntropyError::Io(path.clone(), e)),
+            EntropySpec::Drbg(seed) => Ok(Source::Drbg(HmacDrbg::new(seed))),
+        }
+    }
+
+    fn fill(&mut self, buf: &mut [u8]) -> Result<(), EntropyError> {
+        match self {
+            Source::Os => os_fill(buf),
+            Source::File(path, file) => file
+                .read_exact(buf)
+                .map_err(|e| EntropyError::Io(path.clone(), e)),
+            Source::Drbg(drbg) => {
+                drbg.generate(buf);
+                Ok(())
+            }
+        }
+    }
+}
+
+/// 현재 선택된 소스 (설명, 상태)
+static SELECTED: Mutex<Option<(String, Source)>> = Mutex::new(None);
+
+fn selected() -> MutexGuard<'static, Option<(String, Source)>> {
+    SELECTED
+        .lock()
+        .unwrap_or_else(|poisoned| poisoned.into_inner())
+}
+
+/// getrandom 사용자 백엔드가 호출된 횟수 (선택이 실제로 적용되는지 확인용)
+static BACKEND_CALLS: AtomicUsize = AtomicUsize::new(0);
+
+/// pqcrypto를 포함한 모든 getrandom 호출이 이 모듈을 거치는 빌드인지 여부
+///
+/// `custom-entropy` 기능을 켜고 `RUSTFLAGS='--cfg getrandom_backend="custom"'`으로 빌드해야 합니다.
+pub const fn is_pluggable() -> bool {
+    cfg!(all(
+        feature = "custom-entropy",
+        getrandom_backend = "custom"
+    ))
+}
+
+/// getrandom 호출이 실제로 이 모듈의 사용자 백엔드에 도달하는지 확인합니다.
+fn backend_active() -> bool {
+    let before = BACKEND_CALLS.load(Ordering::SeqCst);
+    let mut probe = [0u8; 1];
+    getrandom::fill(&mut probe).is_ok() && BACKEND_CALLS.load(Ordering::SeqCst) != before
+}
+
+/// 난수 소스를 선택하고 시작 상태 검사를 수행합니다.
+///
+/// 검사에 실패하면 소스를 바꾸지 않고 오류를 반환합니다. 사용자 백엔드가 없거나 getrandom
+/// 호출이 백엔드를 거치지 않는 빌드에서는 운영체제 소스만 선택할 수 있으며, 다른 소스는
+/// 조용히 무시하지 않고 [`EntropyError::Unsupported`]로 거부합니다.
+pub fn select(spec: &EntropySpec) -> Result<(), EntropyError> {
+    if *spec != EntropySpec::Os && !(is_pluggable() && backend_active()) {
+        return Err(EntropyError::Unsupported);
+    }
+    let mut source = Source::open(spec)?;
+    startup_test(&mut source)?;
+    *selected() = Some((spec.describe(), source));
+    Ok(())
+}
+
+/// 현재 난수 소스 설명 (선택하지 않았으면 `os`)
+pub fn describe() -> String {
+    selected()
+        .as_ref()
+        .map_or_else(|| "os".to_string(), |(name, _)| name.clone())
+}
+
+/// 현재 난수 소스로 버퍼를 채웁니다.
+pub fn fill(buf: &mut [u8]) -> Result<(), EntropyError> {
+    match selected().as_mut() {
+        Some((_, source)) => source.fill(buf),
+        None => os_fill(buf),
+    }
+}
+
+/// 시작 상태 검사: 표본을 뽑아 반복 횟수 검사와 적응 비율 검사를 적용합니다.
+fn startup_test(source: &mut Source) -> Result<(), EntropyError> {
+    let mut sample = Zeroizing::new([0u8; STARTUP_SAMPLES]);
+    source.fill(&mut sample[..])?;
+    health_test(&sample[..]).map_err(EntropyError::Health)
+}
+
+/// 반복 횟수 검사 기준 C = 1 + ⌈-log2(α) / H⌉ (SP 800-90B 4.4.1)
+pub fn rct_cutoff() -> usize {
+    1 + (-FALSE_POSITIVE_LOG2 / ASSUMED_MIN_ENTROPY).ceil() as usize
+}
+
+/// 적응 비율 검사 기준 C = 1 + CRITBINOM(W, 2^-H, 1-α) (SP 800-90B 4.4.2)
+pub fn apt_cutoff() -> usize {
+    let n = APT_WINDOW;
+    let p = 2f64.powf(-ASSUMED_MIN_ENTROPY);
+    let alpha = 2f64.powf(FALSE_POSITIVE_LOG2);
+    // 로그 공간에서 pmf를 누적해 아주 작은 확률도 정확히 다룸
+    let ln_choose = |k: usize| -> f64 {
+        (1..=k)
+            .map(|i| ((n - k + i) as f64).ln() - (i as f64).ln())
+            .sum()
+    };
+    let mut cdf = 0.0;
+    for k in 0..=n {
+        cdf += (ln_choose(k) + k as f64 * p.ln() + (n - k) as f64 * (1.0 - p).ln()).exp();
+        if cdf >= 1.0 - alpha {
+            return 1 + k;
+        }
+    }
+    APT_WINDOW
+}
+
+/// 표본(바이트열)에 두 건강 검사를 적용합니다.
+pub fn health_test(samples: &[u8]) -> Result<(), HealthFailure> {
+    let rct = rct_cutoff();
+    let mut run = 1usize;
+    for pair in samples.windows(2) {
+        run = if pair[0] == pair[1] { run + 1 } else { 1 };
+        if run >= rct {
+            return Err(HealthFailure::RepetitionCount { run, cutoff: rct });
+        }
+    }
+
+    let apt = apt_cutoff();
+    for window in samples.chunks_exact(APT_WINDOW) {
+        let count = window.iter().filter(|&&b| b == window[0]).count();
+        if count >= apt {
+            return Err(HealthFailure::AdaptiveProportion { count, cutoff: apt });
+        }
+    }
+    Ok(())
+}
+
+/// 운영체제 난수 생성기로 버퍼를 채웁니다.
+#[cfg(all(feature = "custom-entropy", getrandom_backend = "custom"))]
+fn os_fill(buf: &mut [u8]) -> Result<(), EntropyError> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let mut filled = 0usize;
+        while filled < buf.len() {
+            // SAFETY: 남은 버퍼 범위만 넘깁니다.
+            let n = unsafe {
+                libc::getrandom(
+                    buf[filled..].as_mut_ptr() as *mut libc::c_void,
+                    buf.len() - filled,
+                    0,
+                )
+            };
+            if n < 0 {
+                let err = io::Error::last_os_error();
+                if err.kind() == io::ErrorKind::Interrupted {
+                    continue;
+                }
+                return Err(EntropyError::Os(err));
+            }
+            filled += n as usize;
+        }
+        Ok(())
+    }
+    #[cfg(any(
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "openbsd",
+        target_os = "freebsd"
+    ))]
+    {
+        // getentropy는 한 번에 256바이트까지
+        for chunk in buf.chunks_mut(256) {
+            // SAFETY: 청크 범위만 넘깁니다.
+            if unsafe { libc::getentropy(chunk.as_mut_ptr() as *mut libc::c_void, chunk.len()) }
+                != 0
+            {
+                return Err(EntropyError::Os(io::Error::last_os_error()));
+            }
+        }
+        Ok(())
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        target_os = "ios",
+        target_os = "openbsd",
+        target_os = "freebsd"
+    )))]
+    {
+        File::open("/dev/urandom")
+            .and_then(|mut f| f.read_exact(buf))
+            .map_err(EntropyError::Os)
+    }
+}
+
+/// 운영체제 난수 생성기로 버퍼를 채웁니다 (getrandom 기본 백엔드).
+#[cfg(not(all(feature = "custom-entropy", getrandom_backend = "custom")))]
+fn os_fill(buf: &mut [u8]) -> Result<(), EntropyError> {
+    getrandom::fill(buf).map_err(|e| EntropyError::Os(io::Error::other(e.to_string())))
+}
+
+/// getrandom 사용자 백엔드: pqcrypto의 `randombytes`와 이 크레이트의 모든 getrandom 호출이
+/// 선택된 소스를 거치게 합니다.
+///
+/// 전역 심볼이라 다른 사용자 백엔드와 함께 링크할 수 없으므로, `custom-entropy` 기능을 켜고
+/// `getrandom_backend="custom"`으로 빌드할 때만 내보냅니다.
+#[cfg(all(feature = "custom-entropy", getrandom_backend = "custom"))]
+#[unsafe(no_mangle)]
+unsafe extern "Rust" fn __getrandom_v03_custom(
+    dest: *mut u8,
+    len: usize,
+) -> Result<(), getrandom::Error> {
+    BACKEND_CALLS.fetch_add(1, Ordering::SeqCst);
+    // SAFETY: getrandom이 `len`바이트 쓰기 가능한 버퍼를 넘깁니다.
+    let buf = unsafe { std::slice::from_raw_parts_mut(dest, len) };
+    fill(buf).map_err(|e| {
+        getrandom::Error::new_custom(match e {
+            EntropyError::Os(_) => 0,
+            EntropyError::Io(..) => 1,
+            _ => 2,
+        })
+    })
+}
+
+fn decode_hex(text: &str) -> Option<Zeroizing<Vec<u8>>> {
+    let text = text.trim();
+    if !text.len().is_multiple_of(2) {
+        return None;
+    }
+    let mut out = Zeroizing::new(Vec::with_capacity(text.len() / 2));
+    for pair in text.as_bytes().chunks(2) {
+        let pair = std::str::from_utf8(pair).ok()?;
+        out.push(u8::from_str_radix(pair, 16).ok()?);
+    }
+    Some(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// 반복 없이 `count`개의 0을 512바이트 창에 고르게 흩어 놓은 표본 (첫 값은 0)
+    fn biased_window(count: usize) -> Vec<u8> {
+        let zeros_before = |i: usize| (i * count).div_ceil(APT_WINDOW);
+        (0..APT_WINDOW)
+            .map(|i| {
+                if zeros_before(i + 1) > zeros_before(i) {
+                    0
+                } else {
+                    (i % 255) as u8 + 1
+                }
+            })
+            .collect()
+    }
+
+    #[test]
+    fn hmac_drbg_matches_the_sp_800_90a_known_answer() {
+        // NIST CAVP HMAC_DRBG.rsp, SHA-256, 재시드·개인화·추가 입력 없음, COUNT = 0
+        let entropy =
+            decode_hex("ca851911349384bffe89de1cbdc46e6831e44d34a4fb935ee285dd14b71a7488");
+        let nonce = decode_hex("659ba96c601dc69fc902940805ec0ca8");
+        let expected = decode_hex(
+            "e528e9abf2dece54d47c7e75e5fe302149f817ea9fb4bee6f4199697d04d5b89\
+             d54fbb978a15b5c443c9ec21036d2460b6f73ebad0dc2aba6e624abf07745bc1\
+             07694bb7547bb0995f70de25d6b29e2d3011bb19d27676c07162c8b5ccde0668\
+             961df86803482cb37ed6d5c0bb8d50cf1f50d476aa0458bdaba806f48be9dcb8",
+        );
+        let material = [entropy.unwrap().as_slice(), nonce.unwrap().as_slice()].concat();
+        let mut drbg = HmacDrbg::instantiate(&material);
+        let mut out = [0u8; 128];
+        drbg.generate(&mut out);
+        drbg.generate(&mut out);
+        assert_eq!(out[..], expected.unwrap()[..]);
+    }
+
+    #[test]
+    fn drbg_spec_is_deterministic_and_personalized() {
+        let seed = [0x42u8; 16];
+        let draw = |drbg: &mut HmacDrbg| {
+            let mut out = [0u8; 100];
+            drbg.generate(&mut out);
+            out
+        };
+        let mut a = HmacDrbg::new(&seed);
+        let mut b = HmacDrbg::new(&seed);
+        let first = draw(&mut a);
+        assert_eq!(first, draw(&mut b));
+        assert_ne!(first, draw(&mut a));
+
+        let mut plain = HmacDrbg::instantiate(&seed);
+        assert_ne!(first, draw(&mut plain));
+        let mut personalized =
+            HmacDrbg::instantiate(&[&seed[..], HmacDrbg::PERSONALIZATION].concat());
+        assert_eq!(first, draw(&mut personalized));
+    }
+
+    #[test]
+    fn cutoffs_follow_sp_800_90b_for_one_bit_per_byte() {
+        assert_eq!(rct_cutoff(), 21);
+        assert_eq!(apt_cutoff(), 311);
+    }
+
+    #[test]
+    fn stuck_input_fails_the_repetition_count_test() {
+        let cutoff = rct_cutoff();
+        assert_eq!(
+            health_test(&[0x55; STARTUP_SAMPLES]),
+            Err(HealthFailure::RepetitionCount {
+                run: cutoff,
+                cutoff
+            })
+        );
+
+        // 기준보다 한 번 짧은 반복은 통과
+        let mut samples: Vec<u8> = (0..=255u8).collect();
+        samples.splice(100..100, std::iter::repeat_n(7u8, cutoff - 1));
+        assert!(
+            samples
+                .windows(cutoff - 1)
+                .any(|w| w.iter().all(|&b| b == 7))
+        );
+        assert_eq!(health_test(&samples), Ok(()));
+        samples.insert(100, 7);
+        assert_eq!(
+            health_test(&samples),
+            Err(HealthFailure::RepetitionCount {
+                run: cutoff,
+                cutoff
+            })
+        );
+    }
+
+    #[test]
+    fn biased_input_fails_the_adaptive_proportion_test() {
+        let cutoff = apt_cutoff();
+        let just_below = biased_window(cutoff - 1);
+        assert_eq!(just_below.iter().filter(|&&b| b == 0).count(), cutoff - 1);
+        assert_eq!(health_test(&just_below), Ok(()));
+
+        // 두 번째 창만 치우쳐도 실패
+        let mut samples = just_below.clone();
+        samples.extend(biased_window(cutoff));
+        assert_eq!(
+            health_test(&samples),
+            Err(HealthFailure::AdaptiveProportion {
+                count: cutoff,
+                cutoff
+            })
+        );
+    }
+
+    #[test]
+    fn drbg_output_passes_the_startup_test() {
+        let mut source = Source::open(&EntropySpec::Drbg(Zeroizing::new(vec![1; 32]))).unwrap();
+        startup_test(&mut source).unwrap();
+    }
+
+    #[test]
+    fn specs_parse_and_reject_short_seeds() {
+        assert_eq!(EntropySpec::parse("os").unwrap(), EntropySpec::Os);
+        assert_eq!(
+            EntropySpec::parse("file:/dev/hwrng").unwrap(),
+            EntropySpec::File("/dev/hwrng".into())
+        );
+        let spec = EntropySpec::parse(&format!("drbg:{}", "ab".repeat(16))).unwrap();
+        assert!(spec.is_deterministic());
+        assert!(!spec.describe().contains("ab"));
+        for bad in ["drbg:abcd", "drbg:zz", "file:", "os:x", "rdrand"] {
+            assert!(EntropySpec::parse(bad).is_err(), "{bad}");
+        }
+    }
+
+    #[test]
+    fn selecting_a_source_fails_loudly_without_the_backend() {
+        if is_pluggable() {
+            return;
+        }
+        // 운영체제가 아닌 소스는 조용히 대체하지 않고 거부
+        let spec = EntropySpec::Drbg(Zeroizing::new(vec![1; 32]));
+        assert!(matches!(select(&spec), Err(EntropyError::Unsupported)));
+        assert!(!backend_active());
+        assert_eq!(describe(), "os");
+    }
+}
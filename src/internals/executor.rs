@@ -0,0 +1,407 @@
+use std::fmt;
+use std::io;
+use std::panic::{self, AssertUnwindSafe};
+use std::sync::{Arc, Mutex, mpsc};
+use std::thread;
+
+use super::keygen::{Algorithm, KeyGenResult, Variant, generate_keys};
+use super::stack;
+
+/// 워커가 실행할 작업
+pub(crate) type Job = Box<dyn FnOnce() + Send + 'static>;
+
+/// [`KeygenExecutor`] 설정
+#[derive(Debug, Clone)]
+pub struct ExecutorOptions {
+    /// 워커 스레드 수 (동시에 실행되는 작업 수)
+    pub workers: usize,
+    /// 워커 스레드 스택 크기. `None`이면 [`stack::default_stack_size`](모든 배리언트를 처리할 수
+    /// 있는 크기)이며, 다룰 배리언트가 정해져 있으면 [`stack::stack_size_for_all`]로 줄일 수 있습니다.
+    pub stack_size: Option<usize>,
+    /// 대기열에 쌓아 둘 수 있는 작업 수. `Some`이면 가득 찼을 때 [`KeygenExecutor::submit`]이
+    /// 자리가 날 때까지 기다리고, `None`이면 제한이 없습니다.
+    pub queue_capacity: Option<usize>,
+    /// 워커 스레드 이름 접두사 (`<접두사>-<번호>`)
+    pub thread_name: String,
+}
+
+impl Default for ExecutorOptions {
+    fn default() -> Self {
+        ExecutorOptions {
+            workers: thread::available_parallelism().map_or(1, |n| n.get()),
+            stack_size: None,
+            queue_capacity: None,
+            thread_name: "keypairer-keygen".to_string(),
+        }
+    }
+}
+
+/// 작업 실행 실패
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExecutorError {
+    /// 작업이 패닉으로 끝남 (워커는 계속 동작)
+    Panicked,
+    /// 실행기가 종료되어 작업을 받거나 결과를 돌려줄 수 없음
+    ShutDown,
+    /// 대기열이 가득 참 ([`KeygenExecutor::try_submit`])
+    QueueFull,
+    /// 워커 스레드를 만들 수 없어 실행기를 쓸 수 없음
+    Unavailable,
+}
+
+/// 대기열에 넣은 작업의 결과를 기다리는 핸들
+#[must_use = "작업 결과는 join으로 받아야 합니다"]
+pub struct JobHandle<R> {
+    result: mpsc::Receiver<thread::Result<R>>,
+}
+
+/// 대용량 스택 워커 스레드를 미리 만들어 두고 작업 대기열에서 키 생성 등을 실행하는 풀
+///
+/// [`crate::run_with_large_stack`]처럼 호출마다 스레드를 만들고 합치지 않으므로, 서비스에서
+/// 키를 계속 생성할 때 사용합니다. 작업은 넣은 순서대로 비어 있는 워커가 가져가며,
+/// 패닉은 해당 작업의 [`ExecutorError::Panicked`]로만 전달되고 워커는 계속 동작합니다.
+/// 실행기를 버리면(또는 [`KeygenExecutor::shutdown`]) 대기 중인 작업까지 마친 뒤 워커를 합칩니다.
+pub struct KeygenExecutor {
+    sender: Option<Sender>,
+    workers: Vec<thread::JoinHandle<()>>,
+    stack_size: usize,
+}
+
+/// 대기열 송신자 (제한 여부에 따라)
+enum Sender {
+    Unbounded(mpsc::Sender<Job>),
+    Bounded(mpsc::SyncSender<Job>),
+}
+
+impl KeygenExecutor {
+    /// 기본 설정에 워커 수만 지정해 실행기를 만듭니다.
+    pub fn new(workers: usize) -> io::Result<KeygenExecutor> {
+        KeygenExecutor::with_options(ExecutorOptions {
+            workers,
+            ..ExecutorOptions::default()
+        })
+    }
+
+    /// 설정으로 실행기를 만듭니다. 스레드를 만들지 못하면 이미 만든 워커를 정리하고 오류를 반환합니다.
+    pub fn with_options(options: ExecutorOptions) -> io::Result<KeygenExecutor> {
+        let stack_size = options.stack_size.unwrap_or_else(stack::default_stack_size);
+        let (sender, receiver) = match options.queue_capacity {
+            Some(capacity) => {
+                let (tx, rx) = mpsc::sync_channel::<Job>(capacity);
+                (Sender::Bounded(tx), rx)
+            }
+            None => {
+                let (tx, rx) = mpsc::channel::<Job>();
+                (Sender::Unbounded(tx), rx)
+            }
+        };
+        let receiver = Arc::new(Mutex::new(receiver));
+
+        let mut executor = KeygenExecutor {
+            sender: Some(sender),
+            workers: Vec::new(),
+            stack_size,
+        };
+        for index in 0..options.workers.max(1) {
+            let receiver = Arc::clone(&receiver);
+            let worker = thread::Builder::new()
+                .name(format!("{}-{}", options.thread_name, index))
+                .stack_size(stack_size)
+                .spawn(move || worker_loop(&receiver))?;
+            executor.workers.push(worker);
+        }
+        Ok(executor)
+    }
+
+    /// 워커 스레드 수
+    pub fn workers(&self) -> usize {
+        self.workers.len()
+    }
+
+    /// 워커 스레드 스택 크기
+    pub fn stack_size(&self) -> usize {
+        self.stack_size
+    }
+
+    /// 작업을 대기열에 넣습니다. 대기열에 제한이 있고 가득 찼으면 자리가 날 때까지 기다립니다.
+    pub fn submit<F, R>(&self, f: F) -> Result<JobHandle<R>, ExecutorError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (job, handle) = wrap(f);
+        self.enqueue(job, true)?;
+        Ok(handle)
+    }
+
+    /// 작업을 대기열에 넣되, 제한이 있는 대기열이 가득 찼으면 기다리지 않고
+    /// [`ExecutorError::QueueFull`]을 반환합니다.
+    pub fn try_submit<F, R>(&self, f: F) -> Result<JobHandle<R>, ExecutorError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let (job, handle) = wrap(f);
+        self.enqueue(job, false)?;
+        Ok(handle)
+    }
+
+    /// 감싼 작업을 대기열에 넣습니다. `wait`이면 제한이 있는 대기열에 자리가 날 때까지 기다립니다.
+    pub(crate) fn enqueue(&self, job: Job, wait: bool) -> Result<(), ExecutorError> {
+        match &self.sender {
+            Some(Sender::Unbounded(tx)) => tx.send(job).map_err(|_| ExecutorError::ShutDown),
+            Some(Sender::Bounded(tx)) if wait => tx.send(job).map_err(|_| ExecutorError::ShutDown),
+            Some(Sender::Bounded(tx)) => tx.try_send(job).map_err(|e| match e {
+                mpsc::TrySendError::Full(_) => ExecutorError::QueueFull,
+                mpsc::TrySendError::Disconnected(_) => ExecutorError::ShutDown,
+            }),
+            None => Err(ExecutorError::ShutDown),
+        }
+    }
+
+    /// 키 생성 작업을 대기열에 넣습니다.
+    pub fn generate(
+        &self,
+        algorithm: Algorithm,
+        variant: Variant,
+    ) -> Result<JobHandle<KeyGenResult>, ExecutorError> {
+        self.submit(move || generate_keys(algorithm, variant))
+    }
+
+    /// 새 작업을 받지 않고, 대기 중인 작업까지 마친 뒤 워커를 합칩니다.
+    pub fn shutdown(mut self) {
+        self.join_workers();
+    }
+
+    fn join_workers(&mut self) {
+        // 송신자를 버리면 대기열이 빈 뒤 워커의 recv가 끝남
+        self.sender = None;
+        for worker in self.workers.drain(..) {
+            let _ = worker.join();
+        }
+    }
+}
+
+impl Drop for KeygenExecutor {
+    fn drop(&mut self) {
+        self.join_workers();
+    }
+}
+
+impl<R> JobHandle<R> {
+    /// 작업이 끝날 때까지 기다려 결과를 받습니다.
+    pub fn join(self) -> Result<R, ExecutorError> {
+        match self.result.recv() {
+            Ok(Ok(value)) => Ok(value),
+            Ok(Err(_)) => Err(ExecutorError::Panicked),
+            Err(_) => Err(ExecutorError::ShutDown),
+        }
+    }
+
+    /// 작업이 끝났으면 결과를, 아직이면 핸들을 그대로 돌려줍니다.
+    pub fn try_join(self) -> Result<Result<R, ExecutorError>, JobHandle<R>> {
+        match self.result.try_recv() {
+            Ok(Ok(value)) => Ok(Ok(value)),
+            Ok(Err(_)) => Ok(Err(ExecutorError::Panicked)),
+            Err(mpsc::TryRecvError::Disconnected) => Ok(Err(ExecutorError::ShutDown)),
+            Err(mpsc::TryRecvError::Empty) => Err(self),
+        }
+    }
+}
+
+/// 작업을 패닉을 잡아 결과 채널로 보내는 클로저로 감쌉니다.
+fn wrap<F, R>(f: F) -> (Job, JobHandle<R>)
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let job: Job = Box::new(move || {
+        // 결과를 기다리는 쪽이 핸들을 버렸으면 보내지 못해도 무시
+        let _ = tx.send(panic::catch_unwind(AssertUnwindSafe(f)));
+    });
+    (job, JobHandle { result: rx })
+}
+
+/// 대기열이 닫힐 때까지 작업을 하나씩 가져와 실행합니다.
+fn worker_loop(receiver: &Mutex<mpsc::Receiver<Job>>) {
+    loop {
+        // 잠금은 작업을 꺼내는 동안만 유지
+        let job = match receiver.lock() {
+            Ok(rx) => rx.recv(),
+            Err(_) => return,
+        };
+        match job {
+            Ok(job) => job(),
+            Err(_) => return,
+        }
+    }
+}
+
+impl fmt::Display for ExecutorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExecutorError::Panicked => f.write_str("job panicked"),
+            ExecutorError::ShutDown => f.write_str("executor has shut down"),
+            ExecutorError::QueueFull => f.write_str("job queue is full"),
+            ExecutorError::Unavailable => {
+                f.write_str("executor worker threads could not be started")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExecutorError {}
+
+impl ExecutorError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let (key, fallback) = match self {
+            ExecutorError::Panicked => (
+                "error.executor.panicked",
+                "키 생성 작업에서 패닉이 발생했습니다.",
+            ),
+            ExecutorError::ShutDown => (
+                "error.executor.shutdown",
+                "키 생성 실행기가 종료되어 작업을 처리할 수 없습니다.",
+            ),
+            ExecutorError::QueueFull => (
+                "error.executor.queue_full",
+                "키 생성 대기열이 가득 찼습니다.",
+            ),
+            ExecutorError::Unavailable => (
+                "error.executor.unavailable",
+                "키 생성 워커 스레드를 만들 수 없습니다.",
+            ),
+        };
+        tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    use super::*;
+
+    /// 작은 스택의 워커로 실행기를 만듭니다.
+    fn start(workers: usize, queue_capacity: Option<usize>) -> KeygenExecutor {
+        KeygenExecutor::with_options(ExecutorOptions {
+            workers,
+            stack_size: Some(256 * 1024),
+            queue_capacity,
+            thread_name: "executor-test".to_string(),
+        })
+        .unwrap()
+    }
+
+    /// 워커를 `release`가 보낼 때까지 붙잡아 두는 작업을 넣고, 워커가 작업을 꺼낸 뒤 반환합니다.
+    fn occupy(executor: &KeygenExecutor) -> (JobHandle<()>, mpsc::Sender<()>) {
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let handle = executor
+            .submit(move || {
+                started_tx.send(()).unwrap();
+                let _ = release_rx.recv();
+            })
+            .unwrap();
+        started_rx.recv().unwrap();
+        (handle, release_tx)
+    }
+
+    /// 결과가 나올 때까지 `try_join`을 반복합니다.
+    fn poll<R>(mut handle: JobHandle<R>) -> Result<R, ExecutorError> {
+        loop {
+            match handle.try_join() {
+                Ok(result) => return result,
+                Err(pending) => {
+                    handle = pending;
+                    thread::yield_now();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn panicking_job_is_reported_and_worker_keeps_running() {
+        let executor = start(1, None);
+        let panicked = executor.submit(|| -> u32 { panic!("job failed") }).unwrap();
+        assert_eq!(panicked.join().err(), Some(ExecutorError::Panicked));
+        // 같은 (유일한) 워커가 다음 작업을 처리
+        assert_eq!(executor.submit(|| 1 + 1).unwrap().join(), Ok(2));
+        assert_eq!(executor.workers(), 1);
+        assert_eq!(executor.stack_size(), 256 * 1024);
+    }
+
+    #[test]
+    fn bounded_queue_reports_full_without_waiting() {
+        let executor = start(1, Some(1));
+        let (running, release) = occupy(&executor);
+
+        let queued = executor.try_submit(|| "queued").unwrap();
+        assert_eq!(
+            executor.try_submit(|| "rejected").err(),
+            Some(ExecutorError::QueueFull)
+        );
+
+        release.send(()).unwrap();
+        assert_eq!(running.join(), Ok(()));
+        assert_eq!(queued.join(), Ok("queued"));
+        // 자리가 나면 다시 받음
+        assert_eq!(executor.try_submit(|| "later").unwrap().join(), Ok("later"));
+    }
+
+    #[test]
+    fn try_join_returns_the_handle_until_the_job_finishes() {
+        let executor = start(1, None);
+        let (running, release) = occupy(&executor);
+        let pending = executor.submit(|| 7).unwrap();
+
+        let running = running.try_join().expect_err("job is still blocked");
+        let pending = pending.try_join().expect_err("job is still queued");
+
+        release.send(()).unwrap();
+        assert_eq!(running.join(), Ok(()));
+        assert_eq!(poll(pending), Ok(7));
+
+        let panicked = executor.submit(|| -> u8 { panic!("job failed") }).unwrap();
+        assert_eq!(poll(panicked), Err(ExecutorError::Panicked));
+    }
+
+    #[test]
+    fn shutdown_drains_queued_jobs_and_then_rejects_new_ones() {
+        let executor = start(1, None);
+        let finished = Arc::new(AtomicUsize::new(0));
+        let (running, release) = occupy(&executor);
+        let queued: Vec<_> = (0..4)
+            .map(|i| {
+                let finished = Arc::clone(&finished);
+                executor
+                    .submit(move || {
+                        finished.fetch_add(1, Ordering::SeqCst);
+                        i
+                    })
+                    .unwrap()
+            })
+            .collect();
+
+        release.send(()).unwrap();
+        executor.shutdown();
+        // shutdown은 대기 중이던 작업까지 마친 뒤 돌아옴
+        assert_eq!(finished.load(Ordering::SeqCst), 4);
+        assert_eq!(running.join(), Ok(()));
+        for (i, handle) in queued.into_iter().enumerate() {
+            assert_eq!(handle.join(), Ok(i));
+        }
+
+        let mut executor = start(1, Some(1));
+        executor.join_workers();
+        assert_eq!(executor.workers(), 0);
+        assert_eq!(executor.submit(|| ()).err(), Some(ExecutorError::ShutDown));
+        assert_eq!(
+            executor.try_submit(|| ()).err(),
+            Some(ExecutorError::ShutDown)
+        );
+    }
+}
@@ -55,7 +55,7 @@ pub fn load_translations(lang: &str) -> HashMap<String, String> {
         "info.pk_preview".to_string(),
         "공개키(base64) 미리보기:".to_string(),
     );
-    ko.insert("usage".to_string(), "사용법: {prog} -alg <알고리즘> [-var | -variant <배리언트>] [-pkpath <공개키 경로>] [-skpath <비밀키 경로>] [-pkt | -pktext] [-skt | -sktext] [-lang <ko|en>]".to_string());
+    ko.insert("usage".to_string(), "사용법: {prog} [gen] -alg <알고리즘> [-var | -variant <배리언트>] [-pkpath <공개키 경로>] [-skpath <비밀키 경로>] [-pkt | -pktext] [-skt | -sktext] [-encoding <binary|pem>] [-f | -force] [-meta] [-count <개수> | -manifest <파일> | -suite <묶음>] [-jobs <개수>] [-index <경로>] [-outdir <디렉토리>] [-store [-label <라벨>] [-tag <태그>] [-expires <시각>] [-pending] [-store-dir <디렉토리>]] [-entropy <소스>] [-stack-size <크기>] [-config <파일>] [-profile <프로필>] [-policy <정책>] [-lang <ko|en>]".to_string());
     ko.insert(
         "usage.supported".to_string(),
         "지원되는 알고리즘: mlkem, hqc, mceliece, falcon, mldsa, sphincs+".to_string(),
@@ -72,84 +72,1696 @@ pub fn load_translations(lang: &str) -> HashMap<String, String> {
         "- [-lang]로 출력 로케일을 설정합니다. 기본값은 ko, en 지원.".to_string(),
     );
 
+    ko.insert(
+        "error.unknown_variant".to_string(),
+        "알 수 없는 배리언트: {var} (알고리즘: {alg})".to_string(),
+    );
+    ko.insert(
+        "error.keygen".to_string(),
+        "키 생성 도중 오류가 발생했습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.file.exists".to_string(),
+        "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)".to_string(),
+    );
+    ko.insert(
+        "info.rollback_pk".to_string(),
+        "비밀키 저장에 실패하여 공개키 파일을 이전 상태로 되돌렸습니다: {path}".to_string(),
+    );
+    ko.insert(
+        "usage.force".to_string(),
+        "- 기존 키 파일은 덮어쓰지 않습니다. 덮어쓰려면 [-force]를 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.file.read_key".to_string(),
+        "키 파일을 읽는 도중 오류가 발생했습니다({path}): {err}".to_string(),
+    );
+    ko.insert("usage.stdio".to_string(), "- 경로로 '-'를 지정하면 표준 출력으로 내보내며, 터미널이거나 두 키가 함께 나가면 PEM으로 저장됩니다.".to_string());
+    ko.insert(
+        "error.batch.exclusive".to_string(),
+        "-count와 -manifest는 함께 사용할 수 없습니다.".to_string(),
+    );
+    ko.insert(
+        "error.batch.stdout".to_string(),
+        "일괄 생성에서는 표준 출력('-')을 사용할 수 없습니다.".to_string(),
+    );
+    ko.insert(
+        "error.batch.template".to_string(),
+        "일괄 생성 경로 템플릿에는 {id}, {n}, {fp} 중 하나가 포함되어야 합니다: {template}"
+            .to_string(),
+    );
+    ko.insert(
+        "error.batch.jobs".to_string(),
+        "잘못된 워커 수: {value}".to_string(),
+    );
+    ko.insert(
+        "error.batch.count".to_string(),
+        "잘못된 개수: {value}".to_string(),
+    );
+    ko.insert(
+        "error.batch.failed".to_string(),
+        "[{done}/{total}] {id} 실패: {err}".to_string(),
+    );
+    ko.insert(
+        "error.batch.lost".to_string(),
+        "워커가 결과를 보고하지 못하고 종료되었습니다.".to_string(),
+    );
+    ko.insert(
+        "error.batch.index".to_string(),
+        "인덱스 파일을 기록하는 도중 오류가 발생했습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.batch.manifest_read".to_string(),
+        "매니페스트를 읽는 도중 오류가 발생했습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.batch.manifest_line".to_string(),
+        "매니페스트 {line}행을 해석할 수 없습니다: {reason}".to_string(),
+    );
+    ko.insert(
+        "error.batch.manifest_empty".to_string(),
+        "매니페스트에 생성할 항목이 없습니다: {path}".to_string(),
+    );
+    ko.insert(
+        "error.batch.invalid_id".to_string(),
+        "사용할 수 없는 ID: {id}".to_string(),
+    );
+    ko.insert(
+        "error.batch.duplicate_id".to_string(),
+        "중복된 ID: {id}".to_string(),
+    );
+    ko.insert(
+        "info.batch.progress".to_string(),
+        "[{done}/{total}] {id}: {fp}".to_string(),
+    );
+    ko.insert(
+        "info.batch.done".to_string(),
+        "일괄 생성 완료: 성공 {ok}건, 실패 {failed}건. 인덱스: {path}".to_string(),
+    );
+    ko.insert("usage.batch".to_string(), "- [-count] 또는 [-manifest]로 여러 키 페어를 워커 풀에서 일괄 생성합니다. 경로 템플릿에는 {id}, {n}, {fp} 중 하나가 포함되어야 하며, ID와 지문을 담은 인덱스 파일(기본값 index.json)을 기록합니다.".to_string());
+    ko.insert(
+        "error.file.create_dir".to_string(),
+        "디렉토리를 생성하는 도중 오류가 발생했습니다({path}): {err}".to_string(),
+    );
+    ko.insert("usage.template".to_string(), "- 경로에는 {alg}, {var}, {fp}(지문 앞 6자리), {date}, {n}, {id} 자리표시자를 쓸 수 있으며, [-outdir]를 지정하면 그 아래에 저장합니다. 없는 디렉토리는 0o700 권한으로 생성됩니다.".to_string());
+    ko.insert(
+        "error.meta.write".to_string(),
+        "메타데이터 파일을 기록하는 도중 오류가 발생했습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.meta.stdout".to_string(),
+        "두 키를 모두 표준 출력으로 내보낼 때는 -meta를 사용할 수 없습니다.".to_string(),
+    );
+    ko.insert(
+        "info.meta_saved".to_string(),
+        "메타데이터 저장: {path}".to_string(),
+    );
+    ko.insert("usage.meta".to_string(), "- [-meta]를 지정하면 알고리즘, 배리언트, 생성 시각, 지문, 인코딩, 도구 및 pqcrypto 백엔드 버전을 담은 '<이름>.json' 메타데이터 파일을 함께 기록합니다.".to_string());
+    ko.insert(
+        "error.store.io".to_string(),
+        "키 저장소에 접근하는 도중 오류가 발생했습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.store.corrupt".to_string(),
+        "키 저장소 인덱스를 해석할 수 없습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.store.duplicate".to_string(),
+        "같은 지문의 키가 이미 저장되어 있습니다: {id}".to_string(),
+    );
+    ko.insert(
+        "error.store.label_taken".to_string(),
+        "이미 사용 중인 라벨입니다: {label}".to_string(),
+    );
+    ko.insert(
+        "error.store.not_found".to_string(),
+        "키를 찾을 수 없습니다: {query}".to_string(),
+    );
+    ko.insert(
+        "error.store.ambiguous".to_string(),
+        "여러 키와 일치합니다: {ids}".to_string(),
+    );
+    ko.insert(
+        "error.store.locked".to_string(),
+        "다른 프로세스가 키 저장소를 사용 중입니다 (잠금 파일: {path})".to_string(),
+    );
+    ko.insert(
+        "error.store.no_home".to_string(),
+        "키 저장소 위치를 정할 수 없습니다. -store-dir 또는 KEYPAIRER_HOME을 지정하세요."
+            .to_string(),
+    );
+    ko.insert(
+        "error.store.missing_query".to_string(),
+        "대상 키(ID, ID 접두사, 지문 또는 라벨)를 지정하세요.".to_string(),
+    );
+    ko.insert("error.store.exclusive".to_string(), "-store는 -pkpath, -skpath, -outdir, -meta, -count, -manifest, -suite와 함께 사용할 수 없습니다.".to_string());
+    ko.insert(
+        "info.store.added".to_string(),
+        "키 저장소에 저장했습니다: {id} ({path})".to_string(),
+    );
+    ko.insert(
+        "info.store.empty".to_string(),
+        "저장된 키가 없습니다: {path}".to_string(),
+    );
+    ko.insert(
+        "info.store.exported".to_string(),
+        "키를 내보냈습니다: {id}".to_string(),
+    );
+    ko.insert(
+        "info.store.deleted".to_string(),
+        "키를 삭제했습니다: {id}".to_string(),
+    );
+    ko.insert(
+        "info.store.delete_cancelled".to_string(),
+        "삭제를 취소했습니다.".to_string(),
+    );
+    ko.insert(
+        "prompt.store.delete".to_string(),
+        "키 {id}({label})를 삭제합니다. 비밀키는 복구할 수 없습니다. 계속할까요? [y/N] "
+            .to_string(),
+    );
+    ko.insert("store.col.id".to_string(), "ID".to_string());
+    ko.insert("store.col.algorithm".to_string(), "알고리즘".to_string());
+    ko.insert("store.col.variant".to_string(), "배리언트".to_string());
+    ko.insert("store.col.label".to_string(), "라벨".to_string());
+    ko.insert("store.col.tags".to_string(), "태그".to_string());
+    ko.insert("store.col.created".to_string(), "생성 시각".to_string());
+    ko.insert("store.field.id".to_string(), "ID".to_string());
+    ko.insert("store.field.label".to_string(), "라벨".to_string());
+    ko.insert("store.field.tags".to_string(), "태그".to_string());
+    ko.insert("store.field.algorithm".to_string(), "알고리즘".to_string());
+    ko.insert("store.field.variant".to_string(), "배리언트".to_string());
+    ko.insert("store.field.standard".to_string(), "표준 이름".to_string());
+    ko.insert("store.field.fingerprint".to_string(), "지문".to_string());
+    ko.insert("store.field.created".to_string(), "생성 시각".to_string());
+    ko.insert(
+        "store.field.public_key".to_string(),
+        "공개키 파일".to_string(),
+    );
+    ko.insert(
+        "store.field.secret_key".to_string(),
+        "비밀키 파일".to_string(),
+    );
+    ko.insert("store.field.backend".to_string(), "백엔드".to_string());
+    ko.insert("usage.store".to_string(), "- [-store]로 키 저장소(기본값 ~/.local/share/keypairer, KEYPAIRER_HOME으로 변경)에 저장하며 [-label], [-tag]를 붙일 수 있습니다. '{prog} list|show|export|delete <ID|라벨>'로 관리합니다. delete는 비밀키를 덮어쓴 뒤 삭제합니다.".to_string());
+    ko.insert(
+        "error.store.transition".to_string(),
+        "키 {id}의 상태를 {from}에서 {to}(으)로 바꿀 수 없습니다.".to_string(),
+    );
+    ko.insert(
+        "error.store.already_rotated".to_string(),
+        "키 {id}는 이미 {next}(으)로 교체되었습니다.".to_string(),
+    );
+    ko.insert(
+        "error.store.state".to_string(),
+        "알 수 없는 상태: {state} (active, pending, retired, compromised)".to_string(),
+    );
+    ko.insert(
+        "error.time".to_string(),
+        "시각을 해석할 수 없습니다: {time} (예: 2025-12-31, 2025-12-31T09:00:00Z, +90d)"
+            .to_string(),
+    );
+    ko.insert(
+        "info.store.rotated".to_string(),
+        "키를 교체했습니다: {old}({old_state}) → {new}({new_state})".to_string(),
+    );
+    ko.insert(
+        "info.store.state".to_string(),
+        "키 {id}의 상태: {state}".to_string(),
+    );
+    ko.insert(
+        "info.store.expiry".to_string(),
+        "키 {id}의 만료 시각: {expires}".to_string(),
+    );
+    ko.insert("store.col.state".to_string(), "상태".to_string());
+    ko.insert("store.col.expires".to_string(), "만료 시각".to_string());
+    ko.insert("store.field.state".to_string(), "상태".to_string());
+    ko.insert("store.field.expires".to_string(), "만료 시각".to_string());
+    ko.insert("store.field.predecessor".to_string(), "이전 키".to_string());
+    ko.insert("store.field.successor".to_string(), "다음 키".to_string());
+    ko.insert(
+        "store.history.active".to_string(),
+        "사용 기간: {start} ~ {end}".to_string(),
+    );
+    ko.insert("store.expired".to_string(), "만료됨".to_string());
+    ko.insert("usage.lifecycle".to_string(), "- 저장소의 키는 active, pending, retired, compromised 상태를 가집니다. [-expires <시각|+90d>], [-pending]으로 저장하고, '{prog} rotate <키>'로 같은 배리언트의 새 키로 교체하며(이전 키는 retired), 'state <키> <상태>', 'expire <키> <시각|none>', 'history <키>', 'list -at <시각>'으로 관리·감사합니다.".to_string());
+    ko.insert(
+        "error.crypt.io".to_string(),
+        "입출력 도중 오류가 발생했습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.crypt.magic".to_string(),
+        "keypairer로 암호화된 파일이 아닙니다.".to_string(),
+    );
+    ko.insert(
+        "error.crypt.version".to_string(),
+        "지원하지 않는 암호화 형식 버전입니다: {version}".to_string(),
+    );
+    ko.insert(
+        "error.crypt.malformed".to_string(),
+        "암호화 헤더가 올바르지 않습니다: {what}".to_string(),
+    );
+    ko.insert(
+        "error.crypt.variant".to_string(),
+        "헤더의 알고리즘/배리언트를 알 수 없습니다: {alg} {variant}".to_string(),
+    );
+    ko.insert(
+        "error.crypt.kem".to_string(),
+        "KEM 연산에 실패했습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.crypt.auth".to_string(),
+        "복호화에 실패했습니다. 비밀키가 맞지 않거나 파일이 변조되었습니다.".to_string(),
+    );
+    ko.insert(
+        "error.crypt.truncated".to_string(),
+        "암호화 파일이 중간에 잘렸습니다.".to_string(),
+    );
+    ko.insert(
+        "error.crypt.recipient".to_string(),
+        "수신자를 -to <키 저장소 키> 또는 -pk <공개키 파일>로 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.crypt.secret".to_string(),
+        "비밀키를 -key <키 저장소 키> 또는 -sk <비밀키 파일> 중 하나로 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.crypt.tty".to_string(),
+        "암호문을 터미널에 출력할 수 없습니다. -out으로 파일을 지정하거나 파이프로 연결하세요."
+            .to_string(),
+    );
+    ko.insert(
+        "error.crypt.inactive".to_string(),
+        "사용 중이 아니거나 만료된 키로는 암호화할 수 없습니다: {id} ({state})".to_string(),
+    );
+    ko.insert(
+        "error.crypt.ambiguous_variant".to_string(),
+        "공개키 길이로 배리언트를 정할 수 없습니다({variants}). -alg와 -var를 지정하세요."
+            .to_string(),
+    );
+    ko.insert(
+        "error.crypt.not_kem".to_string(),
+        "KEM(ML-KEM, HQC, Classic McEliece) 공개키가 아닙니다: {name}".to_string(),
+    );
+    ko.insert(
+        "error.file.read".to_string(),
+        "파일을 읽는 도중 오류가 발생했습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.file.write".to_string(),
+        "파일에 쓰는 도중 오류가 발생했습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "info.crypt.encrypted".to_string(),
+        "암호화 완료: {bytes}바이트 → {path} (수신자: {recipients})".to_string(),
+    );
+    ko.insert(
+        "info.crypt.decrypted".to_string(),
+        "복호화 완료: {bytes}바이트 → {path} ({standard})".to_string(),
+    );
+    ko.insert("usage.encrypt".to_string(), "- '{prog} encrypt -to <키>|-pk <공개키> [-in <파일>] [-out <파일>]'로 KEM 공개키에 대해 파일을 암호화하고(기본 출력 <파일>.kpe), '{prog} decrypt -key <키>|-sk <비밀키> [-in <파일>] [-out <파일>]'로 복호화합니다. -to와 -pk는 여러 번 지정할 수 있으며, 배리언트가 다른 수신자 누구나 복호화할 수 있습니다. 공개키 파일의 배리언트는 -alg/-var, 메타데이터 사이드카, 키 길이 순으로 정합니다.".to_string());
+    ko.insert(
+        "error.crypt.no_recipient".to_string(),
+        "이 비밀키로 열 수 있는 수신자가 파일에 없습니다.".to_string(),
+    );
+    ko.insert(
+        "error.crypt.too_many".to_string(),
+        "수신자는 최대 {max}명까지 지정할 수 있습니다.".to_string(),
+    );
+    ko.insert(
+        "error.manifest.io".to_string(),
+        "파일을 처리하는 도중 오류가 발생했습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.manifest.name".to_string(),
+        "매니페스트에 기록할 수 없는 파일 이름입니다: {path}".to_string(),
+    );
+    ko.insert(
+        "error.manifest.parse".to_string(),
+        "매니페스트 {line}번째 줄을 해석할 수 없습니다.".to_string(),
+    );
+    ko.insert(
+        "error.manifest.empty".to_string(),
+        "매니페스트에 파일이 없습니다.".to_string(),
+    );
+    ko.insert(
+        "error.manifest.modified".to_string(),
+        "변경됨: {path}".to_string(),
+    );
+    ko.insert(
+        "error.manifest.missing".to_string(),
+        "없음: {path}".to_string(),
+    );
+    ko.insert(
+        "error.manifest.unreadable".to_string(),
+        "읽을 수 없음: {path} ({err})".to_string(),
+    );
+    ko.insert(
+        "error.manifest.failed".to_string(),
+        "매니페스트 검증 실패: 파일 {count}개에 문제가 있습니다.".to_string(),
+    );
+    ko.insert(
+        "warn.manifest.skipped".to_string(),
+        "일반 파일이 아니므로 건너뜁니다: {path}".to_string(),
+    );
+    ko.insert(
+        "warn.manifest.unlisted".to_string(),
+        "매니페스트에 없는 파일: {path}".to_string(),
+    );
+    ko.insert("info.manifest.signed".to_string(), "매니페스트 서명 완료: 파일 {count}개 → {manifest} (서명: {sig}, {standard}, 서명자: {signer})".to_string());
+    ko.insert("info.manifest.verified".to_string(), "매니페스트 검증 완료: 파일 {count}개 일치 ({standard}, 서명자: {signer}, 서명 시각: {created})".to_string());
+    ko.insert(
+        "error.sign.secret".to_string(),
+        "서명 키를 -key <키 저장소 키> 또는 -sk <비밀키 파일> 중 하나로 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.sign.public".to_string(),
+        "검증 키를 -key <키 저장소 키> 또는 -pk <공개키 파일> 중 하나로 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.sign.pair_mismatch".to_string(),
+        "비밀키와 공개키가 한 쌍이 아닙니다.".to_string(),
+    );
+    ko.insert(
+        "error.sign.format".to_string(),
+        "서명 파일 형식이 올바르지 않습니다: {path}".to_string(),
+    );
+    ko.insert(
+        "error.sign.compromised".to_string(),
+        "유출된 키의 서명은 신뢰할 수 없습니다: {id}".to_string(),
+    );
+    ko.insert(
+        "error.sign.invalid".to_string(),
+        "매니페스트 서명이 올바르지 않습니다: {path}".to_string(),
+    );
+    ko.insert(
+        "error.sign.inactive".to_string(),
+        "사용 중이 아니거나 만료된 키로는 서명할 수 없습니다: {id} ({state})".to_string(),
+    );
+    ko.insert(
+        "error.sign.ambiguous_variant".to_string(),
+        "비밀키 길이로 배리언트를 정할 수 없습니다({variants}). -alg와 -var를 지정하세요."
+            .to_string(),
+    );
+    ko.insert(
+        "error.sign.not_signature".to_string(),
+        "서명(ML-DSA, FALCON, SPHINCS+) 키가 아닙니다: {name}".to_string(),
+    );
+    ko.insert(
+        "error.sign.key_mismatch".to_string(),
+        "검증 키가 서명자({standard})와 일치하지 않습니다.".to_string(),
+    );
+    ko.insert(
+        "error.sign.failed".to_string(),
+        "서명 연산에 실패했습니다: {err}".to_string(),
+    );
+    ko.insert("usage.manifest".to_string(), "- '{prog} sign-manifest [-dir <디렉토리>] -key <키>|-sk <비밀키> [-pk <공개키>]'로 디렉토리의 모든 파일을 해시한 매니페스트(기본 SHA256SUMS)와 서명(<매니페스트>.sig)을 기록하고, '{prog} verify-manifest [-dir <디렉토리>] -key <키>|-pk <공개키> [-strict]'로 서명과 모든 파일을 검사합니다. -manifest, -sig로 경로를 바꿀 수 있습니다.".to_string());
+    ko.insert(
+        "error.share.threshold".to_string(),
+        "임계값과 조각 수는 2 ≤ 임계값 ≤ 조각 수 ≤ 255여야 합니다 (임계값 {k}, 조각 수 {n})."
+            .to_string(),
+    );
+    ko.insert(
+        "error.share.random".to_string(),
+        "난수를 생성할 수 없습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.share.malformed".to_string(),
+        "공유 조각 형식이 올바르지 않습니다: {what}".to_string(),
+    );
+    ko.insert(
+        "error.share.checksum".to_string(),
+        "공유 조각의 체크섬이 맞지 않습니다. 조각이 손상되었습니다.".to_string(),
+    );
+    ko.insert(
+        "error.share.mismatch".to_string(),
+        "서로 다른 분할에서 나온 조각은 함께 쓸 수 없습니다.".to_string(),
+    );
+    ko.insert(
+        "error.share.duplicate".to_string(),
+        "같은 조각이 두 번 주어졌습니다: {index}번".to_string(),
+    );
+    ko.insert(
+        "error.share.not_enough".to_string(),
+        "조각이 부족합니다: {have}개 (필요: {need}개)".to_string(),
+    );
+    ko.insert(
+        "error.share.count".to_string(),
+        "잘못된 개수: {value}".to_string(),
+    );
+    ko.insert(
+        "error.share.template".to_string(),
+        "조각 파일 경로에는 {n}이 포함되어야 합니다.".to_string(),
+    );
+    ko.insert(
+        "error.share.public".to_string(),
+        "복원한 키를 확인할 공개키를 -pk 또는 -key로 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.share.fingerprint".to_string(),
+        "공개키 지문이 조각에 기록된 지문과 다릅니다: {fp}".to_string(),
+    );
+    ko.insert(
+        "error.share.verify".to_string(),
+        "복원한 비밀키가 공개키와 한 쌍이 아닙니다. 조각이 잘못되었습니다.".to_string(),
+    );
+    ko.insert(
+        "error.share.need_public".to_string(),
+        "비밀키를 확인할 공개키를 -pk로 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.share.secret".to_string(),
+        "비밀키를 -key <키 저장소 키> 또는 -sk <비밀키 파일> 중 하나로 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.share.unknown_key".to_string(),
+        "키의 알고리즘/배리언트를 알 수 없습니다: {name}".to_string(),
+    );
+    ko.insert(
+        "info.share.split".to_string(),
+        "비밀키를 조각 {n}개로 나눴습니다 (복원에 {k}개 필요, {standard}, 지문 {fp}):".to_string(),
+    );
+    ko.insert(
+        "info.share.recombined".to_string(),
+        "조각 {count}개로 비밀키를 복원하고 공개키로 확인했습니다: {path} ({standard}, 지문 {fp})"
+            .to_string(),
+    );
+    ko.insert("usage.share".to_string(), "- '{prog} split -key <키>|-sk <비밀키> [-pk <공개키>] -n <조각 수> -k <임계값> [-out <경로{n}>]'로 비밀키를 샤미르 비밀 분산 조각(PEM)으로 나누고, '{prog} recombine <조각>... [-pk <공개키>|-key <키>] [-out <비밀키>] [-sktext]'로 임계값 이상의 조각에서 비밀키를 복원한 뒤 공개키로 확인합니다.".to_string());
+    ko.insert("error.paper.too_large".to_string(), "종이 백업으로 내보내기에는 비밀키가 너무 큽니다: {len}바이트 (최대 {max}바이트, split을 고려하세요)".to_string());
+    ko.insert(
+        "error.paper.header".to_string(),
+        "종이 백업 머리말을 해석할 수 없습니다: {what}".to_string(),
+    );
+    ko.insert(
+        "error.paper.lines".to_string(),
+        "체크섬이 맞지 않는 줄이 있습니다. 옮겨 적은 내용을 확인하세요:".to_string(),
+    );
+    ko.insert(
+        "error.paper.line_suggest".to_string(),
+        "  {line}번 줄 (혹시: {fixed})".to_string(),
+    );
+    ko.insert("error.paper.line".to_string(), "  {line}번 줄".to_string());
+    ko.insert(
+        "error.paper.missing".to_string(),
+        "빠진 줄이 있습니다: {lines}".to_string(),
+    );
+    ko.insert(
+        "error.paper.total".to_string(),
+        "전체 체크섬이 맞지 않습니다. 머리말(알고리즘, 배리언트, 길이)과 END 줄을 확인하세요."
+            .to_string(),
+    );
+    ko.insert(
+        "error.paper.format".to_string(),
+        "지원하지 않는 종이 백업 형식: {format} (text, svg)".to_string(),
+    );
+    ko.insert(
+        "error.paper.fingerprint".to_string(),
+        "공개키 지문이 종이 백업에 적힌 지문과 다릅니다: {fp}".to_string(),
+    );
+    ko.insert(
+        "error.paper.verify".to_string(),
+        "복원한 비밀키가 공개키와 한 쌍이 아닙니다. 옮겨 적은 내용을 확인하세요.".to_string(),
+    );
+    ko.insert("warn.paper.unverified".to_string(), "경고: 공개키가 없어 체크섬만 확인했습니다. -pk 또는 -key로 공개키를 지정하면 한 쌍인지도 확인합니다.".to_string());
+    ko.insert("info.paper.exported".to_string(), "비밀키 종이 백업을 만들었습니다: {path} ({standard}, {len}바이트, 지문 {fp}). 인쇄한 뒤 파일은 안전하게 삭제하세요.".to_string());
+    ko.insert(
+        "info.paper.imported".to_string(),
+        "종이 백업에서 비밀키를 복원했습니다: {path} ({standard}, 지문 {fp})".to_string(),
+    );
+    ko.insert("usage.paper".to_string(), "- '{prog} paper-export -key <키>|-sk <비밀키> [-pk <공개키>] [-format text|svg] [-out <파일>]'로 비밀키를 줄별 체크섬이 붙은 base32 종이 백업으로 내보내고, '{prog} paper-import -in <파일> [-pk <공개키>|-key <키>] [-out <비밀키>] [-sktext]'로 옮겨 적은 내용을 검사해 비밀키를 복원합니다.".to_string());
+    ko.insert("usage.entropy".to_string(), "- [-entropy os|file:<경로>|drbg:<16진수 시드>]로 키 생성에 쓸 난수 소스를 고릅니다(기본값 os). 생성 전에 SP 800-90B 반복 횟수·적응 비율 시작 검사를 수행하며, 실패하면 키를 만들지 않습니다. drbg는 재현 가능한 테스트 전용입니다.".to_string());
+    ko.insert(
+        "error.entropy.spec".to_string(),
+        "알 수 없는 난수 소스: {spec} (os, file:<경로>, drbg:<16진수 시드>)".to_string(),
+    );
+    ko.insert(
+        "error.entropy.seed".to_string(),
+        "DRBG 시드는 16바이트 이상의 16진수여야 합니다.".to_string(),
+    );
+    ko.insert("error.entropy.unsupported".to_string(), "이 빌드는 운영체제 난수 생성기만 사용할 수 있습니다. 다른 소스를 쓰려면 custom-entropy 기능과 RUSTFLAGS='--cfg getrandom_backend=\"custom\"'으로 빌드하세요.".to_string());
+    ko.insert(
+        "error.entropy.io".to_string(),
+        "난수 소스를 읽을 수 없습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.entropy.os".to_string(),
+        "운영체제 난수 생성기가 실패했습니다: {err}".to_string(),
+    );
+    ko.insert("error.entropy.rct".to_string(), "난수 소스 건강 검사 실패(반복 횟수 검사): 같은 값이 {run}번 연속 나왔습니다(기준 {cutoff}). 키 생성을 중단합니다.".to_string());
+    ko.insert("error.entropy.apt".to_string(), "난수 소스 건강 검사 실패(적응 비율 검사): {window}개 중 같은 값이 {count}번 나왔습니다(기준 {cutoff}). 키 생성을 중단합니다.".to_string());
+    ko.insert("warn.entropy.drbg".to_string(), "경고: 결정적 DRBG는 테스트 전용입니다. 시드를 아는 누구나 같은 키를 만들 수 있으므로 실제로 사용하지 마세요.".to_string());
+    ko.insert(
+        "info.entropy.selected".to_string(),
+        "난수 소스: {source} (시작 상태 검사 통과)".to_string(),
+    );
+    ko.insert("usage.config".to_string(), "- 설정 파일(기본값 ~/.config/keypairer/config.toml, -config 또는 KEYPAIRER_CONFIG로 변경)의 [defaults]와 [profiles.<이름>]에 algorithm, variant, encoding, pk_encoding, sk_encoding, outdir, lang 기본값을 둡니다. 설정 파일 → 프로필(-profile, KEYPAIRER_PROFILE) → KEYPAIRER_* 환경 변수 → 명령행 플래그 순으로 우선하며, '{prog} config show'로 적용되는 값과 출처를 확인합니다.".to_string());
+    ko.insert(
+        "error.encoding".to_string(),
+        "지원하지 않는 저장 형식: {encoding} (binary, pem)".to_string(),
+    );
+    ko.insert(
+        "error.config.io".to_string(),
+        "설정 파일을 읽을 수 없습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.config.parse".to_string(),
+        "설정 파일을 해석할 수 없습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.config.profile".to_string(),
+        "설정 파일에 없는 프로필입니다: {profile}".to_string(),
+    );
+    ko.insert(
+        "error.config.env".to_string(),
+        "환경 변수 {var}의 값을 해석할 수 없습니다: {value}".to_string(),
+    );
+    ko.insert(
+        "error.config.action".to_string(),
+        "알 수 없는 config 동작: {action} (show)".to_string(),
+    );
+    ko.insert(
+        "info.config.file".to_string(),
+        "설정 파일: {path}".to_string(),
+    );
+    ko.insert(
+        "info.config.no_file".to_string(),
+        "설정 파일 없음 (기본 경로: {path})".to_string(),
+    );
+    ko.insert(
+        "info.config.profile".to_string(),
+        "프로필: {profile}".to_string(),
+    );
+    ko.insert("config.source.default".to_string(), "기본값".to_string());
+    ko.insert(
+        "config.source.file".to_string(),
+        "설정 파일 [defaults]".to_string(),
+    );
+    ko.insert(
+        "config.source.profile".to_string(),
+        "프로필 [profiles.{profile}]".to_string(),
+    );
+    ko.insert(
+        "config.source.env".to_string(),
+        "환경 변수 {var}".to_string(),
+    );
+    ko.insert("config.source.cli".to_string(), "명령행 {flag}".to_string());
+    ko.insert("config.source.unset".to_string(), "설정 없음".to_string());
+    ko.insert("usage.suite".to_string(), "- [-suite <묶음>]으로 KEM 키 페어와 서명 키 페어를 함께 생성합니다(<묶음>-kem.*, <묶음>-sig.*, 인덱스 <묶음>-index.json). cnsa2 = ML-KEM-1024 + ML-DSA-87, nist-l1 = ML-KEM-512 + ML-DSA-44, nist-l3 = ML-KEM-768 + ML-DSA-65, conservative = Classic-McEliece-6688128 + SPHINCS+-SHAKE-256s-simple, compact = ML-KEM-512 + Falcon-512".to_string());
+    ko.insert(
+        "error.suite.unknown".to_string(),
+        "알 수 없는 보안 묶음: {suite} (사용 가능: {suites})".to_string(),
+    );
+    ko.insert(
+        "error.suite.exclusive".to_string(),
+        "-suite는 -alg, -var, -count, -manifest와 함께 사용할 수 없습니다.".to_string(),
+    );
+    ko.insert(
+        "info.suite.selected".to_string(),
+        "보안 묶음 {suite}: {pairs}".to_string(),
+    );
+    ko.insert("usage.policy".to_string(), "- [-policy <정책>] 또는 설정 파일의 [policy]로 키 생성을 제한합니다. 정책은 strict(= min-category=3,standard-only,no-sktext), min-category=<1-5>(최소 NIST 보안 범주), standard-only(HQC, McEliece, FALCON, SPHINCS+ 금지), no-sktext(비밀키 텍스트 저장 금지)를 쉼표로 이어 지정하며, 여러 곳의 정책은 가장 엄격한 쪽으로 합쳐집니다.".to_string());
+    ko.insert("error.policy.category".to_string(), "정책 위반: {name}은(는) NIST 보안 범주 {category}로, 정책이 요구하는 최소 범주 {min}보다 낮습니다.".to_string());
+    ko.insert(
+        "error.policy.nonstandard".to_string(),
+        "정책 위반: {name}은(는) NIST 표준으로 확정되지 않은 알고리즘입니다 (standard-only)."
+            .to_string(),
+    );
+    ko.insert(
+        "error.policy.sktext".to_string(),
+        "정책 위반: 비밀키를 텍스트(PEM)로 저장할 수 없습니다 (no-sktext).".to_string(),
+    );
+    ko.insert(
+        "error.policy.spec".to_string(),
+        "알 수 없는 정책 항목: {item} (strict, none, min-category=<1-5>, standard-only, no-sktext)"
+            .to_string(),
+    );
+    ko.insert(
+        "config.source.policy".to_string(),
+        "설정 파일 [policy]".to_string(),
+    );
+    ko.insert("usage.bench".to_string(), "- '{prog} bench [-alg <알고리즘> [-var <배리언트>]] [-n <반복 횟수>] [-time <초>] [-format table|json] [-out <파일>]'로 모든 배리언트(또는 지정한 것)의 키 생성과 캡슐화·역캡슐화 또는 서명·검증 시간의 중앙값·p95와 키·암호문·서명 크기를 측정합니다. 연산마다 최대 반복 횟수(기본값 20)와 시간 예산(기본값 2초) 중 먼저 닿는 쪽에서 멈추며, --release로 빌드한 바이너리로 측정하세요.".to_string());
+    ko.insert(
+        "error.bench.format".to_string(),
+        "지원하지 않는 벤치마크 출력 형식: {format} (table, json)".to_string(),
+    );
+    ko.insert(
+        "error.bench.iterations".to_string(),
+        "잘못된 반복 횟수: {value}".to_string(),
+    );
+    ko.insert(
+        "error.bench.time".to_string(),
+        "잘못된 시간 예산(초): {value}".to_string(),
+    );
+    ko.insert(
+        "error.bench.failed".to_string(),
+        "{standard} 측정 실패: {err}".to_string(),
+    );
+    ko.insert(
+        "warn.bench.debug".to_string(),
+        "경고: 디버그 빌드입니다. 비교할 수치는 --release로 빌드해서 측정하세요.".to_string(),
+    );
+    ko.insert(
+        "info.bench.progress".to_string(),
+        "[{done}/{total}] {standard} 측정 중".to_string(),
+    );
+    ko.insert("bench.column.variant".to_string(), "배리언트".to_string());
+    ko.insert("bench.column.category".to_string(), "범주".to_string());
+    ko.insert(
+        "bench.column.keygen".to_string(),
+        "키 생성 (중앙값/p95)".to_string(),
+    );
+    ko.insert(
+        "bench.column.encaps_sign".to_string(),
+        "캡슐화·서명".to_string(),
+    );
+    ko.insert(
+        "bench.column.decaps_verify".to_string(),
+        "역캡슐화·검증".to_string(),
+    );
+    ko.insert("bench.column.pk".to_string(), "공개키".to_string());
+    ko.insert("bench.column.sk".to_string(), "비밀키".to_string());
+    ko.insert("bench.column.ct_sig".to_string(), "암호문·서명".to_string());
+    ko.insert("usage.stack".to_string(), "- 키 생성 스레드는 배리언트마다 필요한 만큼의 스택(ML-KEM·HQC·SPHINCS+ 1 MiB, FALCON·ML-DSA 512 KiB, Classic McEliece 4–24 MiB)으로 실행합니다. [-stack-size <크기>] 또는 KEYPAIRER_STACK_SIZE(예: 512K, 8M, 최소 64K)로 모든 배리언트의 스택 크기를 덮어씁니다.".to_string());
+    ko.insert(
+        "error.stack.size".to_string(),
+        "잘못된 스택 크기: {value} (예: 512K, 8M, 최소 64K)".to_string(),
+    );
+    ko.insert(
+        "error.executor.panicked".to_string(),
+        "키 생성 작업에서 패닉이 발생했습니다.".to_string(),
+    );
+    ko.insert(
+        "error.executor.shutdown".to_string(),
+        "키 생성 실행기가 종료되어 작업을 처리할 수 없습니다.".to_string(),
+    );
+    ko.insert(
+        "error.executor.queue_full".to_string(),
+        "키 생성 대기열이 가득 찼습니다.".to_string(),
+    );
+    ko.insert(
+        "error.executor.unavailable".to_string(),
+        "키 생성 워커 스레드를 만들 수 없습니다.".to_string(),
+    );
+    ko.insert("usage.pool".to_string(), "- '{prog} pool serve -size <알고리즘>[:<배리언트>]=<개수>,... [-dir <디렉토리>] [-socket <경로>] -key-file <파일> [-jobs <개수>]'는 배리언트마다 지정한 수의 키 페어를 암호화된 디스크 풀에 미리 만들어 두고 유닉스 소켓으로 내주며 백그라운드에서 보충합니다. 풀 키 파일은 풀 디렉토리 밖에 있어야 합니다. '{prog} pool take -alg <알고리즘> [-var <배리언트>] [-pkpath <경로>] [-skpath <경로>]'로 키 페어를 하나 꺼내고(풀이 비었으면 요청 시 생성), '{prog} pool status [-json]'으로 남은 개수를 확인합니다. 소켓 기본값은 KEYPAIRER_POOL_SOCKET → $XDG_RUNTIME_DIR/keypairer-pool.sock → <풀 디렉토리>/keypairer-pool.sock입니다.".to_string());
+    ko.insert(
+        "error.pool.spec".to_string(),
+        "잘못된 풀 크기 지정: {value} (예: mlkem:768=4,mceliece:6688128=2)".to_string(),
+    );
+    ko.insert(
+        "error.pool.io".to_string(),
+        "키 풀 입출력 오류: {err}".to_string(),
+    );
+    ko.insert(
+        "error.pool.corrupt".to_string(),
+        "키 풀 파일이 손상되었거나 풀 키와 맞지 않습니다: {path}".to_string(),
+    );
+    ko.insert(
+        "error.pool.running".to_string(),
+        "키 풀 서버가 이미 실행 중입니다: {path}".to_string(),
+    );
+    ko.insert(
+        "error.pool.not_running".to_string(),
+        "키 풀 서버에 연결할 수 없습니다: {path} ('pool serve'로 시작하세요)".to_string(),
+    );
+    ko.insert(
+        "error.pool.generate".to_string(),
+        "키 생성 실패: {err}".to_string(),
+    );
+    ko.insert(
+        "error.pool.protocol".to_string(),
+        "키 풀 서버의 응답을 해석할 수 없습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.pool.not_configured".to_string(),
+        "키 풀 서버가 이 배리언트를 다루지 않습니다: {err}".to_string(),
+    );
+    ko.insert(
+        "error.pool.server".to_string(),
+        "키 풀 서버 오류: {err}".to_string(),
+    );
+    ko.insert(
+        "error.pool.no_dir".to_string(),
+        "키 풀 디렉토리를 정할 수 없습니다. -dir 또는 KEYPAIRER_HOME을 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "info.pool.serving".to_string(),
+        "키 풀을 시작했습니다: {socket} (디렉토리: {dir}, 워커 {jobs}개)".to_string(),
+    );
+    ko.insert(
+        "info.pool.refilled".to_string(),
+        "{standard} 보충: {available}개".to_string(),
+    );
+    ko.insert(
+        "info.pool.served".to_string(),
+        "{standard} 전달: 남은 키 {remaining}개".to_string(),
+    );
+    ko.insert(
+        "info.pool.served_fresh".to_string(),
+        "{standard} 전달: 풀이 비어 요청 시 생성".to_string(),
+    );
+    ko.insert(
+        "info.pool.taken".to_string(),
+        "풀에서 {standard} 키를 꺼냈습니다 (남은 키 {remaining}개)".to_string(),
+    );
+    ko.insert(
+        "info.pool.taken_fresh".to_string(),
+        "풀이 비어 있어 {standard} 키를 새로 생성했습니다".to_string(),
+    );
+    ko.insert("usage.serve".to_string(), "- '{prog} serve [-bind <주소:포트>] [-store-dir <디렉토리>] [-token-file <파일>] [-jobs <개수>]'는 키 생성, 저장된 키와 공개키 조회, 캡슐화·역캡슐화, 서명·검증을 JSON HTTP API(/v1/...)로 제공합니다. 기본 주소는 127.0.0.1:7878이며 루프백 주소에만 바인딩할 수 있고, 항상 'Authorization: Bearer <토큰>'을 요구합니다. [-token-file]이 없으면 임의의 토큰을 만들어 $XDG_RUNTIME_DIR 또는 키 저장소 아래 keypairer-serve-<포트>.token(0o600)에 저장합니다.".to_string());
+    ko.insert(
+        "error.serve.token".to_string(),
+        "토큰 파일을 읽을 수 없습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.serve.bind".to_string(),
+        "주소에 바인딩할 수 없습니다({addr}): {err}".to_string(),
+    );
+    ko.insert(
+        "error.serve.remote".to_string(),
+        "키 서비스는 루프백 주소(127.0.0.1, ::1)에만 바인딩할 수 있습니다: {addr}".to_string(),
+    );
+    ko.insert(
+        "error.serve.io".to_string(),
+        "키 서비스 연결 오류: {err}".to_string(),
+    );
+    ko.insert(
+        "info.serve.listening".to_string(),
+        "키 서비스를 시작했습니다: http://{addr} (워커 {jobs}개)".to_string(),
+    );
+    ko.insert(
+        "info.config.entropy_pluggable".to_string(),
+        "난수 소스 선택(-entropy): 사용 가능".to_string(),
+    );
+    ko.insert("info.config.entropy_os_only".to_string(), "난수 소스 선택(-entropy): 사용 불가, 이 빌드는 custom-entropy 기능과 getrandom_backend=\"custom\" 없이 컴파일되어 os만 지원".to_string());
+    ko.insert("error.pool.key_file".to_string(), "-key-file로 풀 디렉토리 밖의 풀 키 파일을 지정하세요 (없으면 만들어 0o600으로 저장합니다).".to_string());
+    ko.insert(
+        "error.pool.key_inside".to_string(),
+        "풀 키 파일은 풀 디렉토리 밖에 두어야 합니다: {path}".to_string(),
+    );
+    ko.insert(
+        "error.pool.forbidden".to_string(),
+        "다른 사용자(uid {uid})의 연결을 거부했습니다".to_string(),
+    );
+    ko.insert(
+        "error.serve.token_path".to_string(),
+        "인증 토큰을 둘 위치를 정할 수 없습니다. -token-file을 지정하세요.".to_string(),
+    );
+    ko.insert(
+        "error.serve.token_write".to_string(),
+        "인증 토큰을 저장할 수 없습니다({path}): {err}".to_string(),
+    );
+    ko.insert(
+        "info.serve.token".to_string(),
+        "인증 토큰을 만들었습니다: {path} ('Authorization: Bearer <파일 내용>'으로 요청하세요)"
+            .to_string(),
+    );
+    ko.insert(
+        "error.crypt.encrypt".to_string(),
+        "암호화에 실패했습니다.".to_string(),
+    );
+    ko.insert(
+        "error.pool.busy".to_string(),
+        "키 풀 서버가 처리 중인 연결이 한도({max}개)에 도달했습니다. 잠시 뒤 다시 시도하세요"
+            .to_string(),
+    );
     if lang == "en" {
-        if let Ok(text) = std::fs::read_to_string("i18n/en.json") {
-            if let Ok(map) = serde_json::from_str::<HashMap<String, String>>(&text) {
-                return map;
-            }
+        if let Ok(text) = std::fs::read_to_string("i18n/en.json")
+            && let Ok(map) = serde_json::from_str::<HashMap<String, String>>(&text)
+        {
+            return map;
         }
         // 내장 en 폴백
         let mut en: HashMap<String, String> = HashMap::new();
         en.insert(
-            "error.missing_alg".to_string(),
-            "Missing required argument: -alg <algorithm>".to_string(),
+            "error.missing_alg".to_string(),
+            "Missing required argument: -alg <algorithm>".to_string(),
+        );
+        en.insert(
+            "error.unknown_algorithm".to_string(),
+            "Unknown algorithm: {alg}".to_string(),
+        );
+        en.insert(
+            "error.unknown_arg".to_string(),
+            "Unknown argument: {arg}".to_string(),
+        );
+        en.insert(
+            "error.file.create_pk".to_string(),
+            "Error while creating public key file: {err}".to_string(),
+        );
+        en.insert(
+            "error.file.write_pk".to_string(),
+            "Error while writing to public key file: {err}".to_string(),
+        );
+        en.insert(
+            "error.file.create_sk".to_string(),
+            "Error while creating secret key file: {err}".to_string(),
+        );
+        en.insert(
+            "error.file.write_sk".to_string(),
+            "Error while writing to secret key file: {err}".to_string(),
+        );
+        en.insert(
+            "error.large_stack_create".to_string(),
+            "An error occurred while creating a large stack thread: {err}".to_string(),
+        );
+        en.insert(
+            "error.large_stack_panic".to_string(),
+            "Unknown panic occurred in large-stack thread.".to_string(),
+        );
+        en.insert(
+            "info.generated".to_string(),
+            "Generated key pair for '{alg}' (variant: {var}).".to_string(),
+        );
+        en.insert(
+            "info.pk_saved".to_string(),
+            "Public key saved: {path}".to_string(),
+        );
+        en.insert(
+            "info.sk_saved".to_string(),
+            "Secret key saved: {path}".to_string(),
+        );
+        en.insert(
+            "info.pk_preview".to_string(),
+            "Public key (base64) preview:".to_string(),
+        );
+        en.insert("usage".to_string(), "Usage: {prog} [gen] -alg <algorithm> [-var | -variant <variant>] [-pkpath <public key path>] [-skpath <secret key path>] [-pkt | -pktext] [-skt | -sktext] [-encoding <binary|pem>] [-f | -force] [-meta] [-count <n> | -manifest <file> | -suite <suite>] [-jobs <n>] [-index <path>] [-outdir <dir>] [-store [-label <label>] [-tag <tag>] [-expires <time>] [-pending] [-store-dir <dir>]] [-entropy <source>] [-stack-size <size>] [-config <file>] [-profile <profile>] [-policy <policy>] [-l | -lang <i18n lang pack>]".to_string());
+        en.insert(
+            "usage.supported".to_string(),
+            "Supported algorithms: mlkem, hqc, mceliece, falcon, mldsa, sphincs+".to_string(),
+        );
+        en.insert(
+            "usage.req".to_string(),
+            "- Algorithm is required. Variant defaults to the minimum per algorithm.".to_string(),
+        );
+        en.insert("usage.paths".to_string(), "- If paths are omitted, files are saved to the current directory as '<algorithm>.pub' / '<algorithm>.sk'. You may optionally specify extensions.".to_string());
+        en.insert(
+            "usage.text".to_string(),
+            "- Add [-pktext] or [-sktext] to save public/secret keys in a PEM-like text format."
+                .to_string(),
+        );
+        en.insert(
+            "usage.lang".to_string(),
+            "- Set output locale with [-lang]. Default is ko; en supported.".to_string(),
+        );
+        en.insert(
+            "error.unknown_variant".to_string(),
+            "Unknown variant: {var} (algorithm: {alg})".to_string(),
+        );
+        en.insert(
+            "error.keygen".to_string(),
+            "Error while generating key pair: {err}".to_string(),
+        );
+        en.insert(
+            "error.file.exists".to_string(),
+            "File already exists: {path} (use -force to overwrite)".to_string(),
+        );
+        en.insert(
+            "info.rollback_pk".to_string(),
+            "Rolled back the public key file because saving the secret key failed: {path}"
+                .to_string(),
+        );
+        en.insert(
+            "usage.force".to_string(),
+            "- Existing key files are never overwritten unless [-force] is given.".to_string(),
+        );
+        en.insert(
+            "error.file.read_key".to_string(),
+            "Error while reading key file ({path}): {err}".to_string(),
+        );
+        en.insert("usage.stdio".to_string(), "- Use '-' as a path to write to stdout; PEM is forced for terminals or when both keys go to stdout.".to_string());
+        en.insert(
+            "error.batch.exclusive".to_string(),
+            "-count and -manifest cannot be used together.".to_string(),
+        );
+        en.insert(
+            "error.batch.stdout".to_string(),
+            "Standard output ('-') cannot be used in batch generation.".to_string(),
+        );
+        en.insert(
+            "error.batch.template".to_string(),
+            "Batch path templates must contain one of {id}, {n} or {fp}: {template}".to_string(),
+        );
+        en.insert(
+            "error.batch.jobs".to_string(),
+            "Invalid worker count: {value}".to_string(),
+        );
+        en.insert(
+            "error.batch.count".to_string(),
+            "Invalid count: {value}".to_string(),
+        );
+        en.insert(
+            "error.batch.failed".to_string(),
+            "[{done}/{total}] {id} failed: {err}".to_string(),
+        );
+        en.insert(
+            "error.batch.lost".to_string(),
+            "The worker exited without reporting a result.".to_string(),
+        );
+        en.insert(
+            "error.batch.index".to_string(),
+            "Error while writing index file: {err}".to_string(),
+        );
+        en.insert(
+            "error.batch.manifest_read".to_string(),
+            "Error while reading manifest ({path}): {err}".to_string(),
+        );
+        en.insert(
+            "error.batch.manifest_line".to_string(),
+            "Cannot parse manifest line {line}: {reason}".to_string(),
+        );
+        en.insert(
+            "error.batch.manifest_empty".to_string(),
+            "Manifest contains no entries: {path}".to_string(),
+        );
+        en.insert(
+            "error.batch.invalid_id".to_string(),
+            "Invalid ID: {id}".to_string(),
+        );
+        en.insert(
+            "error.batch.duplicate_id".to_string(),
+            "Duplicate ID: {id}".to_string(),
+        );
+        en.insert(
+            "info.batch.progress".to_string(),
+            "[{done}/{total}] {id}: {fp}".to_string(),
+        );
+        en.insert(
+            "info.batch.done".to_string(),
+            "Batch generation finished: {ok} succeeded, {failed} failed. Index: {path}".to_string(),
+        );
+        en.insert("usage.batch".to_string(), "- Use [-count] or [-manifest] to generate many key pairs on a worker pool. Path templates must contain one of {id}, {n} or {fp}; an index file mapping IDs to fingerprints (default index.json) is written.".to_string());
+        en.insert(
+            "error.file.create_dir".to_string(),
+            "Error while creating directory ({path}): {err}".to_string(),
+        );
+        en.insert("usage.template".to_string(), "- Paths may use the placeholders {alg}, {var}, {fp} (first 6 hex digits of the fingerprint), {date}, {n} and {id}; with [-outdir] files are placed under that directory. Missing directories are created with 0o700 permissions.".to_string());
+        en.insert(
+            "error.meta.write".to_string(),
+            "Error while writing metadata file ({path}): {err}".to_string(),
+        );
+        en.insert(
+            "error.meta.stdout".to_string(),
+            "-meta cannot be used when both keys are written to standard output.".to_string(),
+        );
+        en.insert(
+            "info.meta_saved".to_string(),
+            "Metadata saved: {path}".to_string(),
+        );
+        en.insert("usage.meta".to_string(), "- With [-meta], a '<name>.json' metadata file recording algorithm, variant, creation time, fingerprint, encodings, tool and pqcrypto backend versions is written alongside the keys.".to_string());
+        en.insert(
+            "error.store.io".to_string(),
+            "Error while accessing the keystore: {err}".to_string(),
+        );
+        en.insert(
+            "error.store.corrupt".to_string(),
+            "Cannot parse the keystore index: {err}".to_string(),
+        );
+        en.insert(
+            "error.store.duplicate".to_string(),
+            "A key with the same fingerprint is already stored: {id}".to_string(),
+        );
+        en.insert(
+            "error.store.label_taken".to_string(),
+            "Label is already in use: {label}".to_string(),
+        );
+        en.insert(
+            "error.store.not_found".to_string(),
+            "No key matches: {query}".to_string(),
+        );
+        en.insert(
+            "error.store.ambiguous".to_string(),
+            "Query matches several keys: {ids}".to_string(),
+        );
+        en.insert(
+            "error.store.locked".to_string(),
+            "The keystore is in use by another process (lock file: {path})".to_string(),
+        );
+        en.insert(
+            "error.store.no_home".to_string(),
+            "Cannot determine the keystore location. Set -store-dir or KEYPAIRER_HOME.".to_string(),
+        );
+        en.insert(
+            "error.store.missing_query".to_string(),
+            "Specify the target key (ID, ID prefix, fingerprint or label).".to_string(),
+        );
+        en.insert("error.store.exclusive".to_string(), "-store cannot be combined with -pkpath, -skpath, -outdir, -meta, -count, -manifest or -suite.".to_string());
+        en.insert(
+            "info.store.added".to_string(),
+            "Stored in keystore: {id} ({path})".to_string(),
+        );
+        en.insert(
+            "info.store.empty".to_string(),
+            "No keys stored: {path}".to_string(),
+        );
+        en.insert(
+            "info.store.exported".to_string(),
+            "Exported key: {id}".to_string(),
+        );
+        en.insert(
+            "info.store.deleted".to_string(),
+            "Deleted key: {id}".to_string(),
+        );
+        en.insert(
+            "info.store.delete_cancelled".to_string(),
+            "Deletion cancelled.".to_string(),
+        );
+        en.insert(
+            "prompt.store.delete".to_string(),
+            "Delete key {id} ({label})? The secret key cannot be recovered. Continue? [y/N] "
+                .to_string(),
+        );
+        en.insert("store.col.id".to_string(), "ID".to_string());
+        en.insert("store.col.algorithm".to_string(), "ALGORITHM".to_string());
+        en.insert("store.col.variant".to_string(), "VARIANT".to_string());
+        en.insert("store.col.label".to_string(), "LABEL".to_string());
+        en.insert("store.col.tags".to_string(), "TAGS".to_string());
+        en.insert("store.col.created".to_string(), "CREATED".to_string());
+        en.insert("store.field.id".to_string(), "ID".to_string());
+        en.insert("store.field.label".to_string(), "Label".to_string());
+        en.insert("store.field.tags".to_string(), "Tags".to_string());
+        en.insert("store.field.algorithm".to_string(), "Algorithm".to_string());
+        en.insert("store.field.variant".to_string(), "Variant".to_string());
+        en.insert(
+            "store.field.standard".to_string(),
+            "Standard name".to_string(),
+        );
+        en.insert(
+            "store.field.fingerprint".to_string(),
+            "Fingerprint".to_string(),
+        );
+        en.insert("store.field.created".to_string(), "Created".to_string());
+        en.insert(
+            "store.field.public_key".to_string(),
+            "Public key file".to_string(),
+        );
+        en.insert(
+            "store.field.secret_key".to_string(),
+            "Secret key file".to_string(),
+        );
+        en.insert("store.field.backend".to_string(), "Backend".to_string());
+        en.insert("usage.store".to_string(), "- [-store] saves into the keystore (default ~/.local/share/keypairer, override with KEYPAIRER_HOME) with optional [-label] and [-tag]. Manage it with '{prog} list|show|export|delete <ID|label>'. delete overwrites the secret key before removing it.".to_string());
+        en.insert(
+            "error.store.transition".to_string(),
+            "Cannot change key {id} from {from} to {to}.".to_string(),
+        );
+        en.insert(
+            "error.store.already_rotated".to_string(),
+            "Key {id} has already been rotated to {next}.".to_string(),
+        );
+        en.insert(
+            "error.store.state".to_string(),
+            "Unknown state: {state} (active, pending, retired, compromised)".to_string(),
+        );
+        en.insert(
+            "error.time".to_string(),
+            "Cannot parse time: {time} (e.g. 2025-12-31, 2025-12-31T09:00:00Z, +90d)".to_string(),
+        );
+        en.insert(
+            "info.store.rotated".to_string(),
+            "Rotated key: {old} ({old_state}) -> {new} ({new_state})".to_string(),
+        );
+        en.insert(
+            "info.store.state".to_string(),
+            "Key {id} is now {state}".to_string(),
+        );
+        en.insert(
+            "info.store.expiry".to_string(),
+            "Key {id} expires: {expires}".to_string(),
+        );
+        en.insert("store.col.state".to_string(), "STATE".to_string());
+        en.insert("store.col.expires".to_string(), "EXPIRES".to_string());
+        en.insert("store.field.state".to_string(), "State".to_string());
+        en.insert("store.field.expires".to_string(), "Expires".to_string());
+        en.insert(
+            "store.field.predecessor".to_string(),
+            "Predecessor".to_string(),
+        );
+        en.insert("store.field.successor".to_string(), "Successor".to_string());
+        en.insert(
+            "store.history.active".to_string(),
+            "Active: {start} - {end}".to_string(),
+        );
+        en.insert("store.expired".to_string(), "expired".to_string());
+        en.insert("usage.lifecycle".to_string(), "- Stored keys are active, pending, retired or compromised. Save with [-expires <time|+90d>] and [-pending]; '{prog} rotate <key>' replaces a key with a new one of the same variant (the old one is retired). Use 'state <key> <state>', 'expire <key> <time|none>', 'history <key>' and 'list -at <time>' to manage and audit.".to_string());
+        en.insert("error.crypt.io".to_string(), "I/O error: {err}".to_string());
+        en.insert(
+            "error.crypt.magic".to_string(),
+            "Not a keypairer encrypted file.".to_string(),
+        );
+        en.insert(
+            "error.crypt.version".to_string(),
+            "Unsupported encryption format version: {version}".to_string(),
+        );
+        en.insert(
+            "error.crypt.malformed".to_string(),
+            "Malformed encryption header: {what}".to_string(),
+        );
+        en.insert(
+            "error.crypt.variant".to_string(),
+            "Unknown algorithm/variant in header: {alg} {variant}".to_string(),
+        );
+        en.insert(
+            "error.crypt.kem".to_string(),
+            "KEM operation failed: {err}".to_string(),
+        );
+        en.insert(
+            "error.crypt.auth".to_string(),
+            "Decryption failed: wrong secret key or the file was tampered with.".to_string(),
+        );
+        en.insert(
+            "error.crypt.truncated".to_string(),
+            "The encrypted file is truncated.".to_string(),
         );
         en.insert(
-            "error.unknown_algorithm".to_string(),
-            "Unknown algorithm: {alg}".to_string(),
+            "error.crypt.recipient".to_string(),
+            "Specify recipients with -to <keystore key> or -pk <public key file>.".to_string(),
         );
         en.insert(
-            "error.unknown_arg".to_string(),
-            "Unknown argument: {arg}".to_string(),
+            "error.crypt.secret".to_string(),
+            "Specify the secret key with either -key <keystore key> or -sk <secret key file>."
+                .to_string(),
         );
         en.insert(
-            "error.file.create_pk".to_string(),
-            "Error while creating public key file: {err}".to_string(),
+            "error.crypt.tty".to_string(),
+            "Refusing to write ciphertext to a terminal. Use -out or a pipe.".to_string(),
         );
         en.insert(
-            "error.file.write_pk".to_string(),
-            "Error while writing to public key file: {err}".to_string(),
+            "error.crypt.inactive".to_string(),
+            "Cannot encrypt to a key that is not active or has expired: {id} ({state})".to_string(),
         );
+        en.insert("error.crypt.ambiguous_variant".to_string(), "Cannot tell the variant from the public key length ({variants}). Specify -alg and -var.".to_string());
         en.insert(
-            "error.file.create_sk".to_string(),
-            "Error while creating secret key file: {err}".to_string(),
+            "error.crypt.not_kem".to_string(),
+            "Not a KEM (ML-KEM, HQC, Classic McEliece) public key: {name}".to_string(),
         );
         en.insert(
-            "error.file.write_sk".to_string(),
-            "Error while writing to secret key file: {err}".to_string(),
+            "error.file.read".to_string(),
+            "Error while reading file ({path}): {err}".to_string(),
         );
         en.insert(
-            "error.large_stack_create".to_string(),
-            "An error occurred while creating a large stack thread: {err}".to_string(),
+            "error.file.write".to_string(),
+            "Error while writing file ({path}): {err}".to_string(),
         );
         en.insert(
-            "error.large_stack_panic".to_string(),
-            "Unknown panic occurred in large-stack thread.".to_string(),
+            "info.crypt.encrypted".to_string(),
+            "Encrypted {bytes} bytes -> {path} (recipients: {recipients})".to_string(),
         );
         en.insert(
-            "info.generated".to_string(),
-            "Generated key pair for '{alg}' (variant: {var}).".to_string(),
+            "info.crypt.decrypted".to_string(),
+            "Decrypted {bytes} bytes -> {path} ({standard})".to_string(),
         );
+        en.insert("usage.encrypt".to_string(), "- '{prog} encrypt -to <key>|-pk <public key> [-in <file>] [-out <file>]' encrypts a file to KEM public keys (default output <file>.kpe); '{prog} decrypt -key <key>|-sk <secret key> [-in <file>] [-out <file>]' decrypts it. -to and -pk can be repeated, and any recipient can decrypt regardless of variant. The variant of a public key file comes from -alg/-var, its metadata sidecar, or the key length.".to_string());
         en.insert(
-            "info.pk_saved".to_string(),
-            "Public key saved: {path}".to_string(),
+            "error.crypt.no_recipient".to_string(),
+            "The file has no recipient that this secret key can open.".to_string(),
         );
         en.insert(
-            "info.sk_saved".to_string(),
-            "Secret key saved: {path}".to_string(),
+            "error.crypt.too_many".to_string(),
+            "At most {max} recipients can be specified.".to_string(),
         );
         en.insert(
-            "info.pk_preview".to_string(),
-            "Public key (base64) preview:".to_string(),
+            "error.manifest.io".to_string(),
+            "Error while processing file ({path}): {err}".to_string(),
         );
-        en.insert("usage".to_string(), "Usage: {prog} -alg <algorithm> [-var | -variant <variant>] [-pkpath <public key path>] [-skpath <secret key path>] [-pkt | -pktext] [-skt | -sktext] [-lang <ko|en>]".to_string());
         en.insert(
-            "usage.supported".to_string(),
-            "Supported algorithms: mlkem, hqc, mceliece, falcon, mldsa, sphincs+".to_string(),
+            "error.manifest.name".to_string(),
+            "File name cannot be recorded in a manifest: {path}".to_string(),
         );
         en.insert(
-            "usage.req".to_string(),
-            "- Algorithm is required. Variant defaults to the minimum per algorithm.".to_string(),
+            "error.manifest.parse".to_string(),
+            "Cannot parse manifest line {line}.".to_string(),
         );
-        en.insert("usage.paths".to_string(), "- If paths are omitted, files are saved to the current directory as '<algorithm>.pub' / '<algorithm>.sk'. You may optionally specify extensions.".to_string());
         en.insert(
-            "usage.text".to_string(),
-            "- Add [-pktext] or [-sktext] to save public/secret keys in a PEM-like text format."
+            "error.manifest.empty".to_string(),
+            "The manifest has no files.".to_string(),
+        );
+        en.insert(
+            "error.manifest.modified".to_string(),
+            "Modified: {path}".to_string(),
+        );
+        en.insert(
+            "error.manifest.missing".to_string(),
+            "Missing: {path}".to_string(),
+        );
+        en.insert(
+            "error.manifest.unreadable".to_string(),
+            "Unreadable: {path} ({err})".to_string(),
+        );
+        en.insert(
+            "error.manifest.failed".to_string(),
+            "Manifest verification failed: {count} file(s) have problems.".to_string(),
+        );
+        en.insert(
+            "warn.manifest.skipped".to_string(),
+            "Skipping non-regular file: {path}".to_string(),
+        );
+        en.insert(
+            "warn.manifest.unlisted".to_string(),
+            "File not in manifest: {path}".to_string(),
+        );
+        en.insert("info.manifest.signed".to_string(), "Signed manifest: {count} file(s) -> {manifest} (signature: {sig}, {standard}, signer: {signer})".to_string());
+        en.insert("info.manifest.verified".to_string(), "Manifest verified: {count} file(s) match ({standard}, signer: {signer}, signed at: {created})".to_string());
+        en.insert(
+            "error.sign.secret".to_string(),
+            "Specify the signing key with either -key <keystore key> or -sk <secret key file>."
                 .to_string(),
         );
+        en.insert("error.sign.public".to_string(), "Specify the verification key with either -key <keystore key> or -pk <public key file>.".to_string());
         en.insert(
-            "usage.lang".to_string(),
-            "- Set output locale with [-lang]. Default is ko; en supported.".to_string(),
+            "error.sign.pair_mismatch".to_string(),
+            "The secret key and public key are not a pair.".to_string(),
+        );
+        en.insert(
+            "error.sign.format".to_string(),
+            "Malformed signature file: {path}".to_string(),
+        );
+        en.insert(
+            "error.sign.compromised".to_string(),
+            "Signatures from a compromised key are not trusted: {id}".to_string(),
+        );
+        en.insert(
+            "error.sign.invalid".to_string(),
+            "Invalid manifest signature: {path}".to_string(),
+        );
+        en.insert(
+            "error.sign.inactive".to_string(),
+            "Cannot sign with a key that is not active or has expired: {id} ({state})".to_string(),
+        );
+        en.insert("error.sign.ambiguous_variant".to_string(), "Cannot tell the variant from the secret key length ({variants}). Specify -alg and -var.".to_string());
+        en.insert(
+            "error.sign.not_signature".to_string(),
+            "Not a signature (ML-DSA, FALCON, SPHINCS+) key: {name}".to_string(),
+        );
+        en.insert(
+            "error.sign.key_mismatch".to_string(),
+            "The verification key does not match the signer ({standard}).".to_string(),
+        );
+        en.insert(
+            "error.sign.failed".to_string(),
+            "Signature operation failed: {err}".to_string(),
+        );
+        en.insert("usage.manifest".to_string(), "- '{prog} sign-manifest [-dir <dir>] -key <key>|-sk <secret key> [-pk <public key>]' hashes every file in a directory into a manifest (default SHA256SUMS) and signs it (<manifest>.sig); '{prog} verify-manifest [-dir <dir>] -key <key>|-pk <public key> [-strict]' checks the signature and every file. Use -manifest and -sig to change the paths.".to_string());
+        en.insert("error.share.threshold".to_string(), "Threshold and share count must satisfy 2 <= threshold <= shares <= 255 (threshold {k}, shares {n}).".to_string());
+        en.insert(
+            "error.share.random".to_string(),
+            "Cannot generate random bytes: {err}".to_string(),
+        );
+        en.insert(
+            "error.share.malformed".to_string(),
+            "Malformed key share: {what}".to_string(),
+        );
+        en.insert(
+            "error.share.checksum".to_string(),
+            "Key share checksum mismatch: the share is corrupted.".to_string(),
+        );
+        en.insert(
+            "error.share.mismatch".to_string(),
+            "Shares from different splits cannot be combined.".to_string(),
+        );
+        en.insert(
+            "error.share.duplicate".to_string(),
+            "The same share was given twice: #{index}".to_string(),
+        );
+        en.insert(
+            "error.share.not_enough".to_string(),
+            "Not enough shares: {have} (need {need})".to_string(),
+        );
+        en.insert(
+            "error.share.count".to_string(),
+            "Invalid count: {value}".to_string(),
+        );
+        en.insert(
+            "error.share.template".to_string(),
+            "The share file path must contain {n}.".to_string(),
+        );
+        en.insert(
+            "error.share.public".to_string(),
+            "Specify the public key to check the recovered key against with -pk or -key."
+                .to_string(),
+        );
+        en.insert(
+            "error.share.fingerprint".to_string(),
+            "The public key fingerprint differs from the one recorded in the shares: {fp}"
+                .to_string(),
+        );
+        en.insert(
+            "error.share.verify".to_string(),
+            "The recovered secret key does not match the public key; the shares are wrong."
+                .to_string(),
+        );
+        en.insert(
+            "error.share.need_public".to_string(),
+            "Specify the matching public key with -pk.".to_string(),
+        );
+        en.insert(
+            "error.share.secret".to_string(),
+            "Specify the secret key with either -key <keystore key> or -sk <secret key file>."
+                .to_string(),
+        );
+        en.insert(
+            "error.share.unknown_key".to_string(),
+            "Unknown key algorithm/variant: {name}".to_string(),
+        );
+        en.insert("info.share.split".to_string(), "Split the secret key into {n} shares ({k} needed to recover, {standard}, fingerprint {fp}):".to_string());
+        en.insert("info.share.recombined".to_string(), "Recovered the secret key from {count} shares and checked it against the public key: {path} ({standard}, fingerprint {fp})".to_string());
+        en.insert("usage.share".to_string(), "- '{prog} split -key <key>|-sk <secret key> [-pk <public key>] -n <shares> -k <threshold> [-out <path{n}>]' splits a secret key into Shamir shares (PEM); '{prog} recombine <share>... [-pk <public key>|-key <key>] [-out <secret key>] [-sktext]' rebuilds it from at least threshold shares and checks it against the public key.".to_string());
+        en.insert("error.paper.too_large".to_string(), "The secret key is too large for a paper backup: {len} bytes (max {max} bytes; consider split)".to_string());
+        en.insert(
+            "error.paper.header".to_string(),
+            "Cannot parse the paper backup header: {what}".to_string(),
+        );
+        en.insert(
+            "error.paper.lines".to_string(),
+            "Some lines fail their checksum. Check what was typed:".to_string(),
+        );
+        en.insert(
+            "error.paper.line_suggest".to_string(),
+            "  line {line} (did you mean: {fixed})".to_string(),
+        );
+        en.insert("error.paper.line".to_string(), "  line {line}".to_string());
+        en.insert(
+            "error.paper.missing".to_string(),
+            "Missing lines: {lines}".to_string(),
+        );
+        en.insert("error.paper.total".to_string(), "The overall checksum does not match. Check the header (algorithm, variant, length) and the END line.".to_string());
+        en.insert(
+            "error.paper.format".to_string(),
+            "Unsupported paper backup format: {format} (text, svg)".to_string(),
+        );
+        en.insert(
+            "error.paper.fingerprint".to_string(),
+            "The public key fingerprint differs from the one on the paper backup: {fp}".to_string(),
+        );
+        en.insert(
+            "error.paper.verify".to_string(),
+            "The restored secret key does not match the public key. Check what was typed."
+                .to_string(),
+        );
+        en.insert("warn.paper.unverified".to_string(), "Warning: no public key available, so only the checksums were verified. Pass -pk or -key to also check the key pair.".to_string());
+        en.insert("info.paper.exported".to_string(), "Wrote paper backup of the secret key: {path} ({standard}, {len} bytes, fingerprint {fp}). Delete the file securely after printing.".to_string());
+        en.insert(
+            "info.paper.imported".to_string(),
+            "Restored the secret key from the paper backup: {path} ({standard}, fingerprint {fp})"
+                .to_string(),
+        );
+        en.insert("usage.paper".to_string(), "- '{prog} paper-export -key <key>|-sk <secret key> [-pk <public key>] [-format text|svg] [-out <file>]' exports a secret key as a printable base32 sheet with per-line checksums; '{prog} paper-import -in <file> [-pk <public key>|-key <key>] [-out <secret key>] [-sktext]' checks a transcribed sheet and restores the secret key.".to_string());
+        en.insert("usage.entropy".to_string(), "- [-entropy os|file:<path>|drbg:<hex seed>] selects the randomness source for key generation (default os). SP 800-90B repetition count and adaptive proportion startup tests run before generation, and no key is produced if they fail. drbg is for reproducible tests only.".to_string());
+        en.insert(
+            "error.entropy.spec".to_string(),
+            "Unknown entropy source: {spec} (os, file:<path>, drbg:<hex seed>)".to_string(),
+        );
+        en.insert(
+            "error.entropy.seed".to_string(),
+            "The DRBG seed must be at least 16 bytes of hex.".to_string(),
+        );
+        en.insert("error.entropy.unsupported".to_string(), "This build can only use the operating system RNG. To use another source, build with the custom-entropy feature and RUSTFLAGS='--cfg getrandom_backend=\"custom\"'.".to_string());
+        en.insert(
+            "error.entropy.io".to_string(),
+            "Cannot read entropy source ({path}): {err}".to_string(),
+        );
+        en.insert(
+            "error.entropy.os".to_string(),
+            "The operating system RNG failed: {err}".to_string(),
+        );
+        en.insert("error.entropy.rct".to_string(), "Entropy health test failed (repetition count test): the same value appeared {run} times in a row (cutoff {cutoff}). Aborting key generation.".to_string());
+        en.insert("error.entropy.apt".to_string(), "Entropy health test failed (adaptive proportion test): the same value appeared {count} times in {window} samples (cutoff {cutoff}). Aborting key generation.".to_string());
+        en.insert("warn.entropy.drbg".to_string(), "Warning: the deterministic DRBG is for testing only. Anyone who knows the seed can recreate the same keys, so never use it for real keys.".to_string());
+        en.insert(
+            "info.entropy.selected".to_string(),
+            "Entropy source: {source} (startup health tests passed)".to_string(),
+        );
+        en.insert("usage.config".to_string(), "- Defaults for algorithm, variant, encoding, pk_encoding, sk_encoding, outdir and lang go in the [defaults] and [profiles.<name>] tables of the config file (default ~/.config/keypairer/config.toml, change with -config or KEYPAIRER_CONFIG). Precedence is config file, then profile (-profile, KEYPAIRER_PROFILE), then KEYPAIRER_* environment variables, then command-line flags; '{prog} config show' prints the effective values and where each came from.".to_string());
+        en.insert(
+            "error.encoding".to_string(),
+            "Unsupported encoding: {encoding} (binary, pem)".to_string(),
+        );
+        en.insert(
+            "error.config.io".to_string(),
+            "Cannot read config file ({path}): {err}".to_string(),
+        );
+        en.insert(
+            "error.config.parse".to_string(),
+            "Cannot parse config file ({path}): {err}".to_string(),
+        );
+        en.insert(
+            "error.config.profile".to_string(),
+            "Profile not found in config file: {profile}".to_string(),
+        );
+        en.insert(
+            "error.config.env".to_string(),
+            "Cannot interpret the value of environment variable {var}: {value}".to_string(),
+        );
+        en.insert(
+            "error.config.action".to_string(),
+            "Unknown config action: {action} (show)".to_string(),
+        );
+        en.insert(
+            "info.config.file".to_string(),
+            "Config file: {path}".to_string(),
+        );
+        en.insert(
+            "info.config.no_file".to_string(),
+            "No config file (default path: {path})".to_string(),
+        );
+        en.insert(
+            "info.config.profile".to_string(),
+            "Profile: {profile}".to_string(),
+        );
+        en.insert("config.source.default".to_string(), "default".to_string());
+        en.insert(
+            "config.source.file".to_string(),
+            "config file [defaults]".to_string(),
+        );
+        en.insert(
+            "config.source.profile".to_string(),
+            "profile [profiles.{profile}]".to_string(),
+        );
+        en.insert(
+            "config.source.env".to_string(),
+            "environment variable {var}".to_string(),
+        );
+        en.insert(
+            "config.source.cli".to_string(),
+            "command line {flag}".to_string(),
+        );
+        en.insert("config.source.unset".to_string(), "not set".to_string());
+        en.insert("usage.suite".to_string(), "- [-suite <suite>] generates a KEM key pair and a signature key pair together (<suite>-kem.*, <suite>-sig.*, index <suite>-index.json). cnsa2 = ML-KEM-1024 + ML-DSA-87, nist-l1 = ML-KEM-512 + ML-DSA-44, nist-l3 = ML-KEM-768 + ML-DSA-65, conservative = Classic-McEliece-6688128 + SPHINCS+-SHAKE-256s-simple, compact = ML-KEM-512 + Falcon-512".to_string());
+        en.insert(
+            "error.suite.unknown".to_string(),
+            "Unknown suite: {suite} (available: {suites})".to_string(),
+        );
+        en.insert(
+            "error.suite.exclusive".to_string(),
+            "-suite cannot be combined with -alg, -var, -count or -manifest.".to_string(),
+        );
+        en.insert(
+            "info.suite.selected".to_string(),
+            "Suite {suite}: {pairs}".to_string(),
+        );
+        en.insert("usage.policy".to_string(), "- [-policy <policy>] or the [policy] table of the config file restricts key generation. A policy is a comma-separated list of strict (= min-category=3,standard-only,no-sktext), min-category=<1-5> (minimum NIST security category), standard-only (forbids HQC, McEliece, FALCON and SPHINCS+) and no-sktext (forbids text-encoded secret keys); policies from several places are combined into the strictest one.".to_string());
+        en.insert("error.policy.category".to_string(), "Policy violation: {name} is NIST security category {category}, below the minimum category {min} required by the policy.".to_string());
+        en.insert(
+            "error.policy.nonstandard".to_string(),
+            "Policy violation: {name} is not a finalized NIST standard (standard-only)."
+                .to_string(),
+        );
+        en.insert(
+            "error.policy.sktext".to_string(),
+            "Policy violation: secret keys may not be written as text (PEM) (no-sktext)."
+                .to_string(),
+        );
+        en.insert("error.policy.spec".to_string(), "Unknown policy item: {item} (strict, none, min-category=<1-5>, standard-only, no-sktext)".to_string());
+        en.insert(
+            "config.source.policy".to_string(),
+            "config file [policy]".to_string(),
+        );
+        en.insert("usage.bench".to_string(), "- '{prog} bench [-alg <algorithm> [-var <variant>]] [-n <iterations>] [-time <seconds>] [-format table|json] [-out <file>]' measures the median and p95 time of key generation and of encapsulation/decapsulation or signing/verification, plus key, ciphertext and signature sizes, for every variant (or the selected ones). Each operation stops at the iteration limit (default 20) or the time budget (default 2 seconds), whichever comes first; measure with a --release build.".to_string());
+        en.insert(
+            "error.bench.format".to_string(),
+            "Unsupported benchmark output format: {format} (table, json)".to_string(),
+        );
+        en.insert(
+            "error.bench.iterations".to_string(),
+            "Invalid iteration count: {value}".to_string(),
+        );
+        en.insert(
+            "error.bench.time".to_string(),
+            "Invalid time budget (seconds): {value}".to_string(),
+        );
+        en.insert(
+            "error.bench.failed".to_string(),
+            "Benchmark of {standard} failed: {err}".to_string(),
+        );
+        en.insert(
+            "warn.bench.debug".to_string(),
+            "Warning: this is a debug build. Build with --release to get comparable numbers."
+                .to_string(),
+        );
+        en.insert(
+            "info.bench.progress".to_string(),
+            "[{done}/{total}] benchmarking {standard}".to_string(),
+        );
+        en.insert("bench.column.variant".to_string(), "Variant".to_string());
+        en.insert("bench.column.category".to_string(), "Category".to_string());
+        en.insert(
+            "bench.column.keygen".to_string(),
+            "Keygen (median/p95)".to_string(),
+        );
+        en.insert(
+            "bench.column.encaps_sign".to_string(),
+            "Encaps/Sign".to_string(),
+        );
+        en.insert(
+            "bench.column.decaps_verify".to_string(),
+            "Decaps/Verify".to_string(),
+        );
+        en.insert("bench.column.pk".to_string(), "Public key".to_string());
+        en.insert("bench.column.sk".to_string(), "Secret key".to_string());
+        en.insert(
+            "bench.column.ct_sig".to_string(),
+            "Ciphertext/Sig".to_string(),
+        );
+        en.insert("usage.stack".to_string(), "- Key generation threads get as much stack as the variant needs (1 MiB for ML-KEM, HQC and SPHINCS+, 512 KiB for FALCON and ML-DSA, 4–24 MiB for Classic McEliece). [-stack-size <size>] or KEYPAIRER_STACK_SIZE (e.g. 512K, 8M, at least 64K) overrides the stack size for every variant.".to_string());
+        en.insert(
+            "error.stack.size".to_string(),
+            "Invalid stack size: {value} (e.g. 512K, 8M, at least 64K)".to_string(),
+        );
+        en.insert(
+            "error.executor.panicked".to_string(),
+            "The key generation job panicked.".to_string(),
+        );
+        en.insert(
+            "error.executor.shutdown".to_string(),
+            "The key generation executor has shut down and cannot process the job.".to_string(),
+        );
+        en.insert(
+            "error.executor.queue_full".to_string(),
+            "The key generation queue is full.".to_string(),
+        );
+        en.insert(
+            "error.executor.unavailable".to_string(),
+            "Could not start the key generation worker threads.".to_string(),
+        );
+        en.insert("usage.pool".to_string(), "- '{prog} pool serve -size <algorithm>[:<variant>]=<count>,... [-dir <directory>] [-socket <path>] -key-file <file> [-jobs <count>]' keeps the given number of pre-generated key pairs per variant in an encrypted on-disk pool, hands them out over a Unix socket and refills in the background; the pool key file must live outside the pool directory. '{prog} pool take -alg <algorithm> [-var <variant>] [-pkpath <path>] [-skpath <path>]' takes one key pair (generated on demand if the pool is empty), and '{prog} pool status [-json]' shows how many are left. The socket defaults to KEYPAIRER_POOL_SOCKET → $XDG_RUNTIME_DIR/keypairer-pool.sock → <pool directory>/keypairer-pool.sock.".to_string());
+        en.insert(
+            "error.pool.spec".to_string(),
+            "Invalid pool size: {value} (e.g. mlkem:768=4,mceliece:6688128=2)".to_string(),
+        );
+        en.insert(
+            "error.pool.io".to_string(),
+            "Key pool I/O error: {err}".to_string(),
+        );
+        en.insert(
+            "error.pool.corrupt".to_string(),
+            "Key pool file is corrupt or does not match the pool key: {path}".to_string(),
+        );
+        en.insert(
+            "error.pool.running".to_string(),
+            "A key pool server is already running: {path}".to_string(),
+        );
+        en.insert(
+            "error.pool.not_running".to_string(),
+            "Cannot connect to the key pool server: {path} (start it with 'pool serve')"
+                .to_string(),
+        );
+        en.insert(
+            "error.pool.generate".to_string(),
+            "Key generation failed: {err}".to_string(),
+        );
+        en.insert(
+            "error.pool.protocol".to_string(),
+            "Cannot parse the key pool server response: {err}".to_string(),
+        );
+        en.insert(
+            "error.pool.not_configured".to_string(),
+            "The key pool server does not pool this variant: {err}".to_string(),
+        );
+        en.insert(
+            "error.pool.server".to_string(),
+            "Key pool server error: {err}".to_string(),
+        );
+        en.insert(
+            "error.pool.no_dir".to_string(),
+            "Cannot determine the key pool directory. Specify -dir or KEYPAIRER_HOME.".to_string(),
+        );
+        en.insert(
+            "info.pool.serving".to_string(),
+            "Key pool started: {socket} (directory: {dir}, {jobs} workers)".to_string(),
+        );
+        en.insert(
+            "info.pool.refilled".to_string(),
+            "{standard} refilled: {available} available".to_string(),
+        );
+        en.insert(
+            "info.pool.served".to_string(),
+            "{standard} served: {remaining} left".to_string(),
+        );
+        en.insert(
+            "info.pool.served_fresh".to_string(),
+            "{standard} served: pool empty, generated on demand".to_string(),
+        );
+        en.insert(
+            "info.pool.taken".to_string(),
+            "Took a {standard} key from the pool ({remaining} left)".to_string(),
+        );
+        en.insert(
+            "info.pool.taken_fresh".to_string(),
+            "The pool was empty; generated a new {standard} key".to_string(),
+        );
+        en.insert("usage.serve".to_string(), "- '{prog} serve [-bind <address:port>] [-store-dir <directory>] [-token-file <file>] [-jobs <count>]' serves key generation, stored key and public key lookup, encapsulation/decapsulation and signing/verification as a JSON HTTP API (/v1/...). The default address is 127.0.0.1:7878; only loopback addresses can be bound, and 'Authorization: Bearer <token>' is always required. Without [-token-file] a random token is written to keypairer-serve-<port>.token (mode 0o600) in $XDG_RUNTIME_DIR or the key store.".to_string());
+        en.insert(
+            "error.serve.token".to_string(),
+            "Cannot read the token file ({path}): {err}".to_string(),
+        );
+        en.insert(
+            "error.serve.bind".to_string(),
+            "Cannot bind to the address ({addr}): {err}".to_string(),
+        );
+        en.insert(
+            "error.serve.remote".to_string(),
+            "The key service can only bind to a loopback address (127.0.0.1, ::1): {addr}"
+                .to_string(),
+        );
+        en.insert(
+            "error.serve.io".to_string(),
+            "Key service connection error: {err}".to_string(),
+        );
+        en.insert(
+            "info.serve.listening".to_string(),
+            "Key service started: http://{addr} ({jobs} workers)".to_string(),
+        );
+        en.insert(
+            "info.config.entropy_pluggable".to_string(),
+            "Entropy source selection (-entropy): available".to_string(),
+        );
+        en.insert("info.config.entropy_os_only".to_string(), "Entropy source selection (-entropy): unavailable, this build was compiled without the custom-entropy feature and getrandom_backend=\"custom\" and supports only os".to_string());
+        en.insert("error.pool.key_file".to_string(), "Specify a pool key file outside the pool directory with -key-file (it is created with mode 0o600 if missing).".to_string());
+        en.insert(
+            "error.pool.key_inside".to_string(),
+            "The pool key file must be outside the pool directory: {path}".to_string(),
+        );
+        en.insert(
+            "error.pool.forbidden".to_string(),
+            "Rejected a connection from another user (uid {uid})".to_string(),
+        );
+        en.insert(
+            "error.serve.token_path".to_string(),
+            "Cannot decide where to keep the authentication token. Specify -token-file."
+                .to_string(),
+        );
+        en.insert(
+            "error.serve.token_write".to_string(),
+            "Cannot save the authentication token ({path}): {err}".to_string(),
+        );
+        en.insert("info.serve.token".to_string(), "Created an authentication token: {path} (send 'Authorization: Bearer <file contents>')".to_string());
+        en.insert(
+            "error.crypt.encrypt".to_string(),
+            "Encryption failed.".to_string(),
+        );
+        en.insert(
+            "error.pool.busy".to_string(),
+            "The pool server has reached its connection limit ({max}); try again shortly"
+                .to_string(),
         );
         return en;
     }
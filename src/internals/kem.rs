@@ -0,0 +1,240 @@
+use std::error::Error;
+use std::fmt;
+
+use pqcrypto::kem::{
+    hqc128, hqc192, hqc256, mceliece348864, mceliece348864f, mceliece460896, mceliece460896f,
+    mceliece6688128, mceliece6688128f, mceliece6960119, mceliece6960119f, mceliece8192128,
+    mceliece8192128f, mlkem512, mlkem768, mlkem1024,
+};
+use pqcrypto_traits::kem::{Ciphertext as _, PublicKey as _, SecretKey as _, SharedSecret as _};
+use zeroize::Zeroizing;
+
+use super::keygen::Variant;
+use super::secret::wipe_key;
+
+/// KEM 연산 실패 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum KemError {
+    /// KEM이 아닌 배리언트 (예: 서명 알고리즘)
+    NotKem(Variant),
+    /// 키 또는 암호문의 길이가 배리언트와 맞지 않음
+    InvalidInput(&'static str),
+}
+
+impl fmt::Display for KemError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            KemError::NotKem(v) => write!(f, "{} is not a KEM", v.standard_name()),
+            KemError::InvalidInput(what) => write!(f, "invalid {}", what),
+        }
+    }
+}
+
+impl Error for KemError {}
+
+/// 캡슐화 결과: (공유 비밀, 암호문)
+pub type Encapsulated = (Zeroizing<Vec<u8>>, Vec<u8>);
+
+/// 배리언트에 해당하는 pqcrypto 모듈로 `$body`를 실행합니다. KEM이 아니면 `$fallback`입니다.
+///
+/// 각 갈래는 클로저로 감싸 별도 함수 프레임에서 실행합니다. 최적화하지 않은 빌드는 모든 갈래의
+/// 지역 변수(Classic McEliece 키는 1 MiB 이상)에 스택 슬롯을 따로 잡으므로, 한 프레임에 두면
+/// 어느 배리언트를 쓰든 전체 합만큼의 스택이 필요합니다.
+macro_rules! with_kem {
+    ($variant:expr, $m:ident => $body:expr, _ => $fallback:expr) => {
+        match $variant {
+            Variant::MLKEM512 => {
+                use mlkem512 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::MLKEM768 => {
+                use mlkem768 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::MLKEM1024 => {
+                use mlkem1024 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::HQC128 => {
+                use hqc128 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::HQC192 => {
+                use hqc192 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::HQC256 => {
+                use hqc256 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece348864 => {
+                use mceliece348864 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece348864f => {
+                use mceliece348864f as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece460896 => {
+                use mceliece460896 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece460896f => {
+                use mceliece460896f as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece6688128 => {
+                use mceliece6688128 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece6688128f => {
+                use mceliece6688128f as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece6960119 => {
+                use mceliece6960119 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece6960119f => {
+                use mceliece6960119f as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece8192128 => {
+                use mceliece8192128 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::McEliece8192128f => {
+                use mceliece8192128f as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            _ => $fallback,
+        }
+    };
+}
+
+/// 지원하는 모든 KEM 배리언트
+pub const KEM_VARIANTS: [Variant; 16] = [
+    Variant::MLKEM512,
+    Variant::MLKEM768,
+    Variant::MLKEM1024,
+    Variant::HQC128,
+    Variant::HQC192,
+    Variant::HQC256,
+    Variant::McEliece348864,
+    Variant::McEliece348864f,
+    Variant::McEliece460896,
+    Variant::McEliece460896f,
+    Variant::McEliece6688128,
+    Variant::McEliece6688128f,
+    Variant::McEliece6960119,
+    Variant::McEliece6960119f,
+    Variant::McEliece8192128,
+    Variant::McEliece8192128f,
+];
+
+/// 배리언트가 KEM(ML-KEM, HQC, Classic McEliece)인지 여부
+pub fn is_kem(variant: &Variant) -> bool {
+    ciphertext_len(variant).is_some()
+}
+
+/// 배리언트의 암호문 길이(바이트). KEM이 아니면 `None`입니다.
+pub fn ciphertext_len(variant: &Variant) -> Option<usize> {
+    with_kem!(variant, m => Some(m::ciphertext_bytes()), _ => None)
+}
+
+/// 배리언트의 공개키 길이(바이트). KEM이 아니면 `None`입니다.
+pub fn public_key_len(variant: &Variant) -> Option<usize> {
+    with_kem!(variant, m => Some(m::public_key_bytes()), _ => None)
+}
+
+/// 배리언트의 비밀키 길이(바이트). KEM이 아니면 `None`입니다.
+pub fn secret_key_len(variant: &Variant) -> Option<usize> {
+    with_kem!(variant, m => Some(m::secret_key_bytes()), _ => None)
+}
+
+/// 공개키 길이와 일치하는 KEM 배리언트 목록
+///
+/// Classic McEliece의 `f` 배리언트처럼 길이가 같은 경우 여러 개가 반환됩니다.
+pub fn variants_for_public_key(len: usize) -> Vec<Variant> {
+    KEM_VARIANTS
+        .iter()
+        .filter(|v| public_key_len(v) == Some(len))
+        .cloned()
+        .collect()
+}
+
+/// 공개키로 공유 비밀을 캡슐화합니다.
+///
+/// Classic McEliece는 스택 사용량이 크므로 [`crate::run_with_variant_stack`] 안에서 호출하세요.
+pub fn encapsulate(variant: &Variant, pk_bytes: &[u8]) -> Result<Encapsulated, KemError> {
+    with_kem!(variant, m => {
+        let pk = m::PublicKey::from_bytes(pk_bytes)
+            .map_err(|_| KemError::InvalidInput("public key"))?;
+        let (mut ss, ct) = m::encapsulate(&pk);
+        let shared = Zeroizing::new(ss.as_bytes().to_vec());
+        wipe_key(&mut ss);
+        Ok((shared, ct.as_bytes().to_vec()))
+    }, _ => Err(KemError::NotKem(variant.clone())))
+}
+
+/// 비밀키로 암호문을 역캡슐화하여 공유 비밀을 얻습니다.
+///
+/// ML-KEM 등은 잘못된 키로도 오류 없이 무작위 값을 반환하므로(암묵적 거부),
+/// 올바른 키인지는 이후 AEAD 인증으로 확인해야 합니다.
+pub fn decapsulate(
+    variant: &Variant,
+    sk_bytes: &[u8],
+    ct_bytes: &[u8],
+) -> Result<Zeroizing<Vec<u8>>, KemError> {
+    with_kem!(variant, m => {
+        let ct = m::Ciphertext::from_bytes(ct_bytes)
+            .map_err(|_| KemError::InvalidInput("ciphertext"))?;
+        let mut sk = m::SecretKey::from_bytes(sk_bytes)
+            .map_err(|_| KemError::InvalidInput("secret key"))?;
+        let mut ss = m::decapsulate(&ct, &sk);
+        wipe_key(&mut sk);
+        let shared = Zeroizing::new(ss.as_bytes().to_vec());
+        wipe_key(&mut ss);
+        Ok(shared)
+    }, _ => Err(KemError::NotKem(variant.clone())))
+}
+
+/// 공개키로 캡슐화한 공유 비밀을 비밀키로 되찾을 수 있는지로 두 키가 한 쌍인지 확인합니다.
+///
+/// Classic McEliece는 스택 사용량이 크므로 [`crate::run_with_variant_stack`] 안에서 호출하세요.
+pub fn pair_matches(variant: &Variant, pk_bytes: &[u8], sk_bytes: &[u8]) -> Result<bool, KemError> {
+    let (ss, ct) = encapsulate(variant, pk_bytes)?;
+    Ok(decapsulate(variant, sk_bytes, &ct)? == ss)
+}
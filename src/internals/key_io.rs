@@ -1,25 +1,622 @@
-use std::fs::{File, OpenOptions};
-use std::io::Write;
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, IsTerminal, Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
 use std::process;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::time::{SystemTime, UNIX_EPOCH};
 
 use base64::Engine as _;
 use base64::engine::general_purpose;
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
 
+/// PEM 한 줄에 담는 바이트 수 (base64 64글자)
+const PEM_LINE_BYTES: usize = 48;
+
+/// 바이트를 PEM 유사 텍스트로 인코딩합니다.
+///
+/// 결과 길이만큼 한 번에 할당하고 줄 단위로 바로 인코딩하므로, 재할당이나 중간 base64
+/// 문자열로 인한 사본이 남지 않습니다. 비밀키라면 반환값을 `Zeroizing`으로 감싸세요.
 pub fn to_pem(label: &str, der: &[u8]) -> String {
-    let b64 = general_purpose::STANDARD.encode(der);
-    let mut out = String::new();
-    out.push_str(&format!("-----BEGIN {}-----\n", label));
-    let mut i = 0usize;
-    while i < b64.len() {
-        let end = (i + 64).min(b64.len());
-        out.push_str(&b64[i..end]);
+    let mut out = String::with_capacity(pem_len(label, der.len()));
+    push_pem(&mut out, label, der);
+    out
+}
+
+/// `der_len`바이트를 [`to_pem`]으로 인코딩한 텍스트의 길이
+pub fn pem_len(label: &str, der_len: usize) -> usize {
+    let markers = "-----BEGIN -----\n".len() + "-----END -----\n".len() + 2 * label.len();
+    markers + der_len.div_ceil(3) * 4 + der_len.div_ceil(PEM_LINE_BYTES)
+}
+
+/// PEM 유사 텍스트를 `out` 뒤에 바로 인코딩합니다.
+///
+/// 중간 문자열을 만들지 않으므로, `out`에 [`pem_len`]만큼 미리 공간을 잡아 두면 비밀 데이터의
+/// 사본이 남지 않습니다.
+pub fn push_pem(out: &mut String, label: &str, der: &[u8]) {
+    for part in ["-----BEGIN ", label, "-----\n"] {
+        out.push_str(part);
+    }
+    let mut line = Zeroizing::new([0u8; PEM_LINE_BYTES / 3 * 4]);
+    for chunk in der.chunks(PEM_LINE_BYTES) {
+        let len = general_purpose::STANDARD
+            .encode_slice(chunk, &mut line[..])
+            .expect("PEM 줄 버퍼 크기");
+        out.push_str(std::str::from_utf8(&line[..len]).expect("base64는 ASCII"));
         out.push('\n');
-        i = end;
     }
-    out.push_str(&format!("-----END {}-----\n", label));
-    out
+    for part in ["-----END ", label, "-----\n"] {
+        out.push_str(part);
+    }
+}
+
+/// 임시 파일 이름에 붙일 단조 증가 카운터
+static TEMP_COUNTER: AtomicU64 = AtomicU64::new(0);
+
+/// 대상 경로와 같은 디렉토리의 임시 파일 경로를 정합니다.
+///
+/// 같은 디렉토리에 두어야 이후 `rename`/`hard_link`가 원자적으로 동작합니다.
+fn temp_path(path: &Path) -> PathBuf {
+    let dir = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+    let name = path
+        .file_name()
+        .map(|n| n.to_string_lossy().into_owned())
+        .unwrap_or_else(|| "key".to_string());
+    let nanos = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.subsec_nanos())
+        .unwrap_or(0);
+    dir.join(format!(
+        ".{}.{}.{}.{}.tmp",
+        name,
+        process::id(),
+        nanos,
+        TEMP_COUNTER.fetch_add(1, Ordering::Relaxed)
+    ))
+}
+
+/// 새 파일을 지정한 권한으로 만듭니다. 이미 있으면 실패합니다.
+fn create_new_file(path: &Path, mode: u32) -> io::Result<File> {
+    let mut opts = OpenOptions::new();
+    opts.write(true).create_new(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::OpenOptionsExt;
+        opts.mode(mode);
+    }
+    #[cfg(not(unix))]
+    let _ = mode;
+    opts.open(path)
+}
+
+/// 대상 경로와 같은 디렉토리에 임시 파일을 만들어 데이터를 기록하고 fsync합니다.
+pub(crate) fn stage_file(path: &Path, data: &[u8], mode: u32) -> io::Result<PathBuf> {
+    let tmp = temp_path(path);
+    let mut file = create_new_file(&tmp, mode)?;
+    let written = file.write_all(data).and_then(|_| file.sync_all());
+    if let Err(e) = written {
+        let _ = fs::remove_file(&tmp);
+        return Err(e);
+    }
+    Ok(tmp)
+}
+
+/// 임시 파일을 최종 경로로 옮깁니다.
+///
+/// `force`가 아니면 `hard_link`로 연결하여 대상이 이미 존재할 때 원자적으로 실패하게 하고,
+/// 하드 링크를 지원하지 않는 파일 시스템에서는 존재 여부 확인 후 `rename`으로 대체합니다.
+pub(crate) fn commit_file(tmp: &Path, dest: &Path, force: bool) -> io::Result<()> {
+    if force {
+        fs::rename(tmp, dest)?;
+    } else {
+        match fs::hard_link(tmp, dest) {
+            Ok(()) => {
+                fs::remove_file(tmp)?;
+            }
+            Err(e) if e.kind() == io::ErrorKind::AlreadyExists => return Err(e),
+            Err(_) => {
+                if dest.exists() {
+                    return Err(io::Error::from(io::ErrorKind::AlreadyExists));
+                }
+                fs::rename(tmp, dest)?;
+            }
+        }
+    }
+    sync_parent_dir(dest);
+    Ok(())
+}
+
+/// 덮어쓸 기존 파일을 같은 디렉토리의 백업 이름으로 연결해 둡니다. 대상이 없으면 `None`.
+///
+/// 하드 링크를 지원하지 않는 파일 시스템에서는 복사하며, 되돌릴 때 `rename`으로 원래 경로에
+/// 다시 옮깁니다.
+fn backup_existing(dest: &Path) -> io::Result<Option<PathBuf>> {
+    if fs::symlink_metadata(dest).is_err() {
+        return Ok(None);
+    }
+    let backup = temp_path(dest);
+    if fs::hard_link(dest, &backup).is_err() {
+        fs::copy(dest, &backup).inspect_err(|_| {
+            let _ = fs::remove_file(&backup);
+        })?;
+    }
+    Ok(Some(backup))
 }
 
+/// 단일 파일을 원자적으로 기록합니다.
+///
+/// 상위 디렉토리가 없으면 만들고, 임시 파일에 기록·fsync한 뒤 최종 경로로 옮깁니다.
+/// `force`가 아니면 기존 파일을 덮어쓰지 않고 `AlreadyExists`를 반환합니다.
+pub fn write_atomic(path: &Path, data: &[u8], mode: u32, force: bool) -> io::Result<()> {
+    if !force && path.exists() {
+        return Err(io::Error::from(io::ErrorKind::AlreadyExists));
+    }
+    if let Some(dir) = path.parent() {
+        create_private_dir_all(dir)?;
+    }
+    let tmp = stage_file(path, data, mode)?;
+    commit_file(&tmp, path, force).inspect_err(|_| {
+        let _ = fs::remove_file(&tmp);
+    })
+}
+
+/// 스트리밍으로 기록한 뒤 한 번에 최종 경로로 옮기는 파일
+///
+/// 임시 파일에 기록하다가 [`AtomicFile::commit`]에서 fsync 후 옮기며,
+/// 커밋하지 않고 드롭하면 임시 파일을 지웁니다. 중간에 실패해도 일부만 기록된 파일이 남지 않습니다.
+pub struct AtomicFile {
+    tmp: PathBuf,
+    dest: PathBuf,
+    file: Option<File>,
+}
+
+impl AtomicFile {
+    /// 상위 디렉토리를 만들고 임시 파일을 엽니다. `force`가 아니면 기존 파일이 있을 때 실패합니다.
+    pub fn create(path: &Path, mode: u32, force: bool) -> io::Result<AtomicFile> {
+        if !force && path.exists() {
+            return Err(io::Error::from(io::ErrorKind::AlreadyExists));
+        }
+        if let Some(dir) = path.parent() {
+            create_private_dir_all(dir)?;
+        }
+        let tmp = temp_path(path);
+        let file = create_new_file(&tmp, mode)?;
+        Ok(AtomicFile {
+            tmp,
+            dest: path.to_path_buf(),
+            file: Some(file),
+        })
+    }
+
+    /// 기록한 내용을 fsync하고 최종 경로로 옮깁니다.
+    pub fn commit(mut self, force: bool) -> io::Result<()> {
+        if let Some(file) = self.file.take() {
+            file.sync_all()?;
+        }
+        commit_file(&self.tmp, &self.dest, force)
+    }
+
+    fn file(&mut self) -> io::Result<&mut File> {
+        self.file
+            .as_mut()
+            .ok_or_else(|| io::Error::from(io::ErrorKind::BrokenPipe))
+    }
+}
+
+impl Write for AtomicFile {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        self.file()?.write(buf)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        self.file()?.flush()
+    }
+}
+
+impl Drop for AtomicFile {
+    fn drop(&mut self) {
+        // 커밋 후에는 임시 경로가 이미 없으므로 무시됨
+        let _ = fs::remove_file(&self.tmp);
+    }
+}
+
+/// 파일 내용을 덮어쓴 뒤 삭제합니다.
+///
+/// 무작위 바이트와 0으로 차례로 덮어쓰고 각각 fsync한 뒤 길이를 0으로 줄이고 삭제합니다.
+/// 저널링/COW 파일 시스템이나 SSD의 웨어 레벨링에서는 원본 블록이 남을 수 있습니다.
+pub fn secure_delete(path: &Path) -> io::Result<()> {
+    let len = fs::metadata(path)?.len() as usize;
+    let mut file = OpenOptions::new().write(true).open(path)?;
+
+    let mut noise = Zeroizing::new(vec![0u8; len]);
+    getrandom::fill(&mut noise).map_err(|e| io::Error::other(e.to_string()))?;
+    for pass in [&noise[..], &vec![0u8; len][..]] {
+        file.seek(SeekFrom::Start(0))?;
+        file.write_all(pass)?;
+        file.sync_all()?;
+    }
+    file.set_len(0)?;
+    file.sync_all()?;
+    drop(file);
+
+    fs::remove_file(path)?;
+    sync_parent_dir(path);
+    Ok(())
+}
+
+/// 없는 디렉토리를 상위까지 포함하여 소유자 전용(0o700) 권한으로 생성합니다.
+pub fn create_private_dir_all(dir: &Path) -> io::Result<()> {
+    if dir.as_os_str().is_empty() || dir.is_dir() {
+        return Ok(());
+    }
+    let mut builder = fs::DirBuilder::new();
+    builder.recursive(true);
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::DirBuilderExt;
+        builder.mode(0o700);
+    }
+    builder.create(dir)
+}
+
+/// 디렉토리 엔트리 변경(rename)이 디스크에 반영되도록 상위 디렉토리를 fsync합니다.
+fn sync_parent_dir(path: &Path) {
+    #[cfg(unix)]
+    {
+        let dir = match path.parent() {
+            Some(p) if !p.as_os_str().is_empty() => p,
+            _ => Path::new("."),
+        };
+        if let Ok(d) = File::open(dir) {
+            let _ = d.sync_all();
+        }
+    }
+    #[cfg(not(unix))]
+    let _ = path;
+}
+
+/// 표준 입출력을 가리키는 경로 표기
+pub const STDIO_PATH: &str = "-";
+
+/// PEM 유사 텍스트에서 지정한 라벨의 본문을 디코딩합니다.
+///
+/// 라벨 블록이 없거나 base64가 올바르지 않으면 `None`을 반환합니다.
+pub fn from_pem(label: &str, text: &str) -> Option<Zeroizing<Vec<u8>>> {
+    let begin = format!("-----BEGIN {}-----", label);
+    let end = format!("-----END {}-----", label);
+    let start = text.find(&begin)? + begin.len();
+    let stop = start + text[start..].find(&end)?;
+    // 미리 할당한 버퍼에만 기록하여 재할당으로 인한 사본을 남기지 않음
+    let mut body = Zeroizing::new(String::with_capacity(stop - start));
+    body.extend(text[start..stop].chars().filter(|c| !c.is_whitespace()));
+    let mut decoded = Zeroizing::new(Vec::with_capacity(body.len().div_ceil(4) * 3));
+    general_purpose::STANDARD
+        .decode_vec(body.as_bytes(), &mut decoded)
+        .ok()?;
+    Some(decoded)
+}
+
+/// 키 파일(또는 `-`일 때 표준 입력)을 읽습니다.
+///
+/// 내용이 `label`의 PEM 유사 블록이면 디코딩하고, 아니면 바이너리로 간주합니다.
+pub fn read_key(path: &str, label: &str) -> io::Result<Zeroizing<Vec<u8>>> {
+    let raw = if path == STDIO_PATH {
+        read_all_zeroizing(&mut io::stdin().lock(), 0)?
+    } else {
+        let mut file = File::open(path)?;
+        let hint = file.metadata().map(|m| m.len() as usize).unwrap_or(0);
+        read_all_zeroizing(&mut file, hint)?
+    };
+    decode_key(raw, label)
+}
+
+/// 읽은 키가 `label`의 PEM 유사 블록이면 디코딩하고, 아니면 그대로 반환합니다.
+fn decode_key(raw: Zeroizing<Vec<u8>>, label: &str) -> io::Result<Zeroizing<Vec<u8>>> {
+    if let Ok(text) = std::str::from_utf8(&raw)
+        && text.trim_start().starts_with("-----BEGIN ")
+    {
+        return from_pem(label, text)
+            .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidData, label.to_string()));
+    }
+    Ok(raw)
+}
+
+/// 끝까지 읽어 반환합니다. 버퍼를 키울 때 이전 버퍼를 지워 비밀키 사본이 남지 않게 합니다.
+pub(crate) fn read_all_zeroizing<R: Read>(
+    reader: &mut R,
+    hint: usize,
+) -> io::Result<Zeroizing<Vec<u8>>> {
+    let mut buf = Zeroizing::new(vec![0u8; hint.max(4096) + 1]);
+    let mut len = 0usize;
+    loop {
+        if len == buf.len() {
+            let mut grown = Zeroizing::new(vec![0u8; buf.len() * 2]);
+            grown[..len].copy_from_slice(&buf[..len]);
+            buf = grown;
+        }
+        match reader.read(&mut buf[len..]) {
+            Ok(0) => break,
+            Ok(n) => len += n,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    // 길이만 줄이므로 재할당이 없고, 남는 용량은 드롭할 때 함께 지워짐
+    buf.truncate(len);
+    Ok(buf)
+}
+
+/// 비밀이 담긴 직렬화 결과를 모으는 [`Write`]. [`read_all_zeroizing`]처럼 버퍼를 키울 때 새
+/// 버퍼에 복사한 뒤 이전 버퍼를 지우므로, `Vec`의 재할당으로 지워지지 않은 사본이 남지 않습니다.
+pub(crate) struct ZeroizingWriter {
+    buf: Zeroizing<Vec<u8>>,
+}
+
+impl ZeroizingWriter {
+    pub(crate) fn with_capacity(capacity: usize) -> ZeroizingWriter {
+        ZeroizingWriter {
+            buf: Zeroizing::new(Vec::with_capacity(capacity)),
+        }
+    }
+
+    pub(crate) fn into_inner(self) -> Zeroizing<Vec<u8>> {
+        self.buf
+    }
+}
+
+impl Write for ZeroizingWriter {
+    fn write(&mut self, data: &[u8]) -> io::Result<usize> {
+        let needed = self.buf.len() + data.len();
+        if needed > self.buf.capacity() {
+            let mut grown = Zeroizing::new(Vec::with_capacity(needed.max(self.buf.capacity() * 2)));
+            grown.extend_from_slice(&self.buf);
+            self.buf = grown;
+        }
+        self.buf.extend_from_slice(data);
+        Ok(data.len())
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+/// 데이터를 표준 출력에 기록합니다.
+fn write_stdout(data: &[u8]) -> io::Result<()> {
+    let mut out = io::stdout().lock();
+    out.write_all(data)?;
+    out.flush()
+}
+
+/// 공개키의 지문(SHA-256, 소문자 16진수)을 계산합니다.
+pub fn fingerprint(pk_bytes: &[u8]) -> String {
+    Sha256::digest(pk_bytes)
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+/// 키 페어 저장 실패 사유
+#[derive(Debug)]
+pub enum SaveError {
+    /// 덮어쓰기가 허용되지 않았는데 대상 파일이 이미 존재함
+    Exists(String),
+    /// 키를 저장할 디렉토리를 만들지 못함
+    CreateDir { path: String, err: io::Error },
+    /// 공개키 임시 파일 기록 실패
+    WritePk(io::Error),
+    /// 비밀키 임시 파일 기록 실패
+    WriteSk(io::Error),
+    /// 공개키를 최종 경로로 옮기지 못함
+    CommitPk(io::Error),
+    /// 비밀키를 최종 경로로 옮기지 못함 (`rolled_back`이면 공개키를 이전 상태로 되돌림)
+    CommitSk { err: io::Error, rolled_back: bool },
+}
+
+impl SaveError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            SaveError::Exists(path) => text(
+                "error.file.exists",
+                "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)",
+            )
+            .replace("{path}", path),
+            SaveError::CreateDir { path, err } => text(
+                "error.file.create_dir",
+                "디렉토리를 생성하는 도중 오류가 발생했습니다({path}): {err}",
+            )
+            .replace("{path}", path)
+            .replace("{err}", &err.to_string()),
+            SaveError::WritePk(e) => text(
+                "error.file.write_pk",
+                "공개키 파일에 쓰는 도중 오류가 발생했습니다: {err}",
+            )
+            .replace("{err}", &e.to_string()),
+            SaveError::WriteSk(e) => text(
+                "error.file.write_sk",
+                "비밀키 파일에 쓰는 도중 오류가 발생했습니다: {err}",
+            )
+            .replace("{err}", &e.to_string()),
+            SaveError::CommitPk(e) => text(
+                "error.file.create_pk",
+                "공개키 파일을 생성하는 도중 오류가 발생했습니다: {err}",
+            )
+            .replace("{err}", &e.to_string()),
+            SaveError::CommitSk { err, .. } => text(
+                "error.file.create_sk",
+                "비밀키 파일을 생성하는 도중 오류가 발생했습니다: {err}",
+            )
+            .replace("{err}", &err.to_string()),
+        }
+    }
+}
+
+/// 키 페어를 기록합니다. 오류 시 프로세스를 종료하지 않고 사유를 반환합니다.
+///
+/// 두 파일을 같은 디렉토리의 임시 이름으로 기록·fsync한 뒤 최종 경로로 옮기며,
+/// 비밀키를 옮기지 못하면 이미 옮긴 공개키를 되돌립니다. `force`로 덮어쓴 공개키는 옮기기 전에
+/// 백업해 두었다가 복원하고, 새로 만든 공개키는 제거합니다. 경로가 `-`이면 표준 출력에
+/// 기록하고, 이때 `pk_text`/`sk_text`는 호출자가 결정한 값을 그대로 따릅니다.
+pub fn write_key_pair(
+    pk_bytes: &[u8],
+    sk_bytes: &[u8],
+    pk_path: &str,
+    sk_path: &str,
+    pk_text: bool,
+    sk_text: bool,
+    force: bool,
+) -> Result<(), SaveError> {
+    let pk_to_stdout = pk_path == STDIO_PATH;
+    let sk_to_stdout = sk_path == STDIO_PATH;
+    let pk_dest = Path::new(pk_path);
+    let sk_dest = Path::new(sk_path);
+
+    // 덮어쓰기 방지: 기존 파일이 있으면 아무것도 쓰지 않음
+    if !force {
+        for (dest, to_stdout) in [(pk_dest, pk_to_stdout), (sk_dest, sk_to_stdout)] {
+            if !to_stdout && dest.exists() {
+                return Err(SaveError::Exists(dest.display().to_string()));
+            }
+        }
+    }
+
+    // 템플릿/출력 디렉토리로 지정된 하위 디렉토리가 없으면 생성
+    for (dest, to_stdout) in [(pk_dest, pk_to_stdout), (sk_dest, sk_to_stdout)] {
+        if let Some(dir) = dest.parent()
+            && !to_stdout
+        {
+            create_private_dir_all(dir).map_err(|err| SaveError::CreateDir {
+                path: dir.display().to_string(),
+                err,
+            })?;
+        }
+    }
+
+    // 두 파일을 모두 임시 이름으로 기록한 뒤에만 최종 경로로 옮김
+    let pk_data = if pk_text {
+        Zeroizing::new(to_pem("PUBLIC KEY", pk_bytes).into_bytes())
+    } else {
+        Zeroizing::new(pk_bytes.to_vec())
+    };
+    let pk_tmp = if pk_to_stdout {
+        None
+    } else {
+        Some(stage_file(pk_dest, &pk_data, 0o644).map_err(SaveError::WritePk)?)
+    };
+
+    // 비밀키 임시 파일은 생성 시점부터 0o600 권한. 바이너리는 사본 없이 그대로 기록
+    let sk_pem = sk_text.then(|| Zeroizing::new(to_pem("SECRET KEY", sk_bytes)));
+    let sk_data: &[u8] = sk_pem.as_ref().map_or(sk_bytes, |pem| pem.as_bytes());
+    let sk_tmp = if sk_to_stdout {
+        None
+    } else {
+        match stage_file(sk_dest, sk_data, 0o600) {
+            Ok(tmp) => Some(tmp),
+            Err(e) => {
+                if let Some(tmp) = &pk_tmp {
+                    let _ = fs::remove_file(tmp);
+                }
+                return Err(SaveError::WriteSk(e));
+            }
+        }
+    };
+
+    // 덮어쓸 공개키는 비밀키 저장에 실패했을 때 복원할 수 있도록 옆에 연결해 둠
+    let pk_backup = match &pk_tmp {
+        Some(_) if force => match backup_existing(pk_dest) {
+            Ok(backup) => backup,
+            Err(e) => {
+                for tmp in [&pk_tmp, &sk_tmp].into_iter().flatten() {
+                    let _ = fs::remove_file(tmp);
+                }
+                return Err(SaveError::CommitPk(e));
+            }
+        },
+        _ => None,
+    };
+
+    let pk_result = match &pk_tmp {
+        Some(tmp) => commit_file(tmp, pk_dest, force),
+        None => write_stdout(&pk_data),
+    };
+    if let Err(e) = pk_result {
+        for tmp in [&pk_tmp, &sk_tmp, &pk_backup].into_iter().flatten() {
+            let _ = fs::remove_file(tmp);
+        }
+        return Err(if e.kind() == io::ErrorKind::AlreadyExists {
+            SaveError::Exists(pk_path.to_string())
+        } else {
+            SaveError::CommitPk(e)
+        });
+    }
+
+    let sk_result = match &sk_tmp {
+        Some(tmp) => commit_file(tmp, sk_dest, force),
+        None => write_stdout(sk_data),
+    };
+    if let Err(e) = sk_result {
+        // 짝이 맞지 않는 키 페어가 남지 않도록 방금 옮긴 공개키를 되돌림
+        if let Some(tmp) = &sk_tmp {
+            let _ = fs::remove_file(tmp);
+        }
+        let rolled_back = !pk_to_stdout
+            && match &pk_backup {
+                Some(backup) => fs::rename(backup, pk_dest).is_ok(),
+                None => fs::remove_file(pk_dest).is_ok(),
+            };
+        return Err(
+            if e.kind() == io::ErrorKind::AlreadyExists && !rolled_back {
+                SaveError::Exists(sk_path.to_string())
+            } else {
+                SaveError::CommitSk {
+                    err: e,
+                    rolled_back,
+                }
+            },
+        );
+    }
+
+    if let Some(backup) = &pk_backup {
+        let _ = fs::remove_file(backup);
+    }
+    Ok(())
+}
+
+/// 실제로 사용할 (공개키, 비밀키) 텍스트 저장 여부를 결정합니다.
+///
+/// 터미널로 바이너리를 쏟지 않도록, 그리고 두 키가 함께 표준 출력으로 나갈 때
+/// 구분되도록 표준 출력 대상에는 PEM을 강제합니다.
+pub fn effective_text(pk_path: &str, sk_path: &str, pk_text: bool, sk_text: bool) -> (bool, bool) {
+    let terminal = io::stdout().is_terminal();
+    text_for_stdout(pk_path, sk_path, pk_text, sk_text, terminal)
+}
+
+/// 표준 출력이 터미널인지(`terminal`)를 받아 [`effective_text`]를 계산합니다.
+fn text_for_stdout(
+    pk_path: &str,
+    sk_path: &str,
+    pk_text: bool,
+    sk_text: bool,
+    terminal: bool,
+) -> (bool, bool) {
+    let pk_to_stdout = pk_path == STDIO_PATH;
+    let sk_to_stdout = sk_path == STDIO_PATH;
+    let stdout_pem = terminal || (pk_to_stdout && sk_to_stdout);
+    (
+        pk_text || (pk_to_stdout && stdout_pem),
+        sk_text || (sk_to_stdout && stdout_pem),
+    )
+}
+
+#[allow(clippy::too_many_arguments)]
 pub fn save_keys(
     pk_bytes: &[u8],
     sk_bytes: &[u8],
@@ -29,125 +626,374 @@ pub fn save_keys(
     variant: &str,
     pk_text: bool,
     sk_text: bool,
+    force: bool,
     lang: &str,
 ) {
-    let mut pk_file = match File::create(pk_path) {
-        Ok(file) => file,
-        Err(e) => {
-            let tr = super::i18n::load_translations(lang);
-            let msg = tr.get("error.file.create_pk").cloned().unwrap_or_else(|| {
-                "공개키 파일을 생성하는 도중 오류가 발생했습니다: {err}".to_string()
-            });
-            eprintln!("{}", msg.replace("{err}", &e.to_string()));
-            process::exit(1);
-        }
-    };
+    let pk_to_stdout = pk_path == STDIO_PATH;
+    let sk_to_stdout = sk_path == STDIO_PATH;
+    let (pk_text, sk_text) = effective_text(pk_path, sk_path, pk_text, sk_text);
 
-    if pk_text {
-        let pem = to_pem("PUBLIC KEY", pk_bytes);
-        if let Err(e) = pk_file.write_all(pem.as_bytes()) {
-            let tr = super::i18n::load_translations(lang);
-            let msg = tr.get("error.file.write_pk").cloned().unwrap_or_else(|| {
-                "공개키 파일에 쓰는 도중 오류가 발생했습니다: {err}".to_string()
-            });
-            eprintln!("{}", msg.replace("{err}", &e.to_string()));
-            process::exit(1);
-        }
-    } else if let Err(e) = pk_file.write_all(pk_bytes) {
+    if let Err(e) = write_key_pair(
+        pk_bytes, sk_bytes, pk_path, sk_path, pk_text, sk_text, force,
+    ) {
         let tr = super::i18n::load_translations(lang);
-        let msg = tr
-            .get("error.file.write_pk")
-            .cloned()
-            .unwrap_or_else(|| "공개키 파일에 쓰는 도중 오류가 발생했습니다: {err}".to_string());
-        eprintln!("{}", msg.replace("{err}", &e.to_string()));
+        if let SaveError::CommitSk {
+            rolled_back: true, ..
+        } = e
+        {
+            eprintln!(
+                "{}",
+                tr.get("info.rollback_pk")
+                    .cloned()
+                    .unwrap_or_else(|| {
+                        "비밀키 저장에 실패하여 공개키 파일을 이전 상태로 되돌렸습니다: {path}"
+                            .to_string()
+                    })
+                    .replace("{path}", pk_path)
+            );
+        }
+        eprintln!("{}", e.message(lang));
         process::exit(1);
     }
 
-    // 비밀키 파일은 원자적으로 0o600 권한으로 생성
-    #[cfg(unix)]
-    use std::os::unix::fs::OpenOptionsExt;
-    let mut sk_file = match {
-        #[cfg(unix)]
-        {
-            let mut opts = OpenOptions::new();
-            opts.write(true)
-                .create(true)
-                .truncate(true)
-                .mode(0o600)
-                .open(sk_path)
-        }
-        #[cfg(not(unix))]
-        {
-            OpenOptions::new()
-                .write(true)
-                .create(true)
-                .truncate(true)
-                .open(sk_path)
-        }
-    } {
-        Ok(file) => file,
-        Err(e) => {
-            let tr = super::i18n::load_translations(lang);
-            let msg = tr.get("error.file.create_sk").cloned().unwrap_or_else(|| {
-                "비밀키 파일을 생성하는 도중 오류가 발생했습니다: {err}".to_string()
-            });
-            eprintln!("{}", msg.replace("{err}", &e.to_string()));
-            process::exit(1);
+    // 로그 출력 (키가 표준 출력으로 나가면 파이프를 오염시키지 않도록 stderr 사용)
+    let to_stderr = pk_to_stdout || sk_to_stdout;
+    let log = |line: String| {
+        if to_stderr {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    };
+    let stdout_label = |path: &str| {
+        if path == STDIO_PATH {
+            "<stdout>".to_string()
+        } else {
+            path.to_string()
         }
     };
 
-    if sk_text {
-        let pem = to_pem("SECRET KEY", sk_bytes);
-        if let Err(e) = sk_file.write_all(pem.as_bytes()) {
-            let tr = super::i18n::load_translations(lang);
-            let msg = tr.get("error.file.write_sk").cloned().unwrap_or_else(|| {
-                "비밀키 파일에 쓰는 도중 오류가 발생했습니다: {err}".to_string()
-            });
-            eprintln!("{}", msg.replace("{err}", &e.to_string()));
-            process::exit(1);
-        }
-    } else if let Err(e) = sk_file.write_all(sk_bytes) {
-        let tr = super::i18n::load_translations(lang);
-        let msg = tr
-            .get("error.file.write_sk")
-            .cloned()
-            .unwrap_or_else(|| "비밀키 파일에 쓰는 도중 오류가 발생했습니다: {err}".to_string());
-        eprintln!("{}", msg.replace("{err}", &e.to_string()));
-        process::exit(1);
-    }
-
-    // 로그 출력
     let tr = super::i18n::load_translations(lang);
-    println!(
-        "{}",
-        tr.get("info.generated")
-            .cloned()
-            .unwrap_or_else(|| "키 페어 생성 완료".to_string())
-            .replace("{alg}", &algorithm.to_uppercase())
-            .replace("{var}", &variant)
-    );
-
-    println!(
-        "{}",
-        tr.get("info.pk_saved")
-            .cloned()
-            .unwrap_or_else(|| "공개키 저장: {path}".to_string())
-            .replace("{path}", pk_path)
-    );
+    log(tr
+        .get("info.generated")
+        .cloned()
+        .unwrap_or_else(|| "키 페어 생성 완료".to_string())
+        .replace("{alg}", &algorithm.to_uppercase())
+        .replace("{var}", variant));
 
-    println!(
-        "{}",
-        tr.get("info.sk_saved")
-            .cloned()
-            .unwrap_or_else(|| "비밀키 저장: {path}".to_string())
-            .replace("{path}", sk_path)
-    );
+    log(tr
+        .get("info.pk_saved")
+        .cloned()
+        .unwrap_or_else(|| "공개키 저장: {path}".to_string())
+        .replace("{path}", &stdout_label(pk_path)));
 
-    println!(
+    log(tr
+        .get("info.sk_saved")
+        .cloned()
+        .unwrap_or_else(|| "비밀키 저장: {path}".to_string())
+        .replace("{path}", &stdout_label(sk_path)));
+
+    log(format!(
         "{} {}",
         tr.get("info.pk_preview")
             .cloned()
             .unwrap_or_else(|| "공개키(base64) 미리보기:".to_string()),
         general_purpose::STANDARD.encode(&pk_bytes[0..32.min(pk_bytes.len())])
-    );
+    ));
+}
+
+/// JSON 등에서 바이트열을 base64 문자열로 주고받는 serde 도우미 (`#[serde(with = ...)]`)
+///
+/// 비밀키를 담을 수 있으므로 직렬화·역직렬화 중 만든 중간 문자열은 0으로 덮어씁니다.
+pub mod base64_serde {
+    use base64::Engine as _;
+    use base64::engine::general_purpose;
+    use serde::{Deserialize, Deserializer, Serializer, de};
+    use zeroize::Zeroizing;
+
+    pub fn serialize<T, S>(bytes: &T, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        T: AsRef<[u8]>,
+        S: Serializer,
+    {
+        let text = Zeroizing::new(general_purpose::STANDARD.encode(bytes.as_ref()));
+        serializer.serialize_str(&text)
+    }
+
+    pub fn deserialize<'de, T, D>(deserializer: D) -> Result<T, D::Error>
+    where
+        T: From<Vec<u8>>,
+        D: Deserializer<'de>,
+    {
+        let text = Zeroizing::new(String::deserialize(deserializer)?);
+        general_purpose::STANDARD
+            .decode(text.as_bytes())
+            .map(T::from)
+            .map_err(de::Error::custom)
+    }
+
+    /// 생략할 수 있는 필드용 (`#[serde(default, with = "base64_serde::option")]`)
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        pub fn serialize<T, S>(bytes: &Option<T>, serializer: S) -> Result<S::Ok, S::Error>
+        where
+            T: AsRef<[u8]>,
+            S: Serializer,
+        {
+            match bytes {
+                Some(bytes) => super::serialize(bytes, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, T, D>(deserializer: D) -> Result<Option<T>, D::Error>
+        where
+            T: From<Vec<u8>>,
+            D: Deserializer<'de>,
+        {
+            #[derive(Deserialize)]
+            struct Wrapped(#[serde(with = "super")] Vec<u8>);
+            Ok(Option::<Wrapped>::deserialize(deserializer)?.map(|w| T::from(w.0)))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internals::test_util::ScratchDir;
+
+    fn path_str(path: &Path) -> &str {
+        path.to_str().unwrap()
+    }
+
+    #[test]
+    fn pem_round_trips_and_ignores_surrounding_text() {
+        let data: Vec<u8> = (0..=255).collect();
+        let pem = to_pem("SECRET KEY", &data);
+        assert_eq!(pem.len(), pem_len("SECRET KEY", data.len()));
+        assert!(pem.lines().all(|line| line.len() <= 64));
+
+        let wrapped = format!("comment\n{}\ntrailer", pem.replace('\n', "\r\n"));
+        assert_eq!(
+            from_pem("SECRET KEY", &wrapped).unwrap().as_slice(),
+            &data[..]
+        );
+        assert!(from_pem("PUBLIC KEY", &pem).is_none());
+    }
+
+    #[test]
+    fn keys_are_decoded_from_pem_or_passed_through_as_binary() {
+        let binary = Zeroizing::new(vec![0x2d, 0x00, 0xff]);
+        assert_eq!(decode_key(binary.clone(), "PUBLIC KEY").unwrap(), binary);
+
+        let pem = Zeroizing::new(to_pem("PUBLIC KEY", b"key").into_bytes());
+        assert_eq!(
+            decode_key(pem.clone(), "PUBLIC KEY").unwrap().as_slice(),
+            b"key"
+        );
+        // 다른 라벨의 PEM은 바이너리로 오인하지 않고 거부
+        let err = decode_key(pem, "SECRET KEY").unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+    }
+
+    #[test]
+    fn read_key_accepts_files_and_grows_without_losing_bytes() {
+        let dir = ScratchDir::new("key-io", "read");
+        let path = dir.join("big.sk");
+        let data: Vec<u8> = (0..20_000u32).map(|i| (i % 251) as u8).collect();
+        fs::write(&path, &data).unwrap();
+        assert_eq!(
+            read_key(path_str(&path), "SECRET KEY").unwrap().as_slice(),
+            &data[..]
+        );
+        // 크기 힌트 없이 여러 번 키워도 내용이 그대로
+        let read = read_all_zeroizing(&mut &data[..], 0).unwrap();
+        assert_eq!(read.as_slice(), &data[..]);
+    }
+
+    #[test]
+    fn stdout_keys_are_forced_to_pem_when_ambiguous() {
+        // 파일로 가는 키는 요청한 형식을 그대로 따름
+        assert_eq!(
+            text_for_stdout("a.pub", "a.sk", false, true, true),
+            (false, true)
+        );
+        // 터미널이면 표준 출력으로 가는 키만 PEM
+        assert_eq!(
+            text_for_stdout("-", "a.sk", false, false, true),
+            (true, false)
+        );
+        // 파이프로 가는 키 하나는 바이너리 그대로
+        assert_eq!(
+            text_for_stdout("-", "a.sk", false, false, false),
+            (false, false)
+        );
+        // 두 키가 함께 나가면 구분할 수 있도록 둘 다 PEM
+        assert_eq!(text_for_stdout("-", "-", false, false, false), (true, true));
+    }
+
+    #[test]
+    fn write_atomic_refuses_existing_files_unless_forced() {
+        let dir = ScratchDir::new("key-io", "atomic");
+        let path = dir.join("sub/key.pub");
+
+        write_atomic(&path, b"first", 0o644, false).unwrap();
+        let err = write_atomic(&path, b"second", 0o644, false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        assert_eq!(fs::read(&path).unwrap(), b"first");
+
+        write_atomic(&path, b"second", 0o644, true).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"second");
+        // 임시 파일이 남지 않음
+        assert_eq!(dir.names(), ["sub"]);
+        assert_eq!(fs::read_dir(dir.join("sub")).unwrap().count(), 1);
+    }
+
+    #[test]
+    fn atomic_file_is_invisible_until_committed() {
+        let dir = ScratchDir::new("key-io", "atomic-file");
+        let path = dir.join("out.bin");
+
+        let mut file = AtomicFile::create(&path, 0o600, false).unwrap();
+        file.write_all(b"partial").unwrap();
+        assert!(!path.exists());
+        drop(file);
+        assert!(dir.names().is_empty());
+
+        let mut file = AtomicFile::create(&path, 0o600, false).unwrap();
+        file.write_all(b"whole").unwrap();
+        file.commit(false).unwrap();
+        assert_eq!(fs::read(&path).unwrap(), b"whole");
+        assert_eq!(dir.names(), ["out.bin"]);
+    }
+
+    #[test]
+    fn key_pair_is_not_written_when_either_file_exists() {
+        let dir = ScratchDir::new("key-io", "exists");
+        let (pk, sk) = (dir.join("a.pub"), dir.join("a.sk"));
+        fs::write(&sk, b"old secret").unwrap();
+
+        let err = write_key_pair(
+            b"pk",
+            b"sk",
+            path_str(&pk),
+            path_str(&sk),
+            false,
+            false,
+            false,
+        )
+        .unwrap_err();
+        assert!(matches!(err, SaveError::Exists(p) if p == path_str(&sk)));
+        assert!(!pk.exists());
+        assert_eq!(fs::read(&sk).unwrap(), b"old secret");
+        assert_eq!(dir.names(), ["a.sk"]);
+    }
+
+    #[test]
+    fn key_pair_is_written_with_private_secret_key() {
+        let dir = ScratchDir::new("key-io", "pair");
+        let (pk, sk) = (dir.join("a.pub"), dir.join("a.sk"));
+
+        write_key_pair(
+            b"public",
+            b"secret",
+            path_str(&pk),
+            path_str(&sk),
+            true,
+            false,
+            false,
+        )
+        .unwrap();
+        let text = fs::read_to_string(&pk).unwrap();
+        assert_eq!(from_pem("PUBLIC KEY", &text).unwrap().as_slice(), b"public");
+        assert_eq!(fs::read(&sk).unwrap(), b"secret");
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(&sk).unwrap().permissions().mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+        assert_eq!(dir.names(), ["a.pub", "a.sk"]);
+    }
+
+    #[test]
+    fn failed_secret_key_commit_restores_the_overwritten_public_key() {
+        let dir = ScratchDir::new("key-io", "restore");
+        let (pk, sk) = (dir.join("a.pub"), dir.join("a.sk"));
+        fs::write(&pk, b"old public").unwrap();
+        // 비어 있지 않은 디렉토리 위로는 이름을 바꿀 수 없으므로 비밀키 커밋이 실패함
+        fs::create_dir_all(sk.join("busy")).unwrap();
+
+        let err = write_key_pair(
+            b"new public",
+            b"new secret",
+            path_str(&pk),
+            path_str(&sk),
+            false,
+            false,
+            true,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            SaveError::CommitSk {
+                rolled_back: true,
+                ..
+            }
+        ));
+        assert_eq!(fs::read(&pk).unwrap(), b"old public");
+        // 백업과 임시 파일이 남지 않음
+        assert_eq!(dir.names(), ["a.pub", "a.sk"]);
+    }
+
+    #[test]
+    fn failed_secret_key_commit_removes_a_new_public_key() {
+        let dir = ScratchDir::new("key-io", "remove");
+        let (pk, sk) = (dir.join("a.pub"), dir.join("a.sk"));
+        fs::create_dir_all(sk.join("busy")).unwrap();
+
+        let err = write_key_pair(
+            b"public",
+            b"secret",
+            path_str(&pk),
+            path_str(&sk),
+            false,
+            false,
+            true,
+        )
+        .unwrap_err();
+        assert!(matches!(
+            err,
+            SaveError::CommitSk {
+                rolled_back: true,
+                ..
+            }
+        ));
+        assert!(!pk.exists());
+        assert_eq!(dir.names(), ["a.sk"]);
+    }
+
+    #[test]
+    fn forced_overwrite_replaces_both_files() {
+        let dir = ScratchDir::new("key-io", "force");
+        let (pk, sk) = (dir.join("a.pub"), dir.join("a.sk"));
+        fs::write(&pk, b"old public").unwrap();
+        fs::write(&sk, b"old secret").unwrap();
+
+        write_key_pair(
+            b"new public",
+            b"new secret",
+            path_str(&pk),
+            path_str(&sk),
+            false,
+            false,
+            true,
+        )
+        .unwrap();
+        assert_eq!(fs::read(&pk).unwrap(), b"new public");
+        assert_eq!(fs::read(&sk).unwrap(), b"new secret");
+        assert_eq!(dir.names(), ["a.pub", "a.sk"]);
+    }
 }
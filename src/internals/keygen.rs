@@ -22,6 +22,9 @@ use pqcrypto_traits::kem::{PublicKey as KemPublicKey, SecretKey as KemSecretKey}
 use pqcrypto_traits::sign::{PublicKey as SignPublicKey, SecretKey as SignSecretKey};
 use std::collections::HashMap;
 use std::error::Error;
+use std::fmt;
+
+use super::secret::{PlainKey, SecretBuffer, wipe_key};
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Algorithm {
@@ -35,6 +38,36 @@ pub enum Algorithm {
     Unknown,
 }
 
+impl Algorithm {
+    /// 알고리즘의 정규화된 CLI 표기(예: "mlkem", "sphincs+")를 반환합니다.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Algorithm::MLKEM => "mlkem",
+            Algorithm::HQC => "hqc",
+            Algorithm::McEliece => "mceliece",
+            Algorithm::FALCON => "falcon",
+            Algorithm::MLDSA => "mldsa",
+            Algorithm::SPHINCSPlus => "sphincs+",
+            Algorithm::Unknown => "unknown",
+        }
+    }
+
+    /// NIST 표준(FIPS 203/204)으로 확정된 알고리즘인지 여부
+    ///
+    /// HQC는 표준 초안 전, Classic McEliece는 ISO 검토 중, FALCON은 FN-DSA(FIPS 206)
+    /// 확정 전이므로 제외합니다. pqcrypto의 SPHINCS+는 라운드 3 제출본으로, 키와 서명이
+    /// SLH-DSA(FIPS 205)와 바이트 단위로 호환되지 않으므로 역시 제외합니다.
+    pub fn is_standardized(&self) -> bool {
+        matches!(self, Algorithm::MLKEM | Algorithm::MLDSA)
+    }
+}
+
+impl fmt::Display for Algorithm {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub enum Variant {
     // MlKem
@@ -82,370 +115,437 @@ pub enum Variant {
     Unknown,
 }
 
-pub type KeyGenResult = Result<(Vec<u8>, Vec<u8>), Box<dyn Error + Send + Sync>>;
+impl Variant {
+    /// 배리언트의 CLI 표기(예: "768", "348864f", "nopad512")를 반환합니다.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Variant::MLKEM512 => "512",
+            Variant::MLKEM768 => "768",
+            Variant::MLKEM1024 => "1024",
+            Variant::HQC128 => "128",
+            Variant::HQC192 => "192",
+            Variant::HQC256 => "256",
+            Variant::McEliece348864 => "348864",
+            Variant::McEliece348864f => "348864f",
+            Variant::McEliece460896 => "460896",
+            Variant::McEliece460896f => "460896f",
+            Variant::McEliece6688128 => "6688128",
+            Variant::McEliece6688128f => "6688128f",
+            Variant::McEliece6960119 => "6960119",
+            Variant::McEliece6960119f => "6960119f",
+            Variant::McEliece8192128 => "8192128",
+            Variant::McEliece8192128f => "8192128f",
+            Variant::FALCONNoPad512 => "nopad512",
+            Variant::FALCONNoPad1024 => "nopad1024",
+            Variant::FALCONPadded512 => "padded512",
+            Variant::FALCONPadded1024 => "padded1024",
+            Variant::MLDSA44 => "44",
+            Variant::MLDSA65 => "65",
+            Variant::MLDSA87 => "87",
+            Variant::SPHINCSsha2128fsimple => "sha2_128f_simple",
+            Variant::SPHINCSsha2128ssimple => "sha2_128s_simple",
+            Variant::SPHINCSsha2192fsimple => "sha2_192f_simple",
+            Variant::SPHINCSsha2192ssimple => "sha2_192s_simple",
+            Variant::SPHINCSsha2256fsimple => "sha2_256f_simple",
+            Variant::SPHINCSsha2256ssimple => "sha2_256s_simple",
+            Variant::SPHINCSshake128fsimple => "shake_128f_simple",
+            Variant::SPHINCSshake128ssimple => "shake_128s_simple",
+            Variant::SPHINCSshake192fsimple => "shake_192f_simple",
+            Variant::SPHINCSshake192ssimple => "shake_192s_simple",
+            Variant::SPHINCSshake256fsimple => "shake_256f_simple",
+            Variant::SPHINCSshake256ssimple => "shake_256s_simple",
+            Variant::Unknown => "unknown",
+        }
+    }
+}
+
+impl Variant {
+    /// 배리언트가 속한 알고리즘을 반환합니다.
+    pub fn algorithm(&self) -> Algorithm {
+        match self {
+            Variant::MLKEM512 | Variant::MLKEM768 | Variant::MLKEM1024 => Algorithm::MLKEM,
+            Variant::HQC128 | Variant::HQC192 | Variant::HQC256 => Algorithm::HQC,
+            Variant::McEliece348864
+            | Variant::McEliece348864f
+            | Variant::McEliece460896
+            | Variant::McEliece460896f
+            | Variant::McEliece6688128
+            | Variant::McEliece6688128f
+            | Variant::McEliece6960119
+            | Variant::McEliece6960119f
+            | Variant::McEliece8192128
+            | Variant::McEliece8192128f => Algorithm::McEliece,
+            Variant::FALCONNoPad512
+            | Variant::FALCONNoPad1024
+            | Variant::FALCONPadded512
+            | Variant::FALCONPadded1024 => Algorithm::FALCON,
+            Variant::MLDSA44 | Variant::MLDSA65 | Variant::MLDSA87 => Algorithm::MLDSA,
+            Variant::SPHINCSsha2128fsimple
+            | Variant::SPHINCSsha2128ssimple
+            | Variant::SPHINCSsha2192fsimple
+            | Variant::SPHINCSsha2192ssimple
+            | Variant::SPHINCSsha2256fsimple
+            | Variant::SPHINCSsha2256ssimple
+            | Variant::SPHINCSshake128fsimple
+            | Variant::SPHINCSshake128ssimple
+            | Variant::SPHINCSshake192fsimple
+            | Variant::SPHINCSshake192ssimple
+            | Variant::SPHINCSshake256fsimple
+            | Variant::SPHINCSshake256ssimple => Algorithm::SPHINCSPlus,
+            Variant::Unknown => Algorithm::Unknown,
+        }
+    }
+
+    /// 파라미터 세트가 목표로 하는 NIST 보안 범주(1–5)를 반환합니다. 알 수 없으면 0입니다.
+    pub fn nist_category(&self) -> u8 {
+        match self {
+            Variant::MLKEM512
+            | Variant::HQC128
+            | Variant::McEliece348864
+            | Variant::McEliece348864f
+            | Variant::FALCONNoPad512
+            | Variant::FALCONPadded512
+            | Variant::SPHINCSsha2128fsimple
+            | Variant::SPHINCSsha2128ssimple
+            | Variant::SPHINCSshake128fsimple
+            | Variant::SPHINCSshake128ssimple => 1,
+            Variant::MLDSA44 => 2,
+            Variant::MLKEM768
+            | Variant::HQC192
+            | Variant::McEliece460896
+            | Variant::McEliece460896f
+            | Variant::MLDSA65
+            | Variant::SPHINCSsha2192fsimple
+            | Variant::SPHINCSsha2192ssimple
+            | Variant::SPHINCSshake192fsimple
+            | Variant::SPHINCSshake192ssimple => 3,
+            Variant::MLKEM1024
+            | Variant::HQC256
+            | Variant::McEliece6688128
+            | Variant::McEliece6688128f
+            | Variant::McEliece6960119
+            | Variant::McEliece6960119f
+            | Variant::McEliece8192128
+            | Variant::McEliece8192128f
+            | Variant::FALCONNoPad1024
+            | Variant::FALCONPadded1024
+            | Variant::MLDSA87
+            | Variant::SPHINCSsha2256fsimple
+            | Variant::SPHINCSsha2256ssimple
+            | Variant::SPHINCSshake256fsimple
+            | Variant::SPHINCSshake256ssimple => 5,
+            Variant::Unknown => 0,
+        }
+    }
+
+    /// 표준(또는 제출 문서)에서 사용하는 파라미터 세트 이름을 반환합니다.
+    pub fn standard_name(&self) -> String {
+        match self.algorithm() {
+            Algorithm::MLKEM => format!("ML-KEM-{}", self.as_str()),
+            Algorithm::HQC => format!("HQC-{}", self.as_str()),
+            Algorithm::McEliece => format!("Classic-McEliece-{}", self.as_str()),
+            Algorithm::FALCON => match self {
+                Variant::FALCONNoPad512 => "Falcon-512".to_string(),
+                Variant::FALCONNoPad1024 => "Falcon-1024".to_string(),
+                Variant::FALCONPadded512 => "Falcon-padded-512".to_string(),
+                _ => "Falcon-padded-1024".to_string(),
+            },
+            Algorithm::MLDSA => format!("ML-DSA-{}", self.as_str()),
+            Algorithm::SPHINCSPlus => {
+                let (hash, rest) = self.as_str().split_once('_').unwrap_or_default();
+                format!(
+                    "SPHINCS+-{}-{}",
+                    hash.to_uppercase(),
+                    rest.replace('_', "-")
+                )
+            }
+            Algorithm::Unknown => "unknown".to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Variant {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 키 생성 결과 (공개키, 비밀키). 비밀키는 잠긴 [`SecretBuffer`]에 담깁니다.
+pub type KeyGenResult = Result<(Vec<u8>, SecretBuffer), Box<dyn Error + Send + Sync>>;
+
+type KeyGenFn = Box<dyn Fn() -> (Vec<u8>, SecretBuffer)>;
+
+/// pqcrypto KEM 비밀키를 잠긴 버퍼로 옮기고 원래 구조체를 지웁니다.
+fn take_kem_secret<S: KemSecretKey + PlainKey>(mut sk: S) -> SecretBuffer {
+    let buffer = SecretBuffer::from_slice(sk.as_bytes());
+    wipe_key(&mut sk);
+    buffer
+}
+
+/// pqcrypto 서명 비밀키를 잠긴 버퍼로 옮기고 원래 구조체를 지웁니다.
+fn take_sign_secret<S: SignSecretKey + PlainKey>(mut sk: S) -> SecretBuffer {
+    let buffer = SecretBuffer::from_slice(sk.as_bytes());
+    wipe_key(&mut sk);
+    buffer
+}
 
 pub fn generate_keys(algorithm: Algorithm, variant: Variant) -> KeyGenResult {
     // 알고리즘별 HashMap으로 키 생성 클로저 매핑 (중복 제거)
-    let kem_generators: HashMap<Variant, Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>> = {
+    let kem_generators: HashMap<Variant, KeyGenFn> = {
         let mut map = HashMap::new();
         map.insert(
             Variant::MLKEM512,
             Box::new(|| {
                 let (pk, sk) = mlkem512::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::MLKEM768,
             Box::new(|| {
                 let (pk, sk) = mlkem768::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::MLKEM1024,
             Box::new(|| {
                 let (pk, sk) = mlkem1024::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
 
         map.insert(
             Variant::HQC128,
             Box::new(|| {
                 let (pk, sk) = hqc128::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::HQC192,
             Box::new(|| {
                 let (pk, sk) = hqc192::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::HQC256,
             Box::new(|| {
                 let (pk, sk) = hqc256::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
 
         map.insert(
             Variant::McEliece348864,
             Box::new(|| {
                 let (pk, sk) = mceliece348864::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece348864f,
             Box::new(|| {
                 let (pk, sk) = mceliece348864f::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece460896,
             Box::new(|| {
                 let (pk, sk) = mceliece460896::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece460896f,
             Box::new(|| {
                 let (pk, sk) = mceliece460896f::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece6688128,
             Box::new(|| {
                 let (pk, sk) = mceliece6688128::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece6688128f,
             Box::new(|| {
                 let (pk, sk) = mceliece6688128f::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece6960119,
             Box::new(|| {
                 let (pk, sk) = mceliece6960119::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece6960119f,
             Box::new(|| {
                 let (pk, sk) = mceliece6960119f::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece8192128,
             Box::new(|| {
                 let (pk, sk) = mceliece8192128::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::McEliece8192128f,
             Box::new(|| {
                 let (pk, sk) = mceliece8192128f::keypair();
-                (
-                    KemPublicKey::as_bytes(&pk).to_vec(),
-                    KemSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (KemPublicKey::as_bytes(&pk).to_vec(), take_kem_secret(sk))
+            }) as KeyGenFn,
         );
         map
     };
 
-    let sign_generators: HashMap<Variant, Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>> = {
+    let sign_generators: HashMap<Variant, KeyGenFn> = {
         let mut map = HashMap::new();
         map.insert(
             Variant::FALCONNoPad512,
             Box::new(|| {
                 let (pk, sk) = falcon512::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::FALCONNoPad1024,
             Box::new(|| {
                 let (pk, sk) = falcon1024::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::FALCONPadded512,
             Box::new(|| {
                 let (pk, sk) = falconpadded512::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::FALCONPadded1024,
             Box::new(|| {
                 let (pk, sk) = falconpadded1024::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
 
         map.insert(
             Variant::MLDSA44,
             Box::new(|| {
                 let (pk, sk) = mldsa44::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::MLDSA65,
             Box::new(|| {
                 let (pk, sk) = mldsa65::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::MLDSA87,
             Box::new(|| {
                 let (pk, sk) = mldsa87::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
 
         map.insert(
             Variant::SPHINCSsha2128fsimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_sha2_128f_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSsha2128ssimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_sha2_128s_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSsha2192fsimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_sha2_192f_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSsha2192ssimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_sha2_192s_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSsha2256fsimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_sha2_256f_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSsha2256ssimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_sha2_256s_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSshake128fsimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_shake_128f_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSshake128ssimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_shake_128s_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSshake192fsimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_shake_192f_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSshake192ssimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_shake_192s_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSshake256fsimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_shake_256f_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map.insert(
             Variant::SPHINCSshake256ssimple,
             Box::new(|| {
                 let (pk, sk) = sphincs_shake_256s_simple::keypair();
-                (
-                    SignPublicKey::as_bytes(&pk).to_vec(),
-                    SignSecretKey::as_bytes(&sk).to_vec(),
-                )
-            }) as Box<dyn Fn() -> (Vec<u8>, Vec<u8>)>,
+                (SignPublicKey::as_bytes(&pk).to_vec(), take_sign_secret(sk))
+            }) as KeyGenFn,
         );
         map
     };
@@ -491,7 +591,64 @@ pub fn parse_algorithm(input: &str) -> Option<Algorithm> {
     }
 }
 
-// 비슷하게 Variant 파싱 함수 추가
+/// 알고리즘에 속한 배리언트 문자열을 파싱합니다.
+///
+/// "768", "mlkem768", "ml-kem-768"처럼 알고리즘 접두사가 붙은 표기도 허용하며,
+/// 해당 알고리즘에 속하지 않는 배리언트는 `None`을 반환합니다.
+pub fn parse_variant(algorithm: Algorithm, input: &str) -> Option<Variant> {
+    let lowered = input.to_lowercase();
+    let mut token = lowered.as_str();
+    for prefix in [
+        "ml-kem", "mlkem", "hqc", "mceliece", "mce", "falcon", "ml-dsa", "mldsa", "sphincs+",
+        "sphincs", "sph",
+    ] {
+        if let Some(rest) = token.strip_prefix(prefix) {
+            token = rest.trim_start_matches(['-', '_']);
+            break;
+        }
+    }
+
+    let candidates: &[Variant] = match algorithm {
+        Algorithm::MLKEM => &[Variant::MLKEM512, Variant::MLKEM768, Variant::MLKEM1024],
+        Algorithm::HQC => &[Variant::HQC128, Variant::HQC192, Variant::HQC256],
+        Algorithm::McEliece => &[
+            Variant::McEliece348864,
+            Variant::McEliece348864f,
+            Variant::McEliece460896,
+            Variant::McEliece460896f,
+            Variant::McEliece6688128,
+            Variant::McEliece6688128f,
+            Variant::McEliece6960119,
+            Variant::McEliece6960119f,
+            Variant::McEliece8192128,
+            Variant::McEliece8192128f,
+        ],
+        Algorithm::FALCON => &[
+            Variant::FALCONNoPad512,
+            Variant::FALCONNoPad1024,
+            Variant::FALCONPadded512,
+            Variant::FALCONPadded1024,
+        ],
+        Algorithm::MLDSA => &[Variant::MLDSA44, Variant::MLDSA65, Variant::MLDSA87],
+        Algorithm::SPHINCSPlus => &[
+            Variant::SPHINCSsha2128fsimple,
+            Variant::SPHINCSsha2128ssimple,
+            Variant::SPHINCSsha2192fsimple,
+            Variant::SPHINCSsha2192ssimple,
+            Variant::SPHINCSsha2256fsimple,
+            Variant::SPHINCSsha2256ssimple,
+            Variant::SPHINCSshake128fsimple,
+            Variant::SPHINCSshake128ssimple,
+            Variant::SPHINCSshake192fsimple,
+            Variant::SPHINCSshake192ssimple,
+            Variant::SPHINCSshake256fsimple,
+            Variant::SPHINCSshake256ssimple,
+        ],
+        Algorithm::Unknown => &[],
+    };
+
+    candidates.iter().find(|v| v.as_str() == token).cloned()
+}
 
 pub fn minimal_variant_for_algorithm(algo: Algorithm) -> Variant {
     match algo {
@@ -504,3 +661,19 @@ pub fn minimal_variant_for_algorithm(algo: Algorithm) -> Variant {
         _ => Variant::Unknown,
     }
 }
+
+/// 공개키와 비밀키가 한 쌍인지 확인합니다.
+///
+/// KEM은 캡슐화한 공유 비밀을 되찾을 수 있는지, 서명은 시험 서명이 검증되는지로 판정합니다.
+/// Classic McEliece 등은 스택 사용량이 크므로 대용량 스택에서 호출하세요.
+pub fn verify_key_pair(
+    variant: &Variant,
+    pk_bytes: &[u8],
+    sk_bytes: &[u8],
+) -> Result<bool, Box<dyn Error + Send + Sync>> {
+    if super::kem::is_kem(variant) {
+        Ok(super::kem::pair_matches(variant, pk_bytes, sk_bytes)?)
+    } else {
+        Ok(super::sign::pair_matches(variant, pk_bytes, sk_bytes)?)
+    }
+}
@@ -0,0 +1,362 @@
+use std::future::Future;
+use std::panic::{self, AssertUnwindSafe};
+use std::pin::Pin;
+use std::sync::{Arc, Mutex, OnceLock};
+use std::task::{Context, Poll, Waker};
+
+use super::executor::{ExecutorError, ExecutorOptions, Job, KeygenExecutor};
+use super::keygen::{Algorithm, KeyGenResult, Variant, generate_keys};
+
+/// [`generate_keys_async`]가 쓰는 공유 실행기 (처음 호출할 때 만들며 만들지 못하면 `None`)
+static SHARED: OnceLock<Option<KeygenExecutor>> = OnceLock::new();
+
+/// 실행기에서 도는 작업의 결과를 기다리는 퓨처
+///
+/// 특정 비동기 런타임에 의존하지 않으며(tokio 등 어디서나 `.await` 가능), 작업은 실행기의
+/// 대용량 스택 워커에서 실행되므로 런타임 스레드를 막지 않습니다.
+///
+/// # 취소
+/// 퓨처를 끝나기 전에 버리면 작업이 취소됩니다.
+/// * 아직 워커가 가져가지 않은 작업은 실행하지 않습니다.
+/// * 이미 실행 중인 작업(pqcrypto 호출)은 중간에 멈출 수 없으므로 끝까지 실행한 뒤, 결과를
+///   워커 스레드에서 곧바로 버립니다. 비밀키는 [`super::secret::SecretBuffer`]에 담겨 있어
+///   버릴 때 0으로 덮어써지며, 결과가 퓨처 쪽에 전달된 뒤 취소되어도 마찬가지입니다.
+#[must_use = "퓨처는 .await 하거나 poll 해야 실행 결과를 받을 수 있습니다"]
+pub struct JobFuture<R> {
+    shared: Arc<Mutex<State<R>>>,
+}
+
+/// 작업과 퓨처가 공유하는 상태
+struct State<R> {
+    result: Option<Result<R, ExecutorError>>,
+    waker: Option<Waker>,
+    cancelled: bool,
+}
+
+/// 작업 쪽 완료 알림. 작업이 실행되지 않고 버려지면(실행기 종료 등) [`ExecutorError::ShutDown`]을
+/// 전달해 퓨처가 영원히 기다리지 않게 합니다.
+struct Completer<R> {
+    shared: Arc<Mutex<State<R>>>,
+    done: bool,
+}
+
+impl KeygenExecutor {
+    /// 작업을 대기열에 넣고 결과를 기다리는 퓨처를 반환합니다.
+    ///
+    /// 비동기 코드에서 런타임 스레드를 막지 않도록, 제한이 있는 대기열이 가득 찼으면 기다리지 않고
+    /// [`ExecutorError::QueueFull`]을 반환합니다.
+    pub fn submit_async<F, R>(&self, f: F) -> Result<JobFuture<R>, ExecutorError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let shared = Arc::new(Mutex::new(State {
+            result: None,
+            waker: None,
+            cancelled: false,
+        }));
+        let completer = Completer {
+            shared: Arc::clone(&shared),
+            done: false,
+        };
+        let job: Job = Box::new(move || {
+            if completer.is_cancelled() {
+                return;
+            }
+            let outcome =
+                panic::catch_unwind(AssertUnwindSafe(f)).map_err(|_| ExecutorError::Panicked);
+            completer.complete(outcome);
+        });
+        self.enqueue(job, false)?;
+        Ok(JobFuture { shared })
+    }
+
+    /// 키 생성 작업을 대기열에 넣고 결과를 기다리는 퓨처를 반환합니다.
+    pub fn generate_async(
+        &self,
+        algorithm: Algorithm,
+        variant: Variant,
+    ) -> Result<JobFuture<KeyGenResult>, ExecutorError> {
+        self.submit_async(move || generate_keys(algorithm, variant))
+    }
+}
+
+/// 프로세스 공유 실행기(기본 [`ExecutorOptions`])에서 키를 생성하는 퓨처를 반환합니다.
+///
+/// 워커 스레드를 만들 수 없으면 [`ExecutorError::Unavailable`]을 반환합니다.
+pub fn generate_keys_async(
+    algorithm: Algorithm,
+    variant: Variant,
+) -> Result<JobFuture<KeyGenResult>, ExecutorError> {
+    SHARED
+        .get_or_init(|| KeygenExecutor::with_options(ExecutorOptions::default()).ok())
+        .as_ref()
+        .ok_or(ExecutorError::Unavailable)?
+        .generate_async(algorithm, variant)
+}
+
+impl<R> JobFuture<R> {
+    /// 결과가 준비되었는지 여부
+    pub fn is_finished(&self) -> bool {
+        self.shared
+            .lock()
+            .map_or(true, |state| state.result.is_some())
+    }
+}
+
+impl<R> Future for JobFuture<R> {
+    type Output = Result<R, ExecutorError>;
+
+    fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        let Ok(mut state) = self.shared.lock() else {
+            return Poll::Ready(Err(ExecutorError::Panicked));
+        };
+        match state.result.take() {
+            Some(result) => Poll::Ready(result),
+            None => {
+                // 마지막으로 poll한 태스크만 깨우면 됨
+                match &mut state.waker {
+                    Some(waker) if waker.will_wake(cx.waker()) => {}
+                    slot => *slot = Some(cx.waker().clone()),
+                }
+                Poll::Pending
+            }
+        }
+    }
+}
+
+impl<R> Drop for JobFuture<R> {
+    fn drop(&mut self) {
+        // 받지 않은 결과는 잠금을 푼 뒤 여기서 버림 (비밀키는 버릴 때 0으로 덮어씀)
+        let unclaimed = match self.shared.lock() {
+            Ok(mut state) => {
+                state.cancelled = true;
+                state.waker = None;
+                state.result.take()
+            }
+            Err(_) => None,
+        };
+        drop(unclaimed);
+    }
+}
+
+impl<R> Completer<R> {
+    fn is_cancelled(&self) -> bool {
+        self.shared.lock().map_or(true, |state| state.cancelled)
+    }
+
+    /// 결과를 퓨처에 전달하고 기다리는 태스크를 깨웁니다. 취소되었으면 결과를 이 스레드에서 버립니다.
+    fn complete(mut self, outcome: Result<R, ExecutorError>) {
+        self.done = true;
+        self.deliver(outcome);
+    }
+
+    fn deliver(&self, outcome: Result<R, ExecutorError>) {
+        let Ok(mut state) = self.shared.lock() else {
+            return;
+        };
+        if state.cancelled {
+            drop(state);
+            drop(outcome);
+            return;
+        }
+        state.result = Some(outcome);
+        let waker = state.waker.take();
+        drop(state);
+        if let Some(waker) = waker {
+            waker.wake();
+        }
+    }
+}
+
+impl<R> Drop for Completer<R> {
+    fn drop(&mut self) {
+        if !self.done {
+            self.deliver(Err(ExecutorError::ShutDown));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::mpsc;
+    use std::task::Wake;
+    use std::thread::{self, Thread};
+
+    use super::*;
+    use crate::internals::stack;
+
+    /// 깨우면 기다리는 스레드를 다시 돌리는 웨이커 (깨운 횟수를 셈)
+    struct Unpark {
+        thread: Thread,
+        wakes: AtomicUsize,
+    }
+
+    impl Wake for Unpark {
+        fn wake(self: Arc<Self>) {
+            self.wakes.fetch_add(1, Ordering::SeqCst);
+            self.thread.unpark();
+        }
+    }
+
+    fn unpark_waker() -> (Arc<Unpark>, Waker) {
+        let unpark = Arc::new(Unpark {
+            thread: thread::current(),
+            wakes: AtomicUsize::new(0),
+        });
+        (Arc::clone(&unpark), Waker::from(unpark))
+    }
+
+    /// 현재 스레드에서 퓨처가 끝날 때까지 poll하는 최소 실행기
+    fn block_on<F: Future>(future: F) -> F::Output {
+        let (_, waker) = unpark_waker();
+        let mut cx = Context::from_waker(&waker);
+        let mut future = std::pin::pin!(future);
+        loop {
+            match future.as_mut().poll(&mut cx) {
+                Poll::Ready(output) => return output,
+                Poll::Pending => thread::park(),
+            }
+        }
+    }
+
+    /// ML-KEM-512까지 생성할 수 있는 스택의 워커 하나로 실행기를 만듭니다.
+    fn start() -> KeygenExecutor {
+        KeygenExecutor::with_options(ExecutorOptions {
+            workers: 1,
+            stack_size: Some(stack::stack_size_for(&Variant::MLKEM512)),
+            queue_capacity: None,
+            thread_name: "keygen-async-test".to_string(),
+        })
+        .unwrap()
+    }
+
+    /// 유일한 워커를 `release`가 보낼 때까지 붙잡아 둡니다.
+    fn occupy(executor: &KeygenExecutor) -> mpsc::Sender<()> {
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let _ = executor
+            .submit(move || {
+                started_tx.send(()).unwrap();
+                let _ = release_rx.recv();
+            })
+            .unwrap();
+        started_rx.recv().unwrap();
+        release_tx
+    }
+
+    /// 버려질 때 횟수를 세는 결과
+    struct Tracked(Arc<AtomicUsize>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::SeqCst);
+        }
+    }
+
+    #[test]
+    fn futures_resolve_on_a_minimal_executor() {
+        let executor = start();
+        assert_eq!(block_on(executor.submit_async(|| 6 * 7).unwrap()), Ok(42));
+        let panicked = executor.submit_async(|| -> u8 { panic!("job failed") });
+        assert_eq!(block_on(panicked.unwrap()), Err(ExecutorError::Panicked));
+
+        let future = executor
+            .generate_async(Algorithm::MLKEM, Variant::MLKEM512)
+            .unwrap();
+        let (pk, sk) = block_on(future).unwrap().unwrap();
+        assert_eq!((pk.len(), sk.len()), (800, 1632));
+
+        let future = generate_keys_async(Algorithm::MLDSA, Variant::MLDSA44).unwrap();
+        let (pk, sk) = block_on(future).unwrap().unwrap();
+        assert_eq!((pk.len(), sk.len()), (1312, 2560));
+    }
+
+    #[test]
+    fn dropping_a_queued_future_skips_the_job() {
+        let executor = start();
+        let release = occupy(&executor);
+        let ran = Arc::new(AtomicUsize::new(0));
+        let future = {
+            let ran = Arc::clone(&ran);
+            executor
+                .submit_async(move || ran.fetch_add(1, Ordering::SeqCst))
+                .unwrap()
+        };
+        assert!(!future.is_finished());
+        drop(future);
+
+        release.send(()).unwrap();
+        executor.shutdown();
+        assert_eq!(ran.load(Ordering::SeqCst), 0);
+    }
+
+    #[test]
+    fn dropping_a_running_future_discards_the_result_on_the_worker() {
+        let executor = start();
+        let dropped = Arc::new(AtomicUsize::new(0));
+        let (started_tx, started_rx) = mpsc::channel();
+        let (release_tx, release_rx) = mpsc::channel::<()>();
+        let future = {
+            let dropped = Arc::clone(&dropped);
+            executor
+                .submit_async(move || {
+                    started_tx.send(()).unwrap();
+                    let _ = release_rx.recv();
+                    Tracked(dropped)
+                })
+                .unwrap()
+        };
+        started_rx.recv().unwrap();
+        drop(future);
+
+        release_tx.send(()).unwrap();
+        executor.shutdown();
+        assert_eq!(dropped.load(Ordering::SeqCst), 1);
+    }
+
+    #[test]
+    fn dropped_completer_reports_shutdown_and_wakes_the_task() {
+        let shared = Arc::new(Mutex::new(State::<u8> {
+            result: None,
+            waker: None,
+            cancelled: false,
+        }));
+        let mut future = JobFuture {
+            shared: Arc::clone(&shared),
+        };
+        let completer = Completer {
+            shared,
+            done: false,
+        };
+        let (unpark, waker) = unpark_waker();
+        let mut cx = Context::from_waker(&waker);
+
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Pending);
+        assert!(!completer.is_cancelled());
+        drop(completer);
+        assert_eq!(unpark.wakes.load(Ordering::SeqCst), 1);
+        assert!(future.is_finished());
+        assert_eq!(
+            Pin::new(&mut future).poll(&mut cx),
+            Poll::Ready(Err(ExecutorError::ShutDown))
+        );
+
+        // 완료한 작업은 버려져도 다시 알리지 않음
+        let shared = Arc::new(Mutex::new(State {
+            result: None,
+            waker: None,
+            cancelled: false,
+        }));
+        let mut future = JobFuture {
+            shared: Arc::clone(&shared),
+        };
+        Completer {
+            shared,
+            done: false,
+        }
+        .complete(Ok(9));
+        assert_eq!(Pin::new(&mut future).poll(&mut cx), Poll::Ready(Ok(9)));
+    }
+}
@@ -0,0 +1,837 @@
+use std::env;
+use std::fs::{self, OpenOptions};
+use std::io;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::datetime::{format_rfc3339, parse_time};
+use super::key_io::{
+    create_private_dir_all, fingerprint, read_all_zeroizing, secure_delete, write_atomic,
+};
+use super::keygen::{Algorithm, Variant, parse_algorithm, parse_variant};
+use super::lifecycle::{KeyState, Lifecycle};
+use super::metadata::KeyMetadata;
+
+/// 키 ID로 사용하는 지문 접두사 길이 (16진수 글자 수)
+pub const KEY_ID_LEN: usize = 16;
+
+/// ID 접두사로 조회할 때 필요한 최소 길이
+pub const MIN_ID_PREFIX: usize = 4;
+
+const INDEX_FILE: &str = "index.json";
+const LOCK_FILE: &str = ".lock";
+const KEYS_DIR: &str = "keys";
+const PUBLIC_FILE: &str = "public.key";
+const SECRET_FILE: &str = "secret.key";
+
+/// 키 저장소에 기록된 키 페어 한 건
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct StoreEntry {
+    /// 공개키 지문 앞 [`KEY_ID_LEN`]자리
+    pub id: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub label: Option<String>,
+    #[serde(default)]
+    pub tags: Vec<String>,
+    pub metadata: KeyMetadata,
+    #[serde(default)]
+    pub lifecycle: Lifecycle,
+}
+
+impl StoreEntry {
+    /// 기록된 알고리즘/배리언트 문자열을 해석합니다.
+    pub fn algorithm_variant(&self) -> Option<(Algorithm, Variant)> {
+        let alg = parse_algorithm(&self.metadata.algorithm).filter(|a| *a != Algorithm::Unknown)?;
+        let var = parse_variant(alg, &self.metadata.variant)?;
+        Some((alg, var))
+    }
+
+    /// 생성 시각(유닉스 초)
+    pub fn created_at(&self) -> u64 {
+        parse_time(&self.metadata.created, 0).unwrap_or(0)
+    }
+}
+
+/// 새 키를 저장할 때의 선택 사항
+#[derive(Debug, Clone, Default)]
+pub struct KeyOptions<'a> {
+    pub label: Option<&'a str>,
+    pub tags: &'a [String],
+    /// 초기 상태 (`active` 또는 `pending`)
+    pub state: KeyState,
+    /// 만료 시각(유닉스 초)
+    pub expires: Option<u64>,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+struct StoreIndex {
+    version: u32,
+    keys: Vec<StoreEntry>,
+}
+
+/// 키 조회 실패 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LookupError {
+    NotFound(String),
+    /// 질의가 여러 키와 일치함 (일치한 ID 목록)
+    Ambiguous(Vec<String>),
+}
+
+/// 키 저장소 오류
+#[derive(Debug)]
+pub enum StoreError {
+    Io(io::Error),
+    /// 인덱스 파일을 해석할 수 없음
+    Corrupt(String),
+    /// 같은 지문의 키가 이미 있음
+    Duplicate(String),
+    /// 같은 라벨의 키가 이미 있음
+    LabelTaken(String),
+    Lookup(LookupError),
+    /// 다른 프로세스가 잠금을 보유 중
+    Locked(PathBuf),
+    /// 허용되지 않는 상태 전환 (ID, 현재 상태, 요청한 상태)
+    Transition(String, KeyState, KeyState),
+    /// 이미 다음 키로 교체된 키 (ID, 다음 키 ID)
+    AlreadyRotated(String, String),
+}
+
+impl From<io::Error> for StoreError {
+    fn from(e: io::Error) -> Self {
+        StoreError::Io(e)
+    }
+}
+
+impl From<LookupError> for StoreError {
+    fn from(e: LookupError) -> Self {
+        StoreError::Lookup(e)
+    }
+}
+
+impl StoreError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            StoreError::Io(e) => text(
+                "error.store.io",
+                "키 저장소에 접근하는 도중 오류가 발생했습니다: {err}",
+            )
+            .replace("{err}", &e.to_string()),
+            StoreError::Corrupt(err) => text(
+                "error.store.corrupt",
+                "키 저장소 인덱스를 해석할 수 없습니다: {err}",
+            )
+            .replace("{err}", err),
+            StoreError::Duplicate(id) => text(
+                "error.store.duplicate",
+                "같은 지문의 키가 이미 저장되어 있습니다: {id}",
+            )
+            .replace("{id}", id),
+            StoreError::LabelTaken(label) => text(
+                "error.store.label_taken",
+                "이미 사용 중인 라벨입니다: {label}",
+            )
+            .replace("{label}", label),
+            StoreError::Lookup(LookupError::NotFound(query)) => {
+                text("error.store.not_found", "키를 찾을 수 없습니다: {query}")
+                    .replace("{query}", query)
+            }
+            StoreError::Lookup(LookupError::Ambiguous(ids)) => {
+                text("error.store.ambiguous", "여러 키와 일치합니다: {ids}")
+                    .replace("{ids}", &ids.join(", "))
+            }
+            StoreError::Locked(path) => text(
+                "error.store.locked",
+                "다른 프로세스가 키 저장소를 사용 중입니다 (잠금 파일: {path})",
+            )
+            .replace("{path}", &path.display().to_string()),
+            StoreError::Transition(id, from, to) => text(
+                "error.store.transition",
+                "키 {id}의 상태를 {from}에서 {to}(으)로 바꿀 수 없습니다.",
+            )
+            .replace("{id}", id)
+            .replace("{from}", from.as_str())
+            .replace("{to}", to.as_str()),
+            StoreError::AlreadyRotated(id, next) => text(
+                "error.store.already_rotated",
+                "키 {id}는 이미 {next}(으)로 교체되었습니다.",
+            )
+            .replace("{id}", id)
+            .replace("{next}", next),
+        }
+    }
+}
+
+/// 기본 키 저장소 경로를 반환합니다.
+///
+/// `KEYPAIRER_HOME` → `$XDG_DATA_HOME/keypairer` → `~/.local/share/keypairer` 순으로 결정합니다.
+pub fn default_root() -> Option<PathBuf> {
+    if let Some(home) = env::var_os("KEYPAIRER_HOME").filter(|v| !v.is_empty()) {
+        return Some(PathBuf::from(home));
+    }
+    if let Some(data) = env::var_os("XDG_DATA_HOME").filter(|v| !v.is_empty()) {
+        return Some(PathBuf::from(data).join("keypairer"));
+    }
+    env::var_os("HOME")
+        .filter(|v| !v.is_empty())
+        .map(|home| PathBuf::from(home).join(".local/share/keypairer"))
+}
+
+/// 인덱스 갱신 동안 보유하는 잠금 (드롭 시 해제)
+struct IndexLock {
+    path: PathBuf,
+}
+
+impl IndexLock {
+    fn acquire(root: &Path) -> Result<IndexLock, StoreError> {
+        let path = root.join(LOCK_FILE);
+        let started = Instant::now();
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(IndexLock { path }),
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => {
+                    if started.elapsed() > Duration::from_secs(10) {
+                        return Err(StoreError::Locked(path));
+                    }
+                    thread::sleep(Duration::from_millis(50));
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+impl Drop for IndexLock {
+    fn drop(&mut self) {
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+/// 지문 기반 ID로 키 페어를 보관하는 로컬 키 저장소
+pub struct Keystore {
+    root: PathBuf,
+}
+
+impl Keystore {
+    /// 저장소를 엽니다. 디렉토리가 없으면 0o700 권한으로 생성합니다.
+    pub fn open(root: &Path) -> Result<Keystore, StoreError> {
+        create_private_dir_all(&root.join(KEYS_DIR))?;
+        Ok(Keystore {
+            root: root.to_path_buf(),
+        })
+    }
+
+    pub fn root(&self) -> &Path {
+        &self.root
+    }
+
+    fn key_dir(&self, id: &str) -> PathBuf {
+        self.root.join(KEYS_DIR).join(id)
+    }
+
+    /// 공개키 파일 경로
+    pub fn public_path(&self, id: &str) -> PathBuf {
+        self.key_dir(id).join(PUBLIC_FILE)
+    }
+
+    /// 비밀키 파일 경로
+    pub fn secret_path(&self, id: &str) -> PathBuf {
+        self.key_dir(id).join(SECRET_FILE)
+    }
+
+    fn load_index(&self) -> Result<StoreIndex, StoreError> {
+        match fs::read_to_string(self.root.join(INDEX_FILE)) {
+            Ok(text) => {
+                let mut index: StoreIndex =
+                    serde_json::from_str(&text).map_err(|e| StoreError::Corrupt(e.to_string()))?;
+                // 수명 주기 도입 전 항목은 생성 시점부터 사용 중인 것으로 간주
+                for entry in &mut index.keys {
+                    if entry.lifecycle.history.is_empty() {
+                        entry.lifecycle =
+                            Lifecycle::new(entry.lifecycle.state, entry.created_at(), None);
+                    }
+                }
+                Ok(index)
+            }
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(StoreIndex {
+                version: 1,
+                keys: Vec::new(),
+            }),
+            Err(e) => Err(e.into()),
+        }
+    }
+
+    fn save_index(&self, index: &StoreIndex) -> Result<(), StoreError> {
+        let mut json =
+            serde_json::to_string_pretty(index).map_err(|e| StoreError::Corrupt(e.to_string()))?;
+        json.push('\n');
+        write_atomic(&self.root.join(INDEX_FILE), json.as_bytes(), 0o600, true)?;
+        Ok(())
+    }
+
+    /// 잠금을 잡은 상태에서 인덱스를 읽고 수정한 뒤 기록합니다.
+    pub(crate) fn update<T>(
+        &self,
+        f: impl FnOnce(&mut Vec<StoreEntry>) -> Result<T, StoreError>,
+    ) -> Result<T, StoreError> {
+        let _lock = IndexLock::acquire(&self.root)?;
+        let mut index = self.load_index()?;
+        let out = f(&mut index.keys)?;
+        index.version = 1;
+        self.save_index(&index)?;
+        Ok(out)
+    }
+
+    /// 저장된 모든 키를 생성 순서대로 반환합니다.
+    pub fn entries(&self) -> Result<Vec<StoreEntry>, StoreError> {
+        Ok(self.load_index()?.keys)
+    }
+
+    /// ID, ID 접두사(최소 [`MIN_ID_PREFIX`]자), 전체 지문 또는 라벨로 키를 찾습니다.
+    pub fn find(&self, query: &str) -> Result<StoreEntry, StoreError> {
+        Ok(lookup(&self.entries()?, query)?.clone())
+    }
+
+    /// 새 키 페어를 저장합니다.
+    ///
+    /// 키 파일을 먼저 기록한 뒤 인덱스에 추가하며, 인덱스 갱신에 실패하면 키 파일을 지웁니다.
+    pub fn add(
+        &self,
+        algorithm: Algorithm,
+        variant: &Variant,
+        pk_bytes: &[u8],
+        sk_bytes: &[u8],
+        created: u64,
+        options: &KeyOptions,
+    ) -> Result<StoreEntry, StoreError> {
+        let entry = self.stage_entry(algorithm, variant, pk_bytes, sk_bytes, created, options)?;
+        let label = options.label;
+        let added = self.update(|keys| {
+            check_new(keys, &entry.id, label)?;
+            keys.push(entry.clone());
+            Ok(entry.clone())
+        });
+        if added.is_err() {
+            self.discard_files(&entry.id);
+        }
+        added
+    }
+
+    /// 기존 키를 같은 배리언트의 새 키 페어로 교체합니다.
+    ///
+    /// 새 키는 이전 키의 라벨과 태그를 이어받고 양방향으로 연결됩니다. `pending`이 아니면
+    /// 새 키는 즉시 사용 중이 되고 이전 키는 퇴역합니다. 만료 시각을 지정하지 않으면
+    /// 이전 키와 같은 사용 기간을 적용합니다. 반환값은 (이전 키, 새 키)입니다.
+    pub fn rotate(
+        &self,
+        id: &str,
+        pk_bytes: &[u8],
+        sk_bytes: &[u8],
+        created: u64,
+        expires: Option<u64>,
+        pending: bool,
+    ) -> Result<(StoreEntry, StoreEntry), StoreError> {
+        let old = lookup(&self.entries()?, id)?.clone();
+        if let Some(next) = &old.lifecycle.successor {
+            return Err(StoreError::AlreadyRotated(old.id, next.clone()));
+        }
+        let (algorithm, variant) = old
+            .algorithm_variant()
+            .ok_or_else(|| StoreError::Corrupt(format!("{}: {}", old.id, old.metadata.variant)))?;
+        let expires = expires.or_else(|| {
+            old.lifecycle
+                .expires_at()
+                .map(|e| created + e.saturating_sub(old.created_at()))
+        });
+        let options = KeyOptions {
+            label: None,
+            tags: &old.tags,
+            state: if pending {
+                KeyState::Pending
+            } else {
+                KeyState::Active
+            },
+            expires,
+        };
+        let staged =
+            self.stage_entry(algorithm, &variant, pk_bytes, sk_bytes, created, &options)?;
+
+        let rotated = self.update(|keys| {
+            check_new(keys, &staged.id, None)?;
+            let pos = keys
+                .iter()
+                .position(|e| e.id == old.id)
+                .ok_or_else(|| LookupError::NotFound(old.id.clone()))?;
+            let prev = &mut keys[pos];
+            if let Some(next) = &prev.lifecycle.successor {
+                return Err(StoreError::AlreadyRotated(prev.id.clone(), next.clone()));
+            }
+
+            let mut next = staged.clone();
+            next.label = prev.label.take();
+            next.lifecycle.predecessor = Some(prev.id.clone());
+            prev.lifecycle.successor = Some(next.id.clone());
+            if !pending && matches!(prev.lifecycle.state, KeyState::Active | KeyState::Pending) {
+                let reason = format!("rotated to {}", next.id);
+                prev.lifecycle
+                    .transition(KeyState::Retired, created, Some(&reason));
+            }
+            let prev = prev.clone();
+            keys.push(next.clone());
+            Ok((prev, next))
+        });
+        if rotated.is_err() {
+            self.discard_files(&staged.id);
+        }
+        rotated
+    }
+
+    /// 키의 상태를 바꾸고 이력에 기록합니다.
+    ///
+    /// 교체 대기 중인 키를 사용 중으로 바꾸면 사용 중인 이전 키는 퇴역합니다.
+    pub fn set_state(
+        &self,
+        id: &str,
+        state: KeyState,
+        at: u64,
+        reason: Option<&str>,
+    ) -> Result<StoreEntry, StoreError> {
+        self.update(|keys| {
+            let pos = keys
+                .iter()
+                .position(|e| e.id == id)
+                .ok_or_else(|| LookupError::NotFound(id.to_string()))?;
+            let entry = &mut keys[pos];
+            let from = entry.lifecycle.state;
+            if !entry.lifecycle.transition(state, at, reason) {
+                return Err(StoreError::Transition(entry.id.clone(), from, state));
+            }
+            let updated = entry.clone();
+
+            if state == KeyState::Active
+                && let Some(prev_id) = &updated.lifecycle.predecessor
+                && let Some(prev) = keys.iter_mut().find(|e| &e.id == prev_id)
+                && prev.lifecycle.state == KeyState::Active
+            {
+                let reason = format!("rotated to {}", updated.id);
+                prev.lifecycle
+                    .transition(KeyState::Retired, at, Some(&reason));
+            }
+            Ok(updated)
+        })
+    }
+
+    /// 만료 시각을 설정하거나(`Some`) 해제합니다(`None`).
+    pub fn set_expiry(&self, id: &str, expires: Option<u64>) -> Result<StoreEntry, StoreError> {
+        self.update(|keys| {
+            let entry = keys
+                .iter_mut()
+                .find(|e| e.id == id)
+                .ok_or_else(|| LookupError::NotFound(id.to_string()))?;
+            entry.lifecycle.expires = expires.map(format_rfc3339);
+            Ok(entry.clone())
+        })
+    }
+
+    /// 키가 속한 교체 이력을 가장 오래된 키부터 반환합니다.
+    pub fn chain(&self, id: &str) -> Result<Vec<StoreEntry>, StoreError> {
+        let keys = self.entries()?;
+        let find = |id: &str| keys.iter().find(|e| e.id == id);
+        let mut first = lookup(&keys, id)?;
+        // 연결이 순환하더라도 멈추도록 키 수만큼만 따라감
+        for _ in 0..keys.len() {
+            match first.lifecycle.predecessor.as_deref().and_then(find) {
+                Some(prev) => first = prev,
+                None => break,
+            }
+        }
+
+        let mut chain = vec![first.clone()];
+        while chain.len() <= keys.len() {
+            let last = &chain[chain.len() - 1];
+            match last.lifecycle.successor.as_deref().and_then(find) {
+                Some(next) => chain.push(next.clone()),
+                None => break,
+            }
+        }
+        Ok(chain)
+    }
+
+    /// 키 파일을 기록하고 인덱스에 넣을 항목을 만듭니다 (인덱스는 갱신하지 않음).
+    fn stage_entry(
+        &self,
+        algorithm: Algorithm,
+        variant: &Variant,
+        pk_bytes: &[u8],
+        sk_bytes: &[u8],
+        created: u64,
+        options: &KeyOptions,
+    ) -> Result<StoreEntry, StoreError> {
+        let fp = fingerprint(pk_bytes);
+        let id = fp[..KEY_ID_LEN].to_string();
+
+        // 파일을 쓰기 전에 중복을 확인 (최종 확인은 잠금 안에서 다시 수행)
+        let existing = self.entries()?;
+        check_new(&existing, &id, options.label)?;
+
+        let pk_path = self.public_path(&id);
+        let sk_path = self.secret_path(&id);
+        write_atomic(&pk_path, pk_bytes, 0o644, false)?;
+        if let Err(e) = write_atomic(&sk_path, sk_bytes, 0o600, false) {
+            let _ = fs::remove_file(&pk_path);
+            return Err(e.into());
+        }
+
+        let rel = |p: &Path| {
+            p.strip_prefix(&self.root)
+                .unwrap_or(p)
+                .to_string_lossy()
+                .into_owned()
+        };
+        Ok(StoreEntry {
+            id,
+            label: options.label.map(str::to_string),
+            tags: options.tags.to_vec(),
+            metadata: KeyMetadata::new(
+                algorithm,
+                variant,
+                created,
+                &fp,
+                &rel(&pk_path),
+                &rel(&sk_path),
+                false,
+                false,
+            ),
+            lifecycle: Lifecycle::new(options.state, created, options.expires),
+        })
+    }
+
+    /// 인덱스에 들어가지 못한 키 파일을 지웁니다.
+    fn discard_files(&self, id: &str) {
+        let _ = secure_delete(&self.secret_path(id));
+        let _ = fs::remove_file(self.public_path(id));
+        let _ = fs::remove_dir(self.key_dir(id));
+    }
+
+    /// 공개키를 읽습니다.
+    pub fn read_public(&self, id: &str) -> Result<Vec<u8>, StoreError> {
+        Ok(fs::read(self.public_path(id))?)
+    }
+
+    /// 비밀키를 읽습니다.
+    pub fn read_secret(&self, id: &str) -> Result<Zeroizing<Vec<u8>>, StoreError> {
+        let mut file = fs::File::open(self.secret_path(id))?;
+        let hint = file.metadata().map(|m| m.len() as usize).unwrap_or(0);
+        Ok(read_all_zeroizing(&mut file, hint)?)
+    }
+
+    /// 키를 인덱스에서 제거하고, 비밀키는 덮어쓴 뒤 삭제합니다.
+    pub fn delete(&self, id: &str) -> Result<StoreEntry, StoreError> {
+        let removed = self.update(|keys| {
+            let pos = keys
+                .iter()
+                .position(|e| e.id == id)
+                .ok_or_else(|| LookupError::NotFound(id.to_string()))?;
+            Ok(keys.remove(pos))
+        })?;
+
+        let sk_path = self.secret_path(id);
+        if sk_path.exists() {
+            secure_delete(&sk_path)?;
+        }
+        let pk_path = self.public_path(id);
+        if pk_path.exists() {
+            fs::remove_file(&pk_path)?;
+        }
+        let _ = fs::remove_dir(self.key_dir(id));
+        Ok(removed)
+    }
+}
+
+/// 새 키의 ID와 라벨이 기존 키와 겹치지 않는지 확인합니다.
+fn check_new(keys: &[StoreEntry], id: &str, label: Option<&str>) -> Result<(), StoreError> {
+    if keys.iter().any(|e| e.id == id) {
+        return Err(StoreError::Duplicate(id.to_string()));
+    }
+    if let Some(label) = label
+        && keys.iter().any(|e| e.label.as_deref() == Some(label))
+    {
+        return Err(StoreError::LabelTaken(label.to_string()));
+    }
+    Ok(())
+}
+
+/// 키 목록에서 질의와 일치하는 키를 찾습니다.
+pub fn lookup<'a>(keys: &'a [StoreEntry], query: &str) -> Result<&'a StoreEntry, LookupError> {
+    let q = query.to_lowercase();
+
+    // 라벨과 정확한 ID/지문 일치를 우선
+    let exact: Vec<&StoreEntry> = keys
+        .iter()
+        .filter(|e| e.id == q || e.metadata.fingerprint == q || e.label.as_deref() == Some(query))
+        .collect();
+    let matches = if !exact.is_empty() {
+        exact
+    } else if q.len() >= MIN_ID_PREFIX {
+        keys.iter().filter(|e| e.id.starts_with(&q)).collect()
+    } else {
+        Vec::new()
+    };
+
+    match matches.as_slice() {
+        [] => Err(LookupError::NotFound(query.to_string())),
+        [one] => Ok(one),
+        many => Err(LookupError::Ambiguous(
+            many.iter().map(|e| e.id.clone()).collect(),
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internals::test_util::ScratchDir;
+
+    const CREATED: u64 = 1_709_640_000;
+
+    fn add(store: &Keystore, pk: &[u8], label: Option<&str>) -> Result<StoreEntry, StoreError> {
+        let options = KeyOptions {
+            label,
+            ..KeyOptions::default()
+        };
+        store.add(
+            Algorithm::MLKEM,
+            &Variant::MLKEM512,
+            pk,
+            b"secret",
+            CREATED,
+            &options,
+        )
+    }
+
+    /// 지문의 ID 접두사([`MIN_ID_PREFIX`]자)가 같은 서로 다른 두 공개키를 찾습니다.
+    fn colliding_public_keys() -> (Vec<u8>, Vec<u8>) {
+        let mut seen = std::collections::HashMap::new();
+        for i in 0u32.. {
+            let pk = i.to_le_bytes().to_vec();
+            let prefix = fingerprint(&pk)[..MIN_ID_PREFIX].to_string();
+            if let Some(other) = seen.insert(prefix, pk.clone()) {
+                return (other, pk);
+            }
+        }
+        unreachable!()
+    }
+
+    #[test]
+    fn added_keys_are_listed_and_readable() {
+        let dir = ScratchDir::new("keystore", "add");
+        let store = Keystore::open(&dir).unwrap();
+        let entry = add(&store, b"public-a", Some("web")).unwrap();
+
+        assert_eq!(entry.id, fingerprint(b"public-a")[..KEY_ID_LEN]);
+        assert_eq!(store.entries().unwrap(), std::slice::from_ref(&entry));
+        assert_eq!(store.read_public(&entry.id).unwrap(), b"public-a");
+        assert_eq!(store.read_secret(&entry.id).unwrap().as_slice(), b"secret");
+        assert_eq!(
+            entry.metadata.files.secret_key,
+            format!("keys/{}/secret.key", entry.id)
+        );
+        assert_eq!(entry.lifecycle.state, KeyState::Active);
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            let mode = fs::metadata(store.secret_path(&entry.id))
+                .unwrap()
+                .permissions()
+                .mode();
+            assert_eq!(mode & 0o777, 0o600);
+        }
+    }
+
+    #[test]
+    fn duplicate_keys_and_labels_are_refused_without_leaving_files() {
+        let dir = ScratchDir::new("keystore", "duplicate");
+        let store = Keystore::open(&dir).unwrap();
+        let first = add(&store, b"public-a", Some("web")).unwrap();
+
+        assert!(matches!(
+            add(&store, b"public-a", None),
+            Err(StoreError::Duplicate(id)) if id == first.id
+        ));
+        let err = add(&store, b"public-b", Some("web")).unwrap_err();
+        assert!(matches!(err, StoreError::LabelTaken(label) if label == "web"));
+        let other = &fingerprint(b"public-b")[..KEY_ID_LEN];
+        assert!(!store.public_path(other).exists());
+        assert_eq!(store.entries().unwrap().len(), 1);
+    }
+
+    #[test]
+    fn keys_are_found_by_label_id_prefix_or_fingerprint() {
+        let dir = ScratchDir::new("keystore", "find");
+        let store = Keystore::open(&dir).unwrap();
+        let (pk_a, pk_b) = colliding_public_keys();
+        let a = add(&store, &pk_a, Some("web")).unwrap();
+        let b = add(&store, &pk_b, None).unwrap();
+
+        assert_eq!(store.find("web").unwrap(), a);
+        assert_eq!(store.find(&b.id).unwrap(), b);
+        assert_eq!(store.find(&b.metadata.fingerprint).unwrap(), b);
+        assert_eq!(
+            store.find(&a.id[..KEY_ID_LEN - 2].to_uppercase()).unwrap(),
+            a
+        );
+        assert!(matches!(
+            store.find(&a.id[..MIN_ID_PREFIX]),
+            Err(StoreError::Lookup(LookupError::Ambiguous(ids))) if ids.len() == 2
+        ));
+        // 너무 짧은 접두사는 일치로 보지 않음
+        assert!(matches!(
+            store.find(&a.id[..MIN_ID_PREFIX - 1]),
+            Err(StoreError::Lookup(LookupError::NotFound(_)))
+        ));
+    }
+
+    #[test]
+    fn delete_removes_the_entry_and_its_files() {
+        let dir = ScratchDir::new("keystore", "delete");
+        let store = Keystore::open(&dir).unwrap();
+        let kept = add(&store, b"public-a", None).unwrap();
+        let gone = add(&store, b"public-b", None).unwrap();
+
+        assert_eq!(store.delete(&gone.id).unwrap(), gone);
+        assert!(!store.secret_path(&gone.id).exists());
+        assert!(!dir.join(KEYS_DIR).join(&gone.id).exists());
+        assert_eq!(store.entries().unwrap(), [kept]);
+        assert!(matches!(
+            store.delete(&gone.id),
+            Err(StoreError::Lookup(LookupError::NotFound(_)))
+        ));
+    }
+
+    #[test]
+    fn rotation_links_keys_and_retires_the_old_one() {
+        let dir = ScratchDir::new("keystore", "rotate");
+        let store = Keystore::open(&dir).unwrap();
+        let options = KeyOptions {
+            label: Some("web"),
+            expires: Some(CREATED + 30 * 86_400),
+            ..KeyOptions::default()
+        };
+        let old = store
+            .add(
+                Algorithm::MLKEM,
+                &Variant::MLKEM512,
+                b"public-a",
+                b"secret",
+                CREATED,
+                &options,
+            )
+            .unwrap();
+
+        let later = CREATED + 86_400;
+        let (prev, next) = store
+            .rotate("web", b"public-b", b"secret", later, None, false)
+            .unwrap();
+        assert_eq!(prev.lifecycle.state, KeyState::Retired);
+        assert_eq!(prev.lifecycle.successor.as_deref(), Some(next.id.as_str()));
+        assert_eq!(next.lifecycle.predecessor.as_deref(), Some(old.id.as_str()));
+        // 라벨은 새 키로 옮겨가고 같은 사용 기간이 적용됨
+        assert_eq!((prev.label, next.label.as_deref()), (None, Some("web")));
+        assert_eq!(next.lifecycle.expires_at(), Some(later + 30 * 86_400));
+        assert_eq!(
+            store
+                .chain(&next.id)
+                .unwrap()
+                .iter()
+                .map(|e| &e.id)
+                .collect::<Vec<_>>(),
+            [&old.id, &next.id]
+        );
+
+        assert!(matches!(
+            store.rotate(&old.id, b"public-c", b"secret", later, None, false),
+            Err(StoreError::AlreadyRotated(..))
+        ));
+        assert!(
+            !store
+                .public_path(&fingerprint(b"public-c")[..KEY_ID_LEN])
+                .exists()
+        );
+    }
+
+    #[test]
+    fn activating_a_pending_successor_retires_its_predecessor() {
+        let dir = ScratchDir::new("keystore", "pending");
+        let store = Keystore::open(&dir).unwrap();
+        let old = add(&store, b"public-a", None).unwrap();
+        let (prev, next) = store
+            .rotate(&old.id, b"public-b", b"secret", CREATED + 1, None, true)
+            .unwrap();
+        assert_eq!(prev.lifecycle.state, KeyState::Active);
+        assert_eq!(next.lifecycle.state, KeyState::Pending);
+
+        store
+            .set_state(&next.id, KeyState::Active, CREATED + 2, None)
+            .unwrap();
+        assert_eq!(
+            store.find(&old.id).unwrap().lifecycle.state,
+            KeyState::Retired
+        );
+
+        assert!(matches!(
+            store.set_state(&old.id, KeyState::Active, CREATED + 3, None),
+            Err(StoreError::Transition(
+                _,
+                KeyState::Retired,
+                KeyState::Active
+            ))
+        ));
+        let entry = store.set_expiry(&next.id, Some(CREATED + 10)).unwrap();
+        assert!(entry.lifecycle.is_expired(CREATED + 10));
+        assert_eq!(
+            store.set_expiry(&next.id, None).unwrap().lifecycle.expires,
+            None
+        );
+    }
+
+    #[test]
+    fn entries_written_before_lifecycles_start_active_at_creation() {
+        let dir = ScratchDir::new("keystore", "legacy");
+        let store = Keystore::open(&dir).unwrap();
+        let entry = add(&store, b"public-a", None).unwrap();
+        let mut index: serde_json::Value =
+            serde_json::from_slice(&fs::read(dir.join(INDEX_FILE)).unwrap()).unwrap();
+        index["keys"][0]
+            .as_object_mut()
+            .unwrap()
+            .remove("lifecycle");
+        fs::write(dir.join(INDEX_FILE), index.to_string()).unwrap();
+
+        let read = store.find(&entry.id).unwrap();
+        assert_eq!(
+            read.lifecycle,
+            Lifecycle::new(KeyState::Active, CREATED, None)
+        );
+        assert!(read.lifecycle.was_active_at(CREATED));
+    }
+
+    #[test]
+    fn a_corrupt_index_is_reported() {
+        let dir = ScratchDir::new("keystore", "corrupt");
+        let store = Keystore::open(&dir).unwrap();
+        fs::write(dir.join(INDEX_FILE), b"{not json").unwrap();
+        assert!(matches!(store.entries(), Err(StoreError::Corrupt(_))));
+        assert!(matches!(
+            add(&store, b"public-a", None),
+            Err(StoreError::Corrupt(_))
+        ));
+    }
+}
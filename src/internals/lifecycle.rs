@@ -0,0 +1,261 @@
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use super::datetime::{format_rfc3339, parse_time};
+
+/// 키 수명 주기 상태
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum KeyState {
+    /// 사용 중인 키
+    #[default]
+    Active,
+    /// 생성되었으나 아직 사용하지 않는 키 (예: 교체 대기)
+    Pending,
+    /// 교체 등으로 사용을 마친 키
+    Retired,
+    /// 유출이 의심되어 폐기된 키 (되돌릴 수 없음)
+    Compromised,
+}
+
+impl KeyState {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            KeyState::Active => "active",
+            KeyState::Pending => "pending",
+            KeyState::Retired => "retired",
+            KeyState::Compromised => "compromised",
+        }
+    }
+
+    pub fn parse(input: &str) -> Option<KeyState> {
+        match input.to_lowercase().as_str() {
+            "active" => Some(KeyState::Active),
+            "pending" => Some(KeyState::Pending),
+            "retired" => Some(KeyState::Retired),
+            "compromised" => Some(KeyState::Compromised),
+            _ => None,
+        }
+    }
+
+    /// `self`에서 `next`로 전환할 수 있는지 여부
+    ///
+    /// 대기 → 사용 → 퇴역 순으로만 진행하며, 유출은 어느 상태에서나 표시할 수 있지만 되돌릴 수 없습니다.
+    pub fn can_transition(&self, next: KeyState) -> bool {
+        use KeyState::*;
+        matches!(
+            (self, next),
+            (Pending, Active) | (Pending, Retired) | (Active, Retired) | (_, Compromised)
+        ) && *self != Compromised
+    }
+}
+
+impl fmt::Display for KeyState {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+/// 상태 전환 기록 한 건
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StateChange {
+    pub state: KeyState,
+    /// 전환 시각 (RFC 3339, UTC)
+    pub at: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+}
+
+impl StateChange {
+    pub fn new(state: KeyState, at: u64, reason: Option<&str>) -> Self {
+        StateChange {
+            state,
+            at: format_rfc3339(at),
+            reason: reason.map(str::to_string),
+        }
+    }
+}
+
+/// 키의 상태 이력과 만료 시각
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Lifecycle {
+    #[serde(default)]
+    pub state: KeyState,
+    /// 만료 시각 (RFC 3339, UTC)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub expires: Option<String>,
+    /// 이 키가 교체한 이전 키 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predecessor: Option<String>,
+    /// 이 키를 교체한 다음 키 ID
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub successor: Option<String>,
+    /// 시간순 상태 전환 기록 (첫 항목은 생성 시점의 상태)
+    #[serde(default)]
+    pub history: Vec<StateChange>,
+}
+
+impl Lifecycle {
+    /// 생성 시점의 상태로 수명 주기를 시작합니다.
+    pub fn new(state: KeyState, created: u64, expires: Option<u64>) -> Self {
+        Lifecycle {
+            state,
+            expires: expires.map(format_rfc3339),
+            predecessor: None,
+            successor: None,
+            history: vec![StateChange::new(state, created, None)],
+        }
+    }
+
+    /// 상태를 바꾸고 이력에 기록합니다. 허용되지 않는 전환이면 `false`를 반환합니다.
+    pub fn transition(&mut self, next: KeyState, at: u64, reason: Option<&str>) -> bool {
+        if !self.state.can_transition(next) {
+            return false;
+        }
+        self.state = next;
+        self.history.push(StateChange::new(next, at, reason));
+        true
+    }
+
+    /// 만료 시각(유닉스 초)
+    pub fn expires_at(&self) -> Option<u64> {
+        self.expires.as_deref().and_then(|e| parse_time(e, 0))
+    }
+
+    /// 주어진 시각에 만료되었는지 여부
+    pub fn is_expired(&self, now: u64) -> bool {
+        self.expires_at().is_some_and(|e| e <= now)
+    }
+
+    /// 주어진 시각에 사용 중(active)이었는지 이력으로 판정합니다.
+    ///
+    /// 그 시각 이전의 마지막 전환이 `active`이고 만료 전이어야 합니다.
+    pub fn was_active_at(&self, at: u64) -> bool {
+        let state = self
+            .history
+            .iter()
+            .rev()
+            .find(|c| parse_time(&c.at, 0).is_some_and(|t| t <= at))
+            .map(|c| c.state);
+        state == Some(KeyState::Active) && !self.is_expired(at)
+    }
+
+    /// 사용 기간 목록 `(시작, 끝)` (끝이 `None`이면 현재까지 사용 중)
+    pub fn active_periods(&self) -> Vec<(String, Option<String>)> {
+        let mut periods = Vec::new();
+        let mut start: Option<&str> = None;
+        for change in &self.history {
+            match (change.state, start) {
+                (KeyState::Active, None) => start = Some(&change.at),
+                (KeyState::Active, Some(_)) => {}
+                (_, Some(s)) => {
+                    periods.push((s.to_string(), Some(change.at.clone())));
+                    start = None;
+                }
+                (_, None) => {}
+            }
+        }
+        if let Some(s) = start {
+            periods.push((s.to_string(), self.expires.clone()));
+        }
+        periods
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T0: u64 = 1_709_640_000;
+    const DAY: u64 = 86_400;
+
+    #[test]
+    fn transitions_only_move_forward() {
+        use KeyState::*;
+        let all = [Active, Pending, Retired, Compromised];
+        let allowed = [
+            (Pending, Active),
+            (Pending, Retired),
+            (Active, Retired),
+            (Pending, Compromised),
+            (Active, Compromised),
+            (Retired, Compromised),
+        ];
+        for from in all {
+            for to in all {
+                assert_eq!(
+                    from.can_transition(to),
+                    allowed.contains(&(from, to)),
+                    "{from} -> {to}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn rejected_transitions_leave_the_history_alone() {
+        let mut lifecycle = Lifecycle::new(KeyState::Pending, T0, None);
+        assert!(lifecycle.transition(KeyState::Active, T0 + DAY, None));
+        assert!(!lifecycle.transition(KeyState::Pending, T0 + 2 * DAY, None));
+        assert!(lifecycle.transition(KeyState::Compromised, T0 + 3 * DAY, Some("leak")));
+        assert!(!lifecycle.transition(KeyState::Active, T0 + 4 * DAY, None));
+
+        let states: Vec<KeyState> = lifecycle.history.iter().map(|c| c.state).collect();
+        assert_eq!(
+            states,
+            [KeyState::Pending, KeyState::Active, KeyState::Compromised]
+        );
+        assert_eq!(lifecycle.history[2].reason.as_deref(), Some("leak"));
+        assert_eq!(lifecycle.state, KeyState::Compromised);
+    }
+
+    #[test]
+    fn expiry_ends_the_active_period() {
+        let lifecycle = Lifecycle::new(KeyState::Active, T0, Some(T0 + 30 * DAY));
+        assert_eq!(lifecycle.expires_at(), Some(T0 + 30 * DAY));
+        assert!(!lifecycle.is_expired(T0 + 30 * DAY - 1));
+        assert!(lifecycle.is_expired(T0 + 30 * DAY));
+
+        assert!(!lifecycle.was_active_at(T0 - 1));
+        assert!(lifecycle.was_active_at(T0 + DAY));
+        assert!(!lifecycle.was_active_at(T0 + 31 * DAY));
+        assert_eq!(
+            lifecycle.active_periods(),
+            [(format_rfc3339(T0), Some(format_rfc3339(T0 + 30 * DAY)))]
+        );
+    }
+
+    #[test]
+    fn activity_is_judged_from_the_history() {
+        let mut lifecycle = Lifecycle::new(KeyState::Pending, T0, None);
+        lifecycle.transition(KeyState::Active, T0 + DAY, None);
+        lifecycle.transition(KeyState::Retired, T0 + 10 * DAY, Some("rotated"));
+
+        assert!(!lifecycle.was_active_at(T0 + 1));
+        assert!(lifecycle.was_active_at(T0 + 5 * DAY));
+        assert!(!lifecycle.was_active_at(T0 + 10 * DAY));
+        assert_eq!(
+            lifecycle.active_periods(),
+            [(
+                format_rfc3339(T0 + DAY),
+                Some(format_rfc3339(T0 + 10 * DAY))
+            )]
+        );
+        let never = Lifecycle::new(KeyState::Pending, T0, None);
+        assert!(never.active_periods().is_empty());
+    }
+
+    #[test]
+    fn states_parse_case_insensitively() {
+        for state in [
+            KeyState::Active,
+            KeyState::Pending,
+            KeyState::Retired,
+            KeyState::Compromised,
+        ] {
+            assert_eq!(KeyState::parse(&state.as_str().to_uppercase()), Some(state));
+        }
+        assert_eq!(KeyState::parse("revoked"), None);
+    }
+}
@@ -0,0 +1,396 @@
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+/// 기본 매니페스트 파일 이름 (`sha256sum -c`와 호환)
+pub const MANIFEST_NAME: &str = "SHA256SUMS";
+
+/// 서명 파일 확장자 (`<매니페스트>.sig`)
+pub const SIGNATURE_EXT: &str = "sig";
+
+/// 서명 파일 형식 식별자
+pub const SIGNATURE_FORMAT: &str = "keypairer-manifest-signature/1";
+
+/// 매니페스트 생성/검사 실패 사유
+#[derive(Debug)]
+pub enum ManifestError {
+    Io(PathBuf, io::Error),
+    /// 매니페스트에 기록할 수 없는 파일 이름 (UTF-8이 아니거나 줄바꿈·역슬래시 포함)
+    UnsupportedName(PathBuf),
+    /// 매니페스트 줄을 해석할 수 없음 (줄 번호)
+    Parse(usize),
+    /// 매니페스트에 파일이 없음
+    Empty,
+}
+
+impl ManifestError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            ManifestError::Io(path, e) => text(
+                "error.manifest.io",
+                "파일을 처리하는 도중 오류가 발생했습니다({path}): {err}",
+            )
+            .replace("{path}", &path.display().to_string())
+            .replace("{err}", &e.to_string()),
+            ManifestError::UnsupportedName(path) => text(
+                "error.manifest.name",
+                "매니페스트에 기록할 수 없는 파일 이름입니다: {path}",
+            )
+            .replace("{path}", &path.display().to_string()),
+            ManifestError::Parse(line) => text(
+                "error.manifest.parse",
+                "매니페스트 {line}번째 줄을 해석할 수 없습니다.",
+            )
+            .replace("{line}", &line.to_string()),
+            ManifestError::Empty => text("error.manifest.empty", "매니페스트에 파일이 없습니다."),
+        }
+    }
+}
+
+/// 매니페스트 항목 한 건
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ManifestEntry {
+    /// 기준 디렉토리에 대한 상대 경로 (`/` 구분)
+    pub path: String,
+    /// SHA-256 (소문자 16진수)
+    pub digest: String,
+}
+
+/// 파일 해시 목록
+///
+/// `<해시>  <경로>` 형식의 줄을 경로 순으로 기록하므로 `sha256sum -c`로도 확인할 수 있습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Manifest {
+    pub entries: Vec<ManifestEntry>,
+}
+
+impl Manifest {
+    /// 디렉토리 아래의 모든 일반 파일을 해시합니다. `exclude`의 상대 경로는 건너뜁니다.
+    ///
+    /// 심볼릭 링크 등 일반 파일이 아닌 항목은 두 번째 값으로 반환합니다.
+    pub fn build(
+        root: &Path,
+        exclude: &[String],
+    ) -> Result<(Manifest, Vec<String>), ManifestError> {
+        let (files, skipped) = collect_files(root)?;
+        let mut entries = Vec::with_capacity(files.len());
+        for path in files.into_iter().filter(|p| !exclude.contains(p)) {
+            let full = root.join(&path);
+            let digest = hash_file(&full).map_err(|e| ManifestError::Io(full, e))?;
+            entries.push(ManifestEntry { path, digest });
+        }
+        if entries.is_empty() {
+            return Err(ManifestError::Empty);
+        }
+        Ok((Manifest { entries }, skipped))
+    }
+
+    pub fn to_text(&self) -> String {
+        self.entries
+            .iter()
+            .map(|e| format!("{}  {}\n", e.digest, e.path))
+            .collect()
+    }
+
+    /// `sha256sum` 형식의 텍스트를 해석합니다. 빈 줄은 무시합니다.
+    pub fn parse(text: &str) -> Result<Manifest, ManifestError> {
+        let mut entries = Vec::new();
+        for (n, line) in text.lines().enumerate() {
+            if line.is_empty() {
+                continue;
+            }
+            let (digest, path) = line
+                .split_once("  ")
+                .or_else(|| line.split_once(" *"))
+                .ok_or(ManifestError::Parse(n + 1))?;
+            let valid_digest = digest.len() == 64 && digest.bytes().all(|b| b.is_ascii_hexdigit());
+            if !valid_digest || !is_safe_relative(path) {
+                return Err(ManifestError::Parse(n + 1));
+            }
+            entries.push(ManifestEntry {
+                path: path.to_string(),
+                digest: digest.to_ascii_lowercase(),
+            });
+        }
+        if entries.is_empty() {
+            return Err(ManifestError::Empty);
+        }
+        Ok(Manifest { entries })
+    }
+}
+
+/// 파일 하나의 검사 결과
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum FileStatus {
+    Ok,
+    /// 해시가 다름
+    Modified,
+    /// 파일이 없음
+    Missing,
+    /// 읽을 수 없음
+    Unreadable(String),
+}
+
+/// 매니페스트의 각 파일을 다시 해시하여 비교합니다.
+pub fn check(root: &Path, manifest: &Manifest) -> Vec<(String, FileStatus)> {
+    manifest
+        .entries
+        .iter()
+        .map(|entry| {
+            let status = match hash_file(&root.join(&entry.path)) {
+                Ok(digest) if digest == entry.digest => FileStatus::Ok,
+                Ok(_) => FileStatus::Modified,
+                Err(e) if e.kind() == io::ErrorKind::NotFound => FileStatus::Missing,
+                Err(e) => FileStatus::Unreadable(e.to_string()),
+            };
+            (entry.path.clone(), status)
+        })
+        .collect()
+}
+
+/// 디렉토리에는 있지만 매니페스트에 없는 파일 목록
+pub fn unlisted(
+    root: &Path,
+    manifest: &Manifest,
+    exclude: &[String],
+) -> Result<Vec<String>, ManifestError> {
+    let (files, _) = collect_files(root)?;
+    Ok(files
+        .into_iter()
+        .filter(|p| !exclude.contains(p) && !manifest.entries.iter().any(|e| &e.path == p))
+        .collect())
+}
+
+/// `path`가 `root` 아래에 있으면 상대 경로(`/` 구분)를 반환합니다. 파일이 아직 없어도 됩니다.
+pub fn relative_to(root: &Path, path: &Path) -> Option<String> {
+    let root = root.canonicalize().ok()?;
+    let parent = match path.parent() {
+        Some(p) if !p.as_os_str().is_empty() => p,
+        _ => Path::new("."),
+    };
+    let full = parent.canonicalize().ok()?.join(path.file_name()?);
+    let rel = full.strip_prefix(&root).ok()?;
+    let parts: Option<Vec<&str>> = rel.components().map(|c| c.as_os_str().to_str()).collect();
+    Some(parts?.join("/"))
+}
+
+/// 디렉토리를 재귀적으로 훑어 일반 파일의 상대 경로를 정렬하여 반환합니다.
+fn collect_files(root: &Path) -> Result<(Vec<String>, Vec<String>), ManifestError> {
+    let mut files = Vec::new();
+    let mut skipped = Vec::new();
+    let mut pending = vec![String::new()];
+    while let Some(dir) = pending.pop() {
+        let full = root.join(&dir);
+        let read = fs::read_dir(&full).map_err(|e| ManifestError::Io(full.clone(), e))?;
+        for entry in read {
+            let entry = entry.map_err(|e| ManifestError::Io(full.clone(), e))?;
+            let name = entry.file_name();
+            let name = name
+                .to_str()
+                .filter(|n| !n.contains(['\n', '\r', '\\']))
+                .ok_or_else(|| ManifestError::UnsupportedName(entry.path()))?;
+            let rel = if dir.is_empty() {
+                name.to_string()
+            } else {
+                format!("{}/{}", dir, name)
+            };
+            let kind = entry
+                .file_type()
+                .map_err(|e| ManifestError::Io(entry.path(), e))?;
+            if kind.is_dir() {
+                pending.push(rel);
+            } else if kind.is_file() {
+                files.push(rel);
+            } else {
+                skipped.push(rel);
+            }
+        }
+    }
+    files.sort();
+    skipped.sort();
+    Ok((files, skipped))
+}
+
+/// 매니페스트 경로가 기준 디렉토리를 벗어나지 않는 상대 경로인지 확인합니다.
+fn is_safe_relative(path: &str) -> bool {
+    !path.is_empty()
+        && !path.starts_with('/')
+        && !path.contains('\\')
+        && path
+            .split('/')
+            .all(|c| !c.is_empty() && c != "." && c != "..")
+}
+
+/// 파일을 스트리밍으로 읽어 SHA-256을 계산합니다.
+pub fn hash_file(path: &Path) -> io::Result<String> {
+    let mut file = File::open(path)?;
+    let mut hasher = Sha256::new();
+    let mut buf = vec![0u8; 64 * 1024];
+    loop {
+        match file.read(&mut buf) {
+            Ok(0) => break,
+            Ok(n) => hasher.update(&buf[..n]),
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(hasher
+        .finalize()
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect())
+}
+
+/// 매니페스트 서명 파일 (`<매니페스트>.sig`, JSON)
+///
+/// 서명 대상은 매니페스트 파일의 바이트 전체입니다.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct ManifestSignature {
+    pub format: String,
+    pub algorithm: String,
+    pub variant: String,
+    pub standard_name: String,
+    /// 서명자 공개키 SHA-256 지문 (알 수 없으면 생략)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub signer: Option<String>,
+    /// 서명 시각 (RFC 3339, UTC)
+    pub created: String,
+    /// 매니페스트 파일의 SHA-256
+    pub manifest_sha256: String,
+    /// 분리 서명 (base64)
+    pub signature: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internals::keygen::{Variant, generate_keys};
+    use crate::internals::sign::{sign, verify};
+    use crate::internals::test_util::ScratchDir;
+
+    /// 중첩 디렉토리를 포함한 배포 산출물 디렉토리
+    fn release(name: &str) -> ScratchDir {
+        let dir = ScratchDir::new("manifest", name);
+        fs::create_dir_all(dir.join("bin")).unwrap();
+        fs::write(dir.join("bin/app"), b"\x7fELF app").unwrap();
+        fs::write(dir.join("README"), b"read me\n").unwrap();
+        fs::write(dir.join("notes.txt"), b"").unwrap();
+        dir
+    }
+
+    fn status_of(dir: &Path, manifest: &Manifest, path: &str) -> FileStatus {
+        check(dir, manifest)
+            .into_iter()
+            .find(|(p, _)| p == path)
+            .map(|(_, status)| status)
+            .unwrap()
+    }
+
+    #[test]
+    fn signed_manifest_round_trips() {
+        let dir = release("round-trip");
+        let (manifest, skipped) = Manifest::build(&dir, &[]).unwrap();
+        assert!(skipped.is_empty());
+        let paths: Vec<&str> = manifest.entries.iter().map(|e| e.path.as_str()).collect();
+        assert_eq!(paths, ["README", "bin/app", "notes.txt"]);
+        assert_eq!(
+            manifest.entries[2].digest,
+            "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855"
+        );
+
+        let body = manifest.to_text();
+        let (pk, sk) = generate_keys(Variant::MLDSA44.algorithm(), Variant::MLDSA44).unwrap();
+        let signature = sign(&Variant::MLDSA44, &sk, body.as_bytes()).unwrap();
+        assert!(verify(&Variant::MLDSA44, &pk, body.as_bytes(), &signature).unwrap());
+
+        let parsed = Manifest::parse(&body).unwrap();
+        assert_eq!(parsed, manifest);
+        assert!(
+            check(&dir, &parsed)
+                .iter()
+                .all(|(_, s)| *s == FileStatus::Ok)
+        );
+        assert!(unlisted(&dir, &parsed, &[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn modified_missing_and_unlisted_files_are_reported() {
+        let dir = release("changes");
+        let (manifest, _) = Manifest::build(&dir, &[]).unwrap();
+
+        fs::write(dir.join("bin/app"), b"\x7fELF patched").unwrap();
+        fs::remove_file(dir.join("README")).unwrap();
+        fs::write(dir.join("extra.so"), b"injected").unwrap();
+
+        assert_eq!(status_of(&dir, &manifest, "bin/app"), FileStatus::Modified);
+        assert_eq!(status_of(&dir, &manifest, "README"), FileStatus::Missing);
+        assert_eq!(status_of(&dir, &manifest, "notes.txt"), FileStatus::Ok);
+        assert_eq!(unlisted(&dir, &manifest, &[]).unwrap(), ["extra.so"]);
+        assert!(
+            unlisted(&dir, &manifest, &["extra.so".to_string()])
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn signature_fails_for_a_wrong_key_or_edited_manifest() {
+        let dir = release("wrong-key");
+        let (manifest, _) = Manifest::build(&dir, &[]).unwrap();
+        let body = manifest.to_text();
+        let (pk, sk) = generate_keys(Variant::MLDSA44.algorithm(), Variant::MLDSA44).unwrap();
+        let (other_pk, _) = generate_keys(Variant::MLDSA44.algorithm(), Variant::MLDSA44).unwrap();
+        let signature = sign(&Variant::MLDSA44, &sk, body.as_bytes()).unwrap();
+
+        assert!(!verify(&Variant::MLDSA44, &other_pk, body.as_bytes(), &signature).unwrap());
+        // 항목 하나를 뺀 매니페스트는 같은 서명으로 검증되지 않음
+        let edited: String = body.lines().skip(1).map(|l| format!("{l}\n")).collect();
+        assert!(!verify(&Variant::MLDSA44, &pk, edited.as_bytes(), &signature).unwrap());
+    }
+
+    #[test]
+    fn excluded_files_and_symlinks_are_left_out() {
+        let dir = release("exclude");
+        fs::write(dir.join(MANIFEST_NAME), b"old").unwrap();
+        #[cfg(unix)]
+        std::os::unix::fs::symlink(dir.join("README"), dir.join("link")).unwrap();
+
+        let (manifest, skipped) = Manifest::build(&dir, &[MANIFEST_NAME.to_string()]).unwrap();
+        assert!(manifest.entries.iter().all(|e| e.path != MANIFEST_NAME));
+        assert_eq!(skipped.len(), usize::from(cfg!(unix)));
+        assert_eq!(
+            relative_to(&dir, &dir.join("bin/app")).as_deref(),
+            Some("bin/app")
+        );
+        assert_eq!(relative_to(&dir.join("bin"), &dir.join("README")), None);
+    }
+
+    #[test]
+    fn paths_escaping_the_directory_are_rejected() {
+        let digest = "ab".repeat(32);
+        for line in [
+            format!("{digest}  ../etc/passwd"),
+            format!("{digest}  /etc/passwd"),
+            format!("{digest}  bin//app"),
+            format!("{digest}  bin\\app"),
+            format!("{}  app", &digest[..63]),
+            "not a manifest line".to_string(),
+        ] {
+            assert!(
+                matches!(Manifest::parse(&line), Err(ManifestError::Parse(1))),
+                "{line}"
+            );
+        }
+        let parsed = Manifest::parse(&format!("\n{}  *app\n", digest.to_uppercase())).unwrap();
+        assert_eq!(parsed.entries[0].digest, digest);
+        assert!(matches!(Manifest::parse("\n\n"), Err(ManifestError::Empty)));
+    }
+}
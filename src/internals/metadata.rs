@@ -0,0 +1,222 @@
+use std::io;
+use std::path::{Path, PathBuf};
+
+use serde::{Deserialize, Serialize};
+
+use super::datetime::format_rfc3339;
+use super::key_io::{STDIO_PATH, write_atomic};
+use super::keygen::{Algorithm, Variant};
+
+/// 빌드 시점에 기록된 pqcrypto 계열 크레이트 버전 (`이름=버전;...`)
+const BACKEND_VERSIONS: &str = env!("KEYPAIRER_BACKEND_VERSIONS");
+
+/// 키 파일의 저장 형식
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Encodings {
+    /// `binary` 또는 `pem`
+    pub public_key: String,
+    pub secret_key: String,
+}
+
+/// 키 파일 경로
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KeyFiles {
+    pub public_key: String,
+    pub secret_key: String,
+}
+
+/// 키를 생성한 도구와 암호 백엔드 정보
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct Provenance {
+    pub tool: String,
+    pub tool_version: String,
+    /// 상위 `pqcrypto` 크레이트 버전
+    pub backend: String,
+    /// 알고리즘 구현 크레이트 (예: `pqcrypto-mlkem`)
+    pub backend_crate: String,
+    pub backend_crate_version: String,
+    /// 키 생성에 쓴 난수 소스 (`os`, `file:<경로>`, `drbg ...`). 이전 사이드카에는 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entropy: Option<String>,
+    /// 생성한 빌드에서 `-entropy`로 난수 소스를 고를 수 있었는지 여부. 이전 사이드카에는 없음
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub entropy_pluggable: Option<bool>,
+}
+
+/// 키 페어 메타데이터 (사이드카 JSON)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct KeyMetadata {
+    pub algorithm: String,
+    pub variant: String,
+    pub standard_name: String,
+    /// 생성 시각 (RFC 3339, UTC)
+    pub created: String,
+    /// 공개키 SHA-256 지문 (16진수)
+    pub fingerprint: String,
+    pub encodings: Encodings,
+    pub files: KeyFiles,
+    pub provenance: Provenance,
+}
+
+impl KeyMetadata {
+    /// 생성된 키 페어의 메타데이터를 구성합니다.
+    #[allow(clippy::too_many_arguments)]
+    pub fn new(
+        algorithm: Algorithm,
+        variant: &Variant,
+        created: u64,
+        fingerprint: &str,
+        pk_path: &str,
+        sk_path: &str,
+        pk_text: bool,
+        sk_text: bool,
+    ) -> Self {
+        let encoding = |text: bool| if text { "pem" } else { "binary" }.to_string();
+        let family = backend_crate(algorithm);
+        KeyMetadata {
+            algorithm: algorithm.as_str().to_string(),
+            variant: variant.as_str().to_string(),
+            standard_name: variant.standard_name(),
+            created: format_rfc3339(created),
+            fingerprint: fingerprint.to_string(),
+            encodings: Encodings {
+                public_key: encoding(pk_text),
+                secret_key: encoding(sk_text),
+            },
+            files: KeyFiles {
+                public_key: pk_path.to_string(),
+                secret_key: sk_path.to_string(),
+            },
+            provenance: Provenance {
+                tool: env!("CARGO_PKG_NAME").to_string(),
+                tool_version: env!("CARGO_PKG_VERSION").to_string(),
+                backend: backend_version("pqcrypto"),
+                backend_crate: family.to_string(),
+                backend_crate_version: backend_version(family),
+                entropy: Some(super::entropy::describe()),
+                entropy_pluggable: Some(super::entropy::is_pluggable()),
+            },
+        }
+    }
+}
+
+/// 알고리즘을 구현하는 pqcrypto 하위 크레이트 이름을 반환합니다.
+pub fn backend_crate(algorithm: Algorithm) -> &'static str {
+    match algorithm {
+        Algorithm::MLKEM => "pqcrypto-mlkem",
+        Algorithm::HQC => "pqcrypto-hqc",
+        Algorithm::McEliece => "pqcrypto-classicmceliece",
+        Algorithm::FALCON => "pqcrypto-falcon",
+        Algorithm::MLDSA => "pqcrypto-mldsa",
+        Algorithm::SPHINCSPlus => "pqcrypto-sphincsplus",
+        Algorithm::Unknown => "pqcrypto",
+    }
+}
+
+/// 빌드에 사용된 크레이트 버전을 반환합니다. 알 수 없으면 `unknown`입니다.
+pub fn backend_version(crate_name: &str) -> String {
+    BACKEND_VERSIONS
+        .split(';')
+        .filter_map(|entry| entry.split_once('='))
+        .find(|(name, _)| *name == crate_name)
+        .map(|(_, version)| version.to_string())
+        .unwrap_or_else(|| "unknown".to_string())
+}
+
+/// 키 파일 경로로부터 사이드카 경로(`<이름>.json`)를 정합니다.
+///
+/// 공개키 경로를 기준으로 하며, 공개키가 표준 출력이면 비밀키 경로를 사용합니다.
+/// 두 키가 모두 표준 출력이면 `None`을 반환합니다.
+pub fn sidecar_path(pk_path: &str, sk_path: &str) -> Option<PathBuf> {
+    [pk_path, sk_path]
+        .into_iter()
+        .find(|p| *p != STDIO_PATH)
+        .map(|p| Path::new(p).with_extension("json"))
+}
+
+/// 메타데이터를 JSON 사이드카 파일로 원자적으로 기록합니다.
+pub fn write_sidecar(path: &Path, meta: &KeyMetadata, force: bool) -> io::Result<()> {
+    let mut json = serde_json::to_string_pretty(meta).map_err(io::Error::other)?;
+    json.push('\n');
+
+    write_atomic(path, json.as_bytes(), 0o644, force)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internals::test_util::ScratchDir;
+
+    fn sample(pk_path: &str, sk_path: &str) -> KeyMetadata {
+        KeyMetadata::new(
+            Algorithm::MLKEM,
+            &Variant::MLKEM768,
+            1_709_640_000,
+            "ab12",
+            pk_path,
+            sk_path,
+            true,
+            false,
+        )
+    }
+
+    #[test]
+    fn sidecar_follows_the_first_file_path() {
+        assert_eq!(
+            sidecar_path("keys/a.pub", "keys/a.sk"),
+            Some(PathBuf::from("keys/a.json"))
+        );
+        assert_eq!(sidecar_path("-", "b.sk"), Some(PathBuf::from("b.json")));
+        assert_eq!(sidecar_path("-", "-"), None);
+    }
+
+    #[test]
+    fn metadata_describes_the_key_pair() {
+        let meta = sample("a.pub", "a.sk");
+        assert_eq!(meta.algorithm, "mlkem");
+        assert_eq!(meta.variant, "768");
+        assert_eq!(meta.standard_name, "ML-KEM-768");
+        assert_eq!(meta.created, "2024-03-05T12:00:00Z");
+        assert_eq!(
+            meta.encodings,
+            Encodings {
+                public_key: "pem".to_string(),
+                secret_key: "binary".to_string(),
+            }
+        );
+        assert_eq!(meta.provenance.backend_crate, "pqcrypto-mlkem");
+        assert_eq!(meta.provenance.tool_version, env!("CARGO_PKG_VERSION"));
+        assert_eq!(backend_version("not-a-crate"), "unknown");
+    }
+
+    #[test]
+    fn sidecar_round_trips_and_is_not_overwritten() {
+        let dir = ScratchDir::new("metadata", "sidecar");
+        let path = dir.join("a.json");
+        let meta = sample("a.pub", "a.sk");
+
+        write_sidecar(&path, &meta, false).unwrap();
+        let read: KeyMetadata = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(read, meta);
+
+        let err = write_sidecar(&path, &sample("b.pub", "b.sk"), false).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+        write_sidecar(&path, &sample("b.pub", "b.sk"), true).unwrap();
+        let read: KeyMetadata = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+        assert_eq!(read.files.public_key, "b.pub");
+    }
+
+    #[test]
+    fn sidecars_without_entropy_fields_still_parse() {
+        let mut json = serde_json::to_value(sample("a.pub", "a.sk")).unwrap();
+        let provenance = json["provenance"].as_object_mut().unwrap();
+        provenance.remove("entropy");
+        provenance.remove("entropy_pluggable");
+
+        let meta: KeyMetadata = serde_json::from_value(json).unwrap();
+        assert_eq!(meta.provenance.entropy, None);
+        assert_eq!(meta.provenance.entropy_pluggable, None);
+        let text = serde_json::to_string(&meta).unwrap();
+        assert!(!text.contains("entropy"));
+    }
+}
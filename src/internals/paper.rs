@@ -0,0 +1,628 @@
+use std::fmt::{self, Write};
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use super::keygen::{Algorithm, Variant, parse_algorithm, parse_variant};
+
+/// 종이 백업 첫 줄 (형식 식별자)
+pub const PAPER_TITLE: &str = "KEYPAIRER PAPER BACKUP v1";
+
+/// 종이 백업으로 내보낼 수 있는 최대 비밀 길이 (손으로 옮겨 적을 수 있는 분량)
+pub const MAX_PAPER_SECRET: usize = 8 * 1024;
+
+/// 한 줄에 담는 비밀 바이트 수 (base32 32글자)
+const BYTES_PER_LINE: usize = 20;
+
+/// 줄 체크섬 글자 수 (20비트)
+const LINE_CHECK_LEN: usize = 4;
+
+/// 전체 체크섬 글자 수 (40비트)
+const TOTAL_CHECK_LEN: usize = 8;
+
+/// 보기 좋게 묶는 글자 수
+const GROUP_LEN: usize = 4;
+
+const BASE32: &[u8; 32] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZ234567";
+
+/// 종이 백업 생성/해석 실패 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PaperError {
+    /// 비밀이 너무 큼 (길이)
+    TooLarge(usize),
+    /// 머리말 항목이 없거나 올바르지 않음
+    Header(&'static str),
+    /// 줄 체크섬이 맞지 않는 줄 (줄 번호, 한 글자만 고친 후보)
+    Lines(Vec<(usize, Option<String>)>),
+    /// 빠진 줄 번호
+    Missing(Vec<usize>),
+    /// 줄은 모두 맞지만 전체 체크섬이나 길이가 맞지 않음
+    Total,
+}
+
+impl PaperError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            PaperError::TooLarge(len) => text(
+                "error.paper.too_large",
+                "종이 백업으로 내보내기에는 비밀키가 너무 큽니다: {len}바이트 (최대 {max}바이트, split을 고려하세요)",
+            )
+            .replace("{len}", &len.to_string())
+            .replace("{max}", &MAX_PAPER_SECRET.to_string()),
+            PaperError::Header(what) => text(
+                "error.paper.header",
+                "종이 백업 머리말을 해석할 수 없습니다: {what}",
+            )
+            .replace("{what}", what),
+            PaperError::Lines(lines) => {
+                let mut msg = text(
+                    "error.paper.lines",
+                    "체크섬이 맞지 않는 줄이 있습니다. 옮겨 적은 내용을 확인하세요:",
+                );
+                for (line, suggestion) in lines {
+                    msg.push('\n');
+                    msg.push_str(&match suggestion {
+                        Some(fixed) => text(
+                            "error.paper.line_suggest",
+                            "  {line}번 줄 (혹시: {fixed})",
+                        )
+                        .replace("{fixed}", fixed),
+                        None => text("error.paper.line", "  {line}번 줄"),
+                    }
+                    .replace("{line}", &format!("{:03}", line)));
+                }
+                msg
+            }
+            PaperError::Missing(lines) => text(
+                "error.paper.missing",
+                "빠진 줄이 있습니다: {lines}",
+            )
+            .replace(
+                "{lines}",
+                &lines
+                    .iter()
+                    .map(|l| format!("{:03}", l))
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+            PaperError::Total => text(
+                "error.paper.total",
+                "전체 체크섬이 맞지 않습니다. 머리말(알고리즘, 배리언트, 길이)과 END 줄을 확인하세요.",
+            ),
+        }
+    }
+}
+
+/// 종이 백업 내용
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PaperBackup {
+    pub variant: Variant,
+    /// 공개키 지문 (16진수)
+    pub fingerprint: String,
+    /// 생성 시각 (RFC 3339, UTC)
+    pub created: String,
+    pub secret: Zeroizing<Vec<u8>>,
+}
+
+impl PaperBackup {
+    pub fn new(
+        variant: Variant,
+        fingerprint: &str,
+        created: &str,
+        secret: Zeroizing<Vec<u8>>,
+    ) -> Result<PaperBackup, PaperError> {
+        if secret.len() > MAX_PAPER_SECRET {
+            return Err(PaperError::TooLarge(secret.len()));
+        }
+        Ok(PaperBackup {
+            variant,
+            fingerprint: fingerprint.to_string(),
+            created: created.to_string(),
+            secret,
+        })
+    }
+
+    /// 머리말 줄 (`항목: 값`)
+    fn header_lines(&self) -> Vec<(&'static str, String)> {
+        let lines = self.secret.len().div_ceil(BYTES_PER_LINE);
+        vec![
+            ("Key", self.variant.standard_name()),
+            ("Algorithm", self.variant.algorithm().as_str().to_string()),
+            ("Variant", self.variant.as_str().to_string()),
+            ("Fingerprint", group(&self.fingerprint, 8)),
+            ("Created", self.created.clone()),
+            (
+                "Length",
+                format!("{} bytes, {} lines", self.secret.len(), lines),
+            ),
+        ]
+    }
+
+    /// 전체 체크섬 줄 (`END  ABCD EFGH`)
+    fn end_line(&self) -> String {
+        format!(
+            "END  {}",
+            group(&total_check(&self.variant, &self.secret), GROUP_LEN)
+        )
+    }
+
+    /// 인쇄용 텍스트
+    pub fn to_text(&self) -> Zeroizing<String> {
+        render(|out| self.write_text(out))
+    }
+
+    /// 인쇄용 SVG (A4 폭, 고정폭 글꼴)
+    pub fn to_svg(&self) -> Zeroizing<String> {
+        render(|out| self.write_svg(out))
+    }
+
+    fn write_text(&self, out: &mut dyn Write) -> fmt::Result {
+        writeln!(out, "{}\n", PAPER_TITLE)?;
+        for (name, value) in self.header_lines() {
+            writeln!(out, "{:<12} {}", format!("{}:", name), value)?;
+        }
+        writeln!(out)?;
+        for (i, chunk) in self.secret.chunks(BYTES_PER_LINE).enumerate() {
+            write_body_line(out, i + 1, chunk)?;
+            writeln!(out)?;
+        }
+        writeln!(out, "{}", self.end_line())
+    }
+
+    fn write_svg(&self, out: &mut dyn Write) -> fmt::Result {
+        const LINE_HEIGHT: usize = 18;
+        const MARGIN: usize = 40;
+        let header = self.header_lines();
+        let body = self.secret.len().div_ceil(BYTES_PER_LINE);
+        // 제목, 빈 줄, 머리말, 빈 줄, 본문, END 줄 (빈 줄은 간격만 둡니다)
+        let rows = 2 + header.len() + 1 + body + 1;
+        let height = MARGIN * 2 + rows * LINE_HEIGHT;
+
+        write!(
+            out,
+            "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"210mm\" height=\"{:.0}mm\" \
+             viewBox=\"0 0 794 {}\">\n<rect width=\"100%\" height=\"100%\" fill=\"white\"/>\n\
+             <g font-family=\"DejaVu Sans Mono, Menlo, Consolas, monospace\" font-size=\"13\" fill=\"black\">\n",
+            height as f64 * 210.0 / 794.0,
+            height
+        )?;
+        let open = |out: &mut dyn Write, row: usize, bold: bool| {
+            write!(
+                out,
+                "<text x=\"{}\" y=\"{}\" xml:space=\"preserve\"{}>",
+                MARGIN,
+                MARGIN + (row + 1) * LINE_HEIGHT,
+                if bold { " font-weight=\"bold\"" } else { "" },
+            )
+        };
+        const CLOSE: &str = "</text>\n";
+
+        open(out, 0, true)?;
+        write!(out, "{}{}", xml_escape(PAPER_TITLE), CLOSE)?;
+        for (i, (name, value)) in header.iter().enumerate() {
+            open(out, 2 + i, false)?;
+            let line = format!("{:<12} {}", format!("{}:", name), value);
+            write!(out, "{}{}", xml_escape(&line), CLOSE)?;
+        }
+        let first_body = 2 + header.len() + 1;
+        for (i, chunk) in self.secret.chunks(BYTES_PER_LINE).enumerate() {
+            open(out, first_body + i, false)?;
+            // 본문 줄은 숫자, base32 글자, 공백뿐이므로 이스케이프가 필요 없음
+            write_body_line(out, i + 1, chunk)?;
+            out.write_str(CLOSE)?;
+        }
+        open(out, first_body + body, false)?;
+        write!(out, "{}{}", xml_escape(&self.end_line()), CLOSE)?;
+        out.write_str("</g>\n</svg>\n")
+    }
+
+    /// 옮겨 적은 텍스트를 해석합니다.
+    ///
+    /// 대소문자, 공백, 하이픈은 무시하고 base32에 없는 `0`/`1`/`8`은 `O`/`I`/`B`로 읽습니다.
+    /// 줄 체크섬이 틀린 줄은 한 글자만 바꿔 맞는 후보가 하나뿐이면 함께 알려 줍니다.
+    pub fn parse(text: &str) -> Result<PaperBackup, PaperError> {
+        let field = |name: &str| {
+            text.lines().find_map(|line| {
+                let (key, value) = line.split_once(':')?;
+                key.trim()
+                    .eq_ignore_ascii_case(name)
+                    .then(|| value.trim().to_string())
+            })
+        };
+        let algorithm = field("Algorithm")
+            .and_then(|a| parse_algorithm(&a))
+            .filter(|a| *a != Algorithm::Unknown)
+            .ok_or(PaperError::Header("Algorithm"))?;
+        let variant = field("Variant")
+            .and_then(|v| parse_variant(algorithm, &v.to_lowercase()))
+            .ok_or(PaperError::Header("Variant"))?;
+        let fingerprint: String = field("Fingerprint")
+            .ok_or(PaperError::Header("Fingerprint"))?
+            .chars()
+            .filter(|c| !c.is_whitespace())
+            .collect::<String>()
+            .to_lowercase();
+        let created = field("Created").unwrap_or_default();
+        let length: usize = field("Length")
+            .and_then(|l| l.split_whitespace().next()?.parse().ok())
+            .filter(|l| *l <= MAX_PAPER_SECRET)
+            .ok_or(PaperError::Header("Length"))?;
+
+        let expected_lines = length.div_ceil(BYTES_PER_LINE);
+        let mut chunks: Vec<Option<Zeroizing<Vec<u8>>>> = vec![None; expected_lines];
+        let mut bad = Vec::new();
+        let mut end_check = None;
+        for line in text.lines() {
+            let line = line.trim();
+            let Some((label, rest)) = line.split_once(char::is_whitespace) else {
+                continue;
+            };
+            if label.eq_ignore_ascii_case("END") {
+                end_check = Some(normalize(rest));
+                continue;
+            }
+            let Ok(n) = label.parse::<usize>() else {
+                continue;
+            };
+            if n == 0 || n > expected_lines {
+                bad.push((n, None));
+                continue;
+            }
+            let chars = normalize(rest);
+            let len = if n == expected_lines {
+                length - (n - 1) * BYTES_PER_LINE
+            } else {
+                BYTES_PER_LINE
+            };
+            match decode_line(n, &chars, len) {
+                Some(bytes) => chunks[n - 1] = Some(bytes),
+                None => bad.push((n, suggest(n, &chars, len))),
+            }
+        }
+        if !bad.is_empty() {
+            return Err(PaperError::Lines(bad));
+        }
+        let missing: Vec<usize> = chunks
+            .iter()
+            .enumerate()
+            .filter(|(_, c)| c.is_none())
+            .map(|(i, _)| i + 1)
+            .collect();
+        if !missing.is_empty() {
+            return Err(PaperError::Missing(missing));
+        }
+
+        let mut secret = Zeroizing::new(Vec::with_capacity(length));
+        for chunk in chunks.into_iter().flatten() {
+            secret.extend_from_slice(&chunk);
+        }
+        if end_check.as_ref().map(|c| c.as_str()) != Some(total_check(&variant, &secret).as_str()) {
+            return Err(PaperError::Total);
+        }
+        Ok(PaperBackup {
+            variant,
+            fingerprint,
+            created,
+            secret,
+        })
+    }
+}
+
+/// 출력 길이를 먼저 센 뒤 그만큼 잡은 버퍼에 다시 씁니다.
+///
+/// 버퍼가 재할당되지 않고 비밀이 담긴 중간 문자열도 만들지 않으므로, 해제된 메모리에 비밀
+/// 사본이 남지 않습니다.
+fn render(write: impl Fn(&mut dyn Write) -> fmt::Result) -> Zeroizing<String> {
+    struct Counter(usize);
+    impl Write for Counter {
+        fn write_str(&mut self, s: &str) -> fmt::Result {
+            self.0 += s.len();
+            Ok(())
+        }
+    }
+    let mut counter = Counter(0);
+    write(&mut counter).expect("길이 세기");
+    let mut out = Zeroizing::new(String::with_capacity(counter.0));
+    write(&mut *out).expect("문자열에 쓰기");
+    debug_assert_eq!(out.len(), counter.0);
+    out
+}
+
+/// 본문 한 줄(`001  ABCD EFGH ...  CHK1`)을 씁니다. 데이터 글자는 바로 `out`에 인코딩합니다.
+fn write_body_line<W: Write + ?Sized>(out: &mut W, n: usize, chunk: &[u8]) -> fmt::Result {
+    write!(out, "{:03}  ", n)?;
+    let mut written = 0usize;
+    base32_for_each(chunk, |c| {
+        if written > 0 && written.is_multiple_of(GROUP_LEN) {
+            out.write_char(' ')?;
+        }
+        written += 1;
+        out.write_char(c)
+    })?;
+    write!(out, "  {}", line_check(n, chunk))
+}
+
+/// 한 줄(데이터 글자 + 체크섬 글자)을 검사하고 디코딩합니다.
+fn decode_line(n: usize, chars: &str, len: usize) -> Option<Zeroizing<Vec<u8>>> {
+    let data_len = (len * 8).div_ceil(5);
+    if chars.len() != data_len + LINE_CHECK_LEN {
+        return None;
+    }
+    let (data, check) = chars.split_at(data_len);
+    let bytes = Zeroizing::new(base32_decode(data, len)?);
+    (line_check(n, &bytes) == check).then_some(bytes)
+}
+
+/// 한 글자만 바꿔 체크섬이 맞는 후보가 정확히 하나면 그 줄을 묶음 표기로 돌려줍니다.
+fn suggest(n: usize, chars: &str, len: usize) -> Option<String> {
+    let mut found: Option<String> = None;
+    let mut candidate = Zeroizing::new(chars.as_bytes().to_vec());
+    for i in 0..candidate.len() {
+        let original = candidate[i];
+        for &c in BASE32.iter().filter(|&&c| c != original) {
+            candidate[i] = c;
+            let text = std::str::from_utf8(&candidate).ok()?;
+            if decode_line(n, text, len).is_some() {
+                if found.is_some() {
+                    return None;
+                }
+                let (data, check) = text.split_at(text.len() - LINE_CHECK_LEN);
+                found = Some(format!("{}  {}", group(data, GROUP_LEN), check));
+            }
+        }
+        candidate[i] = original;
+    }
+    found
+}
+
+/// 줄 체크섬: SHA-256(줄 번호 || 데이터)의 앞 20비트. 줄 번호가 들어가므로 줄이 바뀌어도 잡아냅니다.
+fn line_check(n: usize, data: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"keypairer/paper/line");
+    hasher.update((n as u32).to_be_bytes());
+    hasher.update(data);
+    base32_encode(&hasher.finalize())[..LINE_CHECK_LEN].to_string()
+}
+
+/// 전체 체크섬: 알고리즘, 배리언트, 길이와 비밀 전체에 대한 SHA-256의 앞 40비트
+fn total_check(variant: &Variant, secret: &[u8]) -> String {
+    let mut hasher = Sha256::new();
+    hasher.update(b"keypairer/paper/total");
+    hasher.update(variant.algorithm().as_str().as_bytes());
+    hasher.update([0]);
+    hasher.update(variant.as_str().as_bytes());
+    hasher.update((secret.len() as u32).to_be_bytes());
+    hasher.update(secret);
+    base32_encode(&hasher.finalize())[..TOTAL_CHECK_LEN].to_string()
+}
+
+/// 공백·하이픈을 지우고 대문자로 바꾸며, 헷갈리기 쉬운 숫자를 base32 글자로 읽습니다.
+fn normalize(text: &str) -> Zeroizing<String> {
+    // 결과는 입력보다 길지 않으므로 한 번에 잡아 재할당 사본을 남기지 않음
+    let mut out = Zeroizing::new(String::with_capacity(text.len()));
+    out.extend(
+        text.chars()
+            .filter(|c| !c.is_whitespace() && *c != '-')
+            .map(|c| match c.to_ascii_uppercase() {
+                '0' => 'O',
+                '1' => 'I',
+                '8' => 'B',
+                other => other,
+            }),
+    );
+    out
+}
+
+/// 글자를 `size`개씩 공백으로 묶습니다.
+fn group(text: &str, size: usize) -> String {
+    text.as_bytes()
+        .chunks(size)
+        .map(|c| String::from_utf8_lossy(c).into_owned())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// RFC 4648 base32 (패딩 없음)
+pub fn base32_encode(data: &[u8]) -> String {
+    let mut out = String::with_capacity((data.len() * 8).div_ceil(5));
+    let _ = base32_for_each(data, |c| {
+        out.push(c);
+        Ok(())
+    });
+    out
+}
+
+/// base32 글자를 하나씩 `emit`에 넘깁니다 (패딩 없음).
+fn base32_for_each(data: &[u8], mut emit: impl FnMut(char) -> fmt::Result) -> fmt::Result {
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for &byte in data {
+        buffer = ((buffer << 8) | byte as u32) & 0xffff;
+        bits += 8;
+        while bits >= 5 {
+            bits -= 5;
+            emit(BASE32[((buffer >> bits) & 31) as usize] as char)?;
+        }
+    }
+    if bits > 0 {
+        emit(BASE32[((buffer << (5 - bits)) & 31) as usize] as char)?;
+    }
+    Ok(())
+}
+
+/// 패딩 없는 base32를 `len`바이트로 디코딩합니다. 남는 비트가 0이 아니면 실패합니다.
+pub fn base32_decode(text: &str, len: usize) -> Option<Vec<u8>> {
+    let mut out = Vec::with_capacity(len);
+    let (mut buffer, mut bits) = (0u32, 0u32);
+    for c in text.bytes() {
+        let value = BASE32.iter().position(|&b| b == c)? as u32;
+        buffer = ((buffer << 5) | value) & 0xffff;
+        bits += 5;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+        }
+    }
+    (out.len() == len && buffer & ((1 << bits) - 1) == 0).then_some(out)
+}
+
+fn xml_escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+#[cfg(test)]
+mod tests {
+    use zeroize::Zeroizing;
+
+    use super::*;
+    use crate::internals::keygen::Variant;
+
+    const FINGERPRINT: &str = "00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff";
+
+    fn backup(len: usize) -> PaperBackup {
+        let secret: Vec<u8> = (0..len).map(|i| (i * 37 % 251) as u8).collect();
+        PaperBackup::new(
+            Variant::FALCONNoPad512,
+            FINGERPRINT,
+            "2026-01-01T00:00:00Z",
+            Zeroizing::new(secret),
+        )
+        .unwrap()
+    }
+
+    /// `n`번 본문 줄
+    fn body_line(text: &str, n: usize) -> &str {
+        let label = format!("{:03}  ", n);
+        text.lines().find(|l| l.starts_with(&label)).unwrap()
+    }
+
+    #[test]
+    fn base32_matches_rfc_4648_vectors() {
+        // RFC 4648 10절 (패딩 제거)
+        let vectors: [(&[u8], &str); 7] = [
+            (b"", ""),
+            (b"f", "MY"),
+            (b"fo", "MZXQ"),
+            (b"foo", "MZXW6"),
+            (b"foob", "MZXW6YQ"),
+            (b"fooba", "MZXW6YTB"),
+            (b"foobar", "MZXW6YTBOI"),
+        ];
+        for (data, encoded) in vectors {
+            assert_eq!(base32_encode(data), encoded);
+            assert_eq!(
+                base32_decode(encoded, data.len()).as_deref(),
+                Some(data),
+                "{encoded}"
+            );
+        }
+    }
+
+    #[test]
+    fn base32_decode_rejects_bad_input() {
+        // 남는 비트가 0이 아님, 길이 불일치, 알파벳 밖 글자
+        assert_eq!(base32_decode("MZ", 1), None);
+        assert_eq!(base32_decode("MZXW6", 2), None);
+        assert_eq!(base32_decode("MZXW1", 3), None);
+    }
+
+    #[test]
+    fn text_round_trips_for_every_line_shape() {
+        for len in [1, 19, 20, 21, 40, 1281] {
+            let original = backup(len);
+            let parsed = PaperBackup::parse(&original.to_text()).unwrap();
+            assert_eq!(parsed, original, "{len} bytes");
+        }
+    }
+
+    #[test]
+    fn transcription_noise_is_tolerated() {
+        let original = backup(64);
+        let text = original.to_text();
+        // 소문자, 하이픈, 헷갈리는 숫자(O→0, I→1, B→8)로 옮겨 적어도 읽어야 함
+        let sloppy = text
+            .lines()
+            .map(|line| match line.split_once("  ") {
+                Some((n, rest)) if n.len() == 3 || n == "END" => format!(
+                    "{}  {}",
+                    n,
+                    rest.to_lowercase()
+                        .replace(' ', "-")
+                        .replace('o', "0")
+                        .replace('i', "1")
+                        .replace('b', "8")
+                ),
+                _ => line.to_string(),
+            })
+            .collect::<Vec<_>>()
+            .join("\n");
+        assert_eq!(PaperBackup::parse(&sloppy).unwrap(), original);
+    }
+
+    #[test]
+    fn a_single_flipped_character_is_located_and_corrected() {
+        let original = backup(64);
+        let text = original.to_text();
+        for n in 1..=4 {
+            let line = body_line(&text, n);
+            let (label, rest) = line.split_at(5);
+            // 데이터 부분의 여러 위치에서 한 글자씩 바꿔 봄
+            for pos in [0, 7, rest.len() - 8] {
+                let mut chars: Vec<u8> = rest.as_bytes().to_vec();
+                if chars[pos] == b' ' {
+                    continue;
+                }
+                chars[pos] = if chars[pos] == b'Q' { b'R' } else { b'Q' };
+                let broken = format!("{}{}", label, String::from_utf8(chars).unwrap());
+                let result = PaperBackup::parse(&text.replace(line, &broken));
+                assert_eq!(
+                    result,
+                    Err(PaperError::Lines(vec![(n, Some(rest.to_string()))])),
+                    "line {n}, position {pos}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn swapped_missing_and_inconsistent_lines_are_reported() {
+        let original = backup(64);
+        let text = original.to_text();
+
+        // 줄 번호가 체크섬에 들어가므로 두 줄의 번호를 바꾸면 두 줄 모두 틀림
+        let (one, two) = (body_line(&text, 1), body_line(&text, 2));
+        let swapped = text
+            .replace(one, &one.replacen("001", "TMP", 1))
+            .replace(two, &two.replacen("002", "001", 1))
+            .replace("TMP  ", "002  ");
+        let Err(PaperError::Lines(lines)) = PaperBackup::parse(&swapped) else {
+            panic!("swapped lines were accepted");
+        };
+        let mut numbers: Vec<usize> = lines.iter().map(|(n, _)| *n).collect();
+        numbers.sort();
+        assert_eq!(numbers, [1, 2]);
+
+        let missing = text.replace(&format!("{}\n", body_line(&text, 3)), "");
+        assert_eq!(
+            PaperBackup::parse(&missing),
+            Err(PaperError::Missing(vec![3]))
+        );
+
+        // 배리언트를 바꾸면 줄은 모두 맞아도 전체 체크섬이 틀림
+        let other_variant = text.replace("Variant:     nopad512", "Variant:     nopad1024");
+        assert_ne!(other_variant, *text);
+        assert_eq!(PaperBackup::parse(&other_variant), Err(PaperError::Total));
+
+        let no_header = text.replace("Algorithm:", "Algo:");
+        assert_eq!(
+            PaperBackup::parse(&no_header),
+            Err(PaperError::Header("Algorithm"))
+        );
+    }
+}
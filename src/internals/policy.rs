@@ -0,0 +1,305 @@
+use std::fmt;
+
+use serde::Deserialize;
+
+use super::keygen::Variant;
+
+/// 키 생성 정책 (설정 파일의 `[policy]`, `KEYPAIRER_POLICY`, `-policy`)
+///
+/// 여러 곳에서 지정하면 [`Policy::merge`]로 가장 엄격한 조합을 적용하므로, 나중에 지정한
+/// 정책이 앞선 정책을 완화할 수는 없습니다.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Policy {
+    /// 허용하는 최소 NIST 보안 범주 (1–5)
+    pub min_category: Option<u8>,
+    /// NIST 표준으로 확정된 알고리즘만 허용
+    pub standard_only: bool,
+    /// 비밀키를 텍스트(PEM)로 저장하지 않음
+    pub forbid_sk_text: bool,
+}
+
+/// 정책 위반 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PolicyViolation {
+    /// 보안 범주 미달 (표준 이름, 범주, 최소 범주)
+    Category(String, u8, u8),
+    /// 표준으로 확정되지 않은 알고리즘 (표준 이름)
+    NonStandard(String),
+    /// 비밀키 텍스트 저장
+    SkText,
+}
+
+/// 정책 문자열 해석 오류 (해석하지 못한 항목)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PolicySpecError(pub String);
+
+impl Policy {
+    /// `strict`, `none` 또는 `min-category=<1-5>`, `standard-only`, `no-sktext`를
+    /// 쉼표로 이은 정책 문자열을 해석합니다.
+    ///
+    /// `strict`는 `min-category=3,standard-only,no-sktext`와 같습니다.
+    pub fn parse(spec: &str) -> Result<Policy, PolicySpecError> {
+        let mut policy = Policy::default();
+        for item in spec.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+            let lowered = item.to_lowercase();
+            match lowered.split_once('=') {
+                Some(("min-category" | "min_category" | "min", value)) => {
+                    match value.trim().parse::<u8>() {
+                        Ok(category @ 1..=5) => {
+                            policy.min_category =
+                                Some(policy.min_category.map_or(category, |c| c.max(category)))
+                        }
+                        _ => return Err(PolicySpecError(item.to_string())),
+                    }
+                }
+                Some(_) => return Err(PolicySpecError(item.to_string())),
+                None => match lowered.as_str() {
+                    "strict" => policy.merge(&Policy::strict()),
+                    "none" => {}
+                    "standard-only" | "standard_only" => policy.standard_only = true,
+                    "no-sktext" | "forbid-sk-text" | "forbid_sk_text" => {
+                        policy.forbid_sk_text = true
+                    }
+                    _ => return Err(PolicySpecError(item.to_string())),
+                },
+            }
+        }
+        Ok(policy)
+    }
+
+    /// `strict` 정책
+    pub fn strict() -> Policy {
+        Policy {
+            min_category: Some(3),
+            standard_only: true,
+            forbid_sk_text: true,
+        }
+    }
+
+    /// 두 정책 중 더 엄격한 조건을 모두 적용합니다.
+    pub fn merge(&mut self, other: &Policy) {
+        self.min_category = match (self.min_category, other.min_category) {
+            (Some(a), Some(b)) => Some(a.max(b)),
+            (a, b) => a.or(b),
+        };
+        self.standard_only |= other.standard_only;
+        self.forbid_sk_text |= other.forbid_sk_text;
+    }
+
+    /// 제한이 하나도 없는지 여부
+    pub fn is_empty(&self) -> bool {
+        *self == Policy::default()
+    }
+
+    /// 배리언트가 정책을 만족하는지 확인합니다.
+    pub fn check_variant(&self, variant: &Variant) -> Result<(), PolicyViolation> {
+        if self.standard_only && !variant.algorithm().is_standardized() {
+            return Err(PolicyViolation::NonStandard(variant.standard_name()));
+        }
+        if let Some(min) = self.min_category
+            && variant.nist_category() < min
+        {
+            return Err(PolicyViolation::Category(
+                variant.standard_name(),
+                variant.nist_category(),
+                min,
+            ));
+        }
+        Ok(())
+    }
+
+    /// 비밀키 저장 형식이 정책을 만족하는지 확인합니다.
+    pub fn check_sk_text(&self, sk_text: bool) -> Result<(), PolicyViolation> {
+        if self.forbid_sk_text && sk_text {
+            return Err(PolicyViolation::SkText);
+        }
+        Ok(())
+    }
+}
+
+/// [`Policy::parse`]가 받는 형식으로 표시합니다 (제한이 없으면 `none`).
+impl fmt::Display for Policy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut items = Vec::new();
+        if let Some(min) = self.min_category {
+            items.push(format!("min-category={}", min));
+        }
+        if self.standard_only {
+            items.push("standard-only".to_string());
+        }
+        if self.forbid_sk_text {
+            items.push("no-sktext".to_string());
+        }
+        if items.is_empty() {
+            f.write_str("none")
+        } else {
+            f.write_str(&items.join(","))
+        }
+    }
+}
+
+impl PolicyViolation {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            PolicyViolation::Category(name, category, min) => text(
+                "error.policy.category",
+                "정책 위반: {name}은(는) NIST 보안 범주 {category}로, 정책이 요구하는 최소 범주 {min}보다 낮습니다.",
+            )
+            .replace("{name}", name)
+            .replace("{category}", &category.to_string())
+            .replace("{min}", &min.to_string()),
+            PolicyViolation::NonStandard(name) => text(
+                "error.policy.nonstandard",
+                "정책 위반: {name}은(는) NIST 표준으로 확정되지 않은 알고리즘입니다 (standard-only).",
+            )
+            .replace("{name}", name),
+            PolicyViolation::SkText => text(
+                "error.policy.sktext",
+                "정책 위반: 비밀키를 텍스트(PEM)로 저장할 수 없습니다 (no-sktext).",
+            ),
+        }
+    }
+}
+
+impl PolicySpecError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        super::i18n::load_translations(lang)
+            .get("error.policy.spec")
+            .cloned()
+            .unwrap_or_else(|| {
+                "알 수 없는 정책 항목: {item} (strict, none, min-category=<1-5>, standard-only, no-sktext)"
+                    .to_string()
+            })
+            .replace("{item}", &self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn specs_parse_into_policies_and_display_back() {
+        let parsed = |spec: &str| Policy::parse(spec).unwrap();
+        assert_eq!(parsed("strict"), Policy::strict());
+        assert_eq!(
+            parsed("min-category=3,standard-only,no-sktext"),
+            Policy::strict()
+        );
+        assert_eq!(
+            parsed(" Standard_Only , , MIN=2 "),
+            Policy {
+                min_category: Some(2),
+                standard_only: true,
+                forbid_sk_text: false,
+            }
+        );
+        assert!(parsed("none").is_empty());
+        assert!(parsed("").is_empty());
+        // 같은 정책 안에서도 높은 범주가 남음
+        assert_eq!(
+            parsed("min-category=4,min-category=2").min_category,
+            Some(4)
+        );
+
+        for policy in [
+            Policy::default(),
+            Policy::strict(),
+            parsed("no-sktext,min=5"),
+        ] {
+            assert_eq!(Policy::parse(&policy.to_string()).unwrap(), policy);
+        }
+        assert_eq!(Policy::default().to_string(), "none");
+        assert_eq!(
+            Policy::strict().to_string(),
+            "min-category=3,standard-only,no-sktext"
+        );
+    }
+
+    #[test]
+    fn unknown_or_out_of_range_items_are_rejected() {
+        for (spec, item) in [
+            ("min-category=0", "min-category=0"),
+            ("min-category=6", "min-category=6"),
+            ("strict,min-category=x", "min-category=x"),
+            ("lenient", "lenient"),
+            ("standard-only=yes", "standard-only=yes"),
+        ] {
+            assert_eq!(
+                Policy::parse(spec),
+                Err(PolicySpecError(item.to_string())),
+                "{spec}"
+            );
+        }
+    }
+
+    #[test]
+    fn merging_keeps_the_strictest_of_each_setting() {
+        let mut policy = Policy::parse("min-category=4").unwrap();
+        policy.merge(&Policy::strict());
+        assert_eq!(
+            policy,
+            Policy::parse("min-category=4,standard-only,no-sktext").unwrap()
+        );
+
+        // 느슨한 정책을 나중에 합쳐도 완화되지 않음
+        policy.merge(&Policy::parse("min-category=1").unwrap());
+        policy.merge(&Policy::default());
+        assert_eq!(policy.min_category, Some(4));
+        assert!(policy.standard_only && policy.forbid_sk_text);
+
+        let mut empty = Policy::default();
+        empty.merge(&Policy::parse("min=2").unwrap());
+        assert_eq!(empty.min_category, Some(2));
+    }
+
+    #[test]
+    fn variants_are_checked_against_category_and_standardization() {
+        let strict = Policy::strict();
+        assert_eq!(strict.check_variant(&Variant::MLKEM768), Ok(()));
+        assert_eq!(strict.check_variant(&Variant::MLDSA87), Ok(()));
+        assert_eq!(
+            strict.check_variant(&Variant::MLKEM512),
+            Err(PolicyViolation::Category("ML-KEM-512".to_string(), 1, 3))
+        );
+        // 표준 여부를 범주보다 먼저 봄
+        assert!(matches!(
+            strict.check_variant(&Variant::HQC128),
+            Err(PolicyViolation::NonStandard(_))
+        ));
+        for variant in [
+            Variant::HQC256,
+            Variant::McEliece6688128,
+            Variant::FALCONNoPad1024,
+            Variant::SPHINCSshake256ssimple,
+        ] {
+            assert!(
+                matches!(
+                    strict.check_variant(&variant),
+                    Err(PolicyViolation::NonStandard(_))
+                ),
+                "{variant:?}"
+            );
+        }
+
+        let category_only = Policy::parse("min-category=5").unwrap();
+        assert_eq!(category_only.check_variant(&Variant::HQC256), Ok(()));
+        assert!(category_only.check_variant(&Variant::MLDSA65).is_err());
+        assert_eq!(Policy::default().check_variant(&Variant::MLKEM512), Ok(()));
+    }
+
+    #[test]
+    fn secret_key_text_is_only_refused_when_forbidden() {
+        let strict = Policy::strict();
+        assert_eq!(strict.check_sk_text(true), Err(PolicyViolation::SkText));
+        assert_eq!(strict.check_sk_text(false), Ok(()));
+        assert_eq!(Policy::default().check_sk_text(true), Ok(()));
+    }
+}
@@ -0,0 +1,1269 @@
+use std::collections::{HashMap, VecDeque};
+use std::env;
+use std::fmt;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::Shutdown;
+use std::os::unix::fs::{FileTypeExt, OpenOptionsExt};
+use std::os::unix::io::AsRawFd;
+use std::os::unix::net::{UnixListener, UnixStream};
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Condvar, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use chacha20poly1305::aead::{Aead, KeyInit, Payload};
+use chacha20poly1305::{ChaCha20Poly1305, Key, Nonce};
+use hkdf::Hkdf;
+use serde::{Deserialize, Serialize};
+use sha2::Sha256;
+use zeroize::Zeroizing;
+
+use super::datetime::now_unix;
+use super::executor::{ExecutorError, ExecutorOptions, KeygenExecutor};
+use super::key_io::{
+    ZeroizingWriter, base64_serde, create_private_dir_all, fingerprint, read_all_zeroizing,
+    secure_delete, write_atomic,
+};
+use super::keygen::{
+    Algorithm, KeyGenResult, Variant, minimal_variant_for_algorithm, parse_algorithm, parse_variant,
+};
+use super::keystore::default_root;
+use super::stack;
+
+/// 풀 소켓 경로를 지정하는 환경 변수 (`-socket`이 없을 때)
+pub const SOCKET_ENV: &str = "KEYPAIRER_POOL_SOCKET";
+
+/// 요청 형식이 잘못됨
+pub const ERR_BAD_REQUEST: &str = "bad_request";
+/// 풀에 설정되지 않은 배리언트
+pub const ERR_NOT_CONFIGURED: &str = "not_configured";
+/// 서버 내부 오류 (키 생성 실패 등)
+pub const ERR_INTERNAL: &str = "internal";
+/// 처리 중인 연결이 [`MAX_CONNECTIONS`]개라 요청을 읽지 않고 거절함
+pub const ERR_BUSY: &str = "busy";
+
+/// 동시에 처리하는 최대 연결 수 (넘으면 [`ERR_BUSY`]로 응답하고 끊음)
+pub const MAX_CONNECTIONS: usize = 64;
+
+/// 요청 한 줄의 최대 크기 (줄바꿈 포함)
+const MAX_REQUEST_LINE: u64 = 4 * 1024;
+
+/// 연결별 읽기·쓰기 제한 시간 (쉬고 있는 연결이 자리를 계속 차지하지 않도록)
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 연결 수를 넘은 연결에 응답하고 요청을 버리는 데 쓰는 제한 시간 (연결을 받는 스레드에서 처리)
+const REJECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+const POOL_DIR: &str = "pool";
+const ENTRIES_DIR: &str = "entries";
+const SOCKET_FILE: &str = "keypairer-pool.sock";
+const LOCK_SUFFIX: &str = ".lock";
+const ENTRY_INFO: &[u8] = b"keypairer/pool/v1/entry";
+const ENTRY_VERSION: u32 = 1;
+const KEY_LEN: usize = 32;
+const SALT_LEN: usize = 32;
+
+/// 키 생성이 한 차례 모두 실패했을 때 다시 시도하기 전까지 기다리는 시간
+const RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// 배리언트별로 미리 만들어 둘 키 페어 수
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PoolTarget {
+    pub variant: Variant,
+    /// 0이면 미리 만들지 않고 요청할 때 생성
+    pub size: usize,
+}
+
+/// 풀에서 꺼낸 키 페어
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PooledKey {
+    pub algorithm: String,
+    pub variant: String,
+    pub fingerprint: String,
+    /// 생성 시각(유닉스 초)
+    pub created: u64,
+    #[serde(with = "base64_serde")]
+    pub public_key: Vec<u8>,
+    #[serde(with = "base64_serde")]
+    pub secret_key: Zeroizing<Vec<u8>>,
+}
+
+/// 배리언트별 풀 상태
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+pub struct PoolStatus {
+    pub algorithm: String,
+    pub variant: String,
+    pub standard_name: String,
+    /// 지금 꺼낼 수 있는 키 페어 수
+    pub available: usize,
+    /// 채워 둘 키 페어 수
+    pub target: usize,
+}
+
+/// 소켓으로 보내는 요청 (한 줄에 JSON 하나)
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq)]
+#[serde(tag = "op", rename_all = "snake_case")]
+pub enum PoolRequest {
+    /// 키 페어 하나를 꺼냄. 배리언트를 생략하면 알고리즘별 최소값
+    Take {
+        algorithm: String,
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        variant: Option<String>,
+    },
+    /// 배리언트별 상태
+    Status,
+}
+
+/// 요청에 대한 응답 (한 줄에 JSON 하나)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct PoolResponse {
+    pub ok: bool,
+    /// 실패 사유 코드 ([`ERR_BAD_REQUEST`], [`ERR_NOT_CONFIGURED`], [`ERR_INTERNAL`], [`ERR_BUSY`])
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub message: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub key: Option<PooledKey>,
+    /// 풀에 있던 키인지(`false`면 풀이 비어 요청 시 생성)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pooled: Option<bool>,
+    /// 꺼낸 뒤 남은 키 페어 수
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub remaining: Option<usize>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub status: Option<Vec<PoolStatus>>,
+}
+
+/// 서버가 알리는 사건 (로그 출력용)
+#[derive(Debug)]
+pub enum PoolEvent {
+    /// 키 페어 하나를 만들어 풀에 넣음
+    Refilled { variant: Variant, available: usize },
+    /// 키 페어 하나를 내줌 (`pooled`가 아니면 요청 시 생성)
+    Served {
+        variant: Variant,
+        pooled: bool,
+        remaining: usize,
+    },
+    /// 처리하지 못한 오류 (서버는 계속 동작)
+    Failed(PoolError),
+}
+
+/// 키 풀 오류
+#[derive(Debug)]
+pub enum PoolError {
+    /// 풀 크기 지정을 해석하지 못함 (해석하지 못한 항목)
+    Spec(String),
+    Io(io::Error),
+    /// 풀 키 또는 항목 파일이 손상되었거나 풀 키와 맞지 않음
+    Corrupt(PathBuf),
+    /// 풀 키 파일이 풀 디렉토리 안에 있음
+    KeyInsidePool(PathBuf),
+    /// 다른 사용자의 프로세스가 소켓에 연결함 (상대의 uid)
+    Forbidden(u32),
+    /// 처리 중인 연결이 [`MAX_CONNECTIONS`]개라 연결을 거절함 (서버는 알림으로, 클라이언트는 응답으로 받음)
+    Busy,
+    /// 소켓에서 이미 다른 서버가 동작 중
+    AlreadyRunning(PathBuf),
+    /// 소켓에 연결할 수 없음 (서버가 동작하지 않음)
+    NotRunning(PathBuf),
+    /// 키 생성 실패
+    Generate(String),
+    Executor(ExecutorError),
+    /// 응답을 해석하지 못함
+    Protocol(String),
+    /// 서버가 돌려준 오류 (사유 코드, 메시지)
+    Server {
+        code: String,
+        message: String,
+    },
+}
+
+/// 키 풀 디스크 저장소
+///
+/// 항목마다 임의의 솔트와 풀 키로 HKDF-SHA256 키를 유도해 비밀키를 ChaCha20-Poly1305로
+/// 봉인하고, `entries/<알고리즘>-<배리언트>/` 아래에 0o600 권한의 JSON 파일로 둡니다.
+/// 꺼낸 항목은 덮어쓴 뒤 삭제합니다.
+///
+/// 봉인은 풀 디렉토리만 복사되거나 백업된 경우를 막기 위한 것이므로, 풀 키는 반드시 풀
+/// 디렉토리 밖(다른 볼륨이나 백업 대상이 아닌 위치)에 둡니다.
+pub struct PoolStore {
+    dir: PathBuf,
+    key: Zeroizing<[u8; KEY_LEN]>,
+}
+
+/// 디스크의 항목 파일
+#[derive(Serialize, Deserialize)]
+struct SealedEntry {
+    version: u32,
+    algorithm: String,
+    variant: String,
+    created: u64,
+    fingerprint: String,
+    #[serde(with = "base64_serde")]
+    public_key: Vec<u8>,
+    #[serde(with = "base64_serde")]
+    salt: Vec<u8>,
+    #[serde(with = "base64_serde")]
+    secret_key: Vec<u8>,
+}
+
+/// 기본 풀 디렉토리 (키 저장소 아래 `pool`)
+pub fn default_dir() -> Option<PathBuf> {
+    default_root().map(|root| root.join(POOL_DIR))
+}
+
+/// 기본 소켓 경로
+///
+/// [`SOCKET_ENV`] → `$XDG_RUNTIME_DIR/keypairer-pool.sock` → `<풀 디렉토리>/keypairer-pool.sock`
+/// 순으로 결정합니다.
+pub fn default_socket(dir: Option<&Path>) -> Option<PathBuf> {
+    if let Some(path) = env::var_os(SOCKET_ENV).filter(|v| !v.is_empty()) {
+        return Some(PathBuf::from(path));
+    }
+    if let Some(runtime) = env::var_os("XDG_RUNTIME_DIR").filter(|v| !v.is_empty()) {
+        return Some(PathBuf::from(runtime).join(SOCKET_FILE));
+    }
+    dir.map(Path::to_path_buf)
+        .or_else(default_dir)
+        .map(|dir| dir.join(SOCKET_FILE))
+}
+
+/// `mlkem:768=4,mceliece:6688128=2`처럼 `<알고리즘>[:<배리언트>]=<개수>`를 쉼표로 이은
+/// 풀 크기 지정을 해석합니다. 배리언트를 생략하면 알고리즘별 최소값이며, 같은 배리언트를
+/// 두 번 지정할 수 없습니다.
+pub fn parse_targets(spec: &str) -> Result<Vec<PoolTarget>, PoolError> {
+    let mut targets: Vec<PoolTarget> = Vec::new();
+    for item in spec.split(',').map(str::trim).filter(|s| !s.is_empty()) {
+        let err = || PoolError::Spec(item.to_string());
+        let (name, size) = item.split_once('=').ok_or_else(err)?;
+        let size = size.trim().parse::<usize>().map_err(|_| err())?;
+        let (alg, var) = match name.split_once(':') {
+            Some((alg, var)) => (alg.trim(), Some(var.trim())),
+            None => (name.trim(), None),
+        };
+        let variant = resolve(alg, var).ok_or_else(err)?;
+        if targets.iter().any(|t| t.variant == variant) {
+            return Err(err());
+        }
+        targets.push(PoolTarget { variant, size });
+    }
+    if targets.is_empty() {
+        return Err(PoolError::Spec(spec.to_string()));
+    }
+    Ok(targets)
+}
+
+/// 알고리즘과 선택적 배리언트 이름을 해석합니다.
+fn resolve(algorithm: &str, variant: Option<&str>) -> Option<Variant> {
+    let alg = parse_algorithm(algorithm).filter(|a| *a != Algorithm::Unknown)?;
+    match variant {
+        Some(v) => parse_variant(alg, v),
+        None => Some(minimal_variant_for_algorithm(alg)),
+    }
+}
+
+impl PoolStore {
+    /// 풀 디렉토리를 엽니다. 디렉토리가 없으면 0o700 권한으로 만들고, 풀 키 파일이 없으면
+    /// 임의의 키를 만들어 0o600 권한으로 저장합니다.
+    ///
+    /// 심볼릭 링크를 풀어 보아 풀 키 파일이 풀 디렉토리 안에 있으면
+    /// [`PoolError::KeyInsidePool`]을 반환합니다.
+    pub fn open(dir: &Path, key_file: &Path) -> Result<PoolStore, PoolError> {
+        create_private_dir_all(&dir.join(ENTRIES_DIR))?;
+        if is_within(key_file, dir)? {
+            return Err(PoolError::KeyInsidePool(key_file.to_path_buf()));
+        }
+        Ok(PoolStore {
+            dir: dir.to_path_buf(),
+            key: load_or_create_key(key_file)?,
+        })
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    fn variant_dir(&self, variant: &Variant) -> PathBuf {
+        self.dir.join(ENTRIES_DIR).join(format!(
+            "{}-{}",
+            variant.algorithm().as_str(),
+            variant.as_str()
+        ))
+    }
+
+    /// 배리언트의 항목 파일을 오래된 순으로 반환합니다.
+    pub fn entries(&self, variant: &Variant) -> Result<Vec<PathBuf>, PoolError> {
+        let dir = self.variant_dir(variant);
+        let read = match fs::read_dir(&dir) {
+            Ok(read) => read,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e.into()),
+        };
+        let mut paths = Vec::new();
+        for entry in read {
+            let path = entry?.path();
+            if path.extension().is_some_and(|ext| ext == "json") {
+                paths.push(path);
+            }
+        }
+        // 파일 이름이 생성 시각으로 시작하므로 이름순이 생성순
+        paths.sort();
+        Ok(paths)
+    }
+
+    /// 키 페어를 봉인해 풀에 넣고 항목 파일 경로를 반환합니다.
+    pub fn put(&self, variant: &Variant, pk: &[u8], sk: &[u8]) -> Result<PathBuf, PoolError> {
+        let fp = fingerprint(pk);
+        let mut salt = vec![0u8; SALT_LEN];
+        getrandom::fill(&mut salt).map_err(|e| io::Error::other(e.to_string()))?;
+        let sealed = ChaCha20Poly1305::new(Key::from_slice(
+            entry_key(&self.key, &salt, variant, &fp).as_ref(),
+        ))
+        .encrypt(&Nonce::default(), Payload { msg: sk, aad: pk })
+        .map_err(|_| io::Error::other("seal failed"))?;
+
+        let created = now_unix();
+        let entry = SealedEntry {
+            version: ENTRY_VERSION,
+            algorithm: variant.algorithm().as_str().to_string(),
+            variant: variant.as_str().to_string(),
+            created,
+            fingerprint: fp.clone(),
+            public_key: pk.to_vec(),
+            salt,
+            secret_key: sealed,
+        };
+        let json = serde_json::to_vec(&entry).map_err(|e| io::Error::other(e.to_string()))?;
+        let path = self
+            .variant_dir(variant)
+            .join(format!("{:016x}-{}.json", created, &fp[..16]));
+        write_atomic(&path, &json, 0o600, false)?;
+        Ok(path)
+    }
+
+    /// 항목 파일을 열어 키 페어를 꺼내고 파일을 덮어쓴 뒤 삭제합니다.
+    ///
+    /// 풀 키와 맞지 않거나 손상된 항목은 [`PoolError::Corrupt`]를 반환하며 파일은 그대로 둡니다.
+    pub fn take(&self, path: &Path) -> Result<PooledKey, PoolError> {
+        let corrupt = || PoolError::Corrupt(path.to_path_buf());
+        let data = fs::read(path)?;
+        let entry: SealedEntry = serde_json::from_slice(&data).map_err(|_| corrupt())?;
+        let (Some(alg), true) = (
+            parse_algorithm(&entry.algorithm).filter(|a| *a != Algorithm::Unknown),
+            entry.version == ENTRY_VERSION && entry.fingerprint == fingerprint(&entry.public_key),
+        ) else {
+            return Err(corrupt());
+        };
+        let variant = parse_variant(alg, &entry.variant).ok_or_else(corrupt)?;
+
+        let secret_key = ChaCha20Poly1305::new(Key::from_slice(
+            entry_key(&self.key, &entry.salt, &variant, &entry.fingerprint).as_ref(),
+        ))
+        .decrypt(
+            &Nonce::default(),
+            Payload {
+                msg: &entry.secret_key,
+                aad: &entry.public_key,
+            },
+        )
+        .map(Zeroizing::new)
+        .map_err(|_| corrupt())?;
+
+        secure_delete(path)?;
+        Ok(PooledKey {
+            algorithm: entry.algorithm,
+            variant: entry.variant,
+            fingerprint: entry.fingerprint,
+            created: entry.created,
+            public_key: entry.public_key,
+            secret_key,
+        })
+    }
+}
+
+/// `path`가 `dir` 아래에 있는지 확인합니다. 아직 없는 경로는 존재하는 가장 가까운 상위
+/// 디렉토리에서 심볼릭 링크를 풀고 나머지 구성 요소를 붙여 비교합니다.
+fn is_within(path: &Path, dir: &Path) -> io::Result<bool> {
+    let dir = fs::canonicalize(dir)?;
+    let path = std::path::absolute(path)?;
+    let mut existing = path.as_path();
+    let mut rest = Vec::new();
+    let resolved = loop {
+        match fs::canonicalize(existing) {
+            Ok(resolved) => break resolved,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => {
+                let (Some(parent), Some(name)) = (existing.parent(), existing.file_name()) else {
+                    return Err(e);
+                };
+                rest.push(name);
+                existing = parent;
+            }
+            Err(e) => return Err(e),
+        }
+    };
+    let full = rest.iter().rev().fold(resolved, |p, name| p.join(name));
+    Ok(full.starts_with(&dir))
+}
+
+/// 풀 키를 읽거나, 파일이 없으면 만들어 저장합니다.
+fn load_or_create_key(path: &Path) -> Result<Zeroizing<[u8; KEY_LEN]>, PoolError> {
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    match fs::read(path).map(Zeroizing::new) {
+        Ok(data) if data.len() == KEY_LEN => {
+            key.copy_from_slice(&data);
+            Ok(key)
+        }
+        Ok(_) => Err(PoolError::Corrupt(path.to_path_buf())),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {
+            getrandom::fill(key.as_mut()).map_err(|e| io::Error::other(e.to_string()))?;
+            match write_atomic(path, key.as_ref(), 0o600, false) {
+                Ok(()) => Ok(key),
+                // 동시에 다른 프로세스가 만들었으면 그 키를 사용
+                Err(e) if e.kind() == io::ErrorKind::AlreadyExists => load_or_create_key(path),
+                Err(e) => Err(e.into()),
+            }
+        }
+        Err(e) => Err(e.into()),
+    }
+}
+
+/// 항목의 비밀키를 봉인하는 키를 유도합니다. 항목마다 한 번만 쓰이므로 논스는 0으로 둡니다.
+fn entry_key(
+    master: &[u8; KEY_LEN],
+    salt: &[u8],
+    variant: &Variant,
+    fp: &str,
+) -> Zeroizing<[u8; KEY_LEN]> {
+    let mut info = ENTRY_INFO.to_vec();
+    info.extend_from_slice(variant.as_str().as_bytes());
+    info.extend_from_slice(fp.as_bytes());
+    let mut key = Zeroizing::new([0u8; KEY_LEN]);
+    Hkdf::<Sha256>::new(Some(salt), master)
+        .expand(&info, key.as_mut())
+        .expect("32 bytes is a valid HKDF-SHA256 output length");
+    key
+}
+
+/// 유닉스 소켓을 엽니다. 소켓은 처음부터 소유자 전용(0o600)으로 만들어집니다.
+///
+/// 소켓 옆의 잠금 파일(`<소켓>.lock`)에 배타적 `flock`을 걸어 서버가 하나만 돌게 하며,
+/// 잠금을 얻은 뒤에만 이전 서버가 남긴 소켓 파일을 지우므로 두 서버가 동시에 시작해도
+/// 서로의 소켓을 지우지 않습니다. 반환하는 잠금 파일은 서버가 끝날 때까지 쥐고 있어야 합니다.
+///
+/// 소켓을 만드는 동안 프로세스의 umask를 바꾸므로 다른 스레드가 파일을 만들기 전에 호출하세요.
+pub fn bind(socket: &Path) -> Result<(UnixListener, File), PoolError> {
+    if let Some(dir) = socket.parent() {
+        create_private_dir_all(dir)?;
+    }
+    let mut lock_path = socket.as_os_str().to_owned();
+    lock_path.push(LOCK_SUFFIX);
+    let lock = fs::OpenOptions::new()
+        .read(true)
+        .write(true)
+        .create(true)
+        .truncate(false)
+        .mode(0o600)
+        .custom_flags(libc::O_NOFOLLOW | libc::O_CLOEXEC)
+        .open(&lock_path)?;
+    // SAFETY: 열려 있는 파일 기술자에 대한 호출
+    if unsafe { libc::flock(lock.as_raw_fd(), libc::LOCK_EX | libc::LOCK_NB) } != 0 {
+        let e = io::Error::last_os_error();
+        return Err(match e.kind() {
+            io::ErrorKind::WouldBlock => PoolError::AlreadyRunning(socket.to_path_buf()),
+            _ => e.into(),
+        });
+    }
+
+    // 잠금을 쥐었으므로 남아 있는 소켓 파일은 끝난 서버의 것. 소켓이 아닌 파일은 지우지 않음
+    match fs::symlink_metadata(socket) {
+        Ok(meta) if meta.file_type().is_socket() => fs::remove_file(socket)?,
+        Ok(_) => return Err(io::Error::from(io::ErrorKind::AlreadyExists).into()),
+        Err(e) if e.kind() == io::ErrorKind::NotFound => {}
+        Err(e) => return Err(e.into()),
+    }
+
+    // SAFETY: umask는 실패하지 않으며, 바로 아래에서 이전 값으로 되돌림
+    let previous = unsafe { libc::umask(0o177) };
+    let listener = UnixListener::bind(socket);
+    // SAFETY: 위와 같음
+    unsafe { libc::umask(previous) };
+    Ok((listener?, lock))
+}
+
+/// 연결한 프로세스의 유효 사용자 ID
+fn peer_uid(stream: &UnixStream) -> io::Result<libc::uid_t> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let mut cred = libc::ucred {
+            pid: 0,
+            uid: 0,
+            gid: 0,
+        };
+        let mut len = std::mem::size_of::<libc::ucred>() as libc::socklen_t;
+        // SAFETY: `cred`와 `len`은 `SO_PEERCRED`가 쓰는 크기에 맞는 지역 변수
+        let rc = unsafe {
+            libc::getsockopt(
+                stream.as_raw_fd(),
+                libc::SOL_SOCKET,
+                libc::SO_PEERCRED,
+                (&mut cred as *mut libc::ucred).cast(),
+                &mut len,
+            )
+        };
+        if rc != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(cred.uid)
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        let (mut uid, mut gid) = (0, 0);
+        // SAFETY: 출력 인자는 지역 변수
+        if unsafe { libc::getpeereid(stream.as_raw_fd(), &mut uid, &mut gid) } != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(uid)
+    }
+}
+
+/// 서버 스레드가 공유하는 상태
+struct Shared {
+    store: PoolStore,
+    targets: Vec<PoolTarget>,
+    queues: Mutex<HashMap<Variant, VecDeque<PathBuf>>>,
+    /// 키를 꺼낼 때마다 보충 스레드를 깨움
+    refill: Condvar,
+    /// 보충과 요청 시 생성을 모두 실행하는 워커
+    executor: KeygenExecutor,
+    connections: AtomicUsize,
+    on_event: Box<dyn Fn(PoolEvent) + Send + Sync>,
+}
+
+/// 처리 중인 연결 하나. 버려질 때(스레드를 만들지 못한 경우 포함) 연결 수를 되돌립니다.
+struct ConnectionSlot(Arc<Shared>);
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.connections.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 키 풀 서버를 실행합니다. 소켓을 열지 못하면 오류를 반환하고, 연 뒤에는 돌아오지 않습니다.
+///
+/// 디스크에 남아 있던 항목을 먼저 풀에 올린 뒤, `workers`개의 대용량 스택 워커
+/// ([`KeygenExecutor`])로 배리언트마다 `size`개가 될 때까지 보충합니다. 연결마다 스레드를 두어
+/// 요청을 처리하며, 풀이 빈 배리언트는 같은 워커에서 요청 시 생성해 돌려줍니다. 서버와 다른
+/// 사용자의 연결은 요청을 읽기 전에 끊습니다.
+///
+/// 처리 중인 연결이 [`MAX_CONNECTIONS`]개면 스레드를 만들거나 요청을 읽지 않고 [`ERR_BUSY`]로
+/// 응답하며, 요청 한 줄이 4 KiB를 넘거나 30초 동안 요청이 없으면 연결을 끊습니다.
+pub fn serve(
+    store: PoolStore,
+    socket: &Path,
+    targets: Vec<PoolTarget>,
+    workers: usize,
+    on_event: impl Fn(PoolEvent) + Send + Sync + 'static,
+) -> Result<(), PoolError> {
+    let mut queues = HashMap::new();
+    for target in &targets {
+        let entries = store.entries(&target.variant)?;
+        queues.insert(target.variant.clone(), VecDeque::from(entries));
+    }
+
+    // umask를 바꾸므로 워커 스레드를 만들기 전에 소켓을 엶
+    let (listener, _lock) = bind(socket)?;
+    let shared = Shared::new(store, targets, queues, workers, Box::new(on_event))?;
+
+    let refiller = Arc::clone(&shared);
+    thread::Builder::new()
+        .name("keypairer-pool-refill".to_string())
+        .spawn(move || refiller.refill_loop())?;
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => shared.accept(stream),
+            Err(e) => shared.emit(PoolEvent::Failed(e.into())),
+        }
+    }
+    Ok(())
+}
+
+impl Shared {
+    /// 배리언트들의 스택 예산으로 워커를 만들어 공유 상태를 준비합니다.
+    fn new(
+        store: PoolStore,
+        targets: Vec<PoolTarget>,
+        queues: HashMap<Variant, VecDeque<PathBuf>>,
+        workers: usize,
+        on_event: Box<dyn Fn(PoolEvent) + Send + Sync>,
+    ) -> Result<Arc<Shared>, PoolError> {
+        let executor = KeygenExecutor::with_options(ExecutorOptions {
+            workers,
+            stack_size: Some(stack::stack_size_for_all(
+                targets.iter().map(|t| &t.variant),
+            )),
+            queue_capacity: None,
+            thread_name: "keypairer-pool".to_string(),
+        })?;
+        Ok(Arc::new(Shared {
+            store,
+            targets,
+            queues: Mutex::new(queues),
+            refill: Condvar::new(),
+            executor,
+            connections: AtomicUsize::new(0),
+            on_event,
+        }))
+    }
+
+    /// 연결을 처리할 스레드를 만듭니다. 처리 중인 연결이 [`MAX_CONNECTIONS`]개면 거절합니다.
+    fn accept(self: &Arc<Self>, stream: UnixStream) {
+        let active = self.connections.fetch_add(1, Ordering::SeqCst);
+        let slot = ConnectionSlot(Arc::clone(self));
+        if active >= MAX_CONNECTIONS {
+            drop(slot);
+            return self.reject(stream);
+        }
+        if let Err(e) = thread::Builder::new()
+            .name("keypairer-pool-client".to_string())
+            .spawn(move || slot.0.handle_client(stream))
+        {
+            self.emit(PoolEvent::Failed(e.into()));
+        }
+    }
+
+    /// 연결 수를 넘은 연결에 요청을 읽지 않고 [`ERR_BUSY`]로 응답합니다.
+    fn reject(&self, mut stream: UnixStream) {
+        let _ = stream.set_write_timeout(Some(REJECT_TIMEOUT));
+        let response = error_response(ERR_BUSY, "too many connections".to_string());
+        let _ = send(&mut stream, &response);
+        let _ = stream.shutdown(Shutdown::Write);
+        discard_request(&mut stream);
+        self.emit(PoolEvent::Failed(PoolError::Busy));
+    }
+
+    fn emit(&self, event: PoolEvent) {
+        (self.on_event)(event);
+    }
+
+    fn queues(&self) -> MutexGuard<'_, HashMap<Variant, VecDeque<PathBuf>>> {
+        // 잠금을 쥔 스레드가 패닉해도 대기열 자체는 일관된 상태
+        self.queues.lock().unwrap_or_else(|e| e.into_inner())
+    }
+
+    /// 모자란 키를 워커 수만큼씩 배리언트를 번갈아 가며 만들어 넣습니다.
+    fn refill_loop(&self) {
+        let executor = &self.executor;
+        loop {
+            let round = {
+                let mut queues = self.queues();
+                loop {
+                    let round = self.plan_round(&queues, executor.workers());
+                    if !round.is_empty() {
+                        break round;
+                    }
+                    queues = self.refill.wait(queues).unwrap_or_else(|e| e.into_inner());
+                }
+            };
+
+            let handles: Vec<_> = round
+                .into_iter()
+                .map(|variant| {
+                    let handle = executor.generate(variant.algorithm(), variant.clone());
+                    (variant, handle)
+                })
+                .collect();
+            let mut added = 0usize;
+            for (variant, handle) in handles {
+                let result = handle.and_then(|h| h.join());
+                match self.store_generated(&variant, result) {
+                    Ok(available) => {
+                        added += 1;
+                        self.emit(PoolEvent::Refilled { variant, available });
+                    }
+                    Err(e) => self.emit(PoolEvent::Failed(e)),
+                }
+            }
+            if added == 0 {
+                thread::sleep(RETRY_DELAY);
+            }
+        }
+    }
+
+    /// 이번 차례에 만들 배리언트 목록 (최대 `limit`개)
+    fn plan_round(
+        &self,
+        queues: &HashMap<Variant, VecDeque<PathBuf>>,
+        limit: usize,
+    ) -> Vec<Variant> {
+        let mut missing: Vec<(Variant, usize)> = self
+            .targets
+            .iter()
+            .map(|t| {
+                let have = queues.get(&t.variant).map_or(0, VecDeque::len);
+                (t.variant.clone(), t.size.saturating_sub(have))
+            })
+            .filter(|(_, n)| *n > 0)
+            .collect();
+        let mut round = Vec::new();
+        while round.len() < limit.max(1) && !missing.is_empty() {
+            for (variant, n) in &mut missing {
+                if round.len() < limit.max(1) {
+                    round.push(variant.clone());
+                    *n -= 1;
+                }
+            }
+            missing.retain(|(_, n)| *n > 0);
+        }
+        round
+    }
+
+    /// 생성한 키를 봉인해 대기열에 넣고 남은 개수를 반환합니다.
+    fn store_generated(
+        &self,
+        variant: &Variant,
+        result: Result<KeyGenResult, ExecutorError>,
+    ) -> Result<usize, PoolError> {
+        let (pk, sk) = result?.map_err(|e| PoolError::Generate(e.to_string()))?;
+        let path = self.store.put(variant, &pk, &sk)?;
+        let mut queues = self.queues();
+        let queue = queues.entry(variant.clone()).or_default();
+        queue.push_back(path);
+        Ok(queue.len())
+    }
+
+    /// 연결이 닫힐 때까지 한 줄씩 요청을 읽어 응답합니다.
+    fn handle_client(&self, stream: UnixStream) {
+        // SAFETY: geteuid는 항상 성공함
+        let own = unsafe { libc::geteuid() };
+        match peer_uid(&stream) {
+            Ok(uid) if uid == own => {}
+            Ok(uid) => return self.emit(PoolEvent::Failed(PoolError::Forbidden(uid))),
+            Err(e) => return self.emit(PoolEvent::Failed(e.into())),
+        }
+        let _ = stream.set_read_timeout(Some(IO_TIMEOUT));
+        let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+        let mut reader = match stream.try_clone() {
+            Ok(s) => BufReader::new(s),
+            Err(e) => return self.emit(PoolEvent::Failed(e.into())),
+        };
+        let mut writer = stream;
+        loop {
+            let mut line = String::new();
+            match reader.by_ref().take(MAX_REQUEST_LINE).read_line(&mut line) {
+                Ok(0) | Err(_) => return,
+                Ok(n) if n as u64 == MAX_REQUEST_LINE && !line.ends_with('\n') => {
+                    // 줄의 나머지를 읽지 않았으므로 다음 요청의 시작을 알 수 없어 응답 뒤 끊음
+                    let response =
+                        error_response(ERR_BAD_REQUEST, "request line too long".to_string());
+                    let _ = send(&mut writer, &response);
+                    return;
+                }
+                Ok(_) => {}
+            }
+            if line.trim().is_empty() {
+                continue;
+            }
+            let response = match serde_json::from_str::<PoolRequest>(&line) {
+                Ok(PoolRequest::Take { algorithm, variant }) => {
+                    self.take(&algorithm, variant.as_deref())
+                }
+                Ok(PoolRequest::Status) => PoolResponse {
+                    ok: true,
+                    status: Some(self.status()),
+                    ..PoolResponse::default()
+                },
+                Err(e) => error_response(ERR_BAD_REQUEST, e.to_string()),
+            };
+            if send(&mut writer, &response).is_err() {
+                return;
+            }
+        }
+    }
+
+    fn take(&self, algorithm: &str, variant: Option<&str>) -> PoolResponse {
+        let Some(variant) = resolve(algorithm, variant) else {
+            return error_response(
+                ERR_BAD_REQUEST,
+                format!("unknown algorithm or variant: {}", algorithm),
+            );
+        };
+        if !self.targets.iter().any(|t| t.variant == variant) {
+            return error_response(
+                ERR_NOT_CONFIGURED,
+                format!("{} is not pooled", variant.standard_name()),
+            );
+        }
+
+        loop {
+            let (next, remaining) = {
+                let mut queues = self.queues();
+                let queue = queues.entry(variant.clone()).or_default();
+                let next = queue.pop_front();
+                (next, queue.len())
+            };
+            let Some(path) = next else { break };
+            self.refill.notify_one();
+            match self.store.take(&path) {
+                Ok(key) => {
+                    self.emit(PoolEvent::Served {
+                        variant,
+                        pooled: true,
+                        remaining,
+                    });
+                    return key_response(key, true, remaining);
+                }
+                // 손상된 항목은 건너뛰고 다음 항목을 시도
+                Err(e) => self.emit(PoolEvent::Failed(e)),
+            }
+        }
+
+        match self.generate_now(&variant) {
+            Ok((pk, sk)) => {
+                self.emit(PoolEvent::Served {
+                    variant: variant.clone(),
+                    pooled: false,
+                    remaining: 0,
+                });
+                let key = PooledKey {
+                    algorithm: variant.algorithm().as_str().to_string(),
+                    variant: variant.as_str().to_string(),
+                    fingerprint: fingerprint(&pk),
+                    created: now_unix(),
+                    public_key: pk,
+                    secret_key: Zeroizing::new(sk.to_vec()),
+                };
+                key_response(key, false, 0)
+            }
+            Err(e) => {
+                let message = e.to_string();
+                self.emit(PoolEvent::Failed(e));
+                error_response(ERR_INTERNAL, message)
+            }
+        }
+    }
+
+    fn status(&self) -> Vec<PoolStatus> {
+        let queues = self.queues();
+        self.targets
+            .iter()
+            .map(|t| PoolStatus {
+                algorithm: t.variant.algorithm().as_str().to_string(),
+                variant: t.variant.as_str().to_string(),
+                standard_name: t.variant.standard_name(),
+                available: queues.get(&t.variant).map_or(0, VecDeque::len),
+                target: t.size,
+            })
+            .collect()
+    }
+
+    /// 풀이 빈 배리언트의 키를 보충과 같은 워커에서 생성합니다. 대기열은 보충 한 차례(워커 수)
+    /// 뒤에 이어지므로, 동시에 생성되는 키는 연결 수가 아니라 워커 수로 제한됩니다.
+    fn generate_now(
+        &self,
+        variant: &Variant,
+    ) -> Result<(Vec<u8>, super::secret::SecretBuffer), PoolError> {
+        self.executor
+            .generate(variant.algorithm(), variant.clone())?
+            .join()?
+            .map_err(|e| PoolError::Generate(e.to_string()))
+    }
+}
+
+/// 거절한 연결의 요청을 [`REJECT_TIMEOUT`] 동안 한 줄 크기까지 읽어 버립니다.
+///
+/// 읽지 않은 요청을 남긴 채 소켓을 닫으면 클라이언트가 응답 대신 연결 재설정을 받습니다.
+fn discard_request(stream: &mut UnixStream) {
+    let deadline = Instant::now() + REJECT_TIMEOUT;
+    let mut buf = [0u8; 512];
+    let mut total = 0u64;
+    while total < MAX_REQUEST_LINE {
+        let Some(left) = deadline.checked_duration_since(Instant::now()) else {
+            return;
+        };
+        if left.is_zero() || stream.set_read_timeout(Some(left)).is_err() {
+            return;
+        }
+        match stream.read(&mut buf) {
+            Ok(0) | Err(_) => return,
+            Ok(n) => total += n as u64,
+        }
+    }
+}
+
+/// 응답을 한 줄로 보냅니다.
+fn send(writer: &mut UnixStream, response: &PoolResponse) -> io::Result<()> {
+    // 응답에 비밀키가 들어 있으므로 직렬화 버퍼는 키울 때와 쓴 뒤 0으로 덮어씀
+    let mut out = ZeroizingWriter::with_capacity(4096);
+    serde_json::to_writer(&mut out, response).map_err(io::Error::other)?;
+    out.write_all(b"\n")?;
+    writer.write_all(&out.into_inner())?;
+    writer.flush()
+}
+
+fn key_response(key: PooledKey, pooled: bool, remaining: usize) -> PoolResponse {
+    PoolResponse {
+        ok: true,
+        key: Some(key),
+        pooled: Some(pooled),
+        remaining: Some(remaining),
+        ..PoolResponse::default()
+    }
+}
+
+fn error_response(code: &str, message: String) -> PoolResponse {
+    PoolResponse {
+        ok: false,
+        error: Some(code.to_string()),
+        message: Some(message),
+        ..PoolResponse::default()
+    }
+}
+
+/// 서버에 요청 하나를 보내고 응답을 받습니다. 서버가 오류를 돌려주면 [`PoolError::Server`]입니다.
+pub fn request(socket: &Path, request: &PoolRequest) -> Result<PoolResponse, PoolError> {
+    let mut stream = UnixStream::connect(socket).map_err(|e| match e.kind() {
+        io::ErrorKind::NotFound | io::ErrorKind::ConnectionRefused => {
+            PoolError::NotRunning(socket.to_path_buf())
+        }
+        _ => PoolError::Io(e),
+    })?;
+    let mut line = serde_json::to_vec(request).map_err(|e| io::Error::other(e.to_string()))?;
+    line.push(b'\n');
+    stream.write_all(&line)?;
+    // 요청을 다 보냈음을 알리면 서버가 응답 뒤 연결을 닫음
+    stream.shutdown(Shutdown::Write)?;
+
+    let data = read_all_zeroizing(&mut stream, 0)?;
+    let response: PoolResponse =
+        serde_json::from_slice(&data).map_err(|e| PoolError::Protocol(e.to_string()))?;
+    if !response.ok {
+        if response.error.as_deref() == Some(ERR_BUSY) {
+            return Err(PoolError::Busy);
+        }
+        return Err(PoolError::Server {
+            code: response.error.unwrap_or_default(),
+            message: response.message.unwrap_or_default(),
+        });
+    }
+    Ok(response)
+}
+
+/// 키 페어 하나를 꺼냅니다. (키, 풀에 있던 키인지, 남은 개수)를 반환합니다.
+pub fn take(socket: &Path, variant: &Variant) -> Result<(PooledKey, bool, usize), PoolError> {
+    let response = request(
+        socket,
+        &PoolRequest::Take {
+            algorithm: variant.algorithm().as_str().to_string(),
+            variant: Some(variant.as_str().to_string()),
+        },
+    )?;
+    let key = response
+        .key
+        .ok_or_else(|| PoolError::Protocol("missing key".to_string()))?;
+    Ok((
+        key,
+        response.pooled.unwrap_or(false),
+        response.remaining.unwrap_or(0),
+    ))
+}
+
+/// 배리언트별 풀 상태를 조회합니다.
+pub fn status(socket: &Path) -> Result<Vec<PoolStatus>, PoolError> {
+    request(socket, &PoolRequest::Status)?
+        .status
+        .ok_or_else(|| PoolError::Protocol("missing status".to_string()))
+}
+
+impl From<io::Error> for PoolError {
+    fn from(e: io::Error) -> Self {
+        PoolError::Io(e)
+    }
+}
+
+impl From<ExecutorError> for PoolError {
+    fn from(e: ExecutorError) -> Self {
+        PoolError::Executor(e)
+    }
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolError::Spec(item) => write!(f, "invalid pool size: {}", item),
+            PoolError::Io(e) => write!(f, "{}", e),
+            PoolError::Corrupt(path) => write!(f, "corrupt pool file: {}", path.display()),
+            PoolError::KeyInsidePool(path) => write!(
+                f,
+                "pool key file must be outside the pool directory: {}",
+                path.display()
+            ),
+            PoolError::Forbidden(uid) => write!(f, "rejected connection from uid {}", uid),
+            PoolError::Busy => write!(
+                f,
+                "pool server is busy ({} connections in progress)",
+                MAX_CONNECTIONS
+            ),
+            PoolError::AlreadyRunning(path) => {
+                write!(f, "a pool server is already running on {}", path.display())
+            }
+            PoolError::NotRunning(path) => {
+                write!(f, "no pool server is running on {}", path.display())
+            }
+            PoolError::Generate(e) => write!(f, "key generation failed: {}", e),
+            PoolError::Executor(e) => write!(f, "{}", e),
+            PoolError::Protocol(e) => write!(f, "invalid pool response: {}", e),
+            PoolError::Server { code, message } => write!(f, "{}: {}", code, message),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {}
+
+impl PoolError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            PoolError::Spec(item) => text(
+                "error.pool.spec",
+                "잘못된 풀 크기 지정: {value} (예: mlkem:768=4,mceliece:6688128=2)",
+            )
+            .replace("{value}", item),
+            PoolError::Io(e) => {
+                text("error.pool.io", "키 풀 입출력 오류: {err}").replace("{err}", &e.to_string())
+            }
+            PoolError::Corrupt(path) => text(
+                "error.pool.corrupt",
+                "키 풀 파일이 손상되었거나 풀 키와 맞지 않습니다: {path}",
+            )
+            .replace("{path}", &path.display().to_string()),
+            PoolError::KeyInsidePool(path) => text(
+                "error.pool.key_inside",
+                "풀 키 파일은 풀 디렉토리 밖에 두어야 합니다: {path}",
+            )
+            .replace("{path}", &path.display().to_string()),
+            PoolError::Forbidden(uid) => text(
+                "error.pool.forbidden",
+                "다른 사용자(uid {uid})의 연결을 거부했습니다",
+            )
+            .replace("{uid}", &uid.to_string()),
+            PoolError::Busy => text(
+                "error.pool.busy",
+                "키 풀 서버가 처리 중인 연결이 한도({max}개)에 도달했습니다. 잠시 뒤 다시 시도하세요",
+            )
+            .replace("{max}", &MAX_CONNECTIONS.to_string()),
+            PoolError::AlreadyRunning(path) => text(
+                "error.pool.running",
+                "키 풀 서버가 이미 실행 중입니다: {path}",
+            )
+            .replace("{path}", &path.display().to_string()),
+            PoolError::NotRunning(path) => text(
+                "error.pool.not_running",
+                "키 풀 서버에 연결할 수 없습니다: {path} ('pool serve'로 시작하세요)",
+            )
+            .replace("{path}", &path.display().to_string()),
+            PoolError::Generate(e) => {
+                text("error.pool.generate", "키 생성 실패: {err}").replace("{err}", e)
+            }
+            PoolError::Executor(e) => e.message(lang),
+            PoolError::Protocol(e) => text(
+                "error.pool.protocol",
+                "키 풀 서버의 응답을 해석할 수 없습니다: {err}",
+            )
+            .replace("{err}", e),
+            PoolError::Server { code, message } if code == ERR_NOT_CONFIGURED => text(
+                "error.pool.not_configured",
+                "키 풀 서버가 이 배리언트를 다루지 않습니다: {err}",
+            )
+            .replace("{err}", message),
+            PoolError::Server { message, .. } => {
+                text("error.pool.server", "키 풀 서버 오류: {err}").replace("{err}", message)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::os::unix::fs::PermissionsExt;
+
+    use super::*;
+    use crate::internals::test_util::ScratchDir;
+
+    /// 요청 시 생성만 하는 ML-KEM-512 풀의 공유 상태. 알림은 `events`에 모읍니다.
+    fn shared(dir: &Path, events: &Arc<Mutex<Vec<PoolEvent>>>) -> Arc<Shared> {
+        let store = PoolStore::open(&dir.join("pool"), &dir.join("pool.key")).unwrap();
+        let targets = vec![PoolTarget {
+            variant: Variant::MLKEM512,
+            size: 0,
+        }];
+        let events = Arc::clone(events);
+        Shared::new(
+            store,
+            targets,
+            HashMap::new(),
+            1,
+            Box::new(move |event| events.lock().unwrap().push(event)),
+        )
+        .unwrap()
+    }
+
+    fn read_response(reader: &mut BufReader<UnixStream>) -> PoolResponse {
+        let mut line = String::new();
+        reader.read_line(&mut line).unwrap();
+        serde_json::from_str(&line).unwrap()
+    }
+
+    #[test]
+    fn key_file_inside_the_pool_directory_is_refused() {
+        let root = ScratchDir::new("pool", "inside");
+        let pool = root.join("pool");
+        fs::create_dir_all(pool.join("sub")).unwrap();
+        std::os::unix::fs::symlink(&pool, root.join("link")).unwrap();
+
+        for key in [
+            pool.join("pool.key"),
+            pool.join("sub/new/pool.key"),
+            root.join("link/pool.key"),
+        ] {
+            assert!(
+                matches!(
+                    PoolStore::open(&pool, &key),
+                    Err(PoolError::KeyInsidePool(_))
+                ),
+                "{}",
+                key.display()
+            );
+            assert!(!key.exists());
+        }
+    }
+
+    #[test]
+    fn sealed_entries_round_trip_only_with_the_same_key() {
+        let root = ScratchDir::new("pool", "seal");
+        let pool = root.join("pool");
+        let variant = Variant::MLKEM512;
+        let (pk, sk) = (vec![1u8; 800], vec![2u8; 1632]);
+
+        let store = PoolStore::open(&pool, &root.join("pool.key")).unwrap();
+        let first = store.put(&variant, &pk, &sk).unwrap();
+        // 항목 파일 이름은 생성 시각과 지문이므로 공개키를 달리 둠
+        let second = store.put(&variant, &[3u8; 800], &sk).unwrap();
+        assert_eq!(store.entries(&variant).unwrap().len(), 2);
+
+        let key = store.take(&first).unwrap();
+        assert_eq!(
+            (key.public_key, key.secret_key.as_slice()),
+            (pk, sk.as_slice())
+        );
+        assert!(!first.exists());
+
+        let other = PoolStore::open(&pool, &root.join("other.key")).unwrap();
+        assert!(matches!(other.take(&second), Err(PoolError::Corrupt(_))));
+        assert!(second.exists());
+    }
+
+    #[test]
+    fn a_second_server_cannot_take_over_the_socket() {
+        let root = ScratchDir::new("pool", "socket");
+        let socket = root.join("run/pool.sock");
+
+        let (listener, lock) = bind(&socket).unwrap();
+        let mode = fs::metadata(&socket).unwrap().permissions().mode();
+        assert_eq!(mode & 0o777, 0o600);
+        assert!(matches!(bind(&socket), Err(PoolError::AlreadyRunning(_))));
+        drop((listener, lock));
+
+        // 잠금이 풀리면 남은 소켓 파일을 지우고 다시 열 수 있음
+        assert!(socket.exists());
+        bind(&socket).unwrap();
+    }
+
+    #[test]
+    fn empty_pool_generates_on_the_executor() {
+        let root = ScratchDir::new("pool", "ondemand");
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let shared = shared(&root, &events);
+
+        let response = shared.take("mlkem", Some("512"));
+        assert!(response.ok);
+        assert_eq!(response.pooled, Some(false));
+        let key = response.key.unwrap();
+        assert_eq!((key.public_key.len(), key.secret_key.len()), (800, 1632));
+        assert_eq!(key.fingerprint, fingerprint(&key.public_key));
+
+        let response = shared.take("mlkem", Some("768"));
+        assert_eq!(response.error.as_deref(), Some(ERR_NOT_CONFIGURED));
+        assert!(matches!(
+            events.lock().unwrap().as_slice(),
+            [PoolEvent::Served { pooled: false, .. }]
+        ));
+    }
+
+    #[test]
+    fn oversized_request_lines_are_refused_and_the_connection_closed() {
+        let root = ScratchDir::new("pool", "line");
+        let shared = shared(&root, &Arc::new(Mutex::new(Vec::new())));
+        let (mut client, server) = UnixStream::pair().unwrap();
+        let worker = thread::spawn(move || shared.handle_client(server));
+        let mut reader = BufReader::new(client.try_clone().unwrap());
+
+        client.write_all(b"{\"op\":\"status\"}\n").unwrap();
+        let response = read_response(&mut reader);
+        assert!(response.ok);
+        assert_eq!(response.status.unwrap()[0].target, 0);
+
+        // 한도를 채운 뒤에도 줄바꿈이 없음
+        let line = vec![b' '; MAX_REQUEST_LINE as usize + 16];
+        client.write_all(&line).unwrap();
+        let response = read_response(&mut reader);
+        assert_eq!(response.error.as_deref(), Some(ERR_BAD_REQUEST));
+        assert_eq!(response.message.as_deref(), Some("request line too long"));
+        worker.join().unwrap();
+        assert_eq!(reader.read(&mut [0u8; 1]).unwrap(), 0);
+    }
+
+    #[test]
+    fn connections_over_the_limit_are_told_the_server_is_busy() {
+        let root = ScratchDir::new("pool", "busy");
+        let socket = root.join("pool.sock");
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let shared = shared(&root, &events);
+        let (listener, _lock) = bind(&socket).unwrap();
+
+        shared.connections.store(MAX_CONNECTIONS, Ordering::SeqCst);
+        let client = {
+            let socket = socket.clone();
+            thread::spawn(move || status(&socket))
+        };
+        shared.accept(listener.accept().unwrap().0);
+        assert!(matches!(client.join().unwrap(), Err(PoolError::Busy)));
+        assert_eq!(shared.connections.load(Ordering::SeqCst), MAX_CONNECTIONS);
+        assert!(matches!(
+            events.lock().unwrap().as_slice(),
+            [PoolEvent::Failed(PoolError::Busy)]
+        ));
+
+        // 자리가 나면 요청을 처리하고, 연결이 끝나면 자리를 돌려줌
+        shared
+            .connections
+            .store(MAX_CONNECTIONS - 1, Ordering::SeqCst);
+        let client = {
+            let socket = socket.clone();
+            thread::spawn(move || status(&socket))
+        };
+        shared.accept(listener.accept().unwrap().0);
+        assert_eq!(client.join().unwrap().unwrap().len(), 1);
+        while shared.connections.load(Ordering::SeqCst) != MAX_CONNECTIONS - 1 {
+            thread::yield_now();
+        }
+    }
+}
@@ -0,0 +1,465 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::ptr::NonNull;
+
+use zeroize::{Zeroize, ZeroizeOnDrop};
+
+/// 비밀키 등 민감한 바이트를 담는 고정 크기 버퍼
+///
+/// 유닉스에서는 전용 익명 매핑에 할당하여 앞뒤에 접근 불가(`PROT_NONE`) 가드 페이지를 두고,
+/// 데이터 페이지를 `mlock`으로 고정해 스왑되지 않게 하며, 리눅스에서는 코어 덤프에서도
+/// 제외합니다(`MADV_DONTDUMP`). 데이터는 뒤쪽 가드 페이지에 붙여 두므로 넘치는 쓰기는 즉시
+/// 실패합니다. 크기가 고정이라 재할당으로 인한 사본이 생기지 않으며, 해제할 때 0으로 덮어씁니다.
+///
+/// 매핑이나 `mlock`이 실패하면(예: `RLIMIT_MEMLOCK` 초과) 일반 힙 또는 잠기지 않은 매핑으로
+/// 계속 동작하며, [`SecretBuffer::is_locked`]로 확인할 수 있습니다.
+pub struct SecretBuffer {
+    data: NonNull<u8>,
+    len: usize,
+    storage: Storage,
+}
+
+enum Storage {
+    /// 가드 페이지가 있는 익명 매핑 (시작 주소, 페이지 크기, 전체 길이, 잠금 여부)
+    #[cfg(unix)]
+    Mapped {
+        base: NonNull<u8>,
+        page: usize,
+        total: usize,
+        locked: bool,
+    },
+    /// 매핑을 쓸 수 없을 때의 일반 힙 할당
+    Heap(Box<[u8]>),
+}
+
+// 버퍼를 단독으로 소유하므로 스레드 간 이동·공유가 안전합니다 (대용량 스택 스레드에서 반환).
+unsafe impl Send for SecretBuffer {}
+unsafe impl Sync for SecretBuffer {}
+
+impl SecretBuffer {
+    /// 0으로 채운 `len`바이트 버퍼를 할당합니다.
+    pub fn zeroed(len: usize) -> SecretBuffer {
+        #[cfg(unix)]
+        if let Some(buffer) = Self::mapped(len) {
+            return buffer;
+        }
+        Self::heap(len)
+    }
+
+    /// 매핑을 쓸 수 없을 때의 일반 힙 버퍼
+    fn heap(len: usize) -> SecretBuffer {
+        let mut heap = vec![0u8; len].into_boxed_slice();
+        SecretBuffer {
+            data: NonNull::new(heap.as_mut_ptr()).unwrap_or(NonNull::dangling()),
+            len,
+            storage: Storage::Heap(heap),
+        }
+    }
+
+    /// 바이트를 보호된 버퍼로 복사합니다. 원본은 호출자가 지워야 합니다.
+    pub fn from_slice(bytes: &[u8]) -> SecretBuffer {
+        let mut buffer = Self::zeroed(bytes.len());
+        buffer.copy_from_slice(bytes);
+        buffer
+    }
+
+    /// 데이터 페이지가 메모리에 잠겨 스왑되지 않는지 여부
+    pub fn is_locked(&self) -> bool {
+        match &self.storage {
+            #[cfg(unix)]
+            Storage::Mapped { locked, .. } => *locked,
+            Storage::Heap(_) => false,
+        }
+    }
+
+    #[cfg(unix)]
+    fn mapped(len: usize) -> Option<SecretBuffer> {
+        let page = match unsafe { libc::sysconf(libc::_SC_PAGESIZE) } {
+            size if size > 0 => size as usize,
+            _ => return None,
+        };
+        let data_len = len.max(1).div_ceil(page) * page;
+        let total = data_len.checked_add(2 * page)?;
+
+        // SAFETY: 새 익명 매핑을 만들고, 그 범위 안에서만 보호 속성을 바꿉니다.
+        unsafe {
+            let base = libc::mmap(
+                std::ptr::null_mut(),
+                total,
+                libc::PROT_READ | libc::PROT_WRITE,
+                libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+                -1,
+                0,
+            );
+            if base == libc::MAP_FAILED {
+                return None;
+            }
+            let base = base as *mut u8;
+            let data_pages = base.add(page);
+            if libc::mprotect(base as *mut libc::c_void, page, libc::PROT_NONE) != 0
+                || libc::mprotect(
+                    data_pages.add(data_len) as *mut libc::c_void,
+                    page,
+                    libc::PROT_NONE,
+                ) != 0
+            {
+                libc::munmap(base as *mut libc::c_void, total);
+                return None;
+            }
+            let locked = libc::mlock(data_pages as *const libc::c_void, data_len) == 0;
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            libc::madvise(
+                data_pages as *mut libc::c_void,
+                data_len,
+                libc::MADV_DONTDUMP,
+            );
+
+            Some(SecretBuffer {
+                data: NonNull::new(data_pages.add(data_len - len))?,
+                len,
+                storage: Storage::Mapped {
+                    base: NonNull::new(base)?,
+                    page,
+                    total,
+                    locked,
+                },
+            })
+        }
+    }
+}
+
+impl Deref for SecretBuffer {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        // SAFETY: `data`는 `len`바이트를 가리키며 버퍼가 살아 있는 동안 유효합니다.
+        unsafe { std::slice::from_raw_parts(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl DerefMut for SecretBuffer {
+    fn deref_mut(&mut self) -> &mut [u8] {
+        // SAFETY: `deref`와 같고, `&mut self`로 배타적 접근이 보장됩니다.
+        unsafe { std::slice::from_raw_parts_mut(self.data.as_ptr(), self.len) }
+    }
+}
+
+impl AsRef<[u8]> for SecretBuffer {
+    fn as_ref(&self) -> &[u8] {
+        self
+    }
+}
+
+impl Clone for SecretBuffer {
+    fn clone(&self) -> SecretBuffer {
+        SecretBuffer::from_slice(self)
+    }
+}
+
+impl fmt::Debug for SecretBuffer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "SecretBuffer([REDACTED; {}])", self.len)
+    }
+}
+
+impl ZeroizeOnDrop for SecretBuffer {}
+
+impl Drop for SecretBuffer {
+    fn drop(&mut self) {
+        self.deref_mut().zeroize();
+        match &mut self.storage {
+            #[cfg(unix)]
+            Storage::Mapped {
+                base,
+                page,
+                total,
+                locked,
+            } => {
+                // SAFETY: `mapped`에서 만든 매핑 전체를 한 번만 해제합니다.
+                unsafe {
+                    if *locked {
+                        libc::munlock(
+                            base.as_ptr().add(*page) as *const libc::c_void,
+                            *total - 2 * *page,
+                        );
+                    }
+                    libc::munmap(base.as_ptr() as *mut libc::c_void, *total);
+                }
+            }
+            Storage::Heap(heap) => heap.zeroize(),
+        }
+    }
+}
+
+/// 살아 있는 동안 프로세스의 코어 덤프를 막는 가드
+///
+/// `RLIMIT_CORE`의 soft 한도를 0으로 낮추고, 리눅스에서는 `PR_SET_DUMPABLE`도 끕니다
+/// (ptrace 부착과 `/proc/<pid>/mem` 읽기도 제한됨). 해제할 때 이전 설정으로 되돌립니다.
+#[must_use = "가드를 버리면 코어 덤프가 곧바로 다시 허용됩니다"]
+pub struct CoreDumpGuard {
+    #[cfg(unix)]
+    core_limit: Option<libc::rlimit>,
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    dumpable: Option<libc::c_int>,
+}
+
+/// 키 생성과 저장이 끝날 때까지 코어 덤프를 막습니다. 실패해도 오류 없이 계속합니다.
+pub fn disable_core_dumps() -> CoreDumpGuard {
+    #[cfg(unix)]
+    // SAFETY: 현재 프로세스의 한도만 조회·변경합니다.
+    let core_limit = unsafe {
+        let mut previous = std::mem::zeroed::<libc::rlimit>();
+        if libc::getrlimit(libc::RLIMIT_CORE, &mut previous) == 0 {
+            let disabled = libc::rlimit {
+                rlim_cur: 0,
+                rlim_max: previous.rlim_max,
+            };
+            (libc::setrlimit(libc::RLIMIT_CORE, &disabled) == 0).then_some(previous)
+        } else {
+            None
+        }
+    };
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    // SAFETY: 인자가 정수뿐인 prctl 호출입니다.
+    let dumpable = unsafe {
+        match libc::prctl(libc::PR_GET_DUMPABLE) {
+            previous if previous >= 0 => {
+                (libc::prctl(libc::PR_SET_DUMPABLE, 0) == 0).then_some(previous)
+            }
+            _ => None,
+        }
+    };
+
+    CoreDumpGuard {
+        #[cfg(unix)]
+        core_limit,
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        dumpable,
+    }
+}
+
+impl Drop for CoreDumpGuard {
+    fn drop(&mut self) {
+        // SAFETY: `disable_core_dumps`에서 읽어 둔 값으로만 되돌립니다.
+        #[cfg(unix)]
+        unsafe {
+            #[cfg(any(target_os = "linux", target_os = "android"))]
+            if let Some(previous) = self.dumpable {
+                libc::prctl(libc::PR_SET_DUMPABLE, previous as libc::c_ulong);
+            }
+            #[cfg(unix)]
+            if let Some(previous) = &self.core_limit {
+                libc::setrlimit(libc::RLIMIT_CORE, previous);
+            }
+        }
+    }
+}
+
+/// 제자리에서 0으로 덮어써도 되는 pqcrypto 키 구조체
+///
+/// pqcrypto의 `SecretKey`, `SharedSecret`은 `Zeroize`를 구현하지 않으므로 [`wipe_key`]로 지웁니다.
+///
+/// # Safety
+/// 구현 타입은 바이트 배열처럼 모든 비트 패턴이 유효하고 힙 포인터를 갖지 않아야 합니다.
+pub unsafe trait PlainKey {}
+
+macro_rules! plain_keys {
+    (kem: $($kem:ident),*; sign: $($sig:ident),* $(,)?) => {
+        $(
+            // SAFETY: pqcrypto 키 구조체는 고정 길이 바이트 배열 하나만 담습니다.
+            unsafe impl PlainKey for pqcrypto::kem::$kem::SecretKey {}
+            // SAFETY: 위와 같음
+            unsafe impl PlainKey for pqcrypto::kem::$kem::SharedSecret {}
+        )*
+        $(
+            // SAFETY: 위와 같음
+            unsafe impl PlainKey for pqcrypto::sign::$sig::SecretKey {}
+        )*
+    };
+}
+
+plain_keys! {
+    kem: hqc128, hqc192, hqc256, mceliece348864, mceliece348864f, mceliece460896,
+        mceliece460896f, mceliece6688128, mceliece6688128f, mceliece6960119, mceliece6960119f,
+        mceliece8192128, mceliece8192128f, mlkem512, mlkem768, mlkem1024;
+    sign: falcon512, falcon1024, falconpadded512, falconpadded1024, mldsa44, mldsa65, mldsa87,
+        sphincssha2128fsimple, sphincssha2128ssimple, sphincssha2192fsimple,
+        sphincssha2192ssimple, sphincssha2256fsimple, sphincssha2256ssimple,
+        sphincsshake128fsimple, sphincsshake128ssimple, sphincsshake192fsimple,
+        sphincsshake192ssimple, sphincsshake256fsimple, sphincsshake256ssimple,
+}
+
+/// pqcrypto 키 구조체를 버리기 전에 제자리에서 0으로 덮어씁니다.
+pub fn wipe_key<T: PlainKey>(key: &mut T) {
+    // SAFETY: `PlainKey` 구현 타입은 모든 바이트를 0으로 써도 안전합니다.
+    unsafe { wipe_in_place(key) }
+}
+
+/// 값의 메모리를 제자리에서 0으로 덮어씁니다.
+///
+/// # Safety
+/// `T`는 바이트 배열처럼 모든 비트 패턴이 유효하고 힙 포인터를 갖지 않는 타입이어야 합니다.
+unsafe fn wipe_in_place<T>(value: &mut T) {
+    // SAFETY: 호출자가 `T`의 모든 바이트를 0으로 써도 안전함을 보장합니다.
+    unsafe { std::slice::from_raw_parts_mut(value as *mut T as *mut u8, std::mem::size_of::<T>()) }
+        .zeroize();
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Mutex;
+
+    use super::*;
+
+    /// 프로세스 전체의 자원 한도를 바꾸는 테스트를 직렬화
+    static RLIMITS: Mutex<()> = Mutex::new(());
+
+    /// `/proc/self/smaps`에서 `addr`를 포함하는 매핑의 권한과 VmFlags
+    #[cfg(target_os = "linux")]
+    fn mapping_of(addr: *const u8) -> (String, Vec<String>) {
+        let smaps = std::fs::read_to_string("/proc/self/smaps").unwrap();
+        let addr = addr as usize;
+        let mut current: Option<String> = None;
+        for line in smaps.lines() {
+            let mut fields = line.split_whitespace();
+            let first = fields.next().unwrap_or_default();
+            if let Some((start, end)) = first.split_once('-')
+                && let (Ok(start), Ok(end)) = (
+                    usize::from_str_radix(start, 16),
+                    usize::from_str_radix(end, 16),
+                )
+            {
+                current = (start..end)
+                    .contains(&addr)
+                    .then(|| fields.next().unwrap_or_default().to_string());
+            } else if first == "VmFlags:"
+                && let Some(perms) = current.take()
+            {
+                return (perms, fields.map(str::to_string).collect());
+            }
+        }
+        panic!("no mapping contains {addr:#x}");
+    }
+
+    #[cfg(unix)]
+    fn layout(buffer: &SecretBuffer) -> (*const u8, usize, usize) {
+        match &buffer.storage {
+            Storage::Mapped {
+                base, page, total, ..
+            } => (base.as_ptr() as *const u8, *page, *total),
+            Storage::Heap(_) => panic!("expected a mapped buffer"),
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn mapped_buffer_ends_at_the_trailing_guard_page() {
+        for len in [0, 1, 31, 4096, 5000] {
+            let mut buffer = SecretBuffer::zeroed(len);
+            assert_eq!(buffer.len(), len);
+            assert!(buffer.iter().all(|&b| b == 0));
+            buffer.iter_mut().for_each(|b| *b = 0xa5);
+            assert!(buffer.iter().all(|&b| b == 0xa5));
+
+            let (base, page, total) = layout(&buffer);
+            let trailing_guard = base.wrapping_add(total - page);
+            assert_eq!(buffer.as_ptr().wrapping_add(len), trailing_guard, "{len}");
+            assert!(total >= len + 2 * page);
+        }
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn guard_pages_are_inaccessible_and_data_is_locked_and_not_dumped() {
+        let _lock = RLIMITS.lock().unwrap_or_else(|e| e.into_inner());
+        let buffer = SecretBuffer::from_slice(b"secret key bytes");
+        assert_eq!(&buffer[..], b"secret key bytes");
+        let (base, _, total) = layout(&buffer);
+
+        assert_eq!(mapping_of(base).0, "---p");
+        assert_eq!(mapping_of(base.wrapping_add(total - 1)).0, "---p");
+        let (perms, flags) = mapping_of(buffer.as_ptr());
+        assert_eq!(perms, "rw-p");
+        assert!(flags.iter().any(|f| f == "dd"), "{flags:?}");
+        // mlock이 성공했다고 보고하면 커널도 잠긴 매핑으로 알고 있어야 함
+        assert_eq!(buffer.is_locked(), flags.iter().any(|f| f == "lo"));
+    }
+
+    #[test]
+    #[cfg(target_os = "linux")]
+    fn mlock_failure_leaves_a_usable_unlocked_mapping() {
+        let _lock = RLIMITS.lock().unwrap_or_else(|e| e.into_inner());
+        let mut previous = libc::rlimit {
+            rlim_cur: 0,
+            rlim_max: 0,
+        };
+        // SAFETY: 현재 프로세스의 한도만 조회·변경하며 끝나면 되돌림
+        unsafe {
+            assert_eq!(libc::getrlimit(libc::RLIMIT_MEMLOCK, &mut previous), 0);
+            let none = libc::rlimit {
+                rlim_cur: 0,
+                rlim_max: previous.rlim_max,
+            };
+            assert_eq!(libc::setrlimit(libc::RLIMIT_MEMLOCK, &none), 0);
+        }
+        let buffer = SecretBuffer::from_slice(&[7u8; 100]);
+        // SAFETY: 위에서 읽어 둔 값으로 되돌림
+        unsafe { libc::setrlimit(libc::RLIMIT_MEMLOCK, &previous) };
+
+        // CAP_IPC_LOCK이 있으면 한도와 관계없이 잠길 수 있으므로 커널의 상태와 비교
+        let (perms, flags) = mapping_of(buffer.as_ptr());
+        assert_eq!(perms, "rw-p");
+        assert_eq!(buffer.is_locked(), flags.iter().any(|f| f == "lo"));
+        assert_eq!(&buffer[..], &[7u8; 100]);
+        assert!(matches!(buffer.storage, Storage::Mapped { .. }));
+    }
+
+    #[test]
+    fn heap_fallback_behaves_like_a_mapped_buffer() {
+        for len in [0, 1, 64] {
+            let mut buffer = SecretBuffer::heap(len);
+            assert!(matches!(buffer.storage, Storage::Heap(_)));
+            assert!(!buffer.is_locked());
+            assert_eq!(buffer.len(), len);
+            assert!(buffer.iter().all(|&b| b == 0));
+            buffer.iter_mut().for_each(|b| *b = 0x5a);
+            assert_eq!(buffer.clone()[..], buffer[..]);
+            assert_eq!(
+                format!("{buffer:?}"),
+                format!("SecretBuffer([REDACTED; {len}])")
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(unix)]
+    fn core_dump_guard_restores_the_previous_limits() {
+        let _lock = RLIMITS.lock().unwrap_or_else(|e| e.into_inner());
+        let read = || {
+            let mut limit = libc::rlimit {
+                rlim_cur: 0,
+                rlim_max: 0,
+            };
+            // SAFETY: 현재 프로세스의 한도를 읽기만 함
+            assert_eq!(unsafe { libc::getrlimit(libc::RLIMIT_CORE, &mut limit) }, 0);
+            (limit.rlim_cur, limit.rlim_max)
+        };
+        #[cfg(target_os = "linux")]
+        // SAFETY: 인자가 정수뿐인 prctl 호출
+        let dumpable = || unsafe { libc::prctl(libc::PR_GET_DUMPABLE) };
+
+        let before = read();
+        #[cfg(target_os = "linux")]
+        let dumpable_before = dumpable();
+        {
+            let _guard = disable_core_dumps();
+            assert_eq!(read(), (0, before.1));
+            #[cfg(target_os = "linux")]
+            assert_eq!(dumpable(), 0);
+        }
+        assert_eq!(read(), before);
+        #[cfg(target_os = "linux")]
+        assert_eq!(dumpable(), dumpable_before);
+    }
+}
@@ -0,0 +1,250 @@
+//! 키 생성부터 저장·읽기까지 비밀키 사본이 해제된 힙 메모리에 남지 않는지 검사합니다.
+//!
+//! 전역 할당자가 기록 중인 스레드에서 해제되는 모든 블록(재할당 전의 블록 포함)의 내용을
+//! 정적 버퍼에 모아 두고, 작업이 끝난 뒤 그 안에서 비밀키 조각과 PEM 본문 조각을 찾습니다.
+//! 다른 테스트가 동시에 해제하는 블록은 기록하지 않습니다.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::{Cell, UnsafeCell};
+use std::collections::HashSet;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+
+use base64::Engine as _;
+use base64::engine::general_purpose;
+use zeroize::Zeroizing;
+
+use super::key_io::{read_key, write_key_pair};
+use super::keygen::{Algorithm, Variant, generate_keys};
+use super::paper::PaperBackup;
+use super::shamir::Share;
+use super::test_util::ScratchDir;
+
+/// 해제된 블록을 모아 둘 용량
+const CAPTURE_SIZE: usize = 32 * 1024 * 1024;
+
+/// 찾을 비밀키 조각 길이 (우연히 일치할 확률은 무시할 수 있음)
+const NEEDLE_LEN: usize = 16;
+
+struct Capture {
+    buf: UnsafeCell<[u8; CAPTURE_SIZE]>,
+    len: AtomicUsize,
+    overflow: AtomicBool,
+    lock: AtomicBool,
+}
+
+// SAFETY: `buf`는 `lock`을 잡은 동안에만 씁니다.
+unsafe impl Sync for Capture {}
+
+static CAPTURE: Capture = Capture {
+    buf: UnsafeCell::new([0; CAPTURE_SIZE]),
+    len: AtomicUsize::new(0),
+    overflow: AtomicBool::new(false),
+    lock: AtomicBool::new(false),
+};
+
+thread_local! {
+    /// 이 스레드에서 해제되는 블록을 기록할지 여부 (소멸자가 없어 할당자 안에서 써도 됨)
+    static RECORDING: Cell<bool> = const { Cell::new(false) };
+}
+
+fn recording() -> bool {
+    RECORDING.try_with(Cell::get).unwrap_or(false)
+}
+
+/// 해제되는 블록의 내용을 기록하는 할당자. `realloc`은 기본 구현(할당·복사·해제)을 쓰므로
+/// 재할당 전 블록도 기록됩니다. 기록 중에 할당한 블록은 0으로 채워, 기록 전에 해제된
+/// 메모리의 잔여 내용이 채우지 않은 부분에 섞여 기록되지 않게 합니다.
+struct RecordingAllocator;
+
+unsafe impl GlobalAlloc for RecordingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        if recording() {
+            unsafe { System.alloc_zeroed(layout) }
+        } else {
+            unsafe { System.alloc(layout) }
+        }
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        if recording() {
+            while CAPTURE
+                .lock
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_err()
+            {
+                std::hint::spin_loop();
+            }
+            let start = CAPTURE.len.load(Ordering::Relaxed);
+            let size = layout.size();
+            if start + size > CAPTURE_SIZE {
+                CAPTURE.overflow.store(true, Ordering::Relaxed);
+            } else {
+                unsafe {
+                    std::ptr::copy_nonoverlapping(
+                        ptr,
+                        (CAPTURE.buf.get() as *mut u8).add(start),
+                        size,
+                    );
+                }
+                CAPTURE.len.store(start + size, Ordering::Relaxed);
+            }
+            CAPTURE.lock.store(false, Ordering::Release);
+        }
+        unsafe { System.dealloc(ptr, layout) }
+    }
+}
+
+#[global_allocator]
+static ALLOCATOR: RecordingAllocator = RecordingAllocator;
+
+fn start_recording() {
+    CAPTURE.len.store(0, Ordering::SeqCst);
+    CAPTURE.overflow.store(false, Ordering::SeqCst);
+    RECORDING.set(true);
+}
+
+fn stop_recording() {
+    RECORDING.set(false);
+    assert!(
+        !CAPTURE.overflow.load(Ordering::SeqCst),
+        "capture buffer overflowed"
+    );
+}
+
+/// 기록한 내용에서 `needles` 중 하나라도 보이는지 반환합니다. 기록을 멈춘 뒤에 호출하세요.
+fn captured_contains(needles: &HashSet<Vec<u8>>) -> bool {
+    let len = CAPTURE.len.load(Ordering::SeqCst);
+    // SAFETY: 기록을 멈췄으므로 더 이상 쓰이지 않습니다.
+    let captured = unsafe { std::slice::from_raw_parts(CAPTURE.buf.get() as *const u8, len) };
+    captured
+        .windows(NEEDLE_LEN)
+        .any(|window| needles.contains(window))
+}
+
+/// 비밀키 바이트와 그 PEM 본문에서 겹치지 않는 조각을 뽑습니다.
+fn needles_for(sk: &[u8]) -> HashSet<Vec<u8>> {
+    let mut needles: HashSet<Vec<u8>> = sk
+        .chunks_exact(NEEDLE_LEN)
+        .filter(|chunk| chunk.iter().any(|&b| b != 0))
+        .map(<[u8]>::to_vec)
+        .collect();
+    let b64 = Zeroizing::new(general_purpose::STANDARD.encode(sk));
+    needles.extend(b64.as_bytes().chunks_exact(NEEDLE_LEN).map(<[u8]>::to_vec));
+    needles
+}
+
+/// 생성 → 바이너리/PEM 저장 → PEM 읽기 과정에서 해제된 힙에 비밀키가 남지 않아야 합니다.
+fn assert_no_lingering_copies(algorithm: Algorithm, variant: Variant) {
+    let dir = ScratchDir::new("secret-copies", variant.as_str());
+    let path = |name: &str| dir.join(name).to_string_lossy().into_owned();
+    let (pk_raw, sk_raw, pk_pem, sk_pem) = (
+        path("raw.pub"),
+        path("raw.sk"),
+        path("pem.pub"),
+        path("pem.sk"),
+    );
+
+    start_recording();
+    let (pk, sk) = generate_keys(algorithm, variant.clone()).unwrap();
+    write_key_pair(&pk, &sk, &pk_raw, &sk_raw, false, false, false).unwrap();
+    write_key_pair(&pk, &sk, &pk_pem, &sk_pem, true, true, false).unwrap();
+    let loaded = read_key(&sk_pem, "SECRET KEY").unwrap();
+    let loaded_raw = read_key(&sk_raw, "SECRET KEY").unwrap();
+    let round_trip = *loaded == *sk && *loaded_raw == *sk;
+    drop(loaded);
+    drop(loaded_raw);
+    stop_recording();
+    let leaked = captured_contains(&needles_for(&sk));
+
+    assert!(round_trip, "{} round trip failed", variant.standard_name());
+    assert!(
+        !leaked,
+        "{}: secret key bytes remain in freed heap memory",
+        variant.standard_name()
+    );
+}
+
+/// 공유 조각을 PEM 텍스트로 만드는 동안 해제된 힙에 조각 데이터가 남지 않아야 합니다.
+fn assert_no_lingering_share_copies() {
+    let (_, sk) = generate_keys(Algorithm::MLKEM, Variant::MLKEM768).unwrap();
+    let share = Share {
+        set_id: [7; 8],
+        threshold: 2,
+        total: 3,
+        index: 1,
+        algorithm: Algorithm::MLKEM,
+        variant: Variant::MLKEM768,
+        fingerprint: "ab".repeat(32),
+        data: Zeroizing::new(sk.to_vec()),
+    };
+    // 조각 데이터와 PEM 본문(전체 바이트열의 base64)만 찾음. 지문 등 공개 필드는 제외
+    let mut needles = needles_for(&share.data);
+    let b64 = Zeroizing::new(general_purpose::STANDARD.encode(share.to_bytes().unwrap()));
+    needles.extend(b64.as_bytes().chunks_exact(NEEDLE_LEN).map(<[u8]>::to_vec));
+
+    start_recording();
+    let armored = share.to_armored().unwrap();
+    let round_trip = Share::from_armored(&armored).is_ok_and(|parsed| parsed == share);
+    drop(armored);
+    stop_recording();
+
+    assert!(round_trip, "share round trip failed");
+    assert!(
+        !captured_contains(&needles),
+        "share bytes remain in freed heap memory"
+    );
+}
+
+/// 종이 백업 텍스트와 SVG를 만드는 동안 해제된 힙에 본문(base32) 조각이 남지 않아야 합니다.
+fn assert_no_lingering_paper_copies() {
+    let (_, sk) = generate_keys(Algorithm::FALCON, Variant::FALCONNoPad512).unwrap();
+    let backup = PaperBackup::new(
+        Variant::FALCONNoPad512,
+        &"cd".repeat(32),
+        "2026-01-01T00:00:00Z",
+        Zeroizing::new(sk.to_vec()),
+    )
+    .unwrap();
+    // 본문 줄의 데이터 부분(줄 번호와 체크섬 제외)을 묶음 표기 그대로 찾음
+    let text = backup.to_text();
+    let needles: HashSet<Vec<u8>> = text
+        .lines()
+        .filter(|line| line.as_bytes().first().is_some_and(u8::is_ascii_digit))
+        .filter_map(|line| line.get(5..5 + NEEDLE_LEN))
+        .map(|data| data.as_bytes().to_vec())
+        .collect();
+    drop(text);
+
+    start_recording();
+    let text = backup.to_text();
+    let svg = backup.to_svg();
+    let round_trip = PaperBackup::parse(&text).is_ok_and(|parsed| parsed == backup);
+    drop(text);
+    drop(svg);
+    stop_recording();
+
+    assert!(round_trip, "paper round trip failed");
+    assert!(
+        !captured_contains(&needles),
+        "paper backup text remains in freed heap memory"
+    );
+}
+
+#[test]
+fn secret_keys_leave_no_copies_in_freed_memory() {
+    // 검출기 자체 확인: 지우지 않은 Vec 사본은 잡아내야 함
+    let (_, sk) = generate_keys(Algorithm::MLKEM, Variant::MLKEM512).unwrap();
+    start_recording();
+    drop(sk.to_vec());
+    stop_recording();
+    assert!(
+        captured_contains(&needles_for(&sk)),
+        "detector missed a plain copy"
+    );
+
+    assert_no_lingering_copies(Algorithm::MLKEM, Variant::MLKEM768);
+    assert_no_lingering_copies(Algorithm::MLDSA, Variant::MLDSA65);
+    assert_no_lingering_copies(Algorithm::FALCON, Variant::FALCONNoPad512);
+    assert_no_lingering_share_copies();
+    assert_no_lingering_paper_copies();
+}
@@ -0,0 +1,945 @@
+use std::env;
+use std::fmt;
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::net::{IpAddr, Shutdown, TcpListener, TcpStream};
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::thread;
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use zeroize::Zeroizing;
+
+use super::datetime::now_unix;
+use super::executor::{ExecutorError, ExecutorOptions, KeygenExecutor};
+use super::kem;
+use super::key_io::{STDIO_PATH, ZeroizingWriter, base64_serde, fingerprint};
+use super::keygen::{Algorithm, Variant, generate_keys, minimal_variant_for_algorithm};
+use super::keygen::{parse_algorithm, parse_variant};
+use super::keystore::{KeyOptions, Keystore, LookupError, StoreEntry, StoreError, default_root};
+use super::lifecycle::KeyState;
+use super::metadata::KeyMetadata;
+use super::policy::{Policy, PolicyViolation};
+use super::sign;
+
+/// 요청 본문 최대 크기 (Classic McEliece 공개키의 base64가 약 1.8 MiB)
+pub const MAX_BODY: usize = 8 * 1024 * 1024;
+
+/// 동시에 처리하는 최대 연결 수 (넘으면 503)
+pub const MAX_CONNECTIONS: usize = 64;
+
+/// 요청 줄과 헤더의 최대 크기
+const MAX_HEADER: u64 = 16 * 1024;
+
+/// 연결별 읽기·쓰기 제한 시간
+const IO_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// 연결 수를 넘어 거절하는 응답을 보낼 때의 쓰기 제한 시간 (연결을 받는 스레드에서 보냄)
+const REJECT_TIMEOUT: Duration = Duration::from_secs(1);
+
+/// 자동으로 만드는 인증 토큰의 바이트 수 (16진수로 두 배 길이)
+const TOKEN_BYTES: usize = 32;
+
+/// HTTP 키 서비스 설정
+pub struct ServiceOptions {
+    /// 저장된 키(`key`)를 쓰는 요청과 `store: true` 생성에 쓰는 키 저장소. `None`이면 해당 요청은 404
+    pub store: Option<Keystore>,
+    /// 모든 요청에 요구하는 `Authorization: Bearer <토큰>`. 비어 있으면 [`serve`]가 시작하지 않음
+    /// ([`generate_token`]으로 만들 수 있음)
+    pub token: Zeroizing<String>,
+    /// 생성에 적용할 정책
+    pub policy: Policy,
+    /// 키 생성·암호 연산 워커 수
+    pub workers: usize,
+}
+
+/// 서비스가 알리는 사건 (로그 출력용)
+#[derive(Debug)]
+pub enum ServiceEvent {
+    /// 요청 하나를 처리함 (메서드, 경로, 상태 코드)
+    Handled {
+        method: String,
+        path: String,
+        status: u16,
+    },
+    /// 연결을 처리하지 못함 (서버는 계속 동작)
+    Failed(io::Error),
+}
+
+/// API 오류. 상태 코드와 `{"error": <코드>, "message": <메시지>}` 본문으로 응답합니다.
+#[derive(Debug)]
+pub enum ServiceError {
+    BadRequest(String),
+    Unauthorized,
+    /// 브라우저 요청(`Origin`)이나 루프백이 아닌 `Host`
+    Forbidden(&'static str),
+    NotFound(String),
+    MethodNotAllowed,
+    PayloadTooLarge,
+    Policy(PolicyViolation),
+    /// 사용 중이 아니거나 만료된 키 (ID, 상태)
+    Inactive(String, KeyState),
+    Store(StoreError),
+    Busy,
+    Internal(String),
+}
+
+/// 키 생성 요청
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct GenerateRequest {
+    algorithm: String,
+    #[serde(default)]
+    variant: Option<String>,
+    /// 키 저장소에 저장하고 비밀키는 돌려주지 않음
+    #[serde(default)]
+    store: bool,
+    #[serde(default)]
+    label: Option<String>,
+    #[serde(default)]
+    tags: Vec<String>,
+    #[serde(default)]
+    pending: bool,
+}
+
+/// 키 생성 응답. `metadata`는 `-meta` 사이드카, `entry`는 `show -json`과 같은 형식입니다.
+#[derive(Serialize)]
+struct GenerateResponse {
+    metadata: KeyMetadata,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entry: Option<StoreEntry>,
+    #[serde(with = "base64_serde")]
+    public_key: Vec<u8>,
+    #[serde(skip_serializing_if = "Option::is_none", with = "base64_serde::option")]
+    secret_key: Option<Zeroizing<Vec<u8>>>,
+}
+
+/// 저장된 키 조회 응답
+#[derive(Serialize)]
+struct KeyResponse {
+    entry: StoreEntry,
+    #[serde(with = "base64_serde")]
+    public_key: Vec<u8>,
+}
+
+/// 암호 연산 요청. 키는 `key`(저장소의 ID·라벨) 또는 base64 키와 `algorithm`/`variant`로 지정합니다.
+#[derive(Deserialize)]
+#[serde(deny_unknown_fields)]
+struct OperationRequest {
+    #[serde(default)]
+    algorithm: Option<String>,
+    #[serde(default)]
+    variant: Option<String>,
+    #[serde(default)]
+    key: Option<String>,
+    #[serde(default, with = "base64_serde::option")]
+    public_key: Option<Vec<u8>>,
+    #[serde(default, with = "base64_serde::option")]
+    secret_key: Option<Zeroizing<Vec<u8>>>,
+    #[serde(default, with = "base64_serde::option")]
+    ciphertext: Option<Vec<u8>>,
+    #[serde(default, with = "base64_serde::option")]
+    message: Option<Vec<u8>>,
+    #[serde(default, with = "base64_serde::option")]
+    signature: Option<Vec<u8>>,
+}
+
+#[derive(Serialize)]
+struct EncapsulateResponse {
+    variant: String,
+    #[serde(with = "base64_serde")]
+    ciphertext: Vec<u8>,
+    #[serde(with = "base64_serde")]
+    shared_secret: Zeroizing<Vec<u8>>,
+}
+
+#[derive(Serialize)]
+struct DecapsulateResponse {
+    variant: String,
+    #[serde(with = "base64_serde")]
+    shared_secret: Zeroizing<Vec<u8>>,
+}
+
+#[derive(Serialize)]
+struct SignResponse {
+    variant: String,
+    #[serde(with = "base64_serde")]
+    signature: Vec<u8>,
+}
+
+#[derive(Serialize)]
+struct VerifyResponse {
+    variant: String,
+    valid: bool,
+}
+
+#[derive(Serialize)]
+struct ErrorResponse<'a> {
+    error: &'a str,
+    message: String,
+}
+
+/// 저장된 키의 용도 (수명 주기 검사 기준)
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum KeyUse {
+    /// 캡슐화·서명: 사용 중이고 만료되지 않은 키만
+    Produce,
+    /// 역캡슐화: 상태와 관계없이 (이전 데이터 복호화)
+    Decapsulate,
+    /// 검증: 유출된 키 제외
+    Verify,
+}
+
+/// 읽어 들인 HTTP 요청
+struct HttpRequest {
+    method: String,
+    path: String,
+    host: Option<String>,
+    origin: bool,
+    authorization: Option<Zeroizing<String>>,
+    content_type: Option<String>,
+    body: Zeroizing<Vec<u8>>,
+}
+
+/// 요청 처리에 필요한 공유 상태
+struct Service {
+    options: ServiceOptions,
+    executor: KeygenExecutor,
+    connections: AtomicUsize,
+    on_event: Box<dyn Fn(ServiceEvent) + Send + Sync>,
+}
+
+/// 처리 중인 연결 하나. 버려질 때(스레드를 만들지 못한 경우 포함) 연결 수를 되돌립니다.
+struct ConnectionSlot(Arc<Service>);
+
+impl Drop for ConnectionSlot {
+    fn drop(&mut self) {
+        self.0.connections.fetch_sub(1, Ordering::SeqCst);
+    }
+}
+
+/// 포트 `port`에서 도는 서비스의 자동 인증 토큰을 둘 기본 경로
+///
+/// `$XDG_RUNTIME_DIR/keypairer-serve-<포트>.token` → `<키 저장소>/keypairer-serve-<포트>.token`
+/// 순으로 결정합니다.
+pub fn default_token_path(port: u16) -> Option<PathBuf> {
+    env::var_os("XDG_RUNTIME_DIR")
+        .filter(|v| !v.is_empty())
+        .map(PathBuf::from)
+        .or_else(default_root)
+        .map(|dir| dir.join(format!("keypairer-serve-{}.token", port)))
+}
+
+/// 임의의 인증 토큰(32바이트, 16진수)을 만듭니다.
+pub fn generate_token() -> io::Result<Zeroizing<String>> {
+    let mut bytes = Zeroizing::new([0u8; TOKEN_BYTES]);
+    getrandom::fill(bytes.as_mut()).map_err(|e| io::Error::other(e.to_string()))?;
+    let mut token = Zeroizing::new(String::with_capacity(TOKEN_BYTES * 2));
+    for b in bytes.iter() {
+        token.push(char::from_digit((b >> 4) as u32, 16).unwrap_or('0'));
+        token.push(char::from_digit((b & 0xf) as u32, 16).unwrap_or('0'));
+    }
+    Ok(token)
+}
+
+/// 루프백 주소에서만 연결을 받도록 확인합니다.
+pub fn is_loopback(listener: &TcpListener) -> io::Result<bool> {
+    Ok(listener.local_addr()?.ip().is_loopback())
+}
+
+/// HTTP 키 서비스를 실행합니다. 워커를 만들지 못하면 오류를 반환하고, 시작한 뒤에는 돌아오지 않습니다.
+///
+/// 연결마다 스레드를 두어 요청 하나를 처리한 뒤 연결을 닫으며(`Connection: close`), 키 생성과
+/// 암호 연산은 모든 배리언트를 처리할 수 있는 스택의 [`KeygenExecutor`] 워커에서 실행합니다.
+/// 처리 중인 연결이 [`MAX_CONNECTIONS`]개면 스레드를 만들거나 요청을 읽지 않고 바로 503으로
+/// 응답합니다.
+///
+/// | 메서드 | 경로 | 설명 |
+/// |---|---|---|
+/// | `GET` | `/v1/health` | 상태 확인 |
+/// | `POST` | `/v1/generate` | 키 생성 (`store: true`면 저장소에 저장) |
+/// | `GET` | `/v1/keys` | 저장된 키 목록 (`list -json`) |
+/// | `GET` | `/v1/keys/<ID 또는 라벨>` | 저장된 키와 공개키 |
+/// | `POST` | `/v1/encapsulate`, `/v1/decapsulate` | KEM 캡슐화·역캡슐화 |
+/// | `POST` | `/v1/sign`, `/v1/verify` | 분리 서명·검증 |
+pub fn serve(
+    listener: TcpListener,
+    options: ServiceOptions,
+    on_event: impl Fn(ServiceEvent) + Send + Sync + 'static,
+) -> io::Result<()> {
+    // 빈 토큰은 빈 `Bearer` 헤더와 일치하므로 인증이 없는 것과 같음
+    if options.token.trim().is_empty() {
+        return Err(io::Error::new(
+            io::ErrorKind::InvalidInput,
+            "service token must not be empty",
+        ));
+    }
+    let executor = KeygenExecutor::with_options(ExecutorOptions {
+        workers: options.workers,
+        stack_size: None,
+        queue_capacity: None,
+        thread_name: "keypairer-serve".to_string(),
+    })?;
+    let service = Arc::new(Service {
+        options,
+        executor,
+        connections: AtomicUsize::new(0),
+        on_event: Box::new(on_event),
+    });
+
+    for stream in listener.incoming() {
+        match stream {
+            Ok(stream) => {
+                let active = service.connections.fetch_add(1, Ordering::SeqCst);
+                let slot = ConnectionSlot(Arc::clone(&service));
+                if active >= MAX_CONNECTIONS {
+                    drop(slot);
+                    service.reject(stream);
+                    continue;
+                }
+                if let Err(e) = thread::Builder::new()
+                    .name("keypairer-serve-conn".to_string())
+                    .spawn(move || slot.0.handle_connection(stream))
+                {
+                    (service.on_event)(ServiceEvent::Failed(e));
+                }
+            }
+            Err(e) => (service.on_event)(ServiceEvent::Failed(e)),
+        }
+    }
+    Ok(())
+}
+
+impl Service {
+    /// 연결 수를 넘은 연결에 요청을 읽지 않고 503으로 응답합니다.
+    fn reject(&self, mut stream: TcpStream) {
+        let _ = stream.set_write_timeout(Some(REJECT_TIMEOUT));
+        let status = ServiceError::Busy.status();
+        if let Err(e) = write_response(&mut stream, status, &ServiceError::Busy.body()) {
+            (self.on_event)(ServiceEvent::Failed(e));
+        }
+        let _ = stream.shutdown(Shutdown::Both);
+        (self.on_event)(ServiceEvent::Handled {
+            method: String::new(),
+            path: String::new(),
+            status,
+        });
+    }
+
+    fn handle_connection(&self, stream: TcpStream) {
+        let _ = stream.set_read_timeout(Some(IO_TIMEOUT));
+        let _ = stream.set_write_timeout(Some(IO_TIMEOUT));
+
+        let mut writer = match stream.try_clone() {
+            Ok(s) => s,
+            Err(e) => return (self.on_event)(ServiceEvent::Failed(e)),
+        };
+        let mut reader = BufReader::new(stream);
+        let (method, path, result) = match read_request(&mut reader) {
+            Ok(request) => {
+                let result = self.handle(&request);
+                (request.method, request.path, result)
+            }
+            Err(e) => (String::new(), String::new(), Err(e)),
+        };
+
+        let (status, body) = match result {
+            Ok(body) => (200, body),
+            Err(e) => (e.status(), e.body()),
+        };
+        if let Err(e) = write_response(&mut writer, status, &body) {
+            (self.on_event)(ServiceEvent::Failed(e));
+        }
+        (self.on_event)(ServiceEvent::Handled {
+            method,
+            path,
+            status,
+        });
+    }
+
+    /// 요청을 검사하고 경로에 맞는 처리기로 넘깁니다. 성공하면 JSON 본문을 반환합니다.
+    fn handle(&self, request: &HttpRequest) -> Result<Zeroizing<Vec<u8>>, ServiceError> {
+        // 브라우저에서 온 요청과 DNS 리바인딩을 막음
+        if request.origin {
+            return Err(ServiceError::Forbidden("browser requests are not accepted"));
+        }
+        if !request.host.as_deref().is_some_and(is_loopback_host) {
+            return Err(ServiceError::Forbidden("Host must be a loopback address"));
+        }
+        let given = request
+            .authorization
+            .as_deref()
+            .and_then(|v| v.strip_prefix("Bearer "))
+            .map(str::trim);
+        if !given
+            .is_some_and(|given| constant_time_eq(given.as_bytes(), self.options.token.as_bytes()))
+        {
+            return Err(ServiceError::Unauthorized);
+        }
+        if request.method == "POST"
+            && !request
+                .content_type
+                .as_deref()
+                .is_some_and(|ct| ct.trim().starts_with("application/json"))
+        {
+            return Err(ServiceError::BadRequest(
+                "Content-Type must be application/json".to_string(),
+            ));
+        }
+
+        let path = request.path.split('?').next().unwrap_or_default();
+        match (request.method.as_str(), path) {
+            ("GET", "/v1/health") => to_json(&serde_json::json!({
+                "status": "ok",
+                "tool_version": env!("CARGO_PKG_VERSION"),
+            })),
+            ("POST", "/v1/generate") => self.generate(parse_body(&request.body)?),
+            ("GET", "/v1/keys") => to_json(&self.store()?.entries().map_err(ServiceError::Store)?),
+            ("GET", p) if p.starts_with("/v1/keys/") => self.show(&p["/v1/keys/".len()..]),
+            ("POST", "/v1/encapsulate") => self.encapsulate(parse_body(&request.body)?),
+            ("POST", "/v1/decapsulate") => self.decapsulate(parse_body(&request.body)?),
+            ("POST", "/v1/sign") => self.sign(parse_body(&request.body)?),
+            ("POST", "/v1/verify") => self.verify(parse_body(&request.body)?),
+            (
+                _,
+                "/v1/health" | "/v1/generate" | "/v1/keys" | "/v1/encapsulate" | "/v1/decapsulate"
+                | "/v1/sign" | "/v1/verify",
+            ) => Err(ServiceError::MethodNotAllowed),
+            (_, p) if p.starts_with("/v1/keys/") => Err(ServiceError::MethodNotAllowed),
+            _ => Err(ServiceError::NotFound(format!("no route for {}", path))),
+        }
+    }
+
+    /// 키 저장소
+    fn store(&self) -> Result<&Keystore, ServiceError> {
+        self.options
+            .store
+            .as_ref()
+            .ok_or_else(|| ServiceError::NotFound("key store is not available".to_string()))
+    }
+
+    /// 실행기 워커에서 작업을 실행하고 결과를 기다립니다.
+    fn run<F, R>(&self, f: F) -> Result<R, ServiceError>
+    where
+        F: FnOnce() -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        self.executor
+            .submit(f)
+            .and_then(|handle| handle.join())
+            .map_err(ServiceError::from)
+    }
+
+    fn generate(&self, request: GenerateRequest) -> Result<Zeroizing<Vec<u8>>, ServiceError> {
+        let (algorithm, variant) = resolve(Some(&request.algorithm), request.variant.as_deref())?;
+        self.options
+            .policy
+            .check_variant(&variant)
+            .map_err(ServiceError::Policy)?;
+
+        let target = variant.clone();
+        let (pk, sk) = self
+            .run(move || generate_keys(algorithm, target))?
+            .map_err(|e| ServiceError::Internal(e.to_string()))?;
+        let created = now_unix();
+        let fp = fingerprint(&pk);
+
+        let (metadata, entry, secret_key) = if request.store {
+            let entry = self
+                .store()?
+                .add(
+                    algorithm,
+                    &variant,
+                    &pk,
+                    &sk,
+                    created,
+                    &KeyOptions {
+                        label: request.label.as_deref(),
+                        tags: &request.tags,
+                        state: if request.pending {
+                            KeyState::Pending
+                        } else {
+                            KeyState::Active
+                        },
+                        expires: None,
+                    },
+                )
+                .map_err(ServiceError::Store)?;
+            (entry.metadata.clone(), Some(entry), None)
+        } else {
+            let metadata = KeyMetadata::new(
+                algorithm, &variant, created, &fp, STDIO_PATH, STDIO_PATH, false, false,
+            );
+            (metadata, None, Some(Zeroizing::new(sk.to_vec())))
+        };
+        to_json(&GenerateResponse {
+            metadata,
+            entry,
+            public_key: pk,
+            secret_key,
+        })
+    }
+
+    fn show(&self, query: &str) -> Result<Zeroizing<Vec<u8>>, ServiceError> {
+        let store = self.store()?;
+        let entry = store.find(query).map_err(ServiceError::Store)?;
+        let public_key = store.read_public(&entry.id).map_err(ServiceError::Store)?;
+        to_json(&KeyResponse { entry, public_key })
+    }
+
+    fn encapsulate(&self, request: OperationRequest) -> Result<Zeroizing<Vec<u8>>, ServiceError> {
+        let (variant, pk) = self.public_key(&request, KeyUse::Produce)?;
+        let target = variant.clone();
+        let (shared_secret, ciphertext) = self
+            .run(move || kem::encapsulate(&target, &pk))?
+            .map_err(|e| ServiceError::BadRequest(e.to_string()))?;
+        to_json(&EncapsulateResponse {
+            variant: variant.standard_name(),
+            ciphertext,
+            shared_secret,
+        })
+    }
+
+    fn decapsulate(&self, request: OperationRequest) -> Result<Zeroizing<Vec<u8>>, ServiceError> {
+        let ciphertext = required(request.ciphertext.clone(), "ciphertext")?;
+        let (variant, sk) = self.secret_key(request, KeyUse::Decapsulate)?;
+        let target = variant.clone();
+        let shared_secret = self
+            .run(move || kem::decapsulate(&target, &sk, &ciphertext))?
+            .map_err(|e| ServiceError::BadRequest(e.to_string()))?;
+        to_json(&DecapsulateResponse {
+            variant: variant.standard_name(),
+            shared_secret,
+        })
+    }
+
+    fn sign(&self, request: OperationRequest) -> Result<Zeroizing<Vec<u8>>, ServiceError> {
+        let message = required(request.message.clone(), "message")?;
+        let (variant, sk) = self.secret_key(request, KeyUse::Produce)?;
+        let target = variant.clone();
+        let signature = self
+            .run(move || sign::sign(&target, &sk, &message))?
+            .map_err(|e| ServiceError::BadRequest(e.to_string()))?;
+        to_json(&SignResponse {
+            variant: variant.standard_name(),
+            signature,
+        })
+    }
+
+    fn verify(&self, request: OperationRequest) -> Result<Zeroizing<Vec<u8>>, ServiceError> {
+        let message = required(request.message.clone(), "message")?;
+        let signature = required(request.signature.clone(), "signature")?;
+        let (variant, pk) = self.public_key(&request, KeyUse::Verify)?;
+        let target = variant.clone();
+        let valid = self
+            .run(move || sign::verify(&target, &pk, &message, &signature))?
+            .map_err(|e| ServiceError::BadRequest(e.to_string()))?;
+        to_json(&VerifyResponse {
+            variant: variant.standard_name(),
+            valid,
+        })
+    }
+
+    /// 요청의 `key` 또는 `public_key`로 배리언트와 공개키를 정합니다.
+    fn public_key(
+        &self,
+        request: &OperationRequest,
+        usage: KeyUse,
+    ) -> Result<(Variant, Vec<u8>), ServiceError> {
+        match (&request.key, &request.public_key) {
+            (Some(query), None) => {
+                let (entry, variant) = self.stored(request, query, usage)?;
+                let pk = self
+                    .store()?
+                    .read_public(&entry.id)
+                    .map_err(ServiceError::Store)?;
+                Ok((variant, pk))
+            }
+            (None, Some(pk)) => {
+                let (_, variant) =
+                    resolve(request.algorithm.as_deref(), request.variant.as_deref())?;
+                Ok((variant, pk.clone()))
+            }
+            _ => Err(ServiceError::BadRequest(
+                "specify exactly one of key or public_key".to_string(),
+            )),
+        }
+    }
+
+    /// 요청의 `key` 또는 `secret_key`로 배리언트와 비밀키를 정합니다.
+    fn secret_key(
+        &self,
+        request: OperationRequest,
+        usage: KeyUse,
+    ) -> Result<(Variant, Zeroizing<Vec<u8>>), ServiceError> {
+        match (&request.key, request.secret_key.is_some()) {
+            (Some(query), false) => {
+                let (entry, variant) = self.stored(&request, query, usage)?;
+                let sk = self
+                    .store()?
+                    .read_secret(&entry.id)
+                    .map_err(ServiceError::Store)?;
+                Ok((variant, sk))
+            }
+            (None, true) => {
+                let (_, variant) =
+                    resolve(request.algorithm.as_deref(), request.variant.as_deref())?;
+                Ok((variant, request.secret_key.unwrap_or_default()))
+            }
+            _ => Err(ServiceError::BadRequest(
+                "specify exactly one of key or secret_key".to_string(),
+            )),
+        }
+    }
+
+    /// 저장된 키를 찾아 용도에 맞는 상태인지 확인합니다. `algorithm`/`variant`를 함께 주면 일치해야 합니다.
+    fn stored(
+        &self,
+        request: &OperationRequest,
+        query: &str,
+        usage: KeyUse,
+    ) -> Result<(StoreEntry, Variant), ServiceError> {
+        let entry = self.store()?.find(query).map_err(ServiceError::Store)?;
+        let Some((_, variant)) = entry.algorithm_variant() else {
+            return Err(ServiceError::Internal(format!(
+                "unsupported stored key: {}",
+                entry.metadata.standard_name
+            )));
+        };
+        if request.algorithm.is_some() {
+            let (_, requested) = resolve(request.algorithm.as_deref(), request.variant.as_deref())?;
+            if requested != variant {
+                return Err(ServiceError::BadRequest(format!(
+                    "key {} is {}, not {}",
+                    entry.id,
+                    variant.standard_name(),
+                    requested.standard_name()
+                )));
+            }
+        }
+
+        let state = entry.lifecycle.state;
+        let usable = match usage {
+            KeyUse::Produce => state == KeyState::Active && !entry.lifecycle.is_expired(now_unix()),
+            KeyUse::Decapsulate => true,
+            KeyUse::Verify => state != KeyState::Compromised,
+        };
+        if !usable {
+            return Err(ServiceError::Inactive(entry.id, state));
+        }
+        Ok((entry, variant))
+    }
+}
+
+/// 알고리즘과 선택적 배리언트를 해석합니다. 배리언트를 생략하면 알고리즘별 최소값입니다.
+fn resolve(
+    algorithm: Option<&str>,
+    variant: Option<&str>,
+) -> Result<(Algorithm, Variant), ServiceError> {
+    let algorithm = algorithm
+        .ok_or_else(|| ServiceError::BadRequest("algorithm is required without key".to_string()))?;
+    let alg = parse_algorithm(algorithm)
+        .filter(|a| *a != Algorithm::Unknown)
+        .ok_or_else(|| ServiceError::BadRequest(format!("unknown algorithm: {}", algorithm)))?;
+    let variant = match variant {
+        Some(v) => parse_variant(alg, v)
+            .ok_or_else(|| ServiceError::BadRequest(format!("unknown variant: {}", v)))?,
+        None => minimal_variant_for_algorithm(alg),
+    };
+    Ok((alg, variant))
+}
+
+fn required<T>(value: Option<T>, field: &str) -> Result<T, ServiceError> {
+    value.ok_or_else(|| ServiceError::BadRequest(format!("{} is required", field)))
+}
+
+fn parse_body<T: for<'de> Deserialize<'de>>(body: &[u8]) -> Result<T, ServiceError> {
+    serde_json::from_slice(body).map_err(|e| ServiceError::BadRequest(e.to_string()))
+}
+
+/// 응답 본문을 직렬화합니다. 비밀값이 들어 있을 수 있으므로 버퍼는 키울 때와 버릴 때 0으로
+/// 덮어씁니다.
+fn to_json<T: Serialize>(value: &T) -> Result<Zeroizing<Vec<u8>>, ServiceError> {
+    let mut out = ZeroizingWriter::with_capacity(4096);
+    serde_json::to_writer(&mut out, value)
+        .and_then(|_| out.write_all(b"\n").map_err(serde_json::Error::io))
+        .map_err(|e| ServiceError::Internal(e.to_string()))?;
+    Ok(out.into_inner())
+}
+
+/// `localhost`, `127.0.0.1`, `[::1]` 등 루프백 호스트인지 확인합니다 (포트 허용).
+fn is_loopback_host(host: &str) -> bool {
+    let host = host.trim();
+    let name = if let Some(rest) = host.strip_prefix('[') {
+        rest.split(']').next().unwrap_or_default()
+    } else {
+        host.rsplit_once(':').map_or(host, |(name, _)| name)
+    };
+    name.eq_ignore_ascii_case("localhost")
+        || name.parse::<IpAddr>().is_ok_and(|ip| ip.is_loopback())
+}
+
+/// 길이 외의 정보가 시간으로 새지 않도록 두 값을 비교합니다.
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |acc, (x, y)| acc | (x ^ y)) == 0
+}
+
+/// 요청 줄, 헤더, 본문(`Content-Length`)을 읽습니다.
+fn read_request(reader: &mut BufReader<TcpStream>) -> Result<HttpRequest, ServiceError> {
+    let bad = |msg: &str| ServiceError::BadRequest(msg.to_string());
+    let mut head = reader.by_ref().take(MAX_HEADER);
+
+    let mut line = String::new();
+    head.read_line(&mut line)
+        .map_err(|_| bad("malformed request line"))?;
+    let mut parts = line.split_whitespace();
+    let (Some(method), Some(path), Some(version)) = (parts.next(), parts.next(), parts.next())
+    else {
+        return Err(bad("malformed request line"));
+    };
+    if !version.starts_with("HTTP/1.") {
+        return Err(bad("unsupported HTTP version"));
+    }
+    let mut request = HttpRequest {
+        method: method.to_string(),
+        path: path.to_string(),
+        host: None,
+        origin: false,
+        authorization: None,
+        content_type: None,
+        body: Zeroizing::new(Vec::new()),
+    };
+
+    let mut content_length = 0usize;
+    loop {
+        // Authorization 헤더에 토큰이 들어 있으므로 줄 버퍼도 0으로 덮어씀
+        let mut header = Zeroizing::new(String::new());
+        match head.read_line(&mut header) {
+            Ok(0) => return Err(bad("request header too large or truncated")),
+            Ok(_) => {}
+            Err(_) => return Err(bad("malformed header")),
+        }
+        let header = header.trim_end_matches(['\r', '\n']);
+        if header.is_empty() {
+            break;
+        }
+        let Some((name, value)) = header.split_once(':') else {
+            return Err(bad("malformed header"));
+        };
+        let value = value.trim();
+        match name.trim().to_ascii_lowercase().as_str() {
+            "host" => request.host = Some(value.to_string()),
+            "origin" => request.origin = true,
+            "authorization" => request.authorization = Some(Zeroizing::new(value.to_string())),
+            "content-type" => request.content_type = Some(value.to_ascii_lowercase()),
+            "content-length" => {
+                content_length = value.parse().map_err(|_| bad("invalid Content-Length"))?;
+            }
+            "transfer-encoding" => return Err(bad("chunked requests are not supported")),
+            _ => {}
+        }
+    }
+
+    if content_length > MAX_BODY {
+        return Err(ServiceError::PayloadTooLarge);
+    }
+    request.body.resize(content_length, 0);
+    reader
+        .read_exact(&mut request.body)
+        .map_err(|_| bad("truncated body"))?;
+    Ok(request)
+}
+
+fn write_response(stream: &mut TcpStream, status: u16, body: &[u8]) -> io::Result<()> {
+    let head = format!(
+        "HTTP/1.1 {} {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nCache-Control: no-store\r\n{}Connection: close\r\n\r\n",
+        status,
+        reason(status),
+        body.len(),
+        if status == 401 {
+            "WWW-Authenticate: Bearer\r\n"
+        } else {
+            ""
+        }
+    );
+    stream.write_all(head.as_bytes())?;
+    stream.write_all(body)?;
+    stream.flush()
+}
+
+fn reason(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        400 => "Bad Request",
+        401 => "Unauthorized",
+        403 => "Forbidden",
+        404 => "Not Found",
+        405 => "Method Not Allowed",
+        409 => "Conflict",
+        413 => "Payload Too Large",
+        503 => "Service Unavailable",
+        _ => "Internal Server Error",
+    }
+}
+
+impl ServiceError {
+    /// HTTP 상태 코드
+    pub fn status(&self) -> u16 {
+        match self {
+            ServiceError::BadRequest(_) => 400,
+            ServiceError::Unauthorized => 401,
+            ServiceError::Forbidden(_) | ServiceError::Policy(_) => 403,
+            ServiceError::NotFound(_) => 404,
+            ServiceError::Store(StoreError::Lookup(LookupError::NotFound(_))) => 404,
+            ServiceError::Store(StoreError::Lookup(LookupError::Ambiguous(_))) => 400,
+            ServiceError::Store(StoreError::Duplicate(_) | StoreError::LabelTaken(_)) => 409,
+            ServiceError::MethodNotAllowed => 405,
+            ServiceError::Inactive(..) => 409,
+            ServiceError::PayloadTooLarge => 413,
+            ServiceError::Busy => 503,
+            ServiceError::Store(_) | ServiceError::Internal(_) => 500,
+        }
+    }
+
+    /// 응답 본문의 `error` 코드
+    pub fn code(&self) -> &'static str {
+        match self {
+            ServiceError::BadRequest(_) => "bad_request",
+            ServiceError::Unauthorized => "unauthorized",
+            ServiceError::Forbidden(_) => "forbidden",
+            ServiceError::NotFound(_) => "not_found",
+            ServiceError::MethodNotAllowed => "method_not_allowed",
+            ServiceError::PayloadTooLarge => "payload_too_large",
+            ServiceError::Policy(_) => "policy",
+            ServiceError::Inactive(..) => "inactive_key",
+            ServiceError::Store(StoreError::Lookup(LookupError::NotFound(_))) => "not_found",
+            ServiceError::Store(_) => "store",
+            ServiceError::Busy => "busy",
+            ServiceError::Internal(_) => "internal",
+        }
+    }
+
+    fn body(&self) -> Zeroizing<Vec<u8>> {
+        to_json(&ErrorResponse {
+            error: self.code(),
+            message: self.to_string(),
+        })
+        .unwrap_or_default()
+    }
+}
+
+impl From<ExecutorError> for ServiceError {
+    fn from(e: ExecutorError) -> Self {
+        match e {
+            ExecutorError::QueueFull => ServiceError::Busy,
+            e => ServiceError::Internal(e.to_string()),
+        }
+    }
+}
+
+impl fmt::Display for ServiceError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ServiceError::BadRequest(msg)
+            | ServiceError::NotFound(msg)
+            | ServiceError::Internal(msg) => f.write_str(msg),
+            ServiceError::Unauthorized => f.write_str("missing or invalid bearer token"),
+            ServiceError::Forbidden(msg) => f.write_str(msg),
+            ServiceError::MethodNotAllowed => f.write_str("method not allowed"),
+            ServiceError::PayloadTooLarge => {
+                write!(f, "request body exceeds {} bytes", MAX_BODY)
+            }
+            // API 응답은 언어 설정과 관계없이 영어
+            ServiceError::Policy(v) => f.write_str(&v.message("en")),
+            ServiceError::Inactive(id, state) => {
+                write!(f, "key {} is not usable in state {}", id, state.as_str())
+            }
+            ServiceError::Store(e) => f.write_str(&e.message("en")),
+            ServiceError::Busy => f.write_str("too many concurrent requests"),
+        }
+    }
+}
+
+impl std::error::Error for ServiceError {}
+
+#[cfg(test)]
+mod tests {
+    use std::net::SocketAddr;
+
+    use super::*;
+    use crate::internals::test_util::ScratchDir;
+
+    const TOKEN: &str = "0123456789abcdef";
+
+    fn options(dir: &ScratchDir, token: &str) -> ServiceOptions {
+        ServiceOptions {
+            store: Some(Keystore::open(dir).unwrap()),
+            token: Zeroizing::new(token.to_string()),
+            policy: Policy::default(),
+            workers: 1,
+        }
+    }
+
+    /// 임의 포트에서 서비스를 띄우고 주소를 반환합니다. 서버 스레드는 테스트가 끝날 때 함께 끝납니다.
+    fn start(dir: &ScratchDir) -> SocketAddr {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let addr = listener.local_addr().unwrap();
+        let options = options(dir, TOKEN);
+        thread::spawn(move || serve(listener, options, |_| {}));
+        addr
+    }
+
+    /// 요청을 보내고 상태 코드와 본문을 반환합니다.
+    fn get(addr: SocketAddr, path: &str, token: Option<&str>) -> (u16, String) {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        let auth = token.map_or_else(String::new, |t| format!("Authorization: Bearer {}\r\n", t));
+        write!(
+            stream,
+            "GET {} HTTP/1.1\r\nHost: 127.0.0.1\r\n{}\r\n",
+            path, auth
+        )
+        .unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        let status = response[9..12].parse().unwrap();
+        let body = response.split("\r\n\r\n").nth(1).unwrap_or_default();
+        (status, body.to_string())
+    }
+
+    #[test]
+    fn token_is_required_on_every_request() {
+        let dir = ScratchDir::new("service", "token");
+        let addr = start(&dir);
+        assert_eq!(get(addr, "/v1/health", None).0, 401);
+        assert_eq!(get(addr, "/v1/keys", Some("wrong")).0, 401);
+        assert_eq!(get(addr, "/v1/keys", Some("")).0, 401);
+        assert_eq!(get(addr, "/v1/health", Some(TOKEN)).0, 200);
+        assert_eq!(
+            get(addr, "/v1/keys", Some(TOKEN)),
+            (200, "[]\n".to_string())
+        );
+        assert_eq!(get(addr, "/v1/keys/missing", Some(TOKEN)).0, 404);
+    }
+
+    #[test]
+    fn empty_token_refuses_to_start() {
+        let dir = ScratchDir::new("service", "empty-token");
+        for token in ["", "  \n"] {
+            let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+            let err = serve(listener, options(&dir, token), |_| {}).unwrap_err();
+            assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+        }
+    }
+
+    #[test]
+    fn generated_tokens_are_random_hex() {
+        let (a, b) = (generate_token().unwrap(), generate_token().unwrap());
+        assert_eq!(a.len(), TOKEN_BYTES * 2);
+        assert!(
+            a.bytes()
+                .all(|c| c.is_ascii_hexdigit() && !c.is_ascii_uppercase())
+        );
+        assert_ne!(a, b);
+    }
+}
@@ -0,0 +1,526 @@
+use std::io;
+
+use sha2::{Digest, Sha256};
+use zeroize::Zeroizing;
+
+use super::key_io::{from_pem, pem_len, push_pem};
+use super::keygen::{Algorithm, Variant, parse_algorithm, parse_variant};
+
+/// 공유 조각 PEM 라벨
+pub const SHARE_LABEL: &str = "KEYPAIRER KEY SHARE";
+
+/// 공유 조각 시작 표식
+const MAGIC: &[u8; 5] = b"KPSHR";
+
+/// 공유 조각 형식 버전
+const VERSION: u8 = 1;
+
+/// 같은 분할에서 나온 조각인지 구분하는 무작위 식별자 길이
+pub const SET_ID_LEN: usize = 8;
+
+/// 조각 끝에 붙는 체크섬(SHA-256 앞부분) 길이
+const CHECKSUM_LEN: usize = 8;
+
+/// 공개키 지문(SHA-256) 길이
+const FINGERPRINT_LEN: usize = 32;
+
+/// 공유 조각 생성/복원 실패 사유
+#[derive(Debug)]
+pub enum ShareError {
+    /// 임계값/조각 수가 허용 범위 밖 (2 ≤ k ≤ n ≤ 255)
+    InvalidThreshold(usize, usize),
+    /// 난수 생성 실패
+    Random(io::Error),
+    /// PEM 블록이 없거나 구조가 올바르지 않음
+    Malformed(&'static str),
+    /// 체크섬 불일치 (손상된 조각)
+    Checksum,
+    /// 서로 다른 분할(또는 키)의 조각이 섞임
+    Mismatch,
+    /// 같은 번호의 조각이 중복됨
+    Duplicate(u8),
+    /// 임계값보다 조각이 적음 (가진 수, 필요한 수)
+    NotEnough(usize, usize),
+}
+
+impl ShareError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        let tr = super::i18n::load_translations(lang);
+        let text = |key: &str, fallback: &str| {
+            tr.get(key).cloned().unwrap_or_else(|| fallback.to_string())
+        };
+        match self {
+            ShareError::InvalidThreshold(k, n) => text(
+                "error.share.threshold",
+                "임계값과 조각 수는 2 ≤ 임계값 ≤ 조각 수 ≤ 255여야 합니다 (임계값 {k}, 조각 수 {n}).",
+            )
+            .replace("{k}", &k.to_string())
+            .replace("{n}", &n.to_string()),
+            ShareError::Random(e) => text("error.share.random", "난수를 생성할 수 없습니다: {err}")
+                .replace("{err}", &e.to_string()),
+            ShareError::Malformed(what) => {
+                text("error.share.malformed", "공유 조각 형식이 올바르지 않습니다: {what}")
+                    .replace("{what}", what)
+            }
+            ShareError::Checksum => text(
+                "error.share.checksum",
+                "공유 조각의 체크섬이 맞지 않습니다. 조각이 손상되었습니다.",
+            ),
+            ShareError::Mismatch => text(
+                "error.share.mismatch",
+                "서로 다른 분할에서 나온 조각은 함께 쓸 수 없습니다.",
+            ),
+            ShareError::Duplicate(index) => {
+                text("error.share.duplicate", "같은 조각이 두 번 주어졌습니다: {index}번")
+                    .replace("{index}", &index.to_string())
+            }
+            ShareError::NotEnough(have, need) => text(
+                "error.share.not_enough",
+                "조각이 부족합니다: {have}개 (필요: {need}개)",
+            )
+            .replace("{have}", &have.to_string())
+            .replace("{need}", &need.to_string()),
+        }
+    }
+}
+
+/// 비밀키를 나눈 공유 조각 하나
+///
+/// ```text
+/// "KPSHR" | 버전(u8) | 분할 ID(8) | 임계값(u8) | 조각 수(u8) | 번호(u8)
+///         | 알고리즘 길이(u8) | 알고리즘 | 배리언트 길이(u8) | 배리언트
+///         | 공개키 지문(32) | 조각 길이(u32 BE) | 조각 | 체크섬(SHA-256 앞 8바이트)
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Share {
+    pub set_id: [u8; SET_ID_LEN],
+    pub threshold: u8,
+    pub total: u8,
+    /// 조각 번호 (1부터, 다항식의 x 좌표)
+    pub index: u8,
+    pub algorithm: Algorithm,
+    pub variant: Variant,
+    /// 원래 키 페어의 공개키 지문 (16진수)
+    pub fingerprint: String,
+    pub data: Zeroizing<Vec<u8>>,
+}
+
+impl Share {
+    /// 조각을 바이너리 형식으로 직렬화합니다. 지문이 64자리 16진수가 아니면 실패합니다.
+    pub fn to_bytes(&self) -> Result<Zeroizing<Vec<u8>>, ShareError> {
+        let fingerprint = decode_hex(&self.fingerprint)
+            .filter(|fp| fp.len() == FINGERPRINT_LEN)
+            .ok_or(ShareError::Malformed("fingerprint"))?;
+        // 재할당으로 조각 사본이 남지 않도록 전체 길이를 미리 계산
+        let names = self.algorithm.as_str().len() + self.variant.as_str().len() + 2;
+        let len = MAGIC.len() + 1 + SET_ID_LEN + 3 + names + FINGERPRINT_LEN + 4;
+        let mut out = Zeroizing::new(Vec::with_capacity(len + self.data.len() + CHECKSUM_LEN));
+        out.extend_from_slice(MAGIC);
+        out.push(VERSION);
+        out.extend_from_slice(&self.set_id);
+        out.extend_from_slice(&[self.threshold, self.total, self.index]);
+        for s in [self.algorithm.as_str(), self.variant.as_str()] {
+            out.push(s.len() as u8);
+            out.extend_from_slice(s.as_bytes());
+        }
+        out.extend_from_slice(&fingerprint);
+        out.extend_from_slice(&(self.data.len() as u32).to_be_bytes());
+        out.extend_from_slice(&self.data);
+        let checksum = Sha256::digest(&out[..]);
+        out.extend_from_slice(&checksum[..CHECKSUM_LEN]);
+        Ok(out)
+    }
+
+    pub fn from_bytes(bytes: &[u8]) -> Result<Share, ShareError> {
+        if bytes.len() < MAGIC.len() + CHECKSUM_LEN || &bytes[..MAGIC.len()] != MAGIC {
+            return Err(ShareError::Malformed("magic"));
+        }
+        let (body, checksum) = bytes.split_at(bytes.len() - CHECKSUM_LEN);
+        if Sha256::digest(body)[..CHECKSUM_LEN] != *checksum {
+            return Err(ShareError::Checksum);
+        }
+
+        let mut pos = MAGIC.len();
+        let mut take = |n: usize| -> Result<&[u8], ShareError> {
+            let slice = body
+                .get(pos..pos + n)
+                .ok_or(ShareError::Malformed("truncated"))?;
+            pos += n;
+            Ok(slice)
+        };
+        if take(1)?[0] != VERSION {
+            return Err(ShareError::Malformed("version"));
+        }
+        let mut set_id = [0u8; SET_ID_LEN];
+        set_id.copy_from_slice(take(SET_ID_LEN)?);
+        let [threshold, total, index] = take(3)? else {
+            return Err(ShareError::Malformed("header"));
+        };
+        let (threshold, total, index) = (*threshold, *total, *index);
+        if threshold < 2 || threshold > total || index == 0 || index > total {
+            return Err(ShareError::Malformed("threshold"));
+        }
+
+        let mut names = Vec::new();
+        for _ in 0..2 {
+            let len = take(1)?[0] as usize;
+            let name =
+                std::str::from_utf8(take(len)?).map_err(|_| ShareError::Malformed("name"))?;
+            names.push(name.to_string());
+        }
+        let algorithm = parse_algorithm(&names[0])
+            .filter(|a| *a != Algorithm::Unknown)
+            .ok_or(ShareError::Malformed("algorithm"))?;
+        let variant =
+            parse_variant(algorithm, &names[1]).ok_or(ShareError::Malformed("variant"))?;
+        let fingerprint = encode_hex(take(FINGERPRINT_LEN)?);
+        let len = u32::from_be_bytes(take(4)?.try_into().unwrap_or_default()) as usize;
+        let data = Zeroizing::new(take(len)?.to_vec());
+        if pos != body.len() {
+            return Err(ShareError::Malformed("trailing data"));
+        }
+
+        Ok(Share {
+            set_id,
+            threshold,
+            total,
+            index,
+            algorithm,
+            variant,
+            fingerprint,
+            data,
+        })
+    }
+
+    /// 사람이 읽을 수 있는 설명을 앞에 붙인 PEM 텍스트로 변환합니다.
+    pub fn to_armored(&self) -> Result<Zeroizing<String>, ShareError> {
+        // 설명 줄은 비밀이 아니지만, 조각을 담는 버퍼는 재할당되지 않도록 한 번에 잡음
+        let header = format!(
+            "keypairer key share {}/{} (threshold {})\nkey: {}\nfingerprint: {}\nset: {}\n",
+            self.index,
+            self.total,
+            self.threshold,
+            self.variant.standard_name(),
+            self.fingerprint,
+            encode_hex(&self.set_id),
+        );
+        let bytes = self.to_bytes()?;
+        let mut out = Zeroizing::new(String::with_capacity(
+            header.len() + pem_len(SHARE_LABEL, bytes.len()),
+        ));
+        out.push_str(&header);
+        push_pem(&mut out, SHARE_LABEL, &bytes);
+        Ok(out)
+    }
+
+    /// PEM 텍스트에서 공유 조각을 읽습니다. 블록 앞의 설명은 무시합니다.
+    pub fn from_armored(text: &str) -> Result<Share, ShareError> {
+        let bytes = from_pem(SHARE_LABEL, text).ok_or(ShareError::Malformed("PEM"))?;
+        Share::from_bytes(&bytes)
+    }
+}
+
+/// GF(2^8) 곱셈 (AES 다항식 x^8 + x^4 + x^3 + x + 1). 분기와 테이블 없이 계산합니다.
+fn gf_mul(mut a: u8, mut b: u8) -> u8 {
+    let mut product = 0u8;
+    for _ in 0..8 {
+        product ^= a & 0u8.wrapping_sub(b & 1);
+        let carry = a >> 7;
+        a = (a << 1) ^ (0x1b & 0u8.wrapping_sub(carry));
+        b >>= 1;
+    }
+    product
+}
+
+/// GF(2^8) 역원 (a^254)
+fn gf_inv(a: u8) -> u8 {
+    let mut result = 1u8;
+    let mut base = a;
+    let mut exp = 254u8;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = gf_mul(result, base);
+        }
+        base = gf_mul(base, base);
+        exp >>= 1;
+    }
+    result
+}
+
+/// 분할 결과 한 건: (조각 번호, 조각 데이터)
+pub type RawShare = (u8, Zeroizing<Vec<u8>>);
+
+/// 비밀을 임계값 `threshold`, 조각 수 `total`로 나눕니다.
+///
+/// 바이트마다 상수항이 비밀인 `threshold - 1`차 무작위 다항식을 만들고 x = 1..=total에서 평가합니다.
+/// 조각의 메타데이터(알고리즘, 지문, 분할 ID)는 호출자가 채웁니다.
+pub fn split(secret: &[u8], threshold: usize, total: usize) -> Result<Vec<RawShare>, ShareError> {
+    if threshold < 2 || threshold > total || total > 255 {
+        return Err(ShareError::InvalidThreshold(threshold, total));
+    }
+    let degree = threshold - 1;
+    let mut coefficients = Zeroizing::new(vec![0u8; degree * secret.len()]);
+    getrandom::fill(&mut coefficients)
+        .map_err(|e| ShareError::Random(io::Error::other(e.to_string())))?;
+
+    let shares = (1..=total as u8)
+        .map(|x| {
+            let data = secret
+                .iter()
+                .enumerate()
+                .map(|(i, &s)| {
+                    // 호너 방법: 최고차항부터
+                    let coeffs = &coefficients[i * degree..(i + 1) * degree];
+                    let high = coeffs.iter().rev().fold(0u8, |acc, &c| gf_mul(acc, x) ^ c);
+                    gf_mul(high, x) ^ s
+                })
+                .collect::<Vec<u8>>();
+            (x, Zeroizing::new(data))
+        })
+        .collect();
+    Ok(shares)
+}
+
+/// 공유 조각들을 검사하고 라그랑주 보간으로 비밀을 복원합니다.
+///
+/// 모든 조각의 분할 ID, 임계값, 키 정보가 같아야 하며 번호가 겹치지 않아야 합니다.
+pub fn combine(shares: &[Share]) -> Result<Zeroizing<Vec<u8>>, ShareError> {
+    let first = shares.first().ok_or(ShareError::NotEnough(0, 2))?;
+    for share in shares {
+        let same = share.set_id == first.set_id
+            && share.threshold == first.threshold
+            && share.total == first.total
+            && share.variant == first.variant
+            && share.fingerprint == first.fingerprint
+            && share.data.len() == first.data.len();
+        if !same {
+            return Err(ShareError::Mismatch);
+        }
+    }
+    let mut seen = [false; 256];
+    for share in shares {
+        if std::mem::replace(&mut seen[share.index as usize], true) {
+            return Err(ShareError::Duplicate(share.index));
+        }
+    }
+    let need = first.threshold as usize;
+    if shares.len() < need {
+        return Err(ShareError::NotEnough(shares.len(), need));
+    }
+
+    // x = 0에서의 라그랑주 기저: l_i = Π x_j / (x_j - x_i)
+    let used = &shares[..need];
+    let basis: Vec<u8> = used
+        .iter()
+        .map(|si| {
+            used.iter()
+                .filter(|sj| sj.index != si.index)
+                .fold(1u8, |acc, sj| {
+                    gf_mul(acc, gf_mul(sj.index, gf_inv(sj.index ^ si.index)))
+                })
+        })
+        .collect();
+    let mut secret = Zeroizing::new(vec![0u8; first.data.len()]);
+    for (share, &l) in used.iter().zip(&basis) {
+        for (out, &y) in secret.iter_mut().zip(share.data.iter()) {
+            *out ^= gf_mul(y, l);
+        }
+    }
+    Ok(secret)
+}
+
+/// 분할 ID를 무작위로 만듭니다.
+pub fn new_set_id() -> Result<[u8; SET_ID_LEN], ShareError> {
+    let mut id = [0u8; SET_ID_LEN];
+    getrandom::fill(&mut id).map_err(|e| ShareError::Random(io::Error::other(e.to_string())))?;
+    Ok(id)
+}
+
+fn encode_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02x}", b)).collect()
+}
+
+fn decode_hex(hex: &str) -> Option<Vec<u8>> {
+    if !hex.len().is_multiple_of(2) {
+        return None;
+    }
+    (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(hex.get(i..i + 2)?, 16).ok())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use zeroize::Zeroizing;
+
+    use super::*;
+    use crate::internals::keygen::{Algorithm, Variant};
+
+    const FINGERPRINT: &str = "00112233445566778899aabbccddeeff00112233445566778899aabbccddeeff";
+
+    fn shares_for(secret: &[u8], threshold: usize, total: usize, set_id: [u8; 8]) -> Vec<Share> {
+        split(secret, threshold, total)
+            .unwrap()
+            .into_iter()
+            .map(|(index, data)| Share {
+                set_id,
+                threshold: threshold as u8,
+                total: total as u8,
+                index,
+                algorithm: Algorithm::MLKEM,
+                variant: Variant::MLKEM512,
+                fingerprint: FINGERPRINT.to_string(),
+                data,
+            })
+            .collect()
+    }
+
+    /// 0..=255를 모두 포함해 모든 바이트 값이 보간되는지 확인
+    fn sample_secret() -> Vec<u8> {
+        (0..=255u8).chain([0, 0, 255, 1]).collect()
+    }
+
+    #[test]
+    fn every_subset_of_at_least_threshold_shares_recovers_the_secret() {
+        let secret = sample_secret();
+        for total in 2..=6usize {
+            for threshold in 2..=total {
+                let shares = shares_for(&secret, threshold, total, [1; 8]);
+                for mask in 1u32..(1 << total) {
+                    let subset: Vec<Share> = shares
+                        .iter()
+                        .enumerate()
+                        .filter(|(i, _)| mask & (1 << i) != 0)
+                        .map(|(_, s)| s.clone())
+                        .collect();
+                    let result = combine(&subset);
+                    if subset.len() >= threshold {
+                        assert_eq!(
+                            result.unwrap().as_slice(),
+                            secret.as_slice(),
+                            "{threshold}-of-{total}, subset {mask:#b}"
+                        );
+                    } else {
+                        assert!(
+                            matches!(result, Err(ShareError::NotEnough(have, need))
+                                if have == subset.len() && need == threshold),
+                            "{threshold}-of-{total}, subset {mask:#b}"
+                        );
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn fewer_than_threshold_shares_do_not_reveal_the_secret() {
+        // 임계값 미만의 조각을 임계값인 척 보간하면 원래 비밀과 달라야 함
+        let secret = sample_secret();
+        let mut shares = shares_for(&secret, 3, 5, [2; 8]);
+        shares.truncate(2);
+        for share in &mut shares {
+            share.threshold = 2;
+        }
+        assert_ne!(combine(&shares).unwrap().as_slice(), secret.as_slice());
+    }
+
+    #[test]
+    fn threshold_must_be_between_two_and_total() {
+        for (k, n) in [(1, 3), (4, 3), (2, 256), (0, 0)] {
+            assert!(
+                matches!(split(b"secret", k, n), Err(ShareError::InvalidThreshold(a, b)) if a == k && b == n),
+                "{k}-of-{n}"
+            );
+        }
+        assert_eq!(split(b"secret", 255, 255).unwrap().len(), 255);
+    }
+
+    #[test]
+    fn duplicate_shares_are_rejected() {
+        let shares = shares_for(b"secret", 2, 3, [3; 8]);
+        let duplicated = [shares[1].clone(), shares[1].clone()];
+        assert!(matches!(
+            combine(&duplicated),
+            Err(ShareError::Duplicate(2))
+        ));
+    }
+
+    #[test]
+    fn shares_from_different_splits_are_rejected() {
+        let a = shares_for(b"secret", 2, 3, [4; 8]);
+        let b = shares_for(b"secret", 2, 3, [5; 8]);
+        assert!(matches!(
+            combine(&[a[0].clone(), b[1].clone()]),
+            Err(ShareError::Mismatch)
+        ));
+
+        let mut other_key = a[1].clone();
+        other_key.fingerprint = FINGERPRINT.replace("00", "ff");
+        assert!(matches!(
+            combine(&[a[0].clone(), other_key]),
+            Err(ShareError::Mismatch)
+        ));
+
+        let mut other_variant = a[1].clone();
+        other_variant.variant = Variant::MLKEM768;
+        assert!(matches!(
+            combine(&[a[0].clone(), other_variant]),
+            Err(ShareError::Mismatch)
+        ));
+
+        let mut truncated = a[1].clone();
+        truncated.data = Zeroizing::new(truncated.data[1..].to_vec());
+        assert!(matches!(
+            combine(&[a[0].clone(), truncated]),
+            Err(ShareError::Mismatch)
+        ));
+
+        assert!(matches!(combine(&[]), Err(ShareError::NotEnough(0, 2))));
+    }
+
+    #[test]
+    fn armored_shares_round_trip_and_detect_corruption() {
+        let secret = sample_secret();
+        let shares = shares_for(&secret, 2, 3, [6; 8]);
+        let parsed: Vec<Share> = shares
+            .iter()
+            .map(|s| Share::from_armored(&s.to_armored().unwrap()).unwrap())
+            .collect();
+        assert_eq!(parsed, shares);
+        assert_eq!(combine(&parsed[1..]).unwrap().as_slice(), secret.as_slice());
+
+        let mut bytes = shares[0].to_bytes().unwrap();
+        let middle = bytes.len() / 2;
+        bytes[middle] ^= 0x01;
+        assert!(matches!(
+            Share::from_bytes(&bytes),
+            Err(ShareError::Checksum)
+        ));
+        assert!(matches!(
+            Share::from_armored("no share here"),
+            Err(ShareError::Malformed(_))
+        ));
+    }
+
+    #[test]
+    fn invalid_fingerprints_are_rejected_instead_of_zeroed() {
+        let share = shares_for(b"secret", 2, 2, [7; 8]).remove(0);
+        for fingerprint in [
+            "",
+            "abc",
+            "zz",
+            &FINGERPRINT[2..],
+            &format!("{FINGERPRINT}00"),
+        ] {
+            let mut bad = share.clone();
+            bad.fingerprint = fingerprint.to_string();
+            assert!(
+                matches!(bad.to_bytes(), Err(ShareError::Malformed("fingerprint"))),
+                "{fingerprint:?}"
+            );
+            assert!(bad.to_armored().is_err(), "{fingerprint:?}");
+        }
+    }
+}
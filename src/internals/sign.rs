@@ -0,0 +1,268 @@
+use std::error::Error;
+use std::fmt;
+
+use pqcrypto::sign::{
+    falcon512, falcon1024, falconpadded512, falconpadded1024, mldsa44, mldsa65, mldsa87,
+    sphincssha2128fsimple, sphincssha2128ssimple, sphincssha2192fsimple, sphincssha2192ssimple,
+    sphincssha2256fsimple, sphincssha2256ssimple, sphincsshake128fsimple, sphincsshake128ssimple,
+    sphincsshake192fsimple, sphincsshake192ssimple, sphincsshake256fsimple, sphincsshake256ssimple,
+};
+use pqcrypto_traits::sign::{DetachedSignature as _, PublicKey as _, SecretKey as _};
+
+use super::keygen::Variant;
+use super::secret::wipe_key;
+
+/// 서명 연산 실패 사유
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum SignError {
+    /// 서명 알고리즘이 아닌 배리언트 (예: KEM)
+    NotSignature(Variant),
+    /// 키 또는 서명의 길이가 배리언트와 맞지 않음
+    InvalidInput(&'static str),
+}
+
+impl fmt::Display for SignError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SignError::NotSignature(v) => {
+                write!(f, "{} is not a signature scheme", v.standard_name())
+            }
+            SignError::InvalidInput(what) => write!(f, "invalid {}", what),
+        }
+    }
+}
+
+impl Error for SignError {}
+
+/// 배리언트에 해당하는 pqcrypto 모듈로 `$body`를 실행합니다. 서명 알고리즘이 아니면 `$fallback`입니다.
+///
+/// 각 갈래는 클로저로 감싸 별도 함수 프레임에서 실행합니다. 최적화하지 않은 빌드는 모든 갈래의
+/// 지역 변수(SPHINCS+-256f 서명은 약 49 KB, ML-DSA-87 비밀키는 약 5 KB)에 스택 슬롯을 따로
+/// 잡으므로, 한 프레임에 두면 SPHINCS+ 12종과 ML-DSA, FALCON의 합만큼 스택이 필요합니다.
+/// 서명 자체도 C 구현이 행렬과 해시 트리 버퍼를 스택에 두므로 [`crate::run_with_variant_stack`]
+/// 안에서 호출하세요.
+macro_rules! with_sig {
+    ($variant:expr, $m:ident => $body:expr, _ => $fallback:expr) => {
+        match $variant {
+            Variant::FALCONNoPad512 => {
+                use falcon512 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::FALCONNoPad1024 => {
+                use falcon1024 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::FALCONPadded512 => {
+                use falconpadded512 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::FALCONPadded1024 => {
+                use falconpadded1024 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::MLDSA44 => {
+                use mldsa44 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::MLDSA65 => {
+                use mldsa65 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::MLDSA87 => {
+                use mldsa87 as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSsha2128fsimple => {
+                use sphincssha2128fsimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSsha2128ssimple => {
+                use sphincssha2128ssimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSsha2192fsimple => {
+                use sphincssha2192fsimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSsha2192ssimple => {
+                use sphincssha2192ssimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSsha2256fsimple => {
+                use sphincssha2256fsimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSsha2256ssimple => {
+                use sphincssha2256ssimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSshake128fsimple => {
+                use sphincsshake128fsimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSshake128ssimple => {
+                use sphincsshake128ssimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSshake192fsimple => {
+                use sphincsshake192fsimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSshake192ssimple => {
+                use sphincsshake192ssimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSshake256fsimple => {
+                use sphincsshake256fsimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            Variant::SPHINCSshake256ssimple => {
+                use sphincsshake256ssimple as $m;
+                #[allow(clippy::redundant_closure_call)]
+                let run = || $body;
+                run()
+            }
+            _ => $fallback,
+        }
+    };
+}
+
+/// 지원하는 모든 서명 배리언트
+pub const SIGNATURE_VARIANTS: [Variant; 19] = [
+    Variant::FALCONNoPad512,
+    Variant::FALCONNoPad1024,
+    Variant::FALCONPadded512,
+    Variant::FALCONPadded1024,
+    Variant::MLDSA44,
+    Variant::MLDSA65,
+    Variant::MLDSA87,
+    Variant::SPHINCSsha2128fsimple,
+    Variant::SPHINCSsha2128ssimple,
+    Variant::SPHINCSsha2192fsimple,
+    Variant::SPHINCSsha2192ssimple,
+    Variant::SPHINCSsha2256fsimple,
+    Variant::SPHINCSsha2256ssimple,
+    Variant::SPHINCSshake128fsimple,
+    Variant::SPHINCSshake128ssimple,
+    Variant::SPHINCSshake192fsimple,
+    Variant::SPHINCSshake192ssimple,
+    Variant::SPHINCSshake256fsimple,
+    Variant::SPHINCSshake256ssimple,
+];
+
+/// 배리언트가 서명 알고리즘(ML-DSA, FALCON, SPHINCS+)인지 여부
+pub fn is_signature(variant: &Variant) -> bool {
+    public_key_len(variant).is_some()
+}
+
+/// 배리언트의 공개키 길이(바이트). 서명 알고리즘이 아니면 `None`입니다.
+pub fn public_key_len(variant: &Variant) -> Option<usize> {
+    with_sig!(variant, m => Some(m::public_key_bytes()), _ => None)
+}
+
+/// 배리언트의 비밀키 길이(바이트). 서명 알고리즘이 아니면 `None`입니다.
+pub fn secret_key_len(variant: &Variant) -> Option<usize> {
+    with_sig!(variant, m => Some(m::secret_key_bytes()), _ => None)
+}
+
+/// 배리언트의 분리 서명 최대 길이(바이트). 서명 알고리즘이 아니면 `None`입니다.
+///
+/// FALCON(패딩 없음)의 서명은 이보다 짧을 수 있습니다.
+pub fn signature_len(variant: &Variant) -> Option<usize> {
+    with_sig!(variant, m => Some(m::signature_bytes()), _ => None)
+}
+
+/// 공개키 길이와 일치하는 서명 배리언트 목록
+pub fn variants_for_public_key(len: usize) -> Vec<Variant> {
+    SIGNATURE_VARIANTS
+        .iter()
+        .filter(|v| public_key_len(v) == Some(len))
+        .cloned()
+        .collect()
+}
+
+/// 비밀키 길이와 일치하는 서명 배리언트 목록
+///
+/// SPHINCS+의 해시(SHA2/SHAKE)나 `f`/`s` 배리언트처럼 길이가 같은 경우 여러 개가 반환됩니다.
+pub fn variants_for_secret_key(len: usize) -> Vec<Variant> {
+    SIGNATURE_VARIANTS
+        .iter()
+        .filter(|v| secret_key_len(v) == Some(len))
+        .cloned()
+        .collect()
+}
+
+/// 비밀키로 메시지의 분리 서명을 만듭니다.
+pub fn sign(variant: &Variant, sk_bytes: &[u8], message: &[u8]) -> Result<Vec<u8>, SignError> {
+    with_sig!(variant, m => {
+        let mut sk = m::SecretKey::from_bytes(sk_bytes)
+            .map_err(|_| SignError::InvalidInput("secret key"))?;
+        let signature = m::detached_sign(message, &sk);
+        wipe_key(&mut sk);
+        Ok(signature.as_bytes().to_vec())
+    }, _ => Err(SignError::NotSignature(variant.clone())))
+}
+
+/// 공개키로 분리 서명을 검증합니다. 서명이 맞지 않으면 `Ok(false)`입니다.
+pub fn verify(
+    variant: &Variant,
+    pk_bytes: &[u8],
+    message: &[u8],
+    signature: &[u8],
+) -> Result<bool, SignError> {
+    with_sig!(variant, m => {
+        let pk = m::PublicKey::from_bytes(pk_bytes)
+            .map_err(|_| SignError::InvalidInput("public key"))?;
+        let Ok(sig) = m::DetachedSignature::from_bytes(signature) else {
+            return Ok(false);
+        };
+        Ok(m::verify_detached_signature(&sig, message, &pk).is_ok())
+    }, _ => Err(SignError::NotSignature(variant.clone())))
+}
+
+/// 비밀키로 만든 서명이 공개키로 검증되는지로 두 키가 한 쌍인지 확인합니다.
+pub fn pair_matches(
+    variant: &Variant,
+    pk_bytes: &[u8],
+    sk_bytes: &[u8],
+) -> Result<bool, SignError> {
+    const PROBE: &[u8] = b"keypairer/pair-check";
+    let signature = sign(variant, sk_bytes, PROBE)?;
+    verify(variant, pk_bytes, PROBE, &signature)
+}
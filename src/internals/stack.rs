@@ -0,0 +1,153 @@
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+use super::keygen::Variant;
+
+/// 키 생성 스레드의 스택 크기를 덮어쓰는 환경 변수
+pub const STACK_SIZE_ENV: &str = "KEYPAIRER_STACK_SIZE";
+
+/// 배리언트를 모를 때 쓰는 스택 크기로, 가장 큰 배리언트 예산과 같습니다 (24 MiB).
+pub const DEFAULT_STACK_SIZE: usize = 24 * MIB;
+
+/// 지정할 수 있는 가장 작은 스택 크기 (64 KiB)
+pub const MIN_STACK_SIZE: usize = 64 * KIB;
+
+const KIB: usize = 1024;
+const MIB: usize = 1024 * KIB;
+
+/// [`set_override`]로 지정한 크기 (0이면 없음)
+static OVERRIDE: AtomicUsize = AtomicUsize::new(0);
+
+/// 스택 크기 문자열 해석 오류 (해석하지 못한 값)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StackSizeError(pub String);
+
+impl Variant {
+    /// 키 생성과 캡슐화·역캡슐화 또는 서명·검증을 마치는 데 필요한 스택 예산(바이트)을 반환합니다.
+    ///
+    /// 최적화하지 않은 빌드에서 잰 최대 사용량(`bench -n 1`)의 두 배 이상입니다. Classic McEliece는
+    /// 키 생성 중 공개키 행렬(최대 약 1.3 MiB)과 작업 버퍼를 스택에 두므로 메가바이트 단위가
+    /// 필요하고, SPHINCS+는 하이퍼트리 서명 버퍼 때문에 격자 기반 배리언트보다 큽니다.
+    pub fn stack_size(&self) -> usize {
+        match self {
+            // 측정값: 335–351 KiB
+            Variant::MLKEM512 | Variant::MLKEM768 | Variant::MLKEM1024 => MIB,
+            // 111–303 KiB
+            Variant::HQC128 | Variant::HQC192 | Variant::HQC256 => MIB,
+            // 2.0 MiB
+            Variant::McEliece348864 | Variant::McEliece348864f => 4 * MIB,
+            // 4.0 MiB
+            Variant::McEliece460896 | Variant::McEliece460896f => 8 * MIB,
+            // 8.0–10.4 MiB
+            Variant::McEliece6688128
+            | Variant::McEliece6688128f
+            | Variant::McEliece6960119
+            | Variant::McEliece6960119f
+            | Variant::McEliece8192128
+            | Variant::McEliece8192128f => 24 * MIB,
+            // 79–127 KiB
+            Variant::FALCONNoPad512
+            | Variant::FALCONPadded512
+            | Variant::FALCONNoPad1024
+            | Variant::FALCONPadded1024 => 512 * KIB,
+            // 95–175 KiB
+            Variant::MLDSA44 | Variant::MLDSA65 | Variant::MLDSA87 => 512 * KIB,
+            // 287–479 KiB
+            Variant::SPHINCSsha2128fsimple
+            | Variant::SPHINCSsha2128ssimple
+            | Variant::SPHINCSshake128fsimple
+            | Variant::SPHINCSshake128ssimple
+            | Variant::SPHINCSsha2192fsimple
+            | Variant::SPHINCSsha2192ssimple
+            | Variant::SPHINCSshake192fsimple
+            | Variant::SPHINCSshake192ssimple
+            | Variant::SPHINCSsha2256fsimple
+            | Variant::SPHINCSsha2256ssimple
+            | Variant::SPHINCSshake256fsimple
+            | Variant::SPHINCSshake256ssimple => MIB,
+            Variant::Unknown => DEFAULT_STACK_SIZE,
+        }
+    }
+}
+
+/// `65536`, `512K`, `8M`, `1G` 형식(접미사는 1024 단위, 대소문자 무시, `iB`/`B` 생략 가능)의
+/// 스택 크기를 해석합니다.
+pub fn parse_size(spec: &str) -> Result<usize, StackSizeError> {
+    let err = || StackSizeError(spec.to_string());
+    let lowered = spec.trim().to_lowercase();
+    let number = lowered
+        .strip_suffix("ib")
+        .or_else(|| lowered.strip_suffix('b'))
+        .unwrap_or(&lowered);
+    let (digits, unit) = match number.char_indices().last() {
+        Some((i, 'k')) => (&number[..i], KIB),
+        Some((i, 'm')) => (&number[..i], MIB),
+        Some((i, 'g')) => (&number[..i], 1024 * MIB),
+        _ => (number, 1),
+    };
+    let size = digits
+        .trim()
+        .parse::<usize>()
+        .ok()
+        .and_then(|n| n.checked_mul(unit))
+        .ok_or_else(err)?;
+    if size < MIN_STACK_SIZE {
+        return Err(err());
+    }
+    Ok(size)
+}
+
+/// 모든 배리언트에 쓸 스택 크기를 지정하거나(`Some`) 배리언트별 예산으로 되돌립니다(`None`).
+pub fn set_override(size: Option<usize>) {
+    OVERRIDE.store(size.unwrap_or(0), Ordering::Relaxed);
+}
+
+/// [`set_override`]로 지정한 스택 크기
+pub fn override_size() -> Option<usize> {
+    match OVERRIDE.load(Ordering::Relaxed) {
+        0 => None,
+        size => Some(size),
+    }
+}
+
+/// [`STACK_SIZE_ENV`]가 있으면 해석해 [`set_override`]로 적용합니다.
+pub fn apply_env_override() -> Result<(), StackSizeError> {
+    match std::env::var(STACK_SIZE_ENV) {
+        Ok(value) if !value.trim().is_empty() => {
+            set_override(Some(parse_size(&value)?));
+            Ok(())
+        }
+        _ => Ok(()),
+    }
+}
+
+/// 배리언트 하나를 처리할 스레드의 스택 크기 (덮어쓴 값이 있으면 그 값)
+pub fn stack_size_for(variant: &Variant) -> usize {
+    override_size().unwrap_or_else(|| variant.stack_size())
+}
+
+/// 여러 배리언트를 번갈아 처리할 스레드의 스택 크기 (가장 큰 예산, 덮어쓴 값이 있으면 그 값)
+pub fn stack_size_for_all<'a>(variants: impl IntoIterator<Item = &'a Variant>) -> usize {
+    override_size().unwrap_or_else(|| {
+        variants
+            .into_iter()
+            .map(Variant::stack_size)
+            .max()
+            .unwrap_or(DEFAULT_STACK_SIZE)
+    })
+}
+
+/// 배리언트를 모르는 작업의 스택 크기 (덮어쓴 값이 있으면 그 값)
+pub fn default_stack_size() -> usize {
+    override_size().unwrap_or(DEFAULT_STACK_SIZE)
+}
+
+impl StackSizeError {
+    /// 번역된 오류 메시지를 반환합니다.
+    pub fn message(&self, lang: &str) -> String {
+        super::i18n::load_translations(lang)
+            .get("error.stack.size")
+            .cloned()
+            .unwrap_or_else(|| "잘못된 스택 크기: {value} (예: 512K, 8M, 최소 64K)".to_string())
+            .replace("{value}", &self.0)
+    }
+}
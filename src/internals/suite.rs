@@ -0,0 +1,138 @@
+use super::keygen::Variant;
+
+/// KEM 키 페어와 서명 키 페어를 함께 만드는 이름 있는 보안 묶음
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Suite {
+    pub name: &'static str,
+    pub kem: Variant,
+    pub sign: Variant,
+}
+
+/// 일괄 생성 작업 ID와 파일 이름에 쓰는 KEM 키 페어 역할 이름
+pub const KEM_ROLE: &str = "kem";
+
+/// 일괄 생성 작업 ID와 파일 이름에 쓰는 서명 키 페어 역할 이름
+pub const SIGN_ROLE: &str = "sig";
+
+/// 내장 묶음 목록
+pub const SUITES: &[Suite] = &[
+    // CNSA 2.0: 모든 용도에 NIST 범주 5
+    Suite {
+        name: "cnsa2",
+        kem: Variant::MLKEM1024,
+        sign: Variant::MLDSA87,
+    },
+    // NIST 범주 1 (ML-DSA는 가장 낮은 세트가 범주 2)
+    Suite {
+        name: "nist-l1",
+        kem: Variant::MLKEM512,
+        sign: Variant::MLDSA44,
+    },
+    Suite {
+        name: "nist-l3",
+        kem: Variant::MLKEM768,
+        sign: Variant::MLDSA65,
+    },
+    // 격자 가정에 기대지 않는 조합: 부호 기반 KEM + 해시 기반 서명
+    Suite {
+        name: "conservative",
+        kem: Variant::McEliece6688128,
+        sign: Variant::SPHINCSshake256ssimple,
+    },
+    // 공개키·서명 크기가 가장 작은 조합
+    Suite {
+        name: "compact",
+        kem: Variant::MLKEM512,
+        sign: Variant::FALCONNoPad512,
+    },
+];
+
+/// 이름(대소문자 무시, `_`는 `-`와 같게 취급)으로 묶음을 찾습니다.
+pub fn find_suite(name: &str) -> Option<&'static Suite> {
+    let name = name.trim().to_lowercase().replace('_', "-");
+    SUITES.iter().find(|suite| suite.name == name)
+}
+
+impl Suite {
+    /// 역할 이름과 배리언트 (KEM, 서명 순)
+    pub fn pairs(&self) -> [(&'static str, &Variant); 2] {
+        [(KEM_ROLE, &self.kem), (SIGN_ROLE, &self.sign)]
+    }
+
+    /// `ML-KEM-1024 + ML-DSA-87` 형식의 설명
+    pub fn describe(&self) -> String {
+        format!(
+            "{} + {}",
+            self.kem.standard_name(),
+            self.sign.standard_name()
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::internals::kem::is_kem;
+    use crate::internals::keygen::Algorithm;
+    use crate::internals::sign::is_signature;
+
+    #[test]
+    fn names_are_matched_loosely_but_not_guessed() {
+        for input in ["cnsa2", "CNSA2", " cnsa2\n"] {
+            assert_eq!(
+                find_suite(input).map(|s| s.name),
+                Some("cnsa2"),
+                "{input:?}"
+            );
+        }
+        for input in ["nist_l3", "NIST-L3", "Nist_L3"] {
+            assert_eq!(
+                find_suite(input).map(|s| s.name),
+                Some("nist-l3"),
+                "{input:?}"
+            );
+        }
+        for input in ["", "nist", "nist l3", "nistl3", "cnsa", "cnsa2.0"] {
+            assert_eq!(find_suite(input), None, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn every_suite_pairs_a_kem_with_a_signature_scheme() {
+        let mut names: Vec<&str> = SUITES.iter().map(|s| s.name).collect();
+        names.sort();
+        names.dedup();
+        assert_eq!(names.len(), SUITES.len());
+
+        for suite in SUITES {
+            assert!(is_kem(&suite.kem), "{}", suite.name);
+            assert!(is_signature(&suite.sign), "{}", suite.name);
+            assert_eq!(find_suite(suite.name), Some(suite));
+            assert_eq!(
+                suite.pairs(),
+                [(KEM_ROLE, &suite.kem), (SIGN_ROLE, &suite.sign)]
+            );
+        }
+    }
+
+    #[test]
+    fn suites_resolve_to_their_documented_variants() {
+        let resolved = |name| {
+            let suite = find_suite(name).unwrap();
+            (
+                suite.describe(),
+                suite.kem.nist_category(),
+                suite.sign.nist_category(),
+            )
+        };
+        assert_eq!(resolved("cnsa2"), ("ML-KEM-1024 + ML-DSA-87".into(), 5, 5));
+        assert_eq!(resolved("nist-l1"), ("ML-KEM-512 + ML-DSA-44".into(), 1, 2));
+        assert_eq!(resolved("nist-l3"), ("ML-KEM-768 + ML-DSA-65".into(), 3, 3));
+
+        let conservative = find_suite("conservative").unwrap();
+        assert_eq!(conservative.kem.algorithm(), Algorithm::McEliece);
+        assert_eq!(conservative.sign.algorithm(), Algorithm::SPHINCSPlus);
+        let compact = find_suite("compact").unwrap();
+        assert_eq!(compact.sign.algorithm(), Algorithm::FALCON);
+    }
+}
@@ -0,0 +1,47 @@
+//! 단위 테스트에서 함께 쓰는 도우미
+
+use std::fs;
+use std::ops::Deref;
+use std::path::{Path, PathBuf};
+
+/// 테스트마다 따로 쓰는 임시 디렉토리. 드롭할 때 내용과 함께 지웁니다.
+pub(crate) struct ScratchDir(PathBuf);
+
+impl ScratchDir {
+    /// `<임시 디렉토리>/keypairer-<모듈>-<pid>-<이름>`을 비운 상태로 만듭니다.
+    pub(crate) fn new(module: &str, name: &str) -> ScratchDir {
+        let dir = std::env::temp_dir().join(format!(
+            "keypairer-{}-{}-{}",
+            module,
+            std::process::id(),
+            name
+        ));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        ScratchDir(dir)
+    }
+
+    /// 디렉토리의 파일 이름을 정렬해 반환합니다.
+    pub(crate) fn names(&self) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(&self.0)
+            .unwrap()
+            .map(|e| e.unwrap().file_name().to_string_lossy().into_owned())
+            .collect();
+        names.sort();
+        names
+    }
+}
+
+impl Deref for ScratchDir {
+    type Target = Path;
+
+    fn deref(&self) -> &Path {
+        &self.0
+    }
+}
+
+impl Drop for ScratchDir {
+    fn drop(&mut self) {
+        let _ = fs::remove_dir_all(&self.0);
+    }
+}
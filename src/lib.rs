@@ -1,22 +1,65 @@
+use std::collections::HashMap;
 use std::process;
 use std::thread;
-use std::collections::HashMap;
+
+use zeroize::Zeroizing;
 
 pub mod internals {
+    pub mod batch;
+    pub mod bench;
+    pub mod config;
+    pub mod datetime;
+    pub mod encrypt;
+    pub mod entropy;
+    pub mod executor;
     pub mod i18n;
+    pub mod kem;
     pub mod key_io;
     pub mod keygen;
+    #[cfg(feature = "async")]
+    pub mod keygen_async;
+    pub mod keystore;
+    pub mod lifecycle;
+    pub mod manifest;
+    pub mod metadata;
+    pub mod paper;
+    pub mod policy;
+    #[cfg(unix)]
+    pub mod pool;
+    pub mod secret;
+    #[cfg(test)]
+    mod secret_copies;
+    pub mod service;
+    pub mod shamir;
+    pub mod sign;
+    pub mod stack;
+    pub mod suite;
+    #[cfg(test)]
+    pub(crate) mod test_util;
 }
 
-/// 대용량 스택에서 함수를 실행합니다 (언어 인자 포함).
-/// 
+pub use internals::executor::{ExecutorError, ExecutorOptions, JobHandle, KeygenExecutor};
+#[cfg(feature = "async")]
+pub use internals::keygen_async::{JobFuture, generate_keys_async};
+
+/// [`run_with_large_stack`]이 할당하는 스택 크기 (64 MiB)
+///
+/// 가장 큰 배리언트 예산([`internals::stack::DEFAULT_STACK_SIZE`])보다 넉넉하며, 이전 버전과
+/// 같은 값을 유지합니다. 배리언트별 예산은 [`internals::keygen::Variant::stack_size`]를 참고하세요.
+pub const LARGE_STACK_SIZE: usize = 64 * 1024 * 1024;
+
+/// 대용량 스택([`LARGE_STACK_SIZE`])에서 함수를 실행합니다 (언어 인자 포함).
+///
+/// `KEYPAIRER_STACK_SIZE` 또는 [`internals::stack::set_override`]로 지정한 크기가 있으면
+/// 그 크기를 씁니다. 배리언트를 알면 필요한 만큼만 할당하는 [`run_with_variant_stack`]을 쓰세요.
+///
 /// # Arguments
 /// * `f` - 실행할 함수
 /// * `lang` - 언어 코드 (예: "ko", "en")
-/// 
+///
 /// # Returns
 /// 함수의 실행 결과를 반환합니다.
-/// 
+///
 /// # Panics
 /// 스레드 생성 실패 시 프로세스를 종료합니다.
 pub fn run_with_large_stack<F, R>(f: F, lang: &str) -> R
@@ -24,7 +67,81 @@ where
     F: FnOnce() -> R + Send + 'static,
     R: Send + 'static,
 {
-    let builder = thread::Builder::new().stack_size(64 * 1024 * 1024); // 64 MiB
+    run_with_stack_size(large_stack_size(), f, lang)
+}
+
+/// 대용량 스택([`LARGE_STACK_SIZE`])에서 함수를 실행합니다 (언어 인자 없음).
+///
+/// # Arguments
+/// * `f` - 실행할 함수
+///
+/// # Returns
+/// 함수의 실행 결과를 반환합니다.
+///
+/// # Panics
+/// 스레드 생성 실패 시 패닉을 발생시킵니다.
+pub fn run_with_large_stack_default<F, R>(f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    run_with_stack_size_default(large_stack_size(), f)
+}
+
+/// 덮어쓴 크기가 없으면 [`LARGE_STACK_SIZE`]
+fn large_stack_size() -> usize {
+    internals::stack::override_size().unwrap_or(LARGE_STACK_SIZE)
+}
+
+/// 배리언트가 선언한 스택 예산만큼의 스레드에서 함수를 실행합니다 (언어 인자 포함).
+///
+/// `KEYPAIRER_STACK_SIZE` 또는 [`internals::stack::set_override`]로 지정한 크기가 있으면
+/// 그 크기를 씁니다.
+///
+/// # Arguments
+/// * `variant` - 함수가 다룰 배리언트
+/// * `f` - 실행할 함수
+/// * `lang` - 언어 코드 (예: "ko", "en")
+///
+/// # Returns
+/// 함수의 실행 결과를 반환합니다.
+///
+/// # Panics
+/// 스레드 생성 실패 시 프로세스를 종료합니다.
+pub fn run_with_variant_stack<F, R>(variant: &internals::keygen::Variant, f: F, lang: &str) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    run_with_stack_size(internals::stack::stack_size_for(variant), f, lang)
+}
+
+/// 배리언트가 선언한 스택 예산만큼의 스레드에서 함수를 실행합니다 (언어 인자 없음).
+///
+/// # Arguments
+/// * `variant` - 함수가 다룰 배리언트
+/// * `f` - 실행할 함수
+///
+/// # Returns
+/// 함수의 실행 결과를 반환합니다.
+///
+/// # Panics
+/// 스레드 생성 실패 시 패닉을 발생시킵니다.
+pub fn run_with_variant_stack_default<F, R>(variant: &internals::keygen::Variant, f: F) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    run_with_stack_size_default(internals::stack::stack_size_for(variant), f)
+}
+
+/// 지정한 크기의 스택에서 함수를 실행하고, 실패하면 번역된 메시지를 출력한 뒤 종료합니다.
+fn run_with_stack_size<F, R>(stack_size: usize, f: F, lang: &str) -> R
+where
+    F: FnOnce() -> R + Send + 'static,
+    R: Send + 'static,
+{
+    let builder = thread::Builder::new().stack_size(stack_size);
     let handle = builder.spawn(f).unwrap_or_else(|e| {
         let tr = internals::i18n::load_translations(lang);
         let msg = tr
@@ -53,32 +170,23 @@ where
     }
 }
 
-/// 대용량 스택에서 함수를 실행합니다 (언어 인자 없음).
-/// 
-/// # Arguments
-/// * `f` - 실행할 함수
-/// 
-/// # Returns
-/// 함수의 실행 결과를 반환합니다.
-/// 
-/// # Panics
-/// 스레드 생성 실패 시 패닉을 발생시킵니다.
-pub fn run_with_large_stack_default<F, R>(f: F) -> R
+/// 지정한 크기의 스택에서 함수를 실행하고, 실패하면 패닉을 발생시킵니다.
+fn run_with_stack_size_default<F, R>(stack_size: usize, f: F) -> R
 where
     F: FnOnce() -> R + Send + 'static,
     R: Send + 'static,
 {
-    let builder = thread::Builder::new().stack_size(64 * 1024 * 1024); // 64 MiB
+    let builder = thread::Builder::new().stack_size(stack_size);
     let handle = builder.spawn(f).expect("대용량 스택 스레드 생성 실패");
 
     handle.join().expect("대용량 스택 스레드 실행 실패")
 }
 
 /// 번역을 로드합니다 (언어 인자 포함).
-/// 
+///
 /// # Arguments
 /// * `lang` - 언어 코드 (예: "ko", "en")
-/// 
+///
 /// # Returns
 /// 번역 맵을 반환합니다.
 pub fn load_translations(lang: &str) -> HashMap<String, String> {
@@ -86,7 +194,7 @@ pub fn load_translations(lang: &str) -> HashMap<String, String> {
 }
 
 /// 번역을 로드합니다 (기본 언어 "ko" 사용).
-/// 
+///
 /// # Returns
 /// 한국어 번역 맵을 반환합니다.
 pub fn load_translations_default() -> HashMap<String, String> {
@@ -94,7 +202,10 @@ pub fn load_translations_default() -> HashMap<String, String> {
 }
 
 /// 키 페어를 파일에 저장합니다 (언어 인자 포함).
-/// 
+///
+/// 두 파일을 임시 이름으로 기록·fsync한 뒤 최종 경로로 옮깁니다. 이전 버전과 같이 기존 파일을
+/// 덮어쓰므로, 기본으로 덮어쓰지 않는 [`save_keys_with_options`]를 `force = false`로 사용하세요.
+///
 /// # Arguments
 /// * `pk_bytes` - 공개키 바이트
 /// * `sk_bytes` - 비밀키 바이트
@@ -105,6 +216,10 @@ pub fn load_translations_default() -> HashMap<String, String> {
 /// * `pk_text` - 공개키를 텍스트 형태로 저장할지 여부
 /// * `sk_text` - 비밀키를 텍스트 형태로 저장할지 여부
 /// * `lang` - 언어 코드
+#[deprecated(
+    note = "기존 키 파일을 덮어씁니다. save_keys_with_options(.., force: false, ..)를 사용하세요"
+)]
+#[allow(clippy::too_many_arguments)]
 pub fn save_keys(
     pk_bytes: &[u8],
     sk_bytes: &[u8],
@@ -116,13 +231,15 @@ pub fn save_keys(
     sk_text: bool,
     lang: &str,
 ) {
-    internals::key_io::save_keys(
-        pk_bytes, sk_bytes, pk_path, sk_path, algorithm, variant, pk_text, sk_text, lang,
+    save_keys_with_options(
+        pk_bytes, sk_bytes, pk_path, sk_path, algorithm, variant, pk_text, sk_text, true, lang,
     );
 }
 
 /// 키 페어를 파일에 저장합니다 (기본 언어 "ko" 사용).
-/// 
+///
+/// [`save_keys`]와 같이 기존 파일을 덮어씁니다. [`save_keys_with_options_default`]를 사용하세요.
+///
 /// # Arguments
 /// * `pk_bytes` - 공개키 바이트
 /// * `sk_bytes` - 비밀키 바이트
@@ -132,6 +249,10 @@ pub fn save_keys(
 /// * `variant` - 알고리즘 배리언트
 /// * `pk_text` - 공개키를 텍스트 형태로 저장할지 여부
 /// * `sk_text` - 비밀키를 텍스트 형태로 저장할지 여부
+#[deprecated(
+    note = "기존 키 파일을 덮어씁니다. save_keys_with_options_default(.., force: false)를 사용하세요"
+)]
+#[allow(clippy::too_many_arguments)]
 pub fn save_keys_default(
     pk_bytes: &[u8],
     sk_bytes: &[u8],
@@ -141,8 +262,108 @@ pub fn save_keys_default(
     variant: &str,
     pk_text: bool,
     sk_text: bool,
+) {
+    save_keys_with_options(
+        pk_bytes, sk_bytes, pk_path, sk_path, algorithm, variant, pk_text, sk_text, true, "ko",
+    );
+}
+
+/// 키 페어를 파일에 저장합니다 (덮어쓰기 여부와 언어 인자 포함).
+///
+/// 두 파일을 임시 이름으로 기록·fsync한 뒤 최종 경로로 옮기며, `force`가 아니면
+/// 기존 파일을 덮어쓰지 않습니다. 비밀키 저장에 실패하면 공개키 파일을 되돌립니다.
+///
+/// # Arguments
+/// * `pk_bytes` - 공개키 바이트
+/// * `sk_bytes` - 비밀키 바이트
+/// * `pk_path` - 공개키 파일 경로
+/// * `sk_path` - 비밀키 파일 경로
+/// * `algorithm` - 알고리즘 이름
+/// * `variant` - 알고리즘 배리언트
+/// * `pk_text` - 공개키를 텍스트 형태로 저장할지 여부
+/// * `sk_text` - 비밀키를 텍스트 형태로 저장할지 여부
+/// * `force` - 기존 파일을 덮어쓸지 여부
+/// * `lang` - 언어 코드
+#[allow(clippy::too_many_arguments)]
+pub fn save_keys_with_options(
+    pk_bytes: &[u8],
+    sk_bytes: &[u8],
+    pk_path: &str,
+    sk_path: &str,
+    algorithm: &str,
+    variant: &str,
+    pk_text: bool,
+    sk_text: bool,
+    force: bool,
+    lang: &str,
 ) {
     internals::key_io::save_keys(
-        pk_bytes, sk_bytes, pk_path, sk_path, algorithm, variant, pk_text, sk_text, "ko",
+        pk_bytes, sk_bytes, pk_path, sk_path, algorithm, variant, pk_text, sk_text, force, lang,
     );
 }
+
+/// 키 페어를 파일에 저장합니다 (덮어쓰기 여부 포함, 기본 언어 "ko" 사용).
+///
+/// # Arguments
+/// * `pk_bytes` - 공개키 바이트
+/// * `sk_bytes` - 비밀키 바이트
+/// * `pk_path` - 공개키 파일 경로
+/// * `sk_path` - 비밀키 파일 경로
+/// * `algorithm` - 알고리즘 이름
+/// * `variant` - 알고리즘 배리언트
+/// * `pk_text` - 공개키를 텍스트 형태로 저장할지 여부
+/// * `sk_text` - 비밀키를 텍스트 형태로 저장할지 여부
+/// * `force` - 기존 파일을 덮어쓸지 여부
+#[allow(clippy::too_many_arguments)]
+pub fn save_keys_with_options_default(
+    pk_bytes: &[u8],
+    sk_bytes: &[u8],
+    pk_path: &str,
+    sk_path: &str,
+    algorithm: &str,
+    variant: &str,
+    pk_text: bool,
+    sk_text: bool,
+    force: bool,
+) {
+    save_keys_with_options(
+        pk_bytes, sk_bytes, pk_path, sk_path, algorithm, variant, pk_text, sk_text, force, "ko",
+    );
+}
+
+/// 키 파일을 읽습니다 (언어 인자 포함).
+///
+/// `path`가 `-`이면 표준 입력에서 읽으며, PEM 유사 텍스트는 자동으로 디코딩합니다.
+///
+/// # Arguments
+/// * `path` - 키 파일 경로 또는 `-`
+/// * `label` - PEM 라벨 (예: "PUBLIC KEY", "SECRET KEY")
+/// * `lang` - 언어 코드
+///
+/// # Returns
+/// 키 바이트를 반환합니다. 읽기에 실패하면 프로세스를 종료합니다.
+pub fn load_key(path: &str, label: &str, lang: &str) -> Zeroizing<Vec<u8>> {
+    internals::key_io::read_key(path, label).unwrap_or_else(|e| {
+        let tr = internals::i18n::load_translations(lang);
+        let msg = tr.get("error.file.read_key").cloned().unwrap_or_else(|| {
+            "키 파일을 읽는 도중 오류가 발생했습니다({path}): {err}".to_string()
+        });
+        eprintln!(
+            "{}",
+            msg.replace("{path}", path).replace("{err}", &e.to_string())
+        );
+        process::exit(1);
+    })
+}
+
+/// 키 파일을 읽습니다 (기본 언어 "ko" 사용).
+///
+/// # Arguments
+/// * `path` - 키 파일 경로 또는 `-`
+/// * `label` - PEM 라벨 (예: "PUBLIC KEY", "SECRET KEY")
+///
+/// # Returns
+/// 키 바이트를 반환합니다. 읽기에 실패하면 프로세스를 종료합니다.
+pub fn load_key_default(path: &str, label: &str) -> Zeroizing<Vec<u8>> {
+    load_key(path, label, "ko")
+}
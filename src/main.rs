@@ -1,21 +1,34 @@
 // 표준 프렐류드
 use std::env;
-use std::process;
+use std::process::{self, ExitCode};
 use zeroize::Zeroize;
 
 // 모듈 선언
 use keypairer::internals;
+mod commands;
 mod util;
 
 // 모듈 사용
-use internals::keygen::{generate_keys, minimal_variant_for_algorithm};
-use keypairer::{load_translations, run_with_large_stack, save_keys};
-use util::finalize_paths;
+use commands::batch::BatchArgs;
+use internals::config::Encoding;
+use internals::datetime::now_unix;
+use internals::key_io::{STDIO_PATH, effective_text, fingerprint};
+use internals::keygen::generate_keys;
+use internals::keygen::parse_algorithm;
+use internals::keystore::KeyOptions;
+use internals::lifecycle::KeyState;
+use internals::metadata::{KeyMetadata, sidecar_path, write_sidecar};
+use internals::secret::disable_core_dumps;
+use keypairer::{load_translations, run_with_variant_stack, save_keys_with_options};
+use util::{
+    TemplateVars, apply_stack_size, default_lang, enforce_policy, finalize_paths, load_settings,
+    resolve_algorithm, resolve_policy, select_entropy,
+};
 
-fn main() {
+fn main() -> ExitCode {
     let args: Vec<String> = env::args().collect();
     let prog = args
-        .get(0)
+        .first()
         .cloned()
         .unwrap_or_else(|| "keypairer".to_string());
 
@@ -26,36 +39,104 @@ fn main() {
     let mut sk_path_opt: Option<String> = None;
     let mut pk_text: bool = false;
     let mut sk_text: bool = false;
-    let mut lang: String = "ko".to_string();
+    let mut force: bool = false;
+    let mut metadata: bool = false;
+    let mut lang: String = default_lang();
+    let mut cli_lang: bool = false;
+    let mut encoding_opt: Option<Encoding> = None;
+    let mut config_opt: Option<String> = None;
+    let mut profile_opt: Option<String> = None;
+    let mut count_opt: Option<String> = None;
+    let mut manifest_opt: Option<String> = None;
+    let mut suite_opt: Option<String> = None;
+    let mut jobs_opt: Option<String> = None;
+    let mut index_opt: Option<String> = None;
+    let mut outdir_opt: Option<String> = None;
+    let mut to_store: bool = false;
+    let mut store_dir_opt: Option<String> = None;
+    let mut label_opt: Option<String> = None;
+    let mut tags: Vec<String> = Vec::new();
+    let mut expires_opt: Option<String> = None;
+    let mut pending: bool = false;
+    let mut entropy_opt: Option<String> = None;
+    let mut policy_opt: Option<String> = None;
+    let mut stack_size_opt: Option<String> = None;
 
-    let mut i = 1usize;
+    // 하위 명령도 KEYPAIRER_STACK_SIZE를 따르도록 먼저 적용
+    apply_stack_size(None, &lang);
+
+    // 하위 명령 (생략 시 gen)
+    let mut i = match args.get(1).map(String::as_str) {
+        Some(
+            cmd @ ("list" | "show" | "export" | "delete" | "rotate" | "state" | "expire"
+            | "history"),
+        ) => {
+            commands::store::run(&prog, cmd, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        Some(cmd @ ("encrypt" | "decrypt")) => {
+            commands::encrypt::run(&prog, cmd, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        Some(cmd @ ("sign-manifest" | "verify-manifest")) => {
+            commands::manifest::run(&prog, cmd, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        Some(cmd @ ("paper-export" | "paper-import")) => {
+            commands::paper::run(&prog, cmd, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        Some(cmd @ ("split" | "recombine")) => {
+            commands::share::run(&prog, cmd, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        Some("config") => {
+            commands::config::run(&prog, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        Some("bench") => {
+            commands::bench::run(&prog, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        Some("serve") => {
+            commands::serve::run(&prog, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        #[cfg(unix)]
+        Some("pool") => {
+            commands::pool::run(&prog, &args[2..]);
+            return ExitCode::SUCCESS;
+        }
+        Some("gen") => 2usize,
+        _ => 1usize,
+    };
     while i < args.len() {
         match args[i].as_str() {
             "-alg" | "-algorithm" => {
                 i += 1;
                 if i >= args.len() {
-                    return usage_and_exit(&prog, &lang);
+                    usage_and_exit(&prog, &lang);
                 }
                 alg_opt = Some(args[i].to_lowercase());
             }
             "-var" | "-variant" => {
                 i += 1;
                 if i >= args.len() {
-                    return usage_and_exit(&prog, &lang);
+                    usage_and_exit(&prog, &lang);
                 }
                 variant_opt = Some(args[i].to_lowercase());
             }
             "-pkpath" => {
                 i += 1;
                 if i >= args.len() {
-                    return usage_and_exit(&prog, &lang);
+                    usage_and_exit(&prog, &lang);
                 }
                 pk_path_opt = Some(args[i].clone());
             }
             "-skpath" => {
                 i += 1;
                 if i >= args.len() {
-                    return usage_and_exit(&prog, &lang);
+                    usage_and_exit(&prog, &lang);
                 }
                 sk_path_opt = Some(args[i].clone());
             }
@@ -65,15 +146,106 @@ fn main() {
             "-skt" | "-sktext" => {
                 sk_text = true;
             }
+            "-f" | "-force" => {
+                force = true;
+            }
+            "-meta" | "-metadata" => {
+                metadata = true;
+            }
             "-l" | "-lang" => {
                 i += 1;
                 if i >= args.len() {
-                    return usage_and_exit(&prog, &lang);
+                    usage_and_exit(&prog, &lang);
                 }
                 lang = args[i].to_lowercase();
+                cli_lang = true;
+            }
+            "-config" | "-profile" | "-policy" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(&prog, &lang);
+                }
+                match flag.as_str() {
+                    "-config" => config_opt = Some(args[i].clone()),
+                    "-policy" => policy_opt = Some(args[i].clone()),
+                    _ => profile_opt = Some(args[i].clone()),
+                }
+            }
+            "-encoding" => {
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(&prog, &lang);
+                }
+                match Encoding::parse(&args[i]) {
+                    Some(encoding) => encoding_opt = Some(encoding),
+                    None => {
+                        let tr = load_translations(&lang);
+                        let msg = tr.get("error.encoding").cloned().unwrap_or_else(|| {
+                            "지원하지 않는 저장 형식: {encoding} (binary, pem)".to_string()
+                        });
+                        eprintln!("{}", msg.replace("{encoding}", &args[i]));
+                        usage_and_exit(&prog, &lang);
+                    }
+                }
+            }
+            "-entropy" => {
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(&prog, &lang);
+                }
+                entropy_opt = Some(args[i].clone());
+            }
+            "-stack-size" => {
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(&prog, &lang);
+                }
+                stack_size_opt = Some(args[i].clone());
+            }
+            "-count" | "-manifest" | "-suite" | "-jobs" | "-index" | "-outdir" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(&prog, &lang);
+                }
+                let value = Some(args[i].clone());
+                match flag.as_str() {
+                    "-count" => count_opt = value,
+                    "-manifest" => manifest_opt = value,
+                    "-suite" => suite_opt = value,
+                    "-jobs" => jobs_opt = value,
+                    "-index" => index_opt = value,
+                    _ => outdir_opt = value,
+                }
+            }
+            "-store" => {
+                to_store = true;
+            }
+            "-pending" => {
+                pending = true;
+            }
+            "-store-dir" | "-label" | "-tag" | "-expires" => {
+                let flag = args[i].clone();
+                i += 1;
+                if i >= args.len() {
+                    usage_and_exit(&prog, &lang);
+                }
+                match flag.as_str() {
+                    "-store-dir" => store_dir_opt = Some(args[i].clone()),
+                    "-label" => label_opt = Some(args[i].clone()),
+                    "-expires" => expires_opt = Some(args[i].clone()),
+                    _ => tags.extend(
+                        args[i]
+                            .split(',')
+                            .map(str::trim)
+                            .filter(|t| !t.is_empty())
+                            .map(str::to_string),
+                    ),
+                }
             }
             "-h" | "--help" => {
-                return usage_and_exit(&prog, &lang);
+                usage_and_exit(&prog, &lang);
             }
             other => {
                 let tr = load_translations(&lang);
@@ -82,12 +254,110 @@ fn main() {
                     .cloned()
                     .unwrap_or_else(|| "알 수 없는 인자: {arg}".to_string());
                 eprintln!("{}", msg.replace("{arg}", other));
-                return usage_and_exit(&prog, &lang);
+                usage_and_exit(&prog, &lang);
             }
         }
         i += 1;
     }
 
+    // 키 저장소 옵션은 -store와 함께, 파일 경로·일괄 생성과는 따로 사용
+    if !to_store
+        && (store_dir_opt.is_some()
+            || label_opt.is_some()
+            || !tags.is_empty()
+            || expires_opt.is_some()
+            || pending)
+    {
+        to_store = true;
+    }
+    if to_store
+        && (pk_path_opt.is_some()
+            || sk_path_opt.is_some()
+            || outdir_opt.is_some()
+            || metadata
+            || count_opt.is_some()
+            || manifest_opt.is_some()
+            || suite_opt.is_some())
+    {
+        let tr = load_translations(&lang);
+        eprintln!(
+            "{}",
+            tr.get("error.store.exclusive").cloned().unwrap_or_else(|| {
+                "-store는 -pkpath, -skpath, -outdir, -meta, -count, -manifest, -suite와 함께 사용할 수 없습니다.".to_string()
+            })
+        );
+        usage_and_exit(&prog, &lang);
+    }
+
+    // 설정 파일·프로필·환경 변수 기본값 적용 (명령행 플래그가 우선)
+    let settings = load_settings(config_opt.as_deref(), profile_opt.as_deref(), &lang);
+    if !cli_lang {
+        lang = settings.lang();
+    }
+    let policy = resolve_policy(&settings, policy_opt.as_deref(), &lang);
+    // 설정의 배리언트는 명령행 -alg가 없거나 설정의 알고리즘과 같을 때만 사용하며,
+    // 보안 묶음을 고르면 설정의 알고리즘·배리언트는 쓰지 않음
+    let same_algorithm = match (&alg_opt, &settings.algorithm) {
+        _ if suite_opt.is_some() => false,
+        (None, _) => true,
+        (Some(cli), Some(configured)) => {
+            parse_algorithm(cli).is_some()
+                && parse_algorithm(cli) == parse_algorithm(&configured.value)
+        }
+        (Some(_), None) => false,
+    };
+    if variant_opt.is_none() && same_algorithm {
+        variant_opt = settings.variant.as_ref().map(|s| s.value.clone());
+    }
+    let alg_opt = match suite_opt {
+        Some(_) => alg_opt,
+        None => alg_opt.or_else(|| settings.algorithm.as_ref().map(|s| s.value.clone())),
+    };
+    let pk_text =
+        pk_text || encoding_opt.map_or(settings.pk_text(), |encoding| encoding == Encoding::Pem);
+    let sk_text =
+        sk_text || encoding_opt.map_or(settings.sk_text(), |encoding| encoding == Encoding::Pem);
+    let outdir_opt = if to_store {
+        None
+    } else {
+        outdir_opt.or_else(|| settings.outdir.as_ref().map(|s| s.value.clone()))
+    };
+
+    // 만료 시각은 키를 생성하기 전에 검증
+    let expires = expires_opt
+        .as_deref()
+        .map(|e| commands::store::parse_time_arg(e, now_unix(), &lang));
+
+    // 난수 소스 선택 및 시작 상태 검사 (실패 시 생성 중단)
+    select_entropy(entropy_opt.as_deref(), &lang);
+    apply_stack_size(stack_size_opt.as_deref(), &lang);
+
+    // 일괄 생성 모드 (보안 묶음은 KEM·서명 두 건의 일괄 생성)
+    if count_opt.is_some() || manifest_opt.is_some() || suite_opt.is_some() {
+        commands::batch::run(
+            &prog,
+            BatchArgs {
+                algorithm: alg_opt,
+                variant: variant_opt,
+                pk_template: pk_path_opt,
+                sk_template: sk_path_opt,
+                count: count_opt,
+                manifest: manifest_opt,
+                suite: suite_opt,
+                jobs: jobs_opt,
+                index: index_opt,
+                outdir: outdir_opt,
+                pk_text,
+                sk_text,
+                force,
+                metadata,
+                policy,
+                lang,
+            },
+        );
+        return ExitCode::SUCCESS;
+    }
+
     // 필수 인자 검증
     let algorithm = match alg_opt {
         Some(a) => a,
@@ -99,48 +369,194 @@ fn main() {
                     .map(String::as_str)
                     .unwrap_or("필수 인자 누락: -alg <알고리즘>")
             );
-            return usage_and_exit(&prog, &lang);
+            usage_and_exit(&prog, &lang);
         }
     };
 
     // 배리언트 결정 (기본값 또는 사용자 지정)
-    let variant =
-        variant_opt.unwrap_or_else(|| minimal_variant_for_algorithm(&algorithm).to_string());
+    let (alg, variant) = match resolve_algorithm(&algorithm, variant_opt.as_deref(), &lang) {
+        Ok(resolved) => resolved,
+        Err(msg) => {
+            eprintln!("{}", msg);
+            usage_and_exit(&prog, &lang);
+        }
+    };
 
-    if variant.is_empty() {
-        let tr = load_translations(&lang);
-        let msg = tr
-            .get("error.unknown_algorithm")
-            .cloned()
-            .unwrap_or_else(|| "알 수 없는 알고리즘: {alg}".to_string());
-        eprintln!("{}", msg.replace("{alg}", &algorithm));
-        return usage_and_exit(&prog, &lang);
+    // 정책 확인 (키 저장소는 항상 바이너리로 저장)
+    let sk_text_out = !to_store
+        && effective_text(
+            pk_path_opt.as_deref().unwrap_or_default(),
+            sk_path_opt.as_deref().unwrap_or_default(),
+            pk_text,
+            sk_text,
+        )
+        .1;
+    enforce_policy(&policy, [&variant], sk_text_out, &lang);
+
+    // 비밀키를 다 쓸 때까지 코어 덤프 차단, 배리언트의 스택 예산만큼의 스레드에서 키 생성 실행
+    let _no_core = disable_core_dumps();
+    let var_clone = variant.clone();
+    let generated = run_with_variant_stack(&variant, move || generate_keys(alg, var_clone), &lang);
+    let (mut pk_bytes, mut sk_bytes) = match generated {
+        Ok(keys) => keys,
+        Err(e) => {
+            let tr = load_translations(&lang);
+            let msg = tr
+                .get("error.keygen")
+                .cloned()
+                .unwrap_or_else(|| "키 생성 도중 오류가 발생했습니다: {err}".to_string());
+            eprintln!("{}", msg.replace("{err}", &e.to_string()));
+            return ExitCode::FAILURE;
+        }
+    };
+
+    // 키 저장소에 저장
+    if to_store {
+        let store = commands::store::open_store(store_dir_opt.as_deref(), &lang);
+        let added = store.add(
+            alg,
+            &variant,
+            &pk_bytes,
+            &sk_bytes,
+            now_unix(),
+            &KeyOptions {
+                label: label_opt.as_deref(),
+                tags: &tags,
+                state: if pending {
+                    KeyState::Pending
+                } else {
+                    KeyState::Active
+                },
+                expires,
+            },
+        );
+        sk_bytes.zeroize();
+        pk_bytes.zeroize();
+        match added {
+            Ok(entry) => {
+                let tr = load_translations(&lang);
+                let msg = tr
+                    .get("info.store.added")
+                    .cloned()
+                    .unwrap_or_else(|| "키 저장소에 저장했습니다: {id} ({path})".to_string());
+                println!(
+                    "{}",
+                    msg.replace("{id}", &entry.id)
+                        .replace("{path}", &store.root().display().to_string())
+                );
+            }
+            Err(e) => {
+                eprintln!("{}", e.message(&lang));
+                return ExitCode::FAILURE;
+            }
+        }
+        return ExitCode::SUCCESS;
     }
 
-    // 파일 경로 결정
-    let (pk_path, sk_path) = finalize_paths(&pk_path_opt, &sk_path_opt, &algorithm);
+    // 파일 경로 결정 ({fp} 등 템플릿 치환을 위해 생성 이후에 결정)
+    let created = now_unix();
+    let fp = fingerprint(&pk_bytes);
+    let vars = TemplateVars {
+        id: "1",
+        n: 1,
+        alg,
+        var: &variant,
+        fp: &fp,
+        created,
+    };
+    let (pk_path, sk_path) = finalize_paths(
+        &pk_path_opt,
+        &sk_path_opt,
+        &algorithm,
+        outdir_opt.as_deref(),
+        &vars,
+    );
 
-    // 대용량 스택에서 키 생성 실행
-    let alg_clone = algorithm.clone();
-    let var_clone = variant.clone();
-    let (mut pk_bytes, mut sk_bytes) =
-        run_with_large_stack(move || generate_keys(&alg_clone, &var_clone), &lang);
+    // 메타데이터 사이드카 경로 (키를 쓰기 전에 충돌 여부 확인)
+    let sidecar = if metadata {
+        match sidecar_path(&pk_path, &sk_path) {
+            Some(path) => Some(path),
+            None => {
+                let tr = load_translations(&lang);
+                eprintln!(
+                    "{}",
+                    tr.get("error.meta.stdout").cloned().unwrap_or_else(|| {
+                        "두 키를 모두 표준 출력으로 내보낼 때는 -meta를 사용할 수 없습니다."
+                            .to_string()
+                    })
+                );
+                return ExitCode::FAILURE;
+            }
+        }
+    } else {
+        None
+    };
+    if let Some(path) = &sidecar
+        && !force
+        && path.exists()
+    {
+        let tr = load_translations(&lang);
+        let msg = tr.get("error.file.exists").cloned().unwrap_or_else(|| {
+            "파일이 이미 존재합니다: {path} (덮어쓰려면 -force를 지정하세요)".to_string()
+        });
+        eprintln!("{}", msg.replace("{path}", &path.display().to_string()));
+        return ExitCode::FAILURE;
+    }
 
-    // 키 저장 (비밀키는 0o600 권한으로 생성)
-    save_keys(
-        &pk_bytes, &sk_bytes, &pk_path, &sk_path, &algorithm, &variant, pk_text, sk_text, &lang,
+    // 키 저장 (임시 파일에 기록 후 원자적으로 이동, 기존 파일은 -force 없이는 보존)
+    save_keys_with_options(
+        &pk_bytes,
+        &sk_bytes,
+        &pk_path,
+        &sk_path,
+        &algorithm,
+        variant.as_str(),
+        pk_text,
+        sk_text,
+        force,
+        &lang,
     );
 
+    if let Some(path) = &sidecar {
+        let (pk_enc, sk_enc) = effective_text(&pk_path, &sk_path, pk_text, sk_text);
+        let meta = KeyMetadata::new(
+            alg, &variant, created, &fp, &pk_path, &sk_path, pk_enc, sk_enc,
+        );
+        let tr = load_translations(&lang);
+        if let Err(e) = write_sidecar(path, &meta, force) {
+            let msg = tr.get("error.meta.write").cloned().unwrap_or_else(|| {
+                "메타데이터 파일을 기록하는 도중 오류가 발생했습니다({path}): {err}".to_string()
+            });
+            eprintln!(
+                "{}",
+                msg.replace("{path}", &path.display().to_string())
+                    .replace("{err}", &e.to_string())
+            );
+            return ExitCode::FAILURE;
+        }
+        let line = tr
+            .get("info.meta_saved")
+            .cloned()
+            .unwrap_or_else(|| "메타데이터 저장: {path}".to_string())
+            .replace("{path}", &path.display().to_string());
+        if pk_path == STDIO_PATH || sk_path == STDIO_PATH {
+            eprintln!("{}", line);
+        } else {
+            println!("{}", line);
+        }
+    }
+
     // 비밀키 메모리 안전 삭제
     sk_bytes.zeroize();
     pk_bytes.zeroize();
+    ExitCode::SUCCESS
 }
 
-fn usage_and_exit(prog: &str, lang: &str) {
+pub(crate) fn usage_and_exit(prog: &str, lang: &str) -> ! {
     let tr = load_translations(lang);
 
     eprintln!("{}", tr.get("usage").cloned()
-        .unwrap_or_else(|| "사용법: {prog} -alg <알고리즘> [-var | -variant <배리언트>] [-pkpath <공개키 경로>] [-skpath <비밀키 경로>] [-pkt | -pktext] [-skt | -sktext] [-lang <i18n 언어팩>]".to_string())
+        .unwrap_or_else(|| "사용법: {prog} [gen] -alg <알고리즘> [-var | -variant <배리언트>] [-pkpath <공개키 경로>] [-skpath <비밀키 경로>] [-pkt | -pktext] [-skt | -sktext] [-encoding <binary|pem>] [-f | -force] [-meta] [-count <개수> | -manifest <파일> | -suite <묶음>] [-jobs <개수>] [-index <경로>] [-outdir <디렉토리>] [-store [-label <라벨>] [-tag <태그>] [-expires <시각>] [-pending] [-store-dir <디렉토리>]] [-entropy <소스>] [-stack-size <크기>] [-config <파일>] [-profile <프로필>] [-policy <정책>] [-lang <i18n 언어팩>]".to_string())
         .replace("{prog}", prog));
 
     eprintln!(
@@ -164,6 +580,87 @@ fn usage_and_exit(prog: &str, lang: &str) {
     eprintln!("{}", tr.get("usage.text").cloned()
         .unwrap_or_else(|| "- [-pktext] 또는 [-sktext] 옵션을 추가하여 각 공개키/비밀키의 PEM 유사하게 저장하여 확인할 수 있게 합니다. 단, 권장하지 않습니다.".to_string()));
 
+    eprintln!(
+        "{}",
+        tr.get("usage.stdio").cloned().unwrap_or_else(|| {
+            "- 경로로 '-'를 지정하면 표준 출력으로 내보내며, 터미널이거나 두 키가 함께 나가면 PEM으로 저장됩니다.".to_string()
+        })
+    );
+
+    eprintln!(
+        "{}",
+        tr.get("usage.force").cloned().unwrap_or_else(|| {
+            "- 기존 키 파일은 덮어쓰지 않습니다. 덮어쓰려면 [-force]를 지정하세요.".to_string()
+        })
+    );
+
+    eprintln!(
+        "{}",
+        tr.get("usage.meta").cloned().unwrap_or_else(|| {
+            "- [-meta]를 지정하면 알고리즘, 배리언트, 생성 시각, 지문, 인코딩, 도구 및 pqcrypto 백엔드 버전을 담은 '<이름>.json' 메타데이터 파일을 함께 기록합니다.".to_string()
+        })
+    );
+
+    eprintln!(
+        "{}",
+        tr.get("usage.batch").cloned().unwrap_or_else(|| {
+            "- [-count] 또는 [-manifest]로 여러 키 페어를 워커 풀에서 일괄 생성합니다. 경로 템플릿에는 {id}, {n}, {fp} 중 하나가 포함되어야 하며, ID와 지문을 담은 인덱스 파일(기본값 index.json)을 기록합니다.".to_string()
+        })
+    );
+
+    eprintln!("{}", tr.get("usage.suite").cloned()
+        .unwrap_or_else(|| "- [-suite <묶음>]으로 KEM 키 페어와 서명 키 페어를 함께 생성합니다(<묶음>-kem.*, <묶음>-sig.*, 인덱스 <묶음>-index.json). cnsa2 = ML-KEM-1024 + ML-DSA-87, nist-l1 = ML-KEM-512 + ML-DSA-44, nist-l3 = ML-KEM-768 + ML-DSA-65, conservative = Classic-McEliece-6688128 + SPHINCS+-SHAKE-256s-simple, compact = ML-KEM-512 + Falcon-512".to_string()));
+
+    eprintln!("{}", tr.get("usage.store").cloned()
+        .unwrap_or_else(|| "- [-store]로 키 저장소(기본값 ~/.local/share/keypairer, KEYPAIRER_HOME으로 변경)에 저장하며 [-label], [-tag]를 붙일 수 있습니다. '{prog} list|show|export|delete <ID|라벨>'로 관리합니다. delete는 비밀키를 덮어쓴 뒤 삭제합니다.".to_string())
+        .replace("{prog}", prog));
+
+    eprintln!("{}", tr.get("usage.lifecycle").cloned()
+        .unwrap_or_else(|| "- 저장소의 키는 active, pending, retired, compromised 상태를 가집니다. [-expires <시각|+90d>], [-pending]으로 저장하고, '{prog} rotate <키>'로 같은 배리언트의 새 키로 교체하며(이전 키는 retired), 'state <키> <상태>', 'expire <키> <시각|none>', 'history <키>', 'list -at <시각>'으로 관리·감사합니다.".to_string())
+        .replace("{prog}", prog));
+
+    eprintln!("{}", tr.get("usage.encrypt").cloned()
+        .unwrap_or_else(|| "- '{prog} encrypt -to <키>|-pk <공개키> [-in <파일>] [-out <파일>]'로 KEM 공개키에 대해 파일을 암호화하고(기본 출력 <파일>.kpe), '{prog} decrypt -key <키>|-sk <비밀키> [-in <파일>] [-out <파일>]'로 복호화합니다. 공개키 파일의 배리언트는 -alg/-var, 메타데이터 사이드카, 키 길이 순으로 정합니다.".to_string())
+        .replace("{prog}", prog));
+
+    eprintln!("{}", tr.get("usage.manifest").cloned()
+        .unwrap_or_else(|| "- '{prog} sign-manifest [-dir <디렉토리>] -key <키>|-sk <비밀키> [-pk <공개키>]'로 디렉토리의 모든 파일을 해시한 매니페스트(기본 SHA256SUMS)와 서명(<매니페스트>.sig)을 기록하고, '{prog} verify-manifest [-dir <디렉토리>] -key <키>|-pk <공개키> [-strict]'로 서명과 모든 파일을 검사합니다. -manifest, -sig로 경로를 바꿀 수 있습니다.".to_string())
+        .replace("{prog}", prog));
+
+    eprintln!("{}", tr.get("usage.paper").cloned()
+        .unwrap_or_else(|| "- '{prog} paper-export -key <키>|-sk <비밀키> [-pk <공개키>] [-format text|svg] [-out <파일>]'로 비밀키를 줄별 체크섬이 붙은 base32 종이 백업으로 내보내고, '{prog} paper-import -in <파일> [-pk <공개키>|-key <키>] [-out <비밀키>] [-sktext]'로 옮겨 적은 내용을 검사해 비밀키를 복원합니다.".to_string())
+        .replace("{prog}", prog));
+
+    eprintln!("{}", tr.get("usage.share").cloned()
+        .unwrap_or_else(|| "- '{prog} split -key <키>|-sk <비밀키> [-pk <공개키>] -n <조각 수> -k <임계값> [-out <경로{n}>]'로 비밀키를 샤미르 비밀 분산 조각(PEM)으로 나누고, '{prog} recombine <조각>... [-pk <공개키>|-key <키>] [-out <비밀키>] [-sktext]'로 임계값 이상의 조각에서 비밀키를 복원한 뒤 공개키로 확인합니다.".to_string())
+        .replace("{prog}", prog));
+
+    eprintln!("{}", tr.get("usage.config").cloned()
+        .unwrap_or_else(|| "- 설정 파일(기본값 ~/.config/keypairer/config.toml, -config 또는 KEYPAIRER_CONFIG로 변경)의 [defaults]와 [profiles.<이름>]에 algorithm, variant, encoding, pk_encoding, sk_encoding, outdir, lang 기본값을 둡니다. 설정 파일 → 프로필(-profile, KEYPAIRER_PROFILE) → KEYPAIRER_* 환경 변수 → 명령행 플래그 순으로 우선하며, '{prog} config show'로 적용되는 값과 출처를 확인합니다.".to_string())
+        .replace("{prog}", prog));
+
+    eprintln!("{}", tr.get("usage.policy").cloned()
+        .unwrap_or_else(|| "- [-policy <정책>] 또는 설정 파일의 [policy]로 키 생성을 제한합니다. 정책은 strict(= min-category=3,standard-only,no-sktext), min-category=<1-5>(최소 NIST 보안 범주), standard-only(HQC, McEliece, FALCON, SPHINCS+ 금지), no-sktext(비밀키 텍스트 저장 금지)를 쉼표로 이어 지정하며, 여러 곳의 정책은 가장 엄격한 쪽으로 합쳐집니다.".to_string()));
+
+    eprintln!("{}", tr.get("usage.entropy").cloned()
+        .unwrap_or_else(|| "- [-entropy os|file:<경로>|drbg:<16진수 시드>]로 키 생성에 쓸 난수 소스를 고릅니다(기본값 os). 생성 전에 SP 800-90B 반복 횟수·적응 비율 시작 검사를 수행하며, 실패하면 키를 만들지 않습니다. drbg는 재현 가능한 테스트 전용입니다.".to_string()));
+
+    eprintln!("{}", tr.get("usage.stack").cloned()
+        .unwrap_or_else(|| "- 키 생성 스레드는 배리언트마다 필요한 만큼의 스택(ML-KEM·HQC·SPHINCS+ 1 MiB, FALCON·ML-DSA 512 KiB, Classic McEliece 4–24 MiB)으로 실행합니다. [-stack-size <크기>] 또는 KEYPAIRER_STACK_SIZE(예: 512K, 8M, 최소 64K)로 모든 배리언트의 스택 크기를 덮어씁니다.".to_string()));
+
+    eprintln!("{}", tr.get("usage.bench").cloned()
+        .unwrap_or_else(|| "- '{prog} bench [-alg <알고리즘> [-var <배리언트>]] [-n <반복 횟수>] [-time <초>] [-format table|json] [-out <파일>]'로 모든 배리언트(또는 지정한 것)의 키 생성과 캡슐화·역캡슐화 또는 서명·검증 시간의 중앙값·p95와 키·암호문·서명 크기를 측정합니다. 연산마다 최대 반복 횟수(기본값 20)와 시간 예산(기본값 2초) 중 먼저 닿는 쪽에서 멈추며, --release로 빌드한 바이너리로 측정하세요.".to_string())
+        .replace("{prog}", prog));
+
+    eprintln!("{}", tr.get("usage.serve").cloned()
+        .unwrap_or_else(|| "- '{prog} serve [-bind <주소:포트>] [-store-dir <디렉토리>] [-token-file <파일>] [-jobs <개수>]'는 키 생성, 저장된 키와 공개키 조회, 캡슐화·역캡슐화, 서명·검증을 JSON HTTP API(/v1/...)로 제공합니다. 기본 주소는 127.0.0.1:7878이며 루프백 주소에만 바인딩할 수 있고, 항상 'Authorization: Bearer <토큰>'을 요구합니다. [-token-file]이 없으면 임의의 토큰을 만들어 $XDG_RUNTIME_DIR 또는 키 저장소 아래 keypairer-serve-<포트>.token(0o600)에 저장합니다.".to_string())
+        .replace("{prog}", prog));
+
+    #[cfg(unix)]
+    eprintln!("{}", tr.get("usage.pool").cloned()
+        .unwrap_or_else(|| "- '{prog} pool serve -size <알고리즘>[:<배리언트>]=<개수>,... [-dir <디렉토리>] [-socket <경로>] -key-file <파일> [-jobs <개수>]'는 배리언트마다 지정한 수의 키 페어를 암호화된 디스크 풀에 미리 만들어 두고 유닉스 소켓으로 내주며 백그라운드에서 보충합니다. 풀 키 파일은 풀 디렉토리 밖에 있어야 합니다. '{prog} pool take -alg <알고리즘> [-var <배리언트>] [-pkpath <경로>] [-skpath <경로>]'로 키 페어를 하나 꺼내고(풀이 비었으면 요청 시 생성), '{prog} pool status [-json]'으로 남은 개수를 확인합니다. 소켓 기본값은 KEYPAIRER_POOL_SOCKET → $XDG_RUNTIME_DIR/keypairer-pool.sock → <풀 디렉토리>/keypairer-pool.sock입니다.".to_string())
+        .replace("{prog}", prog));
+
     eprintln!(
         "{}",
         tr.get("usage.lang").cloned().unwrap_or_else(|| {
@@ -175,7 +672,6 @@ fn usage_and_exit(prog: &str, lang: &str) {
     process::exit(1);
 }
 
-
 fn print_help_variants() {
     // 구성 가능한 섹션 데이터
     struct Section<'a> {
@@ -233,6 +729,6 @@ fn print_help_variants() {
         for item in section.items {
             eprintln!("            {},", item);
         }
-        eprintln!("");
+        eprintln!();
     }
 }
@@ -1,34 +1,328 @@
 use std::path::Path;
 
+use keypairer::internals::config::Settings;
+use keypairer::internals::datetime::format_date;
+use keypairer::internals::entropy::{self, EntropySpec};
+use keypairer::internals::key_io::STDIO_PATH;
+use keypairer::internals::keygen::{
+    Algorithm, Variant, minimal_variant_for_algorithm, parse_algorithm, parse_variant,
+};
+use keypairer::internals::policy::Policy;
+use keypairer::internals::stack;
+use keypairer::load_translations;
+
+/// 파일 이름 템플릿에서 `{fp}`로 치환되는 지문 접두사 길이 (16진수 글자 수)
+pub const FP_SHORT_LEN: usize = 6;
+
+/// 일괄 생성 경로 템플릿에 하나 이상 있어야 하는, 작업마다 값이 달라지는 자리표시자
+pub const UNIQUE_PLACEHOLDERS: [&str; 3] = ["{id}", "{n}", "{fp}"];
+
+/// 템플릿이 작업마다 다른 경로를 만드는지(`{id}`, `{n}`, `{fp}` 중 하나 포함) 확인합니다.
+pub fn has_unique_placeholder(template: &str) -> bool {
+    UNIQUE_PLACEHOLDERS.iter().any(|p| template.contains(p))
+}
+
+/// 파일 이름 템플릿 자리표시자 값
+pub struct TemplateVars<'a> {
+    pub id: &'a str,
+    pub n: usize,
+    pub alg: Algorithm,
+    pub var: &'a Variant,
+    /// 공개키 지문 전체 (16진수)
+    pub fp: &'a str,
+    /// 생성 시각 (유닉스 초)
+    pub created: u64,
+}
+
+/// 공개키/비밀키 최종 경로를 결정합니다.
+///
+/// 경로를 생략하면 `<알고리즘>.pub` / `<알고리즘>.sk`를 사용하고, 지정한 경로는
+/// 템플릿으로 해석합니다. 확장자가 없으면 `.pub`/`.sk`를 붙이며, `outdir`이 있으면
+/// 상대 경로를 그 아래에 둡니다. `-`(표준 출력)는 그대로 유지됩니다.
 pub fn finalize_paths(
     pk_opt: &Option<String>,
     sk_opt: &Option<String>,
     algorithm: &str,
+    outdir: Option<&str>,
+    vars: &TemplateVars,
 ) -> (String, String) {
     let default_pk = format!("{}.pub", algorithm);
     let default_sk = format!("{}.sk", algorithm);
 
-    let pk_path = match pk_opt {
-        Some(p) => {
-            if Path::new(p).extension().is_none() {
-                format!("{}.pub", p)
-            } else {
-                p.clone()
-            }
+    let pk_template = template_with_ext(pk_opt.as_deref(), &default_pk, "pub");
+    let sk_template = template_with_ext(sk_opt.as_deref(), &default_sk, "sk");
+
+    (
+        place_in_outdir(render_template(&pk_template, vars), outdir),
+        place_in_outdir(render_template(&sk_template, vars), outdir),
+    )
+}
+
+/// 확장자가 없는 템플릿에는 기본 확장자를 붙입니다. 생략 시 `default`를 사용합니다.
+pub fn template_with_ext(template: Option<&str>, default: &str, ext: &str) -> String {
+    match template {
+        Some(t) if t != STDIO_PATH && Path::new(t).extension().is_none() => {
+            format!("{}.{}", t, ext)
+        }
+        Some(t) => t.to_string(),
+        None => default.to_string(),
+    }
+}
+
+/// 상대 경로를 출력 디렉토리 아래로 옮깁니다. 표준 출력(`-`)과 절대 경로는 그대로 둡니다.
+pub fn place_in_outdir(path: String, outdir: Option<&str>) -> String {
+    match outdir {
+        Some(dir) if path != STDIO_PATH => {
+            Path::new(dir).join(&path).to_string_lossy().into_owned()
+        }
+        _ => path,
+    }
+}
+
+/// 알고리즘 이름과 선택적 배리언트를 해석합니다.
+///
+/// 배리언트를 생략하면 알고리즘별 최소값을 사용하며, 실패 시 번역된 오류 메시지를 반환합니다.
+pub fn resolve_algorithm(
+    algorithm: &str,
+    variant: Option<&str>,
+    lang: &str,
+) -> Result<(Algorithm, Variant), String> {
+    let tr = load_translations(lang);
+    let alg = match parse_algorithm(algorithm) {
+        Some(a) if a != Algorithm::Unknown => a,
+        _ => {
+            let msg = tr
+                .get("error.unknown_algorithm")
+                .cloned()
+                .unwrap_or_else(|| "알 수 없는 알고리즘: {alg}".to_string());
+            return Err(msg.replace("{alg}", algorithm));
         }
-        None => default_pk,
     };
 
-    let sk_path = match sk_opt {
-        Some(p) => {
-            if Path::new(p).extension().is_none() {
-                format!("{}.sk", p)
-            } else {
-                p.clone()
+    match variant {
+        Some(v) => match parse_variant(alg, v) {
+            Some(parsed) => Ok((alg, parsed)),
+            None => {
+                let msg = tr
+                    .get("error.unknown_variant")
+                    .cloned()
+                    .unwrap_or_else(|| "알 수 없는 배리언트: {var} (알고리즘: {alg})".to_string());
+                Err(msg.replace("{var}", v).replace("{alg}", algorithm))
             }
+        },
+        None => Ok((alg, minimal_variant_for_algorithm(alg))),
+    }
+}
+
+/// 파일 이름 템플릿의 자리표시자를 치환합니다.
+///
+/// 지원: `{id}`, `{n}`, `{alg}`, `{var}`, `{fp}` (지문 앞 6자리), `{date}` (UTC `YYYYMMDD`)
+pub fn render_template(template: &str, vars: &TemplateVars) -> String {
+    template
+        .replace("{id}", vars.id)
+        .replace("{n}", &vars.n.to_string())
+        .replace("{alg}", vars.alg.as_str())
+        .replace("{var}", vars.var.as_str())
+        .replace("{fp}", &vars.fp[..FP_SHORT_LEN.min(vars.fp.len())])
+        .replace("{date}", &format_date(vars.created))
+}
+
+/// 키 생성 전에 난수 소스를 선택하고 시작 상태 검사를 수행합니다.
+///
+/// 생략하면 운영체제 소스를 검사합니다. 검사에 실패하거나 소스를 쓸 수 없으면
+/// 메시지를 출력하고 종료합니다. 결정적 DRBG를 선택하면 경고를 출력합니다.
+pub fn select_entropy(spec: Option<&str>, lang: &str) {
+    let tr = load_translations(lang);
+    let selected = EntropySpec::parse(spec.unwrap_or("os")).and_then(|spec| {
+        entropy::select(&spec)?;
+        Ok(spec)
+    });
+    match selected {
+        Ok(spec) if spec.is_deterministic() => eprintln!(
+            "{}",
+            tr.get("warn.entropy.drbg").cloned().unwrap_or_else(|| {
+                "경고: 결정적 DRBG는 테스트 전용입니다. 시드를 아는 누구나 같은 키를 만들 수 있으므로 실제로 사용하지 마세요.".to_string()
+            })
+        ),
+        Ok(spec) if spec != EntropySpec::Os => eprintln!(
+            "{}",
+            tr.get("info.entropy.selected")
+                .cloned()
+                .unwrap_or_else(|| "난수 소스: {source} (시작 상태 검사 통과)".to_string())
+                .replace("{source}", &spec.describe())
+        ),
+        Ok(_) => {}
+        Err(e) => {
+            eprintln!("{}", e.message(lang));
+            std::process::exit(1);
         }
-        None => default_sk,
-    };
+    }
+}
+
+/// `KEYPAIRER_STACK_SIZE`와 `-stack-size` 값(나중 것이 우선)을 키 생성 스레드의 스택 크기로
+/// 적용합니다. 해석할 수 없으면 메시지를 출력하고 종료합니다.
+pub fn apply_stack_size(spec: Option<&str>, lang: &str) {
+    let applied = stack::apply_env_override().and_then(|()| match spec {
+        Some(spec) => stack::parse_size(spec).map(|size| stack::set_override(Some(size))),
+        None => Ok(()),
+    });
+    if let Err(e) = applied {
+        eprintln!("{}", e.message(lang));
+        std::process::exit(1);
+    }
+}
+
+/// 설정 파일·프로필·환경 변수를 읽습니다. 오류가 있으면 메시지를 출력하고 종료합니다.
+pub fn load_settings(config: Option<&str>, profile: Option<&str>, lang: &str) -> Settings {
+    Settings::load(config.map(Path::new), profile).unwrap_or_else(|e| {
+        eprintln!("{}", e.message(lang));
+        std::process::exit(1);
+    })
+}
+
+/// `-lang`이 없을 때의 출력 언어
+///
+/// 기본 설정 파일과 `KEYPAIRER_*` 환경 변수를 따르며, 설정에 오류가 있으면 무시합니다
+/// (오류는 키 생성과 `config show`에서 보고).
+pub fn default_lang() -> String {
+    Settings::load(None, None)
+        .map(|settings| settings.lang())
+        .unwrap_or_else(|_| keypairer::internals::config::DEFAULT_LANG.to_string())
+}
+
+/// 설정의 정책에 `-policy` 값을 더해(더 엄격한 쪽) 적용할 정책을 만듭니다.
+/// 정책 문자열을 해석할 수 없으면 메시지를 출력하고 종료합니다.
+pub fn resolve_policy(settings: &Settings, spec: Option<&str>, lang: &str) -> Policy {
+    let mut policy = settings.policy();
+    if let Some(spec) = spec {
+        match Policy::parse(spec) {
+            Ok(extra) => policy.merge(&extra),
+            Err(e) => {
+                eprintln!("{}", e.message(lang));
+                std::process::exit(1);
+            }
+        }
+    }
+    policy
+}
+
+/// 설정 파일과 `KEYPAIRER_POLICY`의 정책 (키 생성 외의 하위 명령용)
+pub fn configured_policy(lang: &str) -> Policy {
+    load_settings(None, None, lang).policy()
+}
+
+/// 배리언트와 비밀키 저장 형식이 정책을 만족하는지 확인합니다.
+/// 위반하면 번역된 메시지를 출력하고 종료합니다.
+pub fn enforce_policy<'a>(
+    policy: &Policy,
+    variants: impl IntoIterator<Item = &'a Variant>,
+    sk_text: bool,
+    lang: &str,
+) {
+    let violation = variants
+        .into_iter()
+        .find_map(|variant| policy.check_variant(variant).err())
+        .or_else(|| policy.check_sk_text(sk_text).err());
+    if let Some(violation) = violation {
+        eprintln!("{}", violation.message(lang));
+        std::process::exit(1);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FP: &str = "3fa94c0d1e2b7a685f00112233445566778899aabbccddeeff00112233445566";
+
+    fn vars<'a>(variant: &'a Variant) -> TemplateVars<'a> {
+        TemplateVars {
+            id: "gw-7",
+            n: 12,
+            alg: Algorithm::MLKEM,
+            var: variant,
+            fp: FP,
+            // 2024-03-05T12:00:00Z
+            created: 1_709_640_000,
+        }
+    }
+
+    #[test]
+    fn every_placeholder_is_expanded() {
+        let variant = Variant::MLKEM768;
+        assert_eq!(
+            render_template("keys/{alg}-{var}/{id}_{n}_{fp}_{date}", &vars(&variant)),
+            "keys/mlkem-768/gw-7_12_3fa94c_20240305"
+        );
+        // 모르는 자리표시자는 그대로 둠
+        assert_eq!(render_template("{host}-{n}", &vars(&variant)), "{host}-12");
+    }
+
+    #[test]
+    fn missing_extensions_are_added_but_stdout_is_kept() {
+        assert_eq!(
+            template_with_ext(Some("dev-{n}"), "x.pub", "pub"),
+            "dev-{n}.pub"
+        );
+        assert_eq!(
+            template_with_ext(Some("dev.key"), "x.pub", "pub"),
+            "dev.key"
+        );
+        assert_eq!(template_with_ext(Some("-"), "x.pub", "pub"), "-");
+        assert_eq!(template_with_ext(None, "x.pub", "pub"), "x.pub");
+    }
+
+    #[test]
+    fn outdir_applies_to_relative_file_paths_only() {
+        let variant = Variant::MLKEM512;
+        let (pk, sk) = finalize_paths(
+            &Some("{id}".to_string()),
+            &Some("-".to_string()),
+            "mlkem",
+            Some("out"),
+            &vars(&variant),
+        );
+        assert_eq!(Path::new(&pk), Path::new("out").join("gw-7.pub"));
+        assert_eq!(sk, "-");
+
+        let (pk, sk) = finalize_paths(&None, &None, "mlkem", None, &vars(&variant));
+        assert_eq!((pk.as_str(), sk.as_str()), ("mlkem.pub", "mlkem.sk"));
+        #[cfg(unix)]
+        assert_eq!(
+            place_in_outdir("/abs/k.pub".to_string(), Some("out")),
+            "/abs/k.pub"
+        );
+    }
+
+    #[test]
+    fn batch_templates_need_a_per_job_placeholder() {
+        for template in ["dev-{id}.pub", "{n}.sk", "{alg}-{fp}.pub"] {
+            assert!(has_unique_placeholder(template), "{template}");
+        }
+        for template in ["{alg}-{var}.pub", "{date}.sk", "fixed.pub"] {
+            assert!(!has_unique_placeholder(template), "{template}");
+        }
+    }
 
-    (pk_path, sk_path)
+    #[test]
+    fn algorithm_and_variant_are_resolved_with_a_minimal_default() {
+        assert_eq!(
+            resolve_algorithm("mlkem", None, "en").unwrap(),
+            (Algorithm::MLKEM, Variant::MLKEM512)
+        );
+        assert_eq!(
+            resolve_algorithm("mlkem", Some("1024"), "en").unwrap(),
+            (Algorithm::MLKEM, Variant::MLKEM1024)
+        );
+        assert!(
+            resolve_algorithm("rsa", None, "en")
+                .unwrap_err()
+                .contains("rsa")
+        );
+        assert!(
+            resolve_algorithm("mlkem", Some("65"), "en")
+                .unwrap_err()
+                .contains("65")
+        );
+    }
 }
@@ -0,0 +1,74 @@
+//! 배리언트가 선언한 스택 예산([`Variant::stack_size`]) 안에서 키 생성과
+//! 캡슐화·역캡슐화 또는 서명·검증을 마치는지 검사합니다.
+//!
+//! 기본으로는 알고리즘마다 예산이 가장 큰 배리언트(같으면 가장 큰 매개변수)만 검사합니다.
+//! 모든 배리언트는 `cargo test --test stack_budget -- --ignored`로 검사합니다(디버그 빌드에서 약 1분).
+//!
+//! 예산을 넘으면 스레드가 스택 가드 페이지에 닿아 테스트 프로세스 전체가 중단되므로,
+//! 실패한 배리언트는 중단 직전의 `running <배리언트>` 출력으로 찾습니다.
+
+use std::thread;
+use std::time::Duration;
+
+use keypairer::internals::bench::{BenchOptions, all_variants, bench_variant};
+use keypairer::internals::keygen::Variant;
+
+/// 배리언트마다 스레드를 동시에 실행하고 실패한 배리언트를 모읍니다.
+fn assert_fit_budget(variants: Vec<Variant>) {
+    let options = BenchOptions {
+        iterations: 1,
+        budget: Duration::ZERO,
+    };
+
+    let handles: Vec<(Variant, thread::JoinHandle<Result<(), String>>)> = variants
+        .into_iter()
+        .map(|variant| {
+            let target = variant.clone();
+            let handle = thread::Builder::new()
+                .name(variant.standard_name())
+                .stack_size(variant.stack_size())
+                .spawn(move || {
+                    eprintln!("running {}", target.standard_name());
+                    bench_variant(&target, &options)
+                        .map(|_| ())
+                        .map_err(|e| e.to_string())
+                })
+                .expect("스레드 생성 실패");
+            (variant, handle)
+        })
+        .collect();
+
+    let failures: Vec<String> = handles
+        .into_iter()
+        .filter_map(|(variant, handle)| match handle.join() {
+            Ok(Ok(())) => None,
+            Ok(Err(e)) => Some(format!("{}: {}", variant.standard_name(), e)),
+            Err(_) => Some(format!("{}: panicked", variant.standard_name())),
+        })
+        .collect();
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+#[test]
+fn largest_variant_of_each_algorithm_fits_its_stack_budget() {
+    // 배리언트 목록은 알고리즘별로 매개변수가 커지는 순서
+    let mut largest: Vec<Variant> = Vec::new();
+    for variant in all_variants() {
+        match largest.last_mut() {
+            Some(last) if last.algorithm() == variant.algorithm() => {
+                if variant.stack_size() >= last.stack_size() {
+                    *last = variant;
+                }
+            }
+            _ => largest.push(variant),
+        }
+    }
+    assert_eq!(largest.len(), 6);
+    assert_fit_budget(largest);
+}
+
+#[test]
+#[ignore = "모든 배리언트를 측정하므로 디버그 빌드에서 약 1분 걸림"]
+fn every_variant_fits_its_stack_budget() {
+    assert_fit_budget(all_variants());
+}